@@ -0,0 +1,102 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::config::Opts;
+
+/// Remembers a hash of everything the generated code depends on, so that a run whose inputs did
+/// not change since the last one can be skipped.
+pub struct Cache<'a> {
+    config: &'a Opts,
+    /// `Debug` of the command line options, since the options picked are partly random, e.g. the
+    /// temporary C header when none is given
+    raw_opts: String,
+    hash_path: PathBuf,
+}
+
+impl<'a> Cache<'a> {
+    pub fn new(config: &'a Opts, raw_opts: String) -> Self {
+        let rust_output_path = Path::new(&config.rust_output_path);
+        let hash_path = rust_output_path.with_file_name(format!(
+            ".{}.frb_hash",
+            rust_output_path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+        ));
+        Cache {
+            config,
+            raw_opts,
+            hash_path,
+        }
+    }
+
+    /// Whether the outputs were generated from the same inputs, by the same version of the code
+    /// generator
+    pub fn is_fresh(&self) -> Result<bool> {
+        let outputs_exist = [&self.config.rust_output_path, &self.config.dart_output_path]
+            .iter()
+            .chain(self.config.dart_decl_output_path.as_ref().iter())
+            .chain(self.config.dart_barrel_output_path.as_ref().iter())
+            .chain(self.config.dart_wire_output_path.as_ref().iter())
+            .chain(self.config.dump_ir_path.as_ref().iter())
+            .all(|path| Path::new(path).exists());
+        if !outputs_exist {
+            return Ok(false);
+        }
+        let stored_hash = match fs::read_to_string(&self.hash_path) {
+            Ok(stored_hash) => stored_hash,
+            Err(_) => return Ok(false),
+        };
+        Ok(stored_hash.trim() == self.compute_hash()?)
+    }
+
+    /// Stores the hash of the inputs, to be called after generating since it edits some of them,
+    /// such as `lib.rs`
+    pub fn store(&self) -> Result<()> {
+        fs::write(&self.hash_path, self.compute_hash()? + "\n")
+            .with_context(|| format!("Failed to write {:?}", self.hash_path))
+    }
+
+    fn compute_hash(&self) -> Result<String> {
+        let mut hasher = DefaultHasher::new();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        self.raw_opts.hash(&mut hasher);
+        std::env::current_dir()?.hash(&mut hasher);
+
+        // The types of the API can be anywhere in the crate
+        let mut paths = BTreeSet::new();
+        paths.insert(PathBuf::from(&self.config.manifest_path));
+        collect_rust_files(
+            &Path::new(&self.config.rust_crate_dir).join("src"),
+            &mut paths,
+        )?;
+        paths.extend(self.config.rust_input_paths.iter().map(PathBuf::from));
+        paths.extend(self.config.rust_prelude_path.iter().map(PathBuf::from));
+        paths.retain(|path| !self.config.is_rust_output(path));
+        for path in paths {
+            path.hash(&mut hasher);
+            fs::read(&path)
+                .with_context(|| format!("Failed to read {:?}", path))?
+                .hash(&mut hasher);
+        }
+
+        Ok(format!("{:016x}", hasher.finish()))
+    }
+}
+
+fn collect_rust_files(dir: &Path, paths: &mut BTreeSet<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rust_files(&path, paths)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            paths.insert(path);
+        }
+    }
+    Ok(())
+}
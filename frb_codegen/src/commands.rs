@@ -1,10 +1,15 @@
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Write;
-use std::path::Path;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::process::Output;
 
 use log::{debug, error, warn};
 
+use crate::config::Formatter;
+
 /// Known failures that occur from external commands.
 /// If an error occurs frequently enough, consider adding it here and use
 /// [std::process::exit] explicitly instead of panicking.
@@ -24,57 +29,136 @@ fn call_shell(cmd: &str) -> Output {
     execute_command("sh", &["-c", cmd], None)
 }
 
-pub fn ensure_tools_available() {
-    let output = call_shell("dart pub global list");
-    let output = String::from_utf8_lossy(&output.stdout);
-    if !output.contains("ffigen") {
+/// Checks that the tools called by the code generation are installed. Successful checks are
+/// remembered by a temporary file for the same `PATH` and LLVM paths, so that the next runs skip
+/// them unless forced.
+pub fn ensure_tools_available(llvm_path: &[String], force: bool) {
+    let marker_path = tools_marker_path(llvm_path);
+    if !force && marker_path.exists() {
+        debug!(
+            "Skipping the checks of the tools, which passed before according to {:?}",
+            marker_path
+        );
+        return;
+    }
+
+    check_ffigen();
+    check_shell_executable("cbindgen");
+    check_llvm_path(llvm_path);
+
+    if let Err(err) = fs::write(&marker_path, "") {
+        debug!(
+            "Failed to remember the checks of the tools at {:?}: {}",
+            marker_path, err
+        );
+    }
+}
+
+/// The temporary file telling that the tools were found, named after everything that can change
+/// where they are found
+fn tools_marker_path(llvm_path: &[String]) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    std::env::var_os("PATH").hash(&mut hasher);
+    llvm_path.hash(&mut hasher);
+    std::env::temp_dir().join(format!(
+        "flutter_rust_bridge_tools_{:016x}",
+        hasher.finish()
+    ))
+}
+
+fn check_ffigen() {
+    let cmd = "dart pub global list";
+    let res = call_shell(cmd);
+    if !res.status.success() {
         error!(
             "
-ffigen is not available, please run \"dart pub global activate ffigen\" first."
+dart is not available: `{}` failed with:
+
+    {}
+Please install the Dart SDK and add it to PATH.",
+            cmd,
+            String::from_utf8_lossy(&res.stderr).trim()
+        );
+        std::process::exit(Failures::MissingExe as _);
+    }
+    if !String::from_utf8_lossy(&res.stdout).contains("ffigen") {
+        error!(
+            "
+ffigen is not available: `{}` does not list it.
+Please run \"dart pub global activate ffigen\" first.",
+            cmd
         );
         std::process::exit(Failures::MissingExe as _);
     }
-
-    check_shell_executable("cbindgen");
 }
 
 pub fn check_shell_executable(cmd: &'static str) {
     #[cfg(windows)]
-    let res = execute_command("where", &[cmd], None);
+    let (bin, args) = ("where", vec![cmd.to_owned()]);
     #[cfg(not(windows))]
-    let res = execute_command(
+    let (bin, args) = (
         "sh",
-        &["-c", &format!("test -x \"$(which {})\"", cmd)],
+        vec!["-c".to_owned(), format!("test -x \"$(which {})\"", cmd)],
+    );
+    let res = execute_command(
+        bin,
+        &args.iter().map(String::as_str).collect::<Vec<_>>(),
         None,
     );
     if !res.status.success() {
         error!(
             "
-{cmd} is not a command, or not executable.
+{cmd} is not a command, or not executable: `{bin} {args}` failed.
 Note: This command might be available via cargo, in which case it can be installed with:
 
     cargo install {cmd}",
-            cmd = cmd
+            cmd = cmd,
+            bin = bin,
+            args = args.join(" ")
         );
         std::process::exit(Failures::MissingExe as _);
     }
     debug!("{}", String::from_utf8_lossy(&res.stdout));
 }
 
+/// Looks for LLVM in the given paths first, which ffigen is told to use. It has default locations
+/// of its own, so LLVM may still be found otherwise.
+fn check_llvm_path(llvm_path: &[String]) {
+    match llvm_path.iter().find(|path| Path::new(path).exists()) {
+        Some(path) => debug!("Found LLVM at {}", path),
+        None => warn!(
+            "None of the LLVM paths exist: {}. ffigen will look for LLVM in its default locations, pass --llvm-path if it fails to find it.",
+            llvm_path.join(", ")
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn bindgen_rust_to_dart(
     rust_crate_dir: &str,
     c_output_path: &str,
     dart_output_path: &str,
     dart_class_name: &str,
+    standalone: bool,
+    symbol_prefix: &str,
     c_struct_names: Vec<String>,
+    c_struct_renames: &[(String, String)],
     llvm_install_path: &[String],
     llvm_compiler_opts: &str,
 ) {
-    cbindgen(rust_crate_dir, c_output_path, c_struct_names);
+    cbindgen(
+        rust_crate_dir,
+        c_output_path,
+        c_struct_names,
+        c_struct_renames,
+    );
     ffigen(
         c_output_path,
         dart_output_path,
         dart_class_name,
+        standalone,
+        symbol_prefix,
         llvm_install_path,
         llvm_compiler_opts,
     );
@@ -132,7 +216,14 @@ fn execute_command(bin: &str, args: &[&str], current_dir: Option<&str>) -> Outpu
     result
 }
 
-fn cbindgen(rust_crate_dir: &str, c_output_path: &str, c_struct_names: Vec<String>) {
+/// Generates the C header of the crate. Each of `c_struct_renames` is a struct of the crate that
+/// is its own wire type, through an alias which is excluded so that the struct is named after it.
+fn cbindgen(
+    rust_crate_dir: &str,
+    c_output_path: &str,
+    c_struct_names: Vec<String>,
+    c_struct_renames: &[(String, String)],
+) {
     debug!(
         "execute cbindgen rust_crate_dir={} c_output_path={}",
         rust_crate_dir, c_output_path
@@ -148,12 +239,26 @@ no_includes = true
 
 [export]
 include = [{}]
+exclude = [{}]
+
+[export.rename]
+{}
 "#,
         c_struct_names
             .iter()
             .map(|name| format!("\"{}\"", name))
             .collect::<Vec<_>>()
-            .join(", ")
+            .join(", "),
+        c_struct_renames
+            .iter()
+            .map(|(_, alias)| format!("\"{}\"", alias))
+            .collect::<Vec<_>>()
+            .join(", "),
+        c_struct_renames
+            .iter()
+            .map(|(name, alias)| format!("\"{}\" = \"{}\"", name, alias))
+            .collect::<Vec<_>>()
+            .join("\n"),
     );
     debug!("cbindgen config: {}", config);
 
@@ -187,10 +292,15 @@ include = [{}]
     }
 }
 
+/// Generates the wire class from the C header. A `standalone` output can be used as is, since the
+/// types shared with `flutter_rust_bridge` are imported from it rather than generated. The methods
+/// of the wire class are named without the `symbol_prefix` of the functions they call.
 fn ffigen(
     c_path: &str,
     dart_path: &str,
     dart_class_name: &str,
+    standalone: bool,
+    symbol_prefix: &str,
     llvm_path: &[String],
     llvm_compiler_opts: &str,
 ) {
@@ -214,6 +324,30 @@ fn ffigen(
         ",
         dart_path, dart_class_name, c_path, c_path,
     );
+    if standalone {
+        config += "
+        library-imports:
+          frb: 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
+        type-map:
+          structs:
+            WireSyncReturnStruct:
+              lib: 'frb'
+              c-type: 'WireSyncReturnStruct'
+              dart-type: 'WireSyncReturnStruct'
+        ";
+    }
+    if !symbol_prefix.is_empty() {
+        write!(
+            &mut config,
+            "
+        functions:
+          rename:
+            '{}(.*)': '$1'
+        ",
+            symbol_prefix
+        )
+        .unwrap();
+    }
     if !llvm_path.is_empty() {
         write!(
             &mut config,
@@ -262,20 +396,50 @@ Please supply --llvm-path to flutter_rust_bridge_codegen, e.g.:
     }
 }
 
-pub fn format_rust(path: &str) {
-    debug!("execute format_rust path={}", path);
-    let res = execute_command("rustfmt", &[path], None);
+/// Formats the file at `path` with the `rustfmt.toml` applying to `config_dir`, which is not the
+/// directory of the file for a dry run
+pub fn format_rust(path: &str, config_dir: &Path, formatter: &Formatter) {
+    debug!(
+        "execute format_rust path={} config_dir={:?} formatter={:?}",
+        path, config_dir, formatter
+    );
+    match formatter {
+        Formatter::Default => {}
+        Formatter::Off => return,
+        Formatter::Command(command) => {
+            return format_with_command(command, path, Failures::Rustfmt)
+        }
+    }
+    let config_path = config_dir.ancestors().find_map(|dir| {
+        ["rustfmt.toml", ".rustfmt.toml"]
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|config_path| config_path.exists())
+    });
+    let config_path = config_path.map(|config_path| config_path.to_string_lossy().into_owned());
+    let args = match &config_path {
+        Some(config_path) => vec!["--config-path", config_path, path],
+        None => vec![path],
+    };
+    let res = execute_command("rustfmt", &args, None);
     if !res.status.success() {
         error!("rustfmt failed: {}", String::from_utf8_lossy(&res.stderr));
         std::process::exit(Failures::Rustfmt as _);
     }
 }
 
-pub fn format_dart(path: &str, line_length: i32) {
+pub fn format_dart(path: &str, line_length: i32, formatter: &Formatter) {
     debug!(
-        "execute format_dart path={} line_length={}",
-        path, line_length
+        "execute format_dart path={} line_length={} formatter={:?}",
+        path, line_length, formatter
     );
+    match formatter {
+        Formatter::Default => {}
+        Formatter::Off => return,
+        Formatter::Command(command) => {
+            return format_with_command(command, path, Failures::Dartfmt)
+        }
+    }
     let res = call_shell(&format!(
         "dart format {} --line-length {}",
         path, line_length
@@ -288,3 +452,35 @@ pub fn format_dart(path: &str, line_length: i32) {
         std::process::exit(Failures::Dartfmt as _);
     }
 }
+
+/// Formats the file at `path` with the formatter command given instead of the usual tool
+fn format_with_command(command: &str, path: &str, failure: Failures) {
+    let res = call_shell(&format!("{} {}", command, path));
+    if !res.status.success() {
+        error!(
+            "{} failed: {}",
+            command,
+            String::from_utf8_lossy(&res.stderr)
+        );
+        std::process::exit(failure as _);
+    }
+}
+
+/// The unified diff from the file at `old_path` to the one at `new_path`, both labelled `label`
+pub fn diff_files(old_path: &str, new_path: &str, label: &str) -> String {
+    let args = ["-u", "--label", label, "--label", label, old_path, new_path];
+    debug!("execute command: bin=diff args={:?}", args);
+    // Not `execute_command`, since `diff` fails when the files differ
+    let res = Command::new("diff")
+        .args(args)
+        .output()
+        .unwrap_or_else(|err| panic!("\"diff\" \"{}\" failed: {}", args.join(" "), err));
+    if res.status.code() != Some(1) && !res.status.success() {
+        warn!(
+            "diff of {} failed: {}",
+            label,
+            String::from_utf8_lossy(&res.stderr)
+        );
+    }
+    String::from_utf8_lossy(&res.stdout).into_owned()
+}
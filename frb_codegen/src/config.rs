@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::env;
 use std::ffi::OsString;
 use std::fs;
@@ -7,23 +8,70 @@ use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
-use serde::Deserialize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use structopt::clap::AppSettings;
 use structopt::StructOpt;
 use toml::Value;
 
-#[derive(StructOpt, Debug, PartialEq, Deserialize)]
+#[derive(StructOpt, Debug, Default, PartialEq, Deserialize)]
 #[structopt(setting(AppSettings::DeriveDisplayOrder))]
 pub struct RawOpts {
-    /// Path of input Rust code
-    #[structopt(short, long)]
-    pub rust_input: String,
+    /// Path of input Rust code, or of a directory whose `.rs` files are all inputs; can be repeated
+    #[structopt(short, long, required = true)]
+    pub rust_input: Vec<String>,
+    /// Path glob of the functions, methods and constants of the inputs to generate code for, such
+    /// as `crate::api::*`, or `crate::api::**` to include those of the submodules, methods being
+    /// under their type as in `crate::api::Point::*`; can be repeated, all being included if none
+    #[structopt(long)]
+    pub include_items: Vec<String>,
+    /// Path glob of the functions, methods and constants to leave out even if included, such as
+    /// `**::debug_*`; can be repeated
+    #[structopt(long)]
+    pub exclude_items: Vec<String>,
+    /// Leave out the functions, methods and constants of the modules which, or one of whose
+    /// parents, are not declared `pub`
+    #[structopt(long)]
+    pub public_modules_only: bool,
+    /// Feature of the crate taken as enabled, besides the default ones, when evaluating the
+    /// `#[cfg(..)]` of the functions, methods and constants, which are left out when disabled;
+    /// can be repeated or separated by commas
+    #[structopt(long)]
+    pub features: Vec<String>,
+    /// Evaluate the `#[cfg(..)]` of the inputs without the default features of the crate
+    #[structopt(long)]
+    pub no_default_features: bool,
+    /// Operating system the `#[cfg(..)]` of the inputs are evaluated for, such as `android`; the
+    /// items depending on `target_os` are all kept if not provided
+    #[structopt(long)]
+    pub target_os: Option<String>,
     /// Path of output generated Dart code
     #[structopt(short, long)]
     pub dart_output: String,
     /// If provided, generated Dart declaration code to this separate file
     #[structopt(long)]
     pub dart_decl_output: Option<String>,
+    /// If provided, with --dart-decl-output, also generate to this file a Dart library exporting
+    /// both the declarations and the implementation class, for the app to import a single file
+    #[structopt(long, requires = "dart-decl-output", conflicts_with = "dart-part-of")]
+    pub dart_barrel_output: Option<String>,
+    /// If provided, generate the Dart code as parts of this library, which then has to contain the
+    /// imports and parts of the generated code
+    #[structopt(long)]
+    pub dart_part_of: Option<String>,
+    /// If provided, let ffigen generate the wire class to this separate file as is, using the
+    /// types of `flutter_rust_bridge`, instead of merging its output into the Dart output
+    #[structopt(long)]
+    pub dart_wire_output: Option<String>,
+    /// Path of a file of Dart comments, such as a license or `// ignore_for_file:` lints, put at
+    /// the top of the generated Dart files, before their imports and parts
+    #[structopt(long)]
+    pub dart_header: Option<String>,
+    /// Lint of the Dart analyzer to ignore in the generated Dart files as well, such as
+    /// `lines_longer_than_80_chars`, besides those the generated code is known to trip; can be
+    /// repeated
+    #[structopt(long)]
+    pub dart_ignore_lints: Vec<String>,
 
     /// Path of output generated C header
     #[structopt(short, long)]
@@ -34,44 +82,564 @@ pub struct RawOpts {
     /// Path of output generated Rust code
     #[structopt(long)]
     pub rust_output: Option<String>,
+    /// Split the generated Rust code into a `mod.rs`, in the directory named after --rust-output,
+    /// and submodules next to it: "modules" for the wire functions of each Rust input file to
+    /// have their own, or a number of lines for the code to be cut into submodules of about that
+    /// size
+    #[structopt(long)]
+    pub rust_output_split: Option<String>,
+    /// Path of a file of Rust code, such as `use` declarations, inserted after the imports of the
+    /// generated Rust code
+    #[structopt(long)]
+    pub rust_prelude: Option<String>,
     /// Generated class name
     #[structopt(long)]
     pub class_name: Option<String>,
+    /// Prefix of the symbols of the extern functions, such as `wire_*`, for several bridges to be
+    /// linked into the same app
+    #[structopt(long)]
+    pub symbol_prefix: Option<String>,
     /// Line length for dart formatting
     #[structopt(long)]
     pub dart_format_line_length: Option<i32>,
+    /// Leave the generated Dart code unformatted, e.g. when `dart format` is not available
+    #[structopt(long, conflicts_with = "dart-format-command")]
+    pub dart_format_off: bool,
+    /// Shell command formatting the generated Dart files instead of `dart format`, the path of
+    /// each file being appended to it
+    #[structopt(long)]
+    pub dart_format_command: Option<String>,
+    /// Leave the generated Rust code unformatted, e.g. when `rustfmt` is not available
+    #[structopt(long, conflicts_with = "rust-format-command")]
+    pub rust_format_off: bool,
+    /// Shell command formatting the generated Rust file instead of `rustfmt`, its path being
+    /// appended to it
+    #[structopt(long)]
+    pub rust_format_command: Option<String>,
     /// Skip automatically adding `mod bridge_generated;` to `lib.rs`
     #[structopt(long)]
     pub skip_add_mod_to_lib: bool,
+    /// Regenerate even if the crate and the options did not change since the last run, checking
+    /// the installed tools again
+    #[structopt(long)]
+    pub force: bool,
+    /// Keep running, and regenerate whenever a module of the crate changes
+    #[structopt(long)]
+    pub watch: bool,
     /// Path to the installed LLVM
     #[structopt(long)]
     pub llvm_path: Option<Vec<String>>,
     /// LLVM compiler opts
     #[structopt(long)]
     pub llvm_compiler_opts: Option<String>,
+    /// How to generate Dart classes for enums with associated data
+    #[structopt(long, possible_values = &["freezed", "classes"])]
+    pub dart_enums_style: Option<String>,
+    /// How Dart names the fields, arguments and methods, whose Rust names are in snake_case:
+    /// camelCase by default, or snake_case to keep them as they are
+    #[structopt(long, possible_values = &["camel", "snake"])]
+    pub dart_names_style: Option<String>,
+    /// Runtime driving `async` functions: "tokio", "async-std", or the path of a custom
+    /// `block_on` function
+    #[structopt(long)]
+    pub async_runtime: Option<String>,
+    /// Path of a type implementing `flutter_rust_bridge::support::WireAllocator`, allocating the
+    /// lists and boxes that Dart fills for Rust instead of the global allocator
+    #[structopt(long)]
+    pub wire_allocator: Option<String>,
+    /// Time zone of the Dart `DateTime`s standing for `chrono::NaiveDateTime`
+    #[structopt(long, possible_values = &["utc", "local"])]
+    pub naive_date_time_zone: Option<String>,
+    /// Dart type standing for `uuid::Uuid`: "bytes" for a `Uint8List`, or "uuid-value" for the
+    /// `UuidValue` of the `uuid` package
+    #[structopt(long, possible_values = &["bytes", "uuid-value"])]
+    pub dart_uuid_type: Option<String>,
+    /// Dart type standing for `i64` and `u64`: "int", or "big-int" for a `BigInt`, which keeps
+    /// the values above 2^53 that the `int`s of Dart compiled to JavaScript cannot hold
+    #[structopt(long, possible_values = &["int", "big-int"])]
+    pub dart_int64_type: Option<String>,
+    /// How the `PathBuf`s and `&Path`s returned to Dart as `String`s are converted when they are
+    /// not valid UTF-8: "lossy" to replace the invalid bytes with U+FFFD, or "error" to fail the
+    /// call with a panic
+    #[structopt(long, possible_values = &["lossy", "error"])]
+    pub non_utf8_paths: Option<String>,
+    /// What a zero received for a `NonZero*` integer such as `NonZeroU32` becomes: "error" to
+    /// fail the call, or "clamp" to turn it into one
+    #[structopt(long, possible_values = &["error", "clamp"])]
+    pub non_zero_ints: Option<String>,
+    /// How `Arc<T>` of a struct crosses the bridge: "clone" to send the struct like `T`, or
+    /// "handle" to keep it in Rust, Dart holding the `Arc` as an opaque object
+    #[structopt(long, possible_values = &["clone", "handle"])]
+    pub arc_structs: Option<String>,
+    /// Generate `==`, `hashCode` and `toString` comparing the fields of the Dart classes of
+    /// structs, which needs the `collection` package
+    #[structopt(long)]
+    pub dart_value_equality: bool,
+    /// Give the Dart classes of structs a `const` constructor, unless a field is marked
+    /// `#[frb(non_final)]` or the class extends or mixes in another one
+    #[structopt(long)]
+    pub dart_const_constructors: bool,
+    /// Generate `copyWith` for the Dart classes of structs, returning a copy with the given fields
+    /// replaced, nullable fields being set to `null` when given as `null`
+    #[structopt(long)]
+    pub dart_copy_with: bool,
+    /// Generate `toJson` and `fromJson` for the Dart classes of structs and enums
+    #[structopt(long)]
+    pub dart_json: bool,
+    /// With --dart-json, how enums are written: "tagged" by the names of their variants, or
+    /// "index" by their positions
+    #[structopt(long, requires = "dart-json", possible_values = &["tagged", "index"])]
+    pub dart_json_enums: Option<String>,
+    /// Throw a generated `PanicException`, holding the message of the panic, when a Rust function
+    /// panics, rather than an `FfiException` with the code `PANIC_ERROR`
+    #[structopt(long)]
+    pub panic_exception: bool,
+    /// Document the order and the types of the fields of each wire struct in the generated Rust,
+    /// and hence in the C header, to debug mismatches between the Rust and the Dart layouts
+    #[structopt(long)]
+    pub wire_layout_docs: bool,
+    /// If provided, write the parsed API to this file as JSON, for tools to inspect or compare
+    #[structopt(long)]
+    pub dump_ir: Option<String>,
+    /// Run all the phases without writing any file, telling instead which files would change
+    #[structopt(long)]
+    pub dry_run: bool,
+    /// With --dry-run, also print the differences with the existing files, using `diff`
+    #[structopt(long, requires = "dry-run")]
+    pub diff: bool,
+    /// Check that the generated files are up to date without writing them, printing their
+    /// differences and exiting with 10 if any
+    #[structopt(long)]
+    pub check: bool,
+    /// Print how long each phase took as JSON, once done
+    #[structopt(long)]
+    pub timings: bool,
 }
 
 #[derive(Debug)]
 pub struct Opts {
-    pub rust_input_path: String,
+    pub rust_input_paths: Vec<String>,
+    pub item_filter: ItemFilter,
+    pub active_cfg: ActiveCfg,
     pub dart_output_path: String,
     pub dart_decl_output_path: Option<String>,
+    pub dart_barrel_output_path: Option<String>,
+    pub dart_part_of_path: Option<String>,
+    pub dart_wire_output_path: Option<String>,
+    pub dart_header_path: Option<String>,
+    pub dart_ignore_lints: Vec<String>,
     pub c_output_path: String,
+    /// Whether the C header is a new temporary file, none being asked for
+    pub c_output_is_temporary: bool,
     pub rust_crate_dir: String,
+    /// The top module when the Rust code is split into submodules, e.g. `bridge_generated/mod.rs`
+    /// for `bridge_generated.rs`
     pub rust_output_path: String,
+    pub rust_output_split: RustOutputSplit,
+    pub rust_prelude_path: Option<String>,
     pub class_name: String,
+    pub symbol_prefix: String,
     pub dart_format_line_length: i32,
+    pub dart_formatter: Formatter,
+    pub rust_formatter: Formatter,
     pub skip_add_mod_to_lib: bool,
+    pub force: bool,
+    pub watch: bool,
     pub llvm_path: Vec<String>,
     pub llvm_compiler_opts: String,
     pub manifest_path: String,
+    pub dart_enums_style: DartEnumsStyle,
+    pub dart_names_style: DartNamesStyle,
+    pub async_runtime: AsyncRuntime,
+    pub wire_allocator: Option<String>,
+    pub naive_date_time_zone: NaiveDateTimeZone,
+    pub dart_uuid_type: DartUuidType,
+    pub dart_int64_type: DartInt64Type,
+    pub non_utf8_paths: NonUtf8Paths,
+    pub non_zero_ints: NonZeroInts,
+    pub arc_structs: ArcStructs,
+    pub dart_value_equality: bool,
+    pub dart_const_constructors: bool,
+    pub dart_copy_with: bool,
+    pub dart_json: bool,
+    pub dart_json_enums: DartJsonEnums,
+    pub panic_exception: bool,
+    pub wire_layout_docs: bool,
+    pub dump_ir_path: Option<String>,
+    pub dry_run: bool,
+    pub diff: bool,
+    pub check: bool,
+    pub timings: bool,
+}
+
+/// Which functions, methods and constants of the inputs code is generated for, by their paths
+/// such as `crate::api::add` or, for methods, `crate::api::Point::norm`
+#[derive(Debug, Clone, Default)]
+pub struct ItemFilter {
+    /// The items matching none of these are left out, unless there are none
+    pub include: Vec<ItemPattern>,
+    pub exclude: Vec<ItemPattern>,
+    /// Whether to leave out the items of the modules not declared `pub` all the way from the
+    /// crate root
+    pub public_modules_only: bool,
+}
+
+impl ItemFilter {
+    /// Whether the item of the given path, in a module public from the crate root or not, is kept
+    pub fn keeps(&self, path: &str, in_public_module: bool) -> bool {
+        (in_public_module || !self.public_modules_only)
+            && (self.include.is_empty() || self.include.iter().any(|p| p.matches(path)))
+            && !self.exclude.iter().any(|p| p.matches(path))
+    }
+}
+
+/// A glob of item paths, where `*` stands for any part of a name, and `**` for any number of
+/// names with the `::` between them
+#[derive(Debug, Clone)]
+pub struct ItemPattern {
+    pub pattern: String,
+    regex: Regex,
+}
+
+impl ItemPattern {
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path)
+    }
+}
+
+impl FromStr for ItemPattern {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let segments: Vec<&str> = s.split("::").collect();
+        let mut regex = String::from("^");
+        for (i, segment) in segments.iter().enumerate() {
+            let is_last = i == segments.len() - 1;
+            if *segment == "**" {
+                regex.push_str(if is_last { r"\w+(::\w+)*" } else { r"(\w+::)*" });
+                continue;
+            }
+            if segment.is_empty()
+                || !segment
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '*')
+            {
+                return Err(anyhow!(
+                    "invalid item pattern `{}`, which should be a path such as `crate::api::*`",
+                    s
+                ));
+            }
+            regex.push_str(&segment.replace('*', r"\w*"));
+            if !is_last {
+                regex.push_str("::");
+            }
+        }
+        regex.push('$');
+        Ok(ItemPattern {
+            pattern: s.to_owned(),
+            regex: Regex::new(&regex)?,
+        })
+    }
+}
+
+/// What the `#[cfg(..)]` of the inputs are evaluated against, the items they disable being left
+/// out
+#[derive(Debug, Clone, Default)]
+pub struct ActiveCfg {
+    /// The enabled features of the crate, including those enabled by other features
+    pub features: HashSet<String>,
+    /// Unknown unless provided, the items depending on it then being kept
+    pub target_os: Option<String>,
+}
+
+/// How Dart names the fields, the arguments and the methods of the API, which only changes the
+/// generated Dart code, since the data is bound by position on the wire
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DartNamesStyle {
+    /// `camelCase`, the usual style of Dart
+    #[default]
+    Camel,
+    /// `snake_case`, the Rust names as they are
+    Snake,
+}
+
+impl DartNamesStyle {
+    /// The Dart name of the snake_case Rust name `name`
+    pub fn convert(self, name: &str) -> String {
+        match self {
+            DartNamesStyle::Camel => name.to_case(Case::Camel),
+            DartNamesStyle::Snake => name.to_owned(),
+        }
+    }
+}
+
+impl FromStr for DartNamesStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "camel" => Ok(DartNamesStyle::Camel),
+            "snake" => Ok(DartNamesStyle::Snake),
+            _ => Err(anyhow!("unknown dart names style: {}", s)),
+        }
+    }
+}
+
+/// How enums with associated data are represented in Dart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DartEnumsStyle {
+    /// `@freezed` unions, requires `freezed` and `build_runner` on the Dart side
+    Freezed,
+    /// Plain Dart classes extending an abstract base class
+    Classes,
+}
+
+impl FromStr for DartEnumsStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "freezed" => Ok(DartEnumsStyle::Freezed),
+            "classes" => Ok(DartEnumsStyle::Classes),
+            _ => Err(anyhow!("unknown dart enums style: {}", s)),
+        }
+    }
+}
+
+/// How the generated code waits for the future returned by an `async` function
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsyncRuntime {
+    /// A multi-threaded Tokio runtime created on first use
+    Tokio,
+    /// `async_std::task::block_on`
+    AsyncStd,
+    /// The path of a function with the signature of `block_on`, e.g. `futures::executor::block_on`
+    Custom(String),
+}
+
+impl FromStr for AsyncRuntime {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "tokio" => Ok(AsyncRuntime::Tokio),
+            "async-std" => Ok(AsyncRuntime::AsyncStd),
+            "" => Err(anyhow!("empty async runtime")),
+            path => Ok(AsyncRuntime::Custom(path.to_owned())),
+        }
+    }
+}
+
+/// How the wall-clock time of a `chrono::NaiveDateTime` is read by Dart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NaiveDateTimeZone {
+    /// A UTC `DateTime` with the same wall-clock time
+    Utc,
+    /// A local `DateTime` with the same wall-clock time
+    Local,
+}
+
+impl FromStr for NaiveDateTimeZone {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "utc" => Ok(NaiveDateTimeZone::Utc),
+            "local" => Ok(NaiveDateTimeZone::Local),
+            _ => Err(anyhow!("unknown naive date time zone: {}", s)),
+        }
+    }
+}
+
+/// How a `uuid::Uuid` is represented in Dart
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DartUuidType {
+    /// A `Uint8List` of the 16 bytes
+    Bytes,
+    /// `UuidValue`, requires the `uuid` package on the Dart side
+    UuidValue,
+}
+
+impl FromStr for DartUuidType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "bytes" => Ok(DartUuidType::Bytes),
+            "uuid-value" => Ok(DartUuidType::UuidValue),
+            _ => Err(anyhow!("unknown dart uuid type: {}", s)),
+        }
+    }
+}
+
+/// How an `i64` or a `u64` is represented in Dart
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DartInt64Type {
+    /// An `int`, which is 64-bit in native Dart, but only holds integers up to 2^53 exactly on the
+    /// web, and which has no room for the `u64`s above 2^63
+    Int,
+    /// A `BigInt`, holding every value exactly on every platform
+    BigInt,
+}
+
+impl FromStr for DartInt64Type {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "int" => Ok(DartInt64Type::Int),
+            "big-int" => Ok(DartInt64Type::BigInt),
+            _ => Err(anyhow!("unknown dart int64 type: {}", s)),
+        }
+    }
+}
+
+/// How paths which are not valid UTF-8 are converted into the Dart `String`s they are sent as
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum NonUtf8Paths {
+    /// The invalid bytes are replaced with U+FFFD, as by `Path::to_string_lossy`
+    Lossy,
+    /// The call panics, which Dart receives as an error
+    Error,
+}
+
+impl FromStr for NonUtf8Paths {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "lossy" => Ok(NonUtf8Paths::Lossy),
+            "error" => Ok(NonUtf8Paths::Error),
+            _ => Err(anyhow!("unknown non utf8 paths: {}", s)),
+        }
+    }
+}
+
+/// How a zero received for a `NonZero*` integer is converted
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum NonZeroInts {
+    /// Dart throws an `ArgumentError` before calling, and Rust panics if the zero is sent anyway
+    Error,
+    /// The zero becomes one, which is the closest value allowed
+    Clamp,
+}
+
+impl FromStr for NonZeroInts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "error" => Ok(NonZeroInts::Error),
+            "clamp" => Ok(NonZeroInts::Clamp),
+            _ => Err(anyhow!("unknown non zero ints: {}", s)),
+        }
+    }
+}
+
+/// How `Arc<T>` crosses the bridge when `T` is a struct not marked `#[frb(opaque)]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArcStructs {
+    /// Like `T`, the struct being cloned when the `Arc` is still shared
+    Clone,
+    /// As an opaque object whose `Arc` is held by Dart
+    Handle,
+}
+
+impl FromStr for ArcStructs {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "clone" => Ok(ArcStructs::Clone),
+            "handle" => Ok(ArcStructs::Handle),
+            _ => Err(anyhow!("unknown arc structs: {}", s)),
+        }
+    }
+}
+
+/// How the generated files of a language are formatted
+#[derive(Debug, Clone, PartialEq)]
+pub enum Formatter {
+    /// With the usual tool, `dart format` or `rustfmt`
+    Default,
+    /// Not at all
+    Off,
+    /// With a shell command given the path of the file
+    Command(String),
+}
+
+impl Formatter {
+    fn new(off: bool, command: &Option<String>) -> Self {
+        match command {
+            Some(command) => Formatter::Command(command.clone()),
+            None if off => Formatter::Off,
+            None => Formatter::Default,
+        }
+    }
+}
+
+/// How the generated Rust code is split into submodules of the module of `--rust-output`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RustOutputSplit {
+    /// All in one file
+    Off,
+    /// A submodule for the wire functions of each Rust input file, the rest staying in `mod.rs`
+    Modules,
+    /// Submodules of about this many lines, `mod.rs` keeping only what they share
+    Lines(usize),
+}
+
+impl FromStr for RustOutputSplit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "modules" => Ok(RustOutputSplit::Modules),
+            _ => match s.parse::<usize>() {
+                Ok(lines) if lines > 0 => Ok(RustOutputSplit::Lines(lines)),
+                _ => Err(anyhow!(
+                    "unknown rust output split: {}, expected \"modules\" or a number of lines",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+/// How the JSON of `--dart-json` tells the variants of an enum apart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DartJsonEnums {
+    /// By the name of the variant: the name itself for an enum without data, or a `type` key
+    /// beside the fields otherwise
+    Tagged,
+    /// By the position of the variant: the position itself for an enum without data, or an
+    /// `index` key beside the fields otherwise
+    Index,
+}
+
+impl FromStr for DartJsonEnums {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "tagged" => Ok(DartJsonEnums::Tagged),
+            "index" => Ok(DartJsonEnums::Index),
+            _ => Err(anyhow!("unknown dart json enums: {}", s)),
+        }
+    }
 }
 
 pub fn parse(raw: RawOpts) -> Opts {
-    let rust_input_path = canon_path(&raw.rust_input);
+    let raw_rust_input_paths = raw
+        .rust_input
+        .iter()
+        .map(|path| canon_path(path))
+        .collect::<Vec<_>>();
+    // Paths guessed from the input are relative to the first one
+    let rust_input_path = &raw_rust_input_paths[0];
 
     let rust_crate_dir = canon_path(&raw.rust_crate_dir.unwrap_or_else(|| {
-        fallback_rust_crate_dir(&rust_input_path)
+        fallback_rust_crate_dir(rust_input_path)
             .unwrap_or_else(|_| panic!("{}", format_fail_to_guess_error("rust_crate_dir")))
     }));
     let manifest_path = {
@@ -80,31 +648,95 @@ pub fn parse(raw: RawOpts) -> Opts {
         path_to_string(path).unwrap()
     };
     let rust_output_path = canon_path(&raw.rust_output.unwrap_or_else(|| {
-        fallback_rust_output_path(&rust_input_path)
+        fallback_rust_output_path(rust_input_path)
             .unwrap_or_else(|_| panic!("{}", format_fail_to_guess_error("rust_output")))
     }));
+    let rust_input_paths = expand_rust_input_paths(&raw_rust_input_paths, &rust_output_path)
+        .unwrap_or_else(|e| panic!("fail to read rust_input: {}", e));
+    let rust_output_split = raw
+        .rust_output_split
+        .map(|s| RustOutputSplit::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+        .unwrap_or(RustOutputSplit::Off);
+    let rust_output_path = if rust_output_split == RustOutputSplit::Off {
+        rust_output_path
+    } else {
+        split_rust_output_path(&rust_output_path)
+    };
+    let symbol_prefix = raw.symbol_prefix.unwrap_or_default();
+    let is_identifier = symbol_prefix
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !symbol_prefix.starts_with(|c: char| c.is_ascii_digit());
+    if !is_identifier {
+        panic!(
+            "symbol_prefix `{}` must be made of ASCII letters, digits and underscores, and not start with a digit",
+            symbol_prefix
+        );
+    }
     let class_name = raw.class_name.unwrap_or_else(|| {
-        fallback_class_name(&*rust_crate_dir)
+        fallback_class_name(&rust_crate_dir)
             .unwrap_or_else(|_| panic!("{}", format_fail_to_guess_error("class_name")))
     });
+    for lint in &raw.dart_ignore_lints {
+        let is_lint_name = !lint.is_empty()
+            && lint
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !is_lint_name {
+            panic!(
+                "dart_ignore_lints `{}` is not the name of a lint, such as `lines_longer_than_80_chars`",
+                lint
+            );
+        }
+    }
+    let c_output_is_temporary = raw.c_output.is_none();
     let c_output_path = canon_path(&raw.c_output.unwrap_or_else(|| {
         fallback_c_output_path()
             .unwrap_or_else(|_| panic!("{}", format_fail_to_guess_error("c_output")))
     }));
 
     Opts {
-        rust_input_path,
+        rust_input_paths,
+        item_filter: ItemFilter {
+            include: parse_item_patterns(&raw.include_items, "include_items"),
+            exclude: parse_item_patterns(&raw.exclude_items, "exclude_items"),
+            public_modules_only: raw.public_modules_only,
+        },
+        active_cfg: ActiveCfg {
+            features: enabled_features(&manifest_path, &raw.features, !raw.no_default_features)
+                .unwrap_or_else(|e| panic!("fail to read features: {}", e)),
+            target_os: raw.target_os,
+        },
         dart_output_path: canon_path(&raw.dart_output),
         dart_decl_output_path: raw
             .dart_decl_output
             .as_ref()
             .map(|s| canon_path(s.as_str())),
+        dart_barrel_output_path: raw
+            .dart_barrel_output
+            .as_ref()
+            .map(|s| canon_path(s.as_str())),
+        dart_part_of_path: raw.dart_part_of.as_ref().map(|s| canon_path(s.as_str())),
+        dart_wire_output_path: raw
+            .dart_wire_output
+            .as_ref()
+            .map(|s| canon_path(s.as_str())),
+        dart_header_path: raw.dart_header.as_ref().map(|s| canon_path(s.as_str())),
+        dart_ignore_lints: raw.dart_ignore_lints,
         c_output_path,
+        c_output_is_temporary,
         rust_crate_dir,
         rust_output_path,
+        rust_output_split,
+        rust_prelude_path: raw.rust_prelude.as_ref().map(|s| canon_path(s.as_str())),
         class_name,
+        symbol_prefix,
         dart_format_line_length: raw.dart_format_line_length.unwrap_or(80),
+        dart_formatter: Formatter::new(raw.dart_format_off, &raw.dart_format_command),
+        rust_formatter: Formatter::new(raw.rust_format_off, &raw.rust_format_command),
         skip_add_mod_to_lib: raw.skip_add_mod_to_lib,
+        force: raw.force,
+        watch: raw.watch,
         llvm_path: raw.llvm_path.unwrap_or_else(|| {
             vec![
                 "/opt/homebrew/opt/llvm".to_owned(), // Homebrew root
@@ -119,11 +751,136 @@ pub fn parse(raw: RawOpts) -> Opts {
                 "/usr/lib64/".to_owned(),
             ]
         }),
-        llvm_compiler_opts: raw.llvm_compiler_opts.unwrap_or_else(|| "".to_string()),
+        llvm_compiler_opts: raw.llvm_compiler_opts.unwrap_or_default(),
         manifest_path,
+        dart_enums_style: raw
+            .dart_enums_style
+            .map(|s| DartEnumsStyle::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(DartEnumsStyle::Freezed),
+        dart_names_style: raw
+            .dart_names_style
+            .map(|s| DartNamesStyle::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or_default(),
+        async_runtime: raw
+            .async_runtime
+            .map(|s| AsyncRuntime::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(AsyncRuntime::Tokio),
+        wire_allocator: raw.wire_allocator,
+        naive_date_time_zone: raw
+            .naive_date_time_zone
+            .map(|s| NaiveDateTimeZone::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(NaiveDateTimeZone::Utc),
+        dart_uuid_type: raw
+            .dart_uuid_type
+            .map(|s| DartUuidType::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(DartUuidType::Bytes),
+        dart_int64_type: raw
+            .dart_int64_type
+            .map(|s| DartInt64Type::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(DartInt64Type::Int),
+        non_utf8_paths: raw
+            .non_utf8_paths
+            .map(|s| NonUtf8Paths::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(NonUtf8Paths::Lossy),
+        non_zero_ints: raw
+            .non_zero_ints
+            .map(|s| NonZeroInts::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(NonZeroInts::Error),
+        arc_structs: raw
+            .arc_structs
+            .map(|s| ArcStructs::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(ArcStructs::Clone),
+        dart_value_equality: raw.dart_value_equality,
+        dart_const_constructors: raw.dart_const_constructors,
+        dart_copy_with: raw.dart_copy_with,
+        dart_json: raw.dart_json,
+        dart_json_enums: raw
+            .dart_json_enums
+            .map(|s| DartJsonEnums::from_str(&s).unwrap_or_else(|e| panic!("{}", e)))
+            .unwrap_or(DartJsonEnums::Tagged),
+        panic_exception: raw.panic_exception,
+        wire_layout_docs: raw.wire_layout_docs,
+        dump_ir_path: raw.dump_ir.as_ref().map(|s| canon_path(s.as_str())),
+        dry_run: raw.dry_run,
+        diff: raw.diff,
+        check: raw.check,
+        timings: raw.timings,
     }
 }
 
+fn parse_item_patterns(patterns: &[String], name: &str) -> Vec<ItemPattern> {
+    patterns
+        .iter()
+        .map(|s| {
+            ItemPattern::from_str(s).unwrap_or_else(|e| panic!("fail to read {}: {}", name, e))
+        })
+        .collect()
+}
+
+/// The features of the crate enabled by those asked for, and by the default ones unless left out,
+/// following what each feature of the manifest enables in turn
+fn enabled_features(
+    manifest_path: &str,
+    requested: &[String],
+    default_features: bool,
+) -> Result<HashSet<String>> {
+    let manifest = fs::read_to_string(manifest_path)?.parse::<Value>()?;
+    let features = manifest.get("features").and_then(Value::as_table);
+    // Which are features too, named after the dependency
+    let optional_dependencies = manifest
+        .get("dependencies")
+        .and_then(Value::as_table)
+        .into_iter()
+        .flatten()
+        .filter(|(_, dependency)| dependency.get("optional").and_then(Value::as_bool) == Some(true))
+        .map(|(name, _)| name.as_str())
+        .collect::<HashSet<_>>();
+    let is_feature = |name: &str| {
+        features.is_some_and(|features| features.contains_key(name))
+            || optional_dependencies.contains(name)
+    };
+    // `dep/feature` enables a feature of a dependency, and the dependency as well if optional,
+    // unless written `dep?/feature`, while `dep:dep` only enables the dependency
+    let enabled_by = |value: &str| match value.split_once('/') {
+        Some((dependency, _)) if !dependency.ends_with('?') && is_feature(dependency) => {
+            Some(dependency.to_owned())
+        }
+        Some(_) => None,
+        None if is_feature(value) => Some(value.to_owned()),
+        None => None,
+    };
+
+    let mut pending = Vec::new();
+    for feature in requested.iter().flat_map(|s| s.split(',')).map(str::trim) {
+        if feature.is_empty() {
+            continue;
+        }
+        if !feature.contains('/') && !is_feature(feature) {
+            return Err(anyhow!("the crate has no feature `{}`", feature));
+        }
+        pending.extend(enabled_by(feature));
+    }
+    if default_features && is_feature("default") {
+        pending.push("default".to_owned());
+    }
+    let mut enabled = HashSet::new();
+    while let Some(feature) = pending.pop() {
+        let enables = features
+            .and_then(|features| features.get(&feature))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str);
+        pending.extend(
+            enables
+                .filter_map(enabled_by)
+                .filter(|feature| !enabled.contains(feature)),
+        );
+        enabled.insert(feature);
+    }
+    Ok(enabled)
+}
+
 fn format_fail_to_guess_error(name: &str) -> String {
     format!(
         "fail to guess {}, please specify it manually in command line arguments",
@@ -158,6 +915,42 @@ fn fallback_rust_crate_dir(rust_input_path: &str) -> Result<String> {
     ))
 }
 
+/// Replaces the directories among the inputs by the `.rs` files directly inside them, leaving out
+/// the generated Rust file in case it is put there too.
+fn expand_rust_input_paths(paths: &[String], rust_output_path: &str) -> Result<Vec<String>> {
+    let mut ans = Vec::new();
+    for path in paths {
+        if Path::new(path).is_dir() {
+            let mut files = fs::read_dir(path)?
+                .map(|entry| Ok(entry?.path()))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .filter(|file| file.is_file() && file.extension().is_some_and(|ext| ext == "rs"))
+                .map(|file| path_to_string(file).map_err(|_| anyhow!("non UTF-8 path in {}", path)))
+                .collect::<Result<Vec<_>>>()?;
+            files.retain(|file| file != rust_output_path);
+            if files.is_empty() {
+                return Err(anyhow!("no Rust file in directory {}", path));
+            }
+            files.sort();
+            ans.extend(files);
+        } else {
+            ans.push(path.clone());
+        }
+    }
+    Ok(ans)
+}
+
+/// The `mod.rs` of the directory named after the generated file, unless it already is one
+fn split_rust_output_path(rust_output_path: &str) -> String {
+    let path = Path::new(rust_output_path);
+    if path.file_name().is_some_and(|name| name == "mod.rs") {
+        return rust_output_path.to_owned();
+    }
+    path_to_string(path.with_extension("").join("mod.rs"))
+        .unwrap_or_else(|_| panic!("fail to parse path: {}", rust_output_path))
+}
+
 fn fallback_c_output_path() -> Result<String> {
     let named_temp_file = Box::leak(Box::new(tempfile::Builder::new().suffix(".h").tempfile()?));
     Ok(named_temp_file
@@ -205,6 +998,24 @@ fn path_to_string(path: PathBuf) -> Result<String, OsString> {
 }
 
 impl Opts {
+    /// Whether `path` is a file of the generated Rust code: the one file, or when it is split, any
+    /// file of the directory of its `mod.rs`
+    pub fn is_rust_output(&self, path: &Path) -> bool {
+        let rust_output_path = Path::new(&self.rust_output_path);
+        match self.rust_output_split {
+            RustOutputSplit::Off => path == rust_output_path,
+            _ => path.parent() == rust_output_path.parent(),
+        }
+    }
+
+    /// The file of the submodule `name` of the split Rust code
+    pub fn rust_submodule_path(&self, name: &str) -> String {
+        Path::new(&self.rust_output_path)
+            .with_file_name(format!("{}.rs", name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
     pub fn dart_api_class_name(&self) -> String {
         self.class_name.clone()
     }
@@ -216,4 +1027,28 @@ impl Opts {
     pub fn dart_wire_class_name(&self) -> String {
         format!("{}Wire", self.class_name)
     }
+
+    /// The class generated by ffigen, which is the wire class itself unless it is written to a
+    /// separate file, where the wire class extends it
+    pub fn dart_ffigen_class_name(&self) -> String {
+        match self.dart_wire_output_path {
+            Some(_) => format!("{}Ffi", self.dart_wire_class_name()),
+            None => self.dart_wire_class_name(),
+        }
+    }
+
+    /// The file containing the Dart declarations, or the library they are part of, which is where
+    /// `part` directives go
+    pub fn dart_decl_file_stem(&self) -> String {
+        Path::new(
+            self.dart_part_of_path
+                .as_ref()
+                .or(self.dart_decl_output_path.as_ref())
+                .unwrap_or(&self.dart_output_path),
+        )
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_else(|| panic!("fail to parse path: {}", self.dart_output_path))
+        .to_string()
+    }
 }
@@ -1,10 +1,11 @@
-pub fn generate_dummy(func_names: &[String]) -> String {
+pub fn generate_dummy(func_names: &[String], symbol_prefix: &str) -> String {
     format!(
-        r#"static int64_t dummy_method_to_enforce_bundling(void) {{
+        r#"static int64_t {}dummy_method_to_enforce_bundling(void) {{
     int64_t dummy_var = 0;
 {}
     return dummy_var;
 }}"#,
+        symbol_prefix,
         func_names
             .iter()
             .map(|func_name| { format!("    dummy_var ^= ((int64_t) (void*) {});", func_name) })
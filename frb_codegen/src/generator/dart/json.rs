@@ -0,0 +1,363 @@
+//! The `toJson` and `fromJson` given to the Dart classes by `--dart-json`.
+
+use std::collections::HashSet;
+
+use crate::config::{DartEnumsStyle, DartJsonEnums, DartUuidType, Opts};
+use crate::ir::IrType::*;
+use crate::ir::*;
+
+/// The names of the structs and enums whose values can be written as JSON and read back. Opaque
+/// objects and trait objects cannot, nor can the classes of structs with methods, which hold the
+/// API that JSON does not give. The classes holding any of those are left out in turn.
+pub fn json_classes(ir_file: &IrFile) -> HashSet<String> {
+    let mut classes = ir_file
+        .struct_pool
+        .keys()
+        .filter(|name| ir_file.methods_of(name).next().is_none())
+        .chain(
+            ir_file
+                .enum_pool
+                .values()
+                .filter(|enu| !enu.is_trait_object)
+                .map(|enu| &enu.name),
+        )
+        .cloned()
+        .collect::<HashSet<_>>();
+    loop {
+        let unsupported = classes
+            .iter()
+            .filter(|name| {
+                !class_fields(ir_file, name)
+                    .iter()
+                    .all(|field| is_json_type(&field.ty, &classes))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        if unsupported.is_empty() {
+            return classes;
+        }
+        for name in unsupported {
+            classes.remove(&name);
+        }
+    }
+}
+
+/// The fields of a struct, or of all the variants of an enum
+fn class_fields<'a>(ir_file: &'a IrFile, name: &str) -> Vec<&'a IrField> {
+    match ir_file.struct_pool.get(name) {
+        Some(st) => st.fields.iter().collect(),
+        None => ir_file.enum_pool[name]
+            .variants()
+            .iter()
+            .flat_map(|variant| match &variant.kind {
+                IrVariantKind::Value => &[][..],
+                IrVariantKind::Struct(st) => &st.fields[..],
+            })
+            .collect(),
+    }
+}
+
+fn is_json_type(ty: &IrType, classes: &HashSet<String>) -> bool {
+    match ty {
+        Primitive(primitive) => !matches!(primitive, IrTypePrimitive::Unit),
+        Delegate(delegate) => is_json_type(&delegate.get_delegate(), classes),
+        PrimitiveList(_) | Int128(_) => true,
+        Optional(IrTypeOptional { inner })
+        | Boxed(IrTypeBoxed { inner, .. })
+        | GeneralList(IrTypeGeneralList { inner })
+        | Set(IrTypeSet { inner, .. })
+        | Array(IrTypeArray { inner, .. }) => is_json_type(inner, classes),
+        // The keys of JSON objects are strings
+        Map(map) => json_key_is_string(&map.key).is_some() && is_json_type(&map.value, classes),
+        StructRef(struct_ref) => classes.contains(&struct_ref.name),
+        EnumRef(enum_ref) => classes.contains(&enum_ref.name),
+        Opaque(_) => false,
+    }
+}
+
+/// Whether the keys of a map of `key` are strings already, or integers written as strings, or
+/// `None` if they cannot be JSON keys
+fn json_key_is_string(key: &IrType) -> Option<bool> {
+    match key {
+        Delegate(IrTypeDelegate::String | IrTypeDelegate::Cow(IrTypeDelegateCow::Str)) => {
+            Some(true)
+        }
+        Primitive(
+            IrTypePrimitive::Unit
+            | IrTypePrimitive::Bool
+            | IrTypePrimitive::F32
+            | IrTypePrimitive::F64,
+        ) => None,
+        Primitive(_) => Some(false),
+        _ => None,
+    }
+}
+
+/// The expression writing `value` of type `ty` as JSON, where `nullable` tells whether `value`
+/// may be null, for its members to be accessed with `?.`
+pub fn to_json(ty: &IrType, value: &str, nullable: bool, config: &Opts) -> String {
+    let dot = if nullable { "?." } else { "." };
+    match ty {
+        Primitive(_) | PrimitiveList(_) => value.to_owned(),
+        Delegate(delegate) => match delegate {
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => {
+                format!("{}{}inMicroseconds", value, dot)
+            }
+            IrTypeDelegate::Time(_) => format!("{}{}toIso8601String()", value, dot),
+            IrTypeDelegate::Uuid(DartUuidType::UuidValue) => format!("{}{}uuid", value, dot),
+            // Written as strings, like 128-bit integers, for JSON parsers reading doubles
+            IrTypeDelegate::BigInt64(_) => format!("{}{}toString()", value, dot),
+            IrTypeDelegate::String
+            | IrTypeDelegate::Str
+            | IrTypeDelegate::StringList
+            | IrTypeDelegate::BoolList
+            | IrTypeDelegate::Json => value.to_owned(),
+            _ => to_json(&delegate.get_delegate(), value, nullable, config),
+        },
+        Optional(IrTypeOptional { inner }) => to_json(inner, value, true, config),
+        Boxed(IrTypeBoxed { inner, .. }) => to_json(inner, value, nullable, config),
+        GeneralList(IrTypeGeneralList { inner }) => match to_json(inner, "e", false, config) {
+            item if item == "e" => value.to_owned(),
+            item => format!("{}{}map((e) => {}).toList()", value, dot, item),
+        },
+        // JSON has no sets
+        Set(IrTypeSet { inner, .. }) => match to_json(inner, "e", false, config) {
+            item if item == "e" => format!("{}{}toList()", value, dot),
+            item => format!("{}{}map((e) => {}).toList()", value, dot, item),
+        },
+        Array(array) => to_json(&array.list(), value, nullable, config),
+        Map(map) => {
+            let key = if json_key_is_string(&map.key) == Some(true) {
+                "k"
+            } else {
+                "k.toString()"
+            };
+            match to_json(&map.value, "e", false, config) {
+                item if item == "e" && key == "k" => value.to_owned(),
+                item => format!("{}{}map((k, e) => MapEntry({}, {}))", value, dot, key, item),
+            }
+        }
+        EnumRef(enum_ref) if !enum_ref.is_struct => match config.dart_json_enums {
+            DartJsonEnums::Tagged => format!("{}{}toString().split('.').last", value, dot),
+            DartJsonEnums::Index => format!("{}{}index", value, dot),
+        },
+        StructRef(_) | EnumRef(_) => format!("{}{}toJson()", value, dot),
+        Int128(_) => format!("{}{}toString()", value, dot),
+        Opaque(_) => unreachable!("opaque objects are not written as JSON"),
+    }
+}
+
+/// The expression reading a value of type `ty` from `json`, an expression of a decoded JSON value
+pub fn from_json(ty: &IrType, json: &str, config: &Opts) -> String {
+    match ty {
+        Primitive(IrTypePrimitive::F32 | IrTypePrimitive::F64) => {
+            format!("({} as num).toDouble()", json)
+        }
+        Primitive(_) => format!("{} as {}", json, ty.dart_api_type()),
+        PrimitiveList(list) => match list.primitive {
+            IrTypePrimitive::F32 | IrTypePrimitive::F64 => format!(
+                "{}.fromList(({} as List).map((e) => (e as num).toDouble()).toList())",
+                ty.dart_api_type(),
+                json
+            ),
+            _ => format!(
+                "{}.fromList(List<int>.from({} as List))",
+                ty.dart_api_type(),
+                json
+            ),
+        },
+        Delegate(delegate) => match delegate {
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => {
+                format!("Duration(microseconds: {} as int)", json)
+            }
+            IrTypeDelegate::Time(_) => format!("DateTime.parse({} as String)", json),
+            IrTypeDelegate::Uuid(DartUuidType::UuidValue) => {
+                format!("UuidValue({} as String)", json)
+            }
+            IrTypeDelegate::BigInt64(_) => format!("BigInt.parse({} as String)", json),
+            IrTypeDelegate::String | IrTypeDelegate::Str => format!("{} as String", json),
+            IrTypeDelegate::StringList => format!("List<String>.from({} as List)", json),
+            IrTypeDelegate::BoolList => format!("List<bool>.from({} as List)", json),
+            IrTypeDelegate::Json => json.to_owned(),
+            _ => from_json(&delegate.get_delegate(), json, config),
+        },
+        Optional(IrTypeOptional { inner }) => format!(
+            "{} == null ? null : {}",
+            json,
+            from_json(inner, json, config)
+        ),
+        Boxed(IrTypeBoxed { inner, .. }) => from_json(inner, json, config),
+        GeneralList(IrTypeGeneralList { inner }) => format!(
+            "({} as List).map((e) => {}).toList()",
+            json,
+            from_json(inner, "e", config)
+        ),
+        Set(IrTypeSet { inner, .. }) => format!(
+            "({} as List).map((e) => {}).toSet()",
+            json,
+            from_json(inner, "e", config)
+        ),
+        Array(array) => from_json(&array.list(), json, config),
+        Map(map) => {
+            let key = if json_key_is_string(&map.key) == Some(true) {
+                "k as String".to_owned()
+            } else {
+                "int.parse(k as String)".to_owned()
+            };
+            format!(
+                "({} as Map).map((k, e) => MapEntry({}, {}))",
+                json,
+                key,
+                from_json(&map.value, "e", config)
+            )
+        }
+        EnumRef(enum_ref) if !enum_ref.is_struct => match config.dart_json_enums {
+            DartJsonEnums::Tagged => format!(
+                "{0}.values.firstWhere((value) => value.toString().split('.').last == {1}, orElse: () => throw FormatException('Unknown variant of {0}', {1}))",
+                enum_ref.name, json
+            ),
+            DartJsonEnums::Index => format!("{}.values[{} as int]", enum_ref.name, json),
+        },
+        StructRef(IrTypeStructRef { dart_name: name, .. })
+        | EnumRef(IrTypeEnumRef { name, .. }) => {
+            format!("{}.fromJson({} as Map<String, dynamic>)", name, json)
+        }
+        Int128(_) => format!("BigInt.parse({} as String)", json),
+        Opaque(_) => unreachable!("opaque objects are not read from JSON"),
+    }
+}
+
+/// `fromJson` and `toJson` of the class `class_name` of `src`, an object keyed by the Dart names
+/// of the fields
+pub fn generate_struct_json(class_name: &str, src: &IrStruct, config: &Opts) -> String {
+    let args = src
+        .fields
+        .iter()
+        .map(|field| {
+            let value = from_json(
+                &field.ty,
+                &format!("json['{}']", field.name.dart_style()),
+                config,
+            );
+            if src.is_fields_named {
+                format!("{}: {},", field.name.dart_style(), value)
+            } else {
+                format!("{},", value)
+            }
+        })
+        .collect::<String>();
+    let entries = src
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "'{}': {},",
+                field.name.dart_style(),
+                to_json(&field.ty, &field.name.dart_style(), false, config)
+            )
+        })
+        .collect::<String>();
+    format!(
+        "
+
+        factory {0}.fromJson(Map<String, dynamic> json) => {0}({1});
+
+        Map<String, dynamic> toJson() => {{{2}}};",
+        class_name, args, entries,
+    )
+}
+
+/// `fromJson` and `toJson` of the base class of the enum `src`, an object of the fields of the
+/// variant beside the key telling the variant
+pub fn generate_enum_json(src: &IrEnum, config: &Opts) -> String {
+    let tag_key = match config.dart_json_enums {
+        DartJsonEnums::Tagged => "type",
+        DartJsonEnums::Index => "index",
+    };
+    let (cases, branches): (Vec<_>, Vec<_>) = src
+        .variants()
+        .iter()
+        .enumerate()
+        .map(|(idx, variant)| {
+            let tag = match config.dart_json_enums {
+                DartJsonEnums::Tagged => format!("'{}'", variant.name.rust_style()),
+                DartJsonEnums::Index => idx.to_string(),
+            };
+            let (fields, is_fields_named) = match &variant.kind {
+                IrVariantKind::Value => (&[][..], true),
+                IrVariantKind::Struct(st) => (&st.fields[..], st.is_fields_named),
+            };
+            let args = fields
+                .iter()
+                .map(|field| {
+                    let value = from_json(
+                        &field.ty,
+                        &format!("json['{}']", field.name.dart_style()),
+                        config,
+                    );
+                    if is_fields_named {
+                        format!("{}: {},", field.name.dart_style(), value)
+                    } else {
+                        format!("{},", value)
+                    }
+                })
+                .collect::<String>();
+            let entries = fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "'{}': {},",
+                        field.name.dart_style(),
+                        to_json(
+                            &field.ty,
+                            &format!("self.{}", field.name.dart_style()),
+                            false,
+                            config
+                        )
+                    )
+                })
+                .collect::<String>();
+            (
+                format!(
+                    "case {}: return {}({});",
+                    tag,
+                    variant.name.rust_style(),
+                    args
+                ),
+                format!(
+                    "if (self is {}) return {{'{}': {}, {}}};",
+                    variant.name.rust_style(),
+                    tag_key,
+                    tag,
+                    entries
+                ),
+            )
+        })
+        .unzip();
+    // freezed expects the code of `json_serializable` for a union with a `fromJson` factory
+    let from_json_decl = match config.dart_enums_style {
+        DartEnumsStyle::Freezed => format!("static {} fromJson", src.name),
+        DartEnumsStyle::Classes => format!("factory {}.fromJson", src.name),
+    };
+    format!(
+        "
+
+        {from_json_decl}(Map<String, dynamic> json) {{
+            switch (json['{tag_key}']) {{
+                {cases}
+                default: throw FormatException('Unknown variant of {name}', json['{tag_key}']);
+            }}
+        }}
+
+        Map<String, dynamic> toJson() {{
+            final self = this;
+            {branches}
+            throw StateError('Unknown variant of {name}');
+        }}",
+        from_json_decl = from_json_decl,
+        tag_key = tag_key,
+        cases = cases.join("\n"),
+        name = src.name,
+        branches = branches.join("\n"),
+    )
+}
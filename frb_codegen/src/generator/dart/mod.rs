@@ -1,26 +1,41 @@
+mod json;
 mod ty;
+mod ty_array;
 mod ty_boxed;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_int128;
+mod ty_map;
+mod ty_opaque;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
+mod ty_set;
 mod ty_struct;
 
+pub use json::*;
 pub use ty::*;
+pub use ty_array::*;
 pub use ty_boxed::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_int128::*;
+pub use ty_map::*;
+pub use ty_opaque::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
+pub use ty_set::*;
 pub use ty_struct::*;
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use convert_case::{Case, Casing};
 use log::debug;
 
+use crate::config::{DartEnumsStyle, DartUuidType, Opts};
 use crate::ir::IrType::*;
 use crate::ir::*;
 use crate::others::*;
@@ -31,12 +46,27 @@ pub struct Output {
     pub impl_code: DartBasicCode,
 }
 
-pub fn generate(
-    ir_file: &IrFile,
-    dart_api_class_name: &str,
-    dart_api_impl_class_name: &str,
-    dart_wire_class_name: &str,
-) -> Output {
+/// The lints of the Dart analyzer that the generated code is known to trip
+const IGNORED_LINTS: &[&str] = &[
+    "non_constant_identifier_names",
+    "unused_element",
+    "duplicate_ignore",
+    "directives_ordering",
+    "curly_braces_in_flow_control_structures",
+    "unnecessary_lambdas",
+    "slash_for_doc_comments",
+    "prefer_const_literals_to_create_immutables",
+    "implicit_dynamic_list_literal",
+    "duplicate_import",
+    "unused_import",
+    "deprecated_member_use_from_same_package",
+];
+
+/// `dart_header` is the content of the file given by `--dart-header`, if any
+pub fn generate(ir_file: &IrFile, dart_header: Option<&str>, config: &Opts) -> Output {
+    let dart_api_class_name = &config.dart_api_class_name();
+    let dart_api_impl_class_name = &config.dart_api_impl_class_name();
+    let dart_wire_class_name = &config.dart_wire_class_name();
     let distinct_types = ir_file.distinct_types(true, true);
     let distinct_input_types = ir_file.distinct_types(true, false);
     let distinct_output_types = ir_file.distinct_types(false, true);
@@ -47,36 +77,169 @@ pub fn generate(
         .funcs
         .iter()
         .map(generate_api_func)
+        .chain(distinct_types.iter().filter_map(|ty| match ty {
+            Opaque(opaque) => Some(generate_opaque_drop_func(opaque, &config.symbol_prefix)),
+            _ => None,
+        }))
+        .chain(
+            ir_file
+                .consts
+                .iter()
+                .filter(|constant| constant.value.is_none())
+                .map(generate_const_getter),
+        )
+        .chain(generate_init(ir_file))
+        .chain(std::iter::once(generate_check_wire_hash(ir_file)))
+        .chain(std::iter::once(generate_dispose()))
         .collect::<Vec<_>>();
+    let dart_consts = ir_file
+        .consts
+        .iter()
+        .filter_map(|constant| {
+            let value = constant.value.as_ref()?;
+            Some(format!(
+                "{}{}const {} {} = {};",
+                dart_comments(&constant.comments),
+                dart_deprecation(&constant.deprecation),
+                constant.ty.dart_api_type(),
+                constant.dart_name,
+                value
+            ))
+        })
+        .collect::<Vec<_>>();
+    // Enums returned as errors are thrown as the exception of their variant
+    let error_enums = ir_file
+        .funcs
+        .iter()
+        .filter_map(|func| func.error_output.as_ref().and_then(error_enum))
+        .map(|enum_ref| {
+            let generator = TypeEnumRefGenerator {
+                ir: enum_ref.clone(),
+                context: TypeGeneratorContext { ir_file, config },
+            };
+            (enum_ref.name.clone(), generator)
+        })
+        .collect::<BTreeMap<_, _>>();
     let dart_structs = distinct_types
         .iter()
-        .map(|ty| TypeDartGenerator::new(ty.clone(), ir_file).structs())
+        .map(|ty| TypeDartGenerator::new(ty.clone(), ir_file, config).structs())
+        .chain(error_enums.values().map(TypeEnumRefGenerator::exceptions))
         .collect::<Vec<_>>();
     let dart_api2wire_funcs = distinct_input_types
         .iter()
-        .map(|ty| generate_api2wire_func(ty, ir_file))
+        .map(|ty| generate_api2wire_func(ty, ir_file, config))
         .collect::<Vec<_>>();
     let dart_api_fill_to_wire_funcs = distinct_input_types
         .iter()
-        .map(|ty| generate_api_fill_to_wire_func(ty, ir_file))
+        .map(|ty| generate_api_fill_to_wire_func(ty, ir_file, config))
         .collect::<Vec<_>>();
     let dart_wire2api_funcs = distinct_output_types
         .iter()
-        .map(|ty| generate_wire2api_func(ty, ir_file))
+        .map(|ty| generate_wire2api_func(ty, ir_file, config))
+        .chain(
+            error_enums
+                .values()
+                .map(TypeEnumRefGenerator::wire2api_exception_func),
+        )
         .collect::<Vec<_>>();
 
-    let needs_freezed = distinct_types
-        .iter()
-        .any(|ty| matches!(ty, EnumRef(e) if e.is_struct));
+    let needs_freezed = config.dart_enums_style == DartEnumsStyle::Freezed
+        && distinct_types
+            .iter()
+            .any(|ty| matches!(ty, EnumRef(e) if e.is_struct && !e.is_trait_object));
     let freezed_header = if needs_freezed {
         DartBasicCode {
             import: "import 'package:freezed_annotation/freezed_annotation.dart';".to_string(),
-            part: "part 'bridge_generated.freezed.dart';".to_string(),
+            part: format!("part '{}.freezed.dart';", config.dart_decl_file_stem()),
+            body: "".to_string(),
+        }
+    } else {
+        DartBasicCode::default()
+    };
+
+    // Classes of opaque types with a finalizer implement `ffi.Finalizable`
+    let needs_ffi = distinct_types
+        .iter()
+        .any(|ty| matches!(ty, Opaque(opaque) if opaque.has_finalizer));
+    let ffi_header = if needs_ffi {
+        DartBasicCode {
+            import: "import 'dart:ffi' as ffi;".to_string(),
+            part: "".to_string(),
+            body: "".to_string(),
+        }
+    } else {
+        DartBasicCode::default()
+    };
+
+    let needs_uuid = distinct_types
+        .iter()
+        .any(|ty| matches!(ty, Delegate(IrTypeDelegate::Uuid(DartUuidType::UuidValue))));
+    let uuid_header = if needs_uuid {
+        DartBasicCode {
+            import: "import 'package:uuid/uuid.dart';".to_string(),
+            part: "".to_string(),
+            body: "".to_string(),
+        }
+    } else {
+        DartBasicCode::default()
+    };
+
+    // The libraries named by `#[frb(dart_import = "..")]` for the metadata of the classes
+    let metadata_imports = distinct_types
+        .iter()
+        .filter_map(|ty| match ty {
+            StructRef(struct_ref) => Some(&struct_ref.get(ir_file).dart_imports),
+            _ => None,
+        })
+        .flatten()
+        .collect::<BTreeSet<_>>();
+    let metadata_header = DartBasicCode {
+        import: metadata_imports
+            .iter()
+            .map(|uri| format!("import '{}';", uri))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        part: "".to_string(),
+        body: "".to_string(),
+    };
+
+    let needs_collection =
+        config.dart_value_equality && distinct_types.iter().any(|ty| matches!(ty, StructRef(_)));
+    let collection_header = if needs_collection {
+        DartBasicCode {
+            import: "import 'package:collection/collection.dart';".to_string(),
+            part: "".to_string(),
+            body: "".to_string(),
+        }
+    } else {
+        DartBasicCode::default()
+    };
+
+    // `PanicException` extends `FfiException`
+    let panic_header = if config.panic_exception {
+        DartBasicCode {
+            import: "import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';".to_string(),
+            part: "".to_string(),
             body: "".to_string(),
         }
     } else {
         DartBasicCode::default()
     };
+    let (panic_exception_class, panic_exception_transform) = if config.panic_exception {
+        generate_panic_exception()
+    } else {
+        ("".to_string(), "".to_string())
+    };
+
+    // The default of the parameters of `copyWith` for nullable fields, telling them apart from `null`
+    let copy_with_unset_class =
+        if config.dart_copy_with && distinct_types.iter().any(|ty| matches!(ty, StructRef(_))) {
+            "class _CopyWithUnset {
+                const _CopyWithUnset();
+            }"
+        } else {
+            ""
+        };
 
     let common_header = DartBasicCode {
         import: "import 'dart:convert';
@@ -87,19 +250,31 @@ pub fn generate(
     };
 
     let decl_body = format!(
-        "abstract class {} {{
+        "{}
+
+        abstract class {} {{
             {}
         }}
 
+        {}
+
+        {}
+
+        {}
+
         {}
         ",
+        dart_consts.join("\n\n"),
         dart_api_class_name,
         dart_func_signatures_and_implementations
             .iter()
             .map(|(sig, _, comm)| format!("{}{}", comm, sig))
             .collect::<Vec<_>>()
             .join("\n\n"),
+        generate_extensions(ir_file, dart_api_class_name).join("\n\n"),
         dart_structs.join("\n\n"),
+        panic_exception_class,
+        copy_with_unset_class,
     );
 
     let impl_body = format!(
@@ -108,20 +283,22 @@ pub fn generate(
 
             {dart_api_impl_class_name}.raw({dart_wire_class_name} inner) : super(inner);
 
-            {}
+            {}{}
 
             // Section: api2wire
             {}
 
             // Section: api_fill_to_wire
             {}
-        }}
 
-        // Section: wire2api
-        {}
+            // Section: wire2api
+            {}
+        }}
         ",
+        panic_exception_transform,
         dart_func_signatures_and_implementations
             .iter()
+            .filter(|(_, imp, _)| !imp.is_empty())
             .map(|(_, imp, _)| imp.clone())
             .collect::<Vec<_>>()
             .join("\n\n"),
@@ -135,6 +312,11 @@ pub fn generate(
 
     let decl_code = &common_header
         + &freezed_header
+        + &ffi_header
+        + &uuid_header
+        + &collection_header
+        + &panic_header
+        + &metadata_header
         + &DartBasicCode {
             import: "".to_string(),
             part: "".to_string(),
@@ -142,18 +324,37 @@ pub fn generate(
         };
 
     let impl_code = &common_header
+        + &uuid_header
         + &DartBasicCode {
             import: "import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';".to_string(),
             part: "".to_string(),
             body: impl_body,
         };
 
+    let ignored_lints = IGNORED_LINTS
+        .iter()
+        .copied()
+        .chain(
+            config
+                .dart_ignore_lints
+                .iter()
+                .map(String::as_str)
+                .filter(|lint| !IGNORED_LINTS.contains(lint)),
+        )
+        .collect::<Vec<_>>();
+    // Directives can only follow comments, so the header of the user comes first
     let file_prelude = DartBasicCode {
-        import: format!("{}
+        import: format!(
+            "{}{}
             
-                // ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import
+                // ignore_for_file: {}
                 ",
-                CODE_HEADER
+            dart_header.map_or("".to_string(), |header| format!(
+                "{}\n\n",
+                header.trim_end()
+            )),
+            CODE_HEADER,
+            ignored_lints.join(", ")
         ),
         part: "".to_string(),
         body: "".to_string(),
@@ -166,21 +367,123 @@ pub fn generate(
     }
 }
 
+/// The library re-exporting the declarations and the implementation class of the API, given the
+/// URIs of their files relative to it. The wire class of the implementation stays internal.
+pub fn generate_barrel(decl_uri: &str, impl_uri: &str, dart_api_impl_class_name: &str) -> String {
+    format!(
+        "{}
+
+        export '{}';
+        export '{}' show {};
+        ",
+        CODE_HEADER, decl_uri, impl_uri, dart_api_impl_class_name
+    )
+}
+
+/// The getter of the API class reading a constant whose value is not a literal. It is called
+/// directly, strings being decoded from the bytes of a sync task.
+fn generate_const_getter(constant: &IrConst) -> (String, String, String) {
+    let partial = format!("{} get {}", constant.ty.dart_api_type(), constant.dart_name);
+    let implementation = match &constant.ty {
+        Delegate(IrTypeDelegate::String) => format!(
+            "{} => utf8.decode(executeSync(FlutterRustBridgeSyncTask(
+            callFfi: () => inner.{}(),
+            constMeta: const FlutterRustBridgeTaskConstMeta(
+                debugName: \"{}\",
+                argNames: [],
+            ),
+            argValues: [],
+            hint: null,
+        )));",
+            partial,
+            constant.wire_func_name(),
+            constant.name,
+        ),
+        _ => format!("{} => inner.{}();", partial, constant.wire_func_name()),
+    };
+    let comments = dart_comments(&constant.comments) + &dart_deprecation(&constant.deprecation);
+    (format!("{};", partial), implementation, comments)
+}
+
+/// The method running the functions marked `#[frb(init)]`, if any
+fn generate_init(ir_file: &IrFile) -> Option<(String, String, String)> {
+    if ir_file.init_funcs.is_empty() {
+        return None;
+    }
+    let partial = format!("Future<void> {}({{dynamic hint}})", INIT_FUNC_NAME);
+    let implementation = format!(
+        "{partial} => executeNormal(FlutterRustBridgeTask(
+            callFfi: (port_) => inner.wire_{name}(port_),
+            parseSuccessData: (_) {{}},
+            constMeta: const FlutterRustBridgeTaskConstMeta(
+                debugName: \"{name}\",
+                argNames: [],
+            ),
+            argValues: [],
+            hint: hint,
+        ));",
+        partial = partial,
+        name = INIT_FUNC_NAME,
+    );
+    let comments = "/// Initializes the Rust library, running the functions marked `#[frb(init)]` the first time
+        /// it is called. Later calls do nothing, so it is safe to call it from several places.
+        "
+    .to_string();
+    Some((format!("{};", partial), implementation, comments))
+}
+
+/// The method comparing the hash of the IR with the one the Rust library was generated with,
+/// which only differ when one of them is out of date
+fn generate_check_wire_hash(ir_file: &IrFile) -> (String, String, String) {
+    let implementation = format!(
+        "static const wireHash = {:#010x};
+
+        void checkWireHash() {{
+            final rustWireHash = inner.get_wire_hash();
+            if (rustWireHash != wireHash) {{
+                throw StateError(
+                    'The Rust library was generated from another version of the API than this Dart code: its wire hash is 0x${{rustWireHash.toRadixString(16)}}, while 0x${{wireHash.toRadixString(16)}} was expected. Generate the code again, then rebuild the Rust library.');
+            }}
+        }}",
+        ir_file.wire_hash()
+    );
+    let comments = "/// Throws a [StateError] if the Rust library was generated from another version of the API
+        /// than this code, such as a stale build, instead of the functions failing in obscure ways.
+        /// Meant to be called once at startup.
+        "
+    .to_string();
+    (
+        "void checkWireHash();".to_string(),
+        implementation,
+        comments,
+    )
+}
+
+/// The method tearing down the bridge, which `FlutterRustBridgeBase` implements, so the
+/// implementation class has nothing to generate for it
+fn generate_dispose() -> (String, String, String) {
+    let comments = "/// Tears down the bridge, such as between tests or before a hot restart: the streams still
+        /// open are closed, no function can be called anymore, and another instance of the API can
+        /// then be created. The calls already made still complete. Calling it again does nothing.
+        "
+    .to_string();
+    (
+        format!("void {}();", DISPOSE_FUNC_NAME),
+        "".to_string(),
+        comments,
+    )
+}
+
 fn generate_api_func(func: &IrFunc) -> (String, String, String) {
-    let raw_func_param_list = func
-        .inputs
-        .iter()
-        .map(|input| {
-            format!(
-                "{}{} {}",
-                input.ty.dart_required_modifier(),
-                input.ty.dart_api_type(),
-                input.name.dart_style()
-            )
-        })
-        .collect::<Vec<_>>();
+    let raw_func_param_list = func.inputs.iter().map(dart_param).collect::<Vec<_>>();
 
-    let full_func_param_list = [raw_func_param_list, vec!["dynamic hint".to_string()]].concat();
+    let full_func_param_list = [
+        raw_func_param_list,
+        dart_callback_params(func),
+        dart_cancellation_param(func).into_iter().collect(),
+        vec!["dynamic hint".to_string()],
+    ]
+    .concat();
 
     let wire_param_list = [
         if func.mode.has_port_argument() {
@@ -203,26 +506,34 @@ fn generate_api_func(func: &IrFunc) -> (String, String, String) {
                 }
             })
             .collect::<Vec<_>>(),
+        func.cancellation_token
+            .iter()
+            .map(|token| format!("{}?.id ?? 0", token.name.dart_style()))
+            .collect::<Vec<_>>(),
+        func.callbacks
+            .iter()
+            .map(|callback| format!("{}Callback.port", callback.name.dart_style()))
+            .collect::<Vec<_>>(),
     ]
     .concat();
 
     let partial = format!(
         "{} {}({{ {} }})",
         func.mode.dart_return_type(&func.output.dart_api_type()),
-        func.name.to_case(Case::Camel),
+        func.dart_func_name(),
         full_func_param_list.join(","),
     );
 
+    let signature = format!("{};", partial);
+
+    let comments = dart_comments(&func.comments) + &dart_deprecation(&func.deprecation);
+
     let execute_func_name = match func.mode {
         IrFuncMode::Normal => "executeNormal",
-        IrFuncMode::Sync => "executeSync",
+        IrFuncMode::Sync | IrFuncMode::Direct => "executeSync",
         IrFuncMode::Stream => "executeStream",
     };
 
-    let signature = format!("{};", partial);
-
-    let comments = dart_comments(&func.comments);
-
     let task_common_args = format!(
         "
         constMeta: const FlutterRustBridgeTaskConstMeta(
@@ -246,37 +557,349 @@ fn generate_api_func(func: &IrFunc) -> (String, String, String) {
     );
 
     let implementation = match func.mode {
-        IrFuncMode::Sync => format!(
-            "{} => {}(FlutterRustBridgeSyncTask(
-            callFfi: () => inner.{}({}),
-            {}
-        ));",
-            partial,
-            execute_func_name,
-            func.wire_func_name(),
-            wire_param_list.join(", "),
-            task_common_args,
-        ),
+        IrFuncMode::Sync | IrFuncMode::Direct => {
+            let code_execute = format!(
+                "{}(FlutterRustBridgeSyncTask(
+                callFfi: () => inner.{}({}),
+                {}
+            ))",
+                execute_func_name,
+                func.wire_func_name(),
+                wire_param_list.join(", "),
+                task_common_args,
+            );
+            format!(
+                "{} => {};",
+                partial,
+                match &func.output {
+                    Primitive(primitive) if func.mode == IrFuncMode::Direct => {
+                        generate_direct_output(primitive, &code_execute)
+                    }
+                    _ => code_execute,
+                }
+            )
+        }
         _ => format!(
-            "{} => {}(FlutterRustBridgeTask(
-            callFfi: (port_) => inner.{}({}),
+            "{}(FlutterRustBridgeTask(
+            callFfi: {},
             parseSuccessData: _wire2api_{},
-            {}
-        ));",
-            partial,
+            {}{}
+        ))",
             execute_func_name,
-            func.wire_func_name(),
-            wire_param_list.join(", "),
+            // The token can cancel the task once Rust registered it
+            match &func.cancellation_token {
+                Some(token) => format!(
+                    "(port_) {{
+                        inner.{}({});
+                        {}?.attach(inner.cancel_task);
+                    }}",
+                    func.wire_func_name(),
+                    wire_param_list.join(", "),
+                    token.name.dart_style()
+                ),
+                None => format!(
+                    "(port_) => inner.{}({})",
+                    func.wire_func_name(),
+                    wire_param_list.join(", ")
+                ),
+            },
             func.output.safe_ident(),
+            func.error_output
+                .as_ref()
+                .map_or("".to_string(), |error| format!(
+                    "parseErrorData: _wire2api_{}{},\n",
+                    error.safe_ident(),
+                    if error_enum(error).is_some() {
+                        "_exception"
+                    } else {
+                        ""
+                    }
+                )),
             task_common_args,
         ),
     };
+    let implementation = match func.mode {
+        IrFuncMode::Sync | IrFuncMode::Direct => implementation,
+        _ if func.callbacks.is_empty() => format!("{} => {};", partial, implementation),
+        // Dart listens to the calls of the callbacks until the task ends
+        _ => format!(
+            "{} {{
+                {}
+                return {}.whenComplete(() {{
+                    {}
+                }});
+            }}",
+            partial,
+            func.callbacks
+                .iter()
+                .map(generate_rust_callback)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            implementation,
+            func.callbacks
+                .iter()
+                .map(|callback| format!("{}Callback.close();", callback.name.dart_style()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+    };
+
+    (signature, implementation, comments)
+}
+
+/// Reads the primitive returned by a direct call from the bytes of its sync return, which Rust
+/// writes in the order of the platform
+fn generate_direct_output(primitive: &IrTypePrimitive, bytes: &str) -> String {
+    let getter = match primitive {
+        IrTypePrimitive::Unit => return bytes.to_owned(),
+        IrTypePrimitive::Bool => return format!("{}[0] != 0", bytes),
+        IrTypePrimitive::U8 => return format!("{}[0]", bytes),
+        IrTypePrimitive::I8 => return format!("ByteData.sublistView({}).getInt8(0)", bytes),
+        IrTypePrimitive::U16 => "getUint16",
+        IrTypePrimitive::I16 => "getInt16",
+        IrTypePrimitive::U32 => "getUint32",
+        IrTypePrimitive::I32 => "getInt32",
+        IrTypePrimitive::U64 => "getUint64",
+        IrTypePrimitive::I64 => "getInt64",
+        IrTypePrimitive::F32 => "getFloat32",
+        IrTypePrimitive::F64 => "getFloat64",
+    };
+    format!("ByteData.sublistView({}).{}(0, Endian.host)", bytes, getter)
+}
+
+/// The `RustCallback` receiving the calls of a `DartCallback`, which gives the Dart function the
+/// arguments sent by Rust as they are, then replies with what it returns
+fn generate_rust_callback(callback: &IrCallback) -> String {
+    let signature = &callback.signature;
+    let args = signature
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, (_, ty))| format!("args[{}] as {}", i, ty.dart_api_type()))
+        .collect::<Vec<_>>();
+    let reply = match &signature.output {
+        Primitive(IrTypePrimitive::Unit) => {
+            format!(
+                "(callId, _) => inner.{}(callId)",
+                signature.reply_func_name()
+            )
+        }
+        _ => format!("inner.{}", signature.reply_func_name()),
+    };
+    format!(
+        "final {name}Callback = RustCallback<{}>((args) => {name}({}), {}, inner.fail_callback);",
+        signature.output.dart_api_type(),
+        args.join(", "),
+        reply,
+        name = callback.name.dart_style(),
+    )
+}
+
+/// The named parameter of a Dart function standing for an argument, optional if it has a default
+fn dart_param(input: &IrField) -> String {
+    match &input.default {
+        Some(default) => format!(
+            "{} {} = {}",
+            input.ty.dart_api_type(),
+            input.name.dart_style(),
+            default
+        ),
+        None => format!(
+            "{}{} {}",
+            input.ty.dart_required_modifier(),
+            input.ty.dart_api_type(),
+            input.name.dart_style()
+        ),
+    }
+}
+
+/// The `PanicException` class, and the override of the API implementation throwing it instead of
+/// the `FfiException`s of panics, whose message is that of the panic if it is a string.
+fn generate_panic_exception() -> (String, String) {
+    (
+        "/// Thrown when a Rust function panics, with the message of the panic, or `Box<dyn Any>` if it is
+        /// not a string.
+        class PanicException extends FfiException {
+            PanicException(String message) : super('PANIC_ERROR', message, null);
+
+            @override
+            String toString() => 'PanicException($message)';
+        }"
+        .to_string(),
+        "@override
+        FfiException transformFfiException(FfiException exception) =>
+            exception.code == 'PANIC_ERROR' ? PanicException(exception.message) : exception;
+
+        "
+        .to_string(),
+    )
+}
+
+/// The parameters of the Dart functions given as the `DartCallback`s of a function
+fn dart_callback_params(func: &IrFunc) -> Vec<String> {
+    func.callbacks
+        .iter()
+        .map(|callback| {
+            format!(
+                "required {} {}",
+                callback.signature.dart_api_type(),
+                callback.name.dart_style()
+            )
+        })
+        .collect()
+}
+
+/// The optional parameter of the `CancellationToken` of a function, if it takes one
+fn dart_cancellation_param(func: &IrFunc) -> Option<String> {
+    func.cancellation_token
+        .as_ref()
+        .map(|token| format!("CancellationToken? {}", token.name.dart_style()))
+}
+
+/// The parameters of a method forwarding to the API function, which gives it all of them but the
+/// first, and the arguments it calls the function with
+fn forwarded_params_and_args(func: &IrFunc) -> (String, String) {
+    let params = func.inputs[1..]
+        .iter()
+        .map(dart_param)
+        .chain(dart_callback_params(func))
+        .chain(dart_cancellation_param(func))
+        .map(|param| param + ",")
+        .collect::<String>();
+    let args = func.inputs[1..]
+        .iter()
+        .map(|input| &input.name)
+        .chain(func.callbacks.iter().map(|callback| &callback.name))
+        .chain(func.cancellation_token.iter().map(|token| &token.name))
+        .map(|name| format!("{}: {},", name.dart_style(), name.dart_style()))
+        .collect::<String>();
+    (params, args)
+}
 
+/// Releases the reference Dart holds on an opaque object, used by its `dispose` method. Unless
+/// disposal is manual, a finalizer calls the same extern function once the object is unreachable.
+/// The symbol looked up by the finalizer is prefixed by `symbol_prefix`, unlike the methods of the
+/// wire class, which ffigen names without it.
+fn generate_opaque_drop_func(
+    opaque: &IrTypeOpaque,
+    symbol_prefix: &str,
+) -> (String, String, String) {
+    let partial = format!(
+        "void {}({} raw)",
+        opaque.drop_func_name().to_case(Case::Camel),
+        opaque.dart_name
+    );
+    let signature = format!("{};", partial);
+    let implementation = if opaque.has_finalizer {
+        format!(
+            "late final {finalizer} = ffi.NativeFinalizer(inner._lookup<ffi.NativeFinalizerFunction>('{symbol_prefix}{drop}'));
+
+            {} {{
+                {finalizer}.detach(raw);
+                inner.{drop}(ffi.Pointer.fromAddress(raw.address));
+            }}",
+            partial,
+            finalizer = opaque.dart_finalizer_name(),
+            drop = opaque.drop_func_name(),
+            symbol_prefix = symbol_prefix,
+        )
+    } else {
+        format!(
+            "{} => inner.{}(ffi.Pointer.fromAddress(raw.address));",
+            partial,
+            opaque.drop_func_name()
+        )
+    };
+    let comments = format!(
+        "/// Releases the Rust object of a [{}]. Prefer calling its `dispose` method instead.\n",
+        opaque.dart_name
+    );
     (signature, implementation, comments)
 }
 
-fn generate_api2wire_func(ty: &IrType, ir_file: &IrFile) -> String {
-    if let Some(body) = TypeDartGenerator::new(ty.clone(), ir_file).api2wire_body() {
+/// The instance method of a struct's class, which forwards to the API function.
+fn generate_api_method(func: &IrFunc, method: &IrMethod) -> String {
+    let (param_list, arg_list) = forwarded_params_and_args(func);
+
+    // The object is copied across the bridge, so document what this means for the receiver
+    let mut comments = dart_comments(&func.comments);
+    let receiver_note = match method.receiver {
+        IrMethodReceiver::Ref => None,
+        IrMethodReceiver::RefMut => Some(
+            "/// Rust receives a copy of this object, so changes made to it by the method are not visible here.",
+        ),
+        IrMethodReceiver::Value => {
+            Some("/// Rust takes ownership of a copy of this object, which stays usable afterwards.")
+        }
+    };
+    if let Some(note) = receiver_note {
+        if !comments.is_empty() {
+            comments.push_str("///\n");
+        }
+        comments.push_str(note);
+        comments.push('\n');
+    }
+    comments.push_str(&dart_deprecation(&func.deprecation));
+
+    format!(
+        "{}{} {}({{ {} dynamic hint }}) => bridge.{}({}: this, {} hint: hint);",
+        comments,
+        func.mode.dart_return_type(&func.output.dart_api_type()),
+        func.dart_method_name(method),
+        param_list,
+        func.dart_func_name(),
+        func.inputs[0].name.dart_style(),
+        arg_list,
+    )
+}
+
+/// The extensions of the types that the functions marked `#[frb(extension)]` are called on, one
+/// for each type, named after the API class for those of several bridges not to collide.
+fn generate_extensions(ir_file: &IrFile, dart_api_class_name: &str) -> Vec<String> {
+    let mut methods_by_type = BTreeMap::<_, Vec<_>>::new();
+    for func in ir_file.funcs.iter().filter(|func| func.is_extension) {
+        methods_by_type
+            .entry(func.inputs[0].ty.dart_api_type())
+            .or_default()
+            .push(generate_extension_method(func, dart_api_class_name));
+    }
+    methods_by_type
+        .into_iter()
+        .map(|(dart_type, methods)| {
+            format!(
+                "extension {}{}Extension on {} {{
+                    {}
+                }}",
+                dart_api_class_name,
+                dart_type.to_case(Case::UpperCamel),
+                dart_type,
+                methods.join("\n\n"),
+            )
+        })
+        .collect()
+}
+
+/// The extension method of a function marked `#[frb(extension)]`, which forwards to the API
+/// function, with the value it is called on as the first argument. The API is given as a
+/// parameter, since an extension has no state.
+fn generate_extension_method(func: &IrFunc, dart_api_class_name: &str) -> String {
+    let (param_list, arg_list) = forwarded_params_and_args(func);
+    format!(
+        "{}{}{} {}({} bridge, {{ {} dynamic hint }}) => bridge.{}({}: this, {} hint: hint);",
+        dart_comments(&func.comments),
+        dart_deprecation(&func.deprecation),
+        func.mode.dart_return_type(&func.output.dart_api_type()),
+        func.dart_func_name(),
+        dart_api_class_name,
+        param_list,
+        func.dart_func_name(),
+        func.inputs[0].name.dart_style(),
+        arg_list,
+    )
+}
+
+fn generate_api2wire_func(ty: &IrType, ir_file: &IrFile, config: &Opts) -> String {
+    if let Some(body) = TypeDartGenerator::new(ty.clone(), ir_file, config).api2wire_body() {
         format!(
             "{} _api2wire_{}({} raw) {{
             {}
@@ -292,10 +915,11 @@ fn generate_api2wire_func(ty: &IrType, ir_file: &IrFile) -> String {
     }
 }
 
-fn generate_api_fill_to_wire_func(ty: &IrType, ir_file: &IrFile) -> String {
-    if let Some(body) = TypeDartGenerator::new(ty.clone(), ir_file).api_fill_to_wire_body() {
+fn generate_api_fill_to_wire_func(ty: &IrType, ir_file: &IrFile, config: &Opts) -> String {
+    if let Some(body) = TypeDartGenerator::new(ty.clone(), ir_file, config).api_fill_to_wire_body()
+    {
         let target_wire_type = match ty {
-            Optional(inner) => &inner.inner,
+            Optional(inner) if inner.compact_primitive().is_none() => &inner.inner,
             it => it,
         };
 
@@ -313,8 +937,8 @@ fn generate_api_fill_to_wire_func(ty: &IrType, ir_file: &IrFile) -> String {
     }
 }
 
-fn generate_wire2api_func(ty: &IrType, ir_file: &IrFile) -> String {
-    let body = TypeDartGenerator::new(ty.clone(), ir_file).wire2api_body();
+fn generate_wire2api_func(ty: &IrType, ir_file: &IrFile, config: &Opts) -> String {
+    let body = TypeDartGenerator::new(ty.clone(), ir_file, config).wire2api_body();
 
     format!(
         "{} _wire2api_{}(dynamic raw) {{
@@ -331,6 +955,34 @@ fn gen_wire2api_simple_type_cast(s: &str) -> String {
     format!("return raw as {};", s)
 }
 
+/// Whether the wire values of `ty` are stored inline in the structs and lists holding them, so
+/// that they are filled in place rather than assigned an api2wire of their own.
+fn is_filled_in_place(ty: &IrType) -> bool {
+    match ty {
+        StructRef(_)
+        | EnumRef(IrTypeEnumRef {
+            is_struct: true, ..
+        }) => true,
+        Optional(opt) => opt.compact_primitive().is_some(),
+        _ => false,
+    }
+}
+
+/// The `@Deprecated` annotation of a deprecated item, including a trailing newline.
+fn dart_deprecation(deprecation: &Option<IrDeprecation>) -> String {
+    match deprecation {
+        Some(IrDeprecation { note: Some(note) }) => format!(
+            "@Deprecated('{}')\n",
+            note.replace('\\', "\\\\")
+                .replace('\'', "\\'")
+                .replace('$', "\\$")
+                .replace('\n', "\\n")
+        ),
+        Some(IrDeprecation { note: None }) => "@deprecated\n".to_string(),
+        None => "".to_string(),
+    }
+}
+
 /// A trailing newline is included if comments is not empty.
 fn dart_comments(comments: &[IrComment]) -> String {
     let mut comments = comments
@@ -343,3 +995,11 @@ fn dart_comments(comments: &[IrComment]) -> String {
     }
     comments
 }
+
+/// Collects a Dart iterable into the list type `list` expects on the API side.
+fn list_of_iterable(list: &IrType, iterable: &str) -> String {
+    match list {
+        PrimitiveList(_) => format!("{}.fromList({}.toList())", list.dart_api_type(), iterable),
+        _ => format!("{}.toList()", iterable),
+    }
+}
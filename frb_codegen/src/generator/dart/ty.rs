@@ -21,6 +21,7 @@ pub trait TypeDartGeneratorTrait {
 #[derive(Debug, Clone)]
 pub struct TypeGeneratorContext<'a> {
     pub ir_file: &'a IrFile,
+    pub config: &'a Opts,
 }
 
 #[macro_export]
@@ -32,33 +33,50 @@ macro_rules! type_dart_generator_struct {
             pub context: TypeGeneratorContext<'a>,
         }
     };
+    // For the types generated without looking up others in the IR file
+    ($cls:ident, $ir_cls:ty, without_context) => {
+        #[derive(Debug, Clone)]
+        pub struct $cls {
+            pub ir: $ir_cls,
+        }
+    };
 }
 
 #[enum_dispatch(TypeDartGeneratorTrait)]
 #[derive(Debug, Clone)]
 pub enum TypeDartGenerator<'a> {
-    Primitive(TypePrimitiveGenerator<'a>),
+    Primitive(TypePrimitiveGenerator),
     Delegate(TypeDelegateGenerator<'a>),
-    PrimitiveList(TypePrimitiveListGenerator<'a>),
-    Optional(TypeOptionalGenerator<'a>),
-    GeneralList(TypeGeneralListGenerator<'a>),
+    PrimitiveList(TypePrimitiveListGenerator),
+    Optional(TypeOptionalGenerator),
+    GeneralList(TypeGeneralListGenerator),
     StructRef(TypeStructRefGenerator<'a>),
-    Boxed(TypeBoxedGenerator<'a>),
+    Boxed(TypeBoxedGenerator),
     EnumRef(TypeEnumRefGenerator<'a>),
+    Map(TypeMapGenerator),
+    Set(TypeSetGenerator),
+    Opaque(TypeOpaqueGenerator<'a>),
+    Array(TypeArrayGenerator),
+    Int128(TypeInt128Generator),
 }
 
 impl<'a> TypeDartGenerator<'a> {
-    pub fn new(ty: IrType, ir_file: &'a IrFile) -> Self {
-        let context = TypeGeneratorContext { ir_file };
+    pub fn new(ty: IrType, ir_file: &'a IrFile, config: &'a Opts) -> Self {
+        let context = TypeGeneratorContext { ir_file, config };
         match ty {
-            Primitive(ir) => TypePrimitiveGenerator { ir, context }.into(),
+            Primitive(ir) => TypePrimitiveGenerator { ir }.into(),
             Delegate(ir) => TypeDelegateGenerator { ir, context }.into(),
-            PrimitiveList(ir) => TypePrimitiveListGenerator { ir, context }.into(),
-            Optional(ir) => TypeOptionalGenerator { ir, context }.into(),
-            GeneralList(ir) => TypeGeneralListGenerator { ir, context }.into(),
+            PrimitiveList(ir) => TypePrimitiveListGenerator { ir }.into(),
+            Optional(ir) => TypeOptionalGenerator { ir }.into(),
+            GeneralList(ir) => TypeGeneralListGenerator { ir }.into(),
             StructRef(ir) => TypeStructRefGenerator { ir, context }.into(),
-            Boxed(ir) => TypeBoxedGenerator { ir, context }.into(),
+            Boxed(ir) => TypeBoxedGenerator { ir }.into(),
             EnumRef(ir) => TypeEnumRefGenerator { ir, context }.into(),
+            Map(ir) => TypeMapGenerator { ir }.into(),
+            Set(ir) => TypeSetGenerator { ir }.into(),
+            Opaque(ir) => TypeOpaqueGenerator { ir, context }.into(),
+            Array(ir) => TypeArrayGenerator { ir }.into(),
+            Int128(ir) => TypeInt128Generator { ir }.into(),
         }
     }
 }
@@ -0,0 +1,21 @@
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeArrayGenerator, IrTypeArray, without_context);
+
+impl TypeDartGeneratorTrait for TypeArrayGenerator {
+    fn api2wire_body(&self) -> Option<String> {
+        Some(format!(
+            "if (raw.length != {}) throw ArgumentError('Expected a list of {} items, but got ${{raw.length}}');
+                return _api2wire_{}(raw);",
+            self.ir.length,
+            self.ir.length,
+            self.ir.list().safe_ident(),
+        ))
+    }
+
+    fn wire2api_body(&self) -> String {
+        format!("return _wire2api_{}(raw);", self.ir.list().safe_ident())
+    }
+}
@@ -1,17 +1,28 @@
 use crate::generator::dart::gen_wire2api_simple_type_cast;
 use crate::generator::dart::ty::*;
-use crate::ir::IrType::{Primitive, StructRef};
+use crate::ir::IrType::{Delegate, EnumRef, Primitive};
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
-type_dart_generator_struct!(TypeBoxedGenerator, IrTypeBoxed);
+type_dart_generator_struct!(TypeBoxedGenerator, IrTypeBoxed, without_context);
 
-impl TypeDartGeneratorTrait for TypeBoxedGenerator<'_> {
+impl TypeDartGeneratorTrait for TypeBoxedGenerator {
     fn api2wire_body(&self) -> Option<String> {
+        if self.ir.reuses_inner_pointer() {
+            return Some(format!(
+                "return _api2wire_{}(raw);",
+                self.ir.inner.safe_ident()
+            ));
+        }
         Some(match &*self.ir.inner {
             Primitive(_) => {
                 format!("return inner.new_{}(raw);", self.ir.safe_ident())
             }
+            inner @ (Delegate(_) | EnumRef(_)) if self.ir.inner_primitive().is_some() => format!(
+                "return inner.new_{}(_api2wire_{}(raw));",
+                self.ir.safe_ident(),
+                inner.safe_ident()
+            ),
             inner => {
                 format!(
                     "final ptr = inner.new_{}();
@@ -25,7 +36,7 @@ impl TypeDartGeneratorTrait for TypeBoxedGenerator<'_> {
     }
 
     fn api_fill_to_wire_body(&self) -> Option<String> {
-        if !matches!(*self.ir.inner, Primitive(_)) {
+        if self.ir.inner_primitive().is_none() && !self.ir.reuses_inner_pointer() {
             Some(format!(
                 " _api_fill_to_wire_{}(apiObj, wireObj.ref);",
                 self.ir.inner.safe_ident()
@@ -37,8 +48,8 @@ impl TypeDartGeneratorTrait for TypeBoxedGenerator<'_> {
 
     fn wire2api_body(&self) -> String {
         match &*self.ir.inner {
-            StructRef(inner) => format!("return _wire2api_{}(raw);", inner.safe_ident()),
-            _ => gen_wire2api_simple_type_cast(&self.ir.dart_api_type()),
+            Primitive(_) => gen_wire2api_simple_type_cast(&self.ir.dart_api_type()),
+            inner => format!("return _wire2api_{}(raw);", inner.safe_ident()),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::config::{DartUuidType, NaiveDateTimeZone, NonZeroInts};
 use crate::generator::dart::gen_wire2api_simple_type_cast;
 use crate::generator::dart::ty::*;
 use crate::ir::*;
@@ -7,8 +8,8 @@ type_dart_generator_struct!(TypeDelegateGenerator, IrTypeDelegate);
 
 impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
     fn api2wire_body(&self) -> Option<String> {
-        Some(match self.ir {
-            IrTypeDelegate::String => {
+        Some(match &self.ir {
+            IrTypeDelegate::String | IrTypeDelegate::Str => {
                 "return _api2wire_uint_8_list(utf8.encoder.convert(raw));".to_string()
             }
             IrTypeDelegate::SyncReturnVecU8 => "/*unsupported*/".to_string(),
@@ -24,19 +25,130 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
             }
             return ans;"
                 .to_owned(),
+            IrTypeDelegate::Time(IrTypeDelegateTime::Naive)
+                if self.context.config.naive_date_time_zone == NaiveDateTimeZone::Local =>
+            {
+                // Rust reads the microseconds as a UTC wall-clock time
+                "final local = raw.toLocal();
+                return DateTime.utc(local.year, local.month, local.day, local.hour, local.minute,
+                    local.second, local.millisecond, local.microsecond).microsecondsSinceEpoch;"
+                    .to_owned()
+            }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => {
+                "if (raw.isNegative) throw ArgumentError.value(raw, 'raw', 'Rust durations cannot be negative');
+                return raw.inMicroseconds;"
+                    .to_owned()
+            }
+            IrTypeDelegate::Time(_) => "return raw.microsecondsSinceEpoch;".to_owned(),
+            IrTypeDelegate::Uuid(DartUuidType::Bytes) => format!(
+                "if (raw.length != 16) throw ArgumentError.value(raw, 'raw', 'A UUID has 16 bytes');
+                return _api2wire_{}(raw);",
+                self.ir.get_delegate().safe_ident()
+            ),
+            IrTypeDelegate::Uuid(DartUuidType::UuidValue) => format!(
+                "return _api2wire_{}(Uint8List.fromList(raw.toBytes()));",
+                self.ir.get_delegate().safe_ident()
+            ),
+            // Surrogates are code points, but not Rust `char`s
+            IrTypeDelegate::Char => {
+                "if (raw < 0 || raw > 0x10FFFF || (raw >= 0xD800 && raw <= 0xDFFF)) {
+                    throw ArgumentError.value(raw, 'raw', 'Not a Unicode scalar value');
+                }
+                return raw;"
+                    .to_owned()
+            }
+            IrTypeDelegate::BigInt64(IrTypePrimitive::U64) => {
+                // `toSigned` keeps the 64 bits as a Dart `int`, which the `Uint64` stores as is
+                "if (raw.isNegative || raw.bitLength > 64) {
+                    throw ArgumentError.value(raw, 'raw', 'Out of range for u64');
+                }
+                return raw.toSigned(64).toInt();"
+                    .to_owned()
+            }
+            IrTypeDelegate::BigInt64(_) => {
+                "if (raw.bitLength > 63) throw ArgumentError.value(raw, 'raw', 'Out of range for i64');
+                return raw.toInt();"
+                    .to_owned()
+            }
+            IrTypeDelegate::BoolList => format!(
+                "final ans = inner.new_{}(raw.length);
+                final bytes = ans.ref.ptr.asTypedList(raw.length);
+                for (var i = 0; i < raw.length; i++) {{
+                    bytes[i] = raw[i] ? 1 : 0;
+                }}
+                return ans;",
+                self.ir.get_delegate().safe_ident()
+            ),
+            IrTypeDelegate::NonZero(inner, NonZeroInts::Error) => format!(
+                "if (raw == {}) throw ArgumentError.value(raw, 'raw', 'Must not be zero');
+                return _api2wire_{}(raw);",
+                if inner.dart_api_type() == "BigInt" { "BigInt.zero" } else { "0" },
+                inner.safe_ident()
+            ),
+            IrTypeDelegate::Json => "return _api2wire_String(jsonEncode(raw));".to_owned(),
+            // Rust turns the zero into one
+            IrTypeDelegate::Slice(_)
+            | IrTypeDelegate::BoxedSlice(_)
+            | IrTypeDelegate::Cow(_)
+            | IrTypeDelegate::PathBuf(_)
+            | IrTypeDelegate::Path(_)
+            | IrTypeDelegate::NonZero(_, NonZeroInts::Clamp) => format!(
+                "return _api2wire_{}(raw);",
+                self.ir.get_delegate().safe_ident()
+            ),
         })
     }
 
     fn wire2api_body(&self) -> String {
         match &self.ir {
-            IrTypeDelegate::String
-            | IrTypeDelegate::SyncReturnVecU8
-            | IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
+            // The strings of streams and errors are sent by Rust as Dart strings already
+            IrTypeDelegate::String | IrTypeDelegate::Str => "if (raw is String) return raw;
+            return utf8.decode(raw as Uint8List);"
+                .to_owned(),
+            IrTypeDelegate::SyncReturnVecU8 | IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
                 gen_wire2api_simple_type_cast(&self.ir.dart_api_type())
             }
             IrTypeDelegate::StringList => {
                 "return (raw as List<dynamic>).cast<String>();".to_owned()
             }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Naive)
+                if self.context.config.naive_date_time_zone == NaiveDateTimeZone::Local =>
+            {
+                "final utc = DateTime.fromMicrosecondsSinceEpoch(raw as int, isUtc: true);
+                return DateTime(utc.year, utc.month, utc.day, utc.hour, utc.minute, utc.second,
+                    utc.millisecond, utc.microsecond);"
+                    .to_owned()
+            }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => {
+                "return Duration(microseconds: raw as int);".to_owned()
+            }
+            IrTypeDelegate::Time(time) => format!(
+                "return DateTime.fromMicrosecondsSinceEpoch(raw as int, isUtc: {});",
+                !matches!(time, IrTypeDelegateTime::Local)
+            ),
+            IrTypeDelegate::Uuid(DartUuidType::Bytes) => "return raw as Uint8List;".to_owned(),
+            IrTypeDelegate::Uuid(DartUuidType::UuidValue) => {
+                "return UuidValue.fromByteList(raw as Uint8List);".to_owned()
+            }
+            IrTypeDelegate::Char => gen_wire2api_simple_type_cast(&self.ir.dart_api_type()),
+            // Rust sends the bits of a `u64` as those of an `i64`
+            IrTypeDelegate::BigInt64(IrTypePrimitive::U64) => {
+                "return BigInt.from(raw as int).toUnsigned(64);".to_owned()
+            }
+            IrTypeDelegate::BigInt64(_) => "return BigInt.from(raw as int);".to_owned(),
+            IrTypeDelegate::BoolList => "final bytes = raw as Uint8List;
+            return List<bool>.generate(bytes.length, (i) => bytes[i] != 0, growable: false);"
+                .to_owned(),
+            IrTypeDelegate::Json => "return jsonDecode(_wire2api_String(raw));".to_owned(),
+            IrTypeDelegate::Slice(_)
+            | IrTypeDelegate::BoxedSlice(_)
+            | IrTypeDelegate::Cow(_)
+            | IrTypeDelegate::PathBuf(_)
+            | IrTypeDelegate::Path(_)
+            | IrTypeDelegate::NonZero(..) => format!(
+                "return _wire2api_{}(raw);",
+                self.ir.get_delegate().safe_ident()
+            ),
         }
     }
 }
@@ -1,5 +1,8 @@
-use crate::generator::dart::dart_comments;
+use crate::config::DartEnumsStyle;
 use crate::generator::dart::ty::*;
+use crate::generator::dart::{
+    dart_comments, dart_deprecation, generate_enum_json, is_filled_in_place, json_classes,
+};
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
@@ -8,14 +11,38 @@ type_dart_generator_struct!(TypeEnumRefGenerator, IrTypeEnumRef);
 impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
     fn api2wire_body(&self) -> Option<String> {
         if !self.ir.is_struct {
-            Some("return raw.index;".to_owned())
+            let enu = self.ir.get(self.context.ir_file);
+            let guard = if enu.is_non_exhaustive {
+                format!(
+                    "if (raw == {}.unknown) throw ArgumentError('{}');\n",
+                    self.ir.name,
+                    unknown_send_error(&self.ir)
+                )
+            } else {
+                "".to_owned()
+            };
+            Some(
+                guard
+                    + &if enu.has_explicit_discriminants() {
+                        format!(
+                            "return const [{}][raw.index];",
+                            enu.discriminants()
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    } else {
+                        "return raw.index;".to_owned()
+                    },
+            )
         } else {
             None
         }
     }
 
     fn api_fill_to_wire_body(&self) -> Option<String> {
-        if self.ir.is_struct {
+        if self.ir.is_trait_object {
             Some(
                 self.ir
                     .get(self.context.ir_file)
@@ -23,6 +50,37 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                     .iter()
                     .enumerate()
                     .map(|(idx, variant)| {
+                        let field = trait_object_field(variant);
+                        format!(
+                            "if (apiObj is {0}) {{
+                            wireObj.tag = {1};
+                            wireObj.kind = inner.inflate_{2}_{3}();
+                            wireObj.kind.ref.{3}.ref.field0 = _api2wire_{4}(apiObj);
+                            return;
+                        }}",
+                            field.ty.dart_api_type(),
+                            idx,
+                            self.ir.name,
+                            variant.name,
+                            field.ty.safe_ident(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        } else if self.ir.is_struct {
+            let enu = self.ir.get(self.context.ir_file);
+            let guard = enu.is_non_exhaustive.then(|| {
+                format!(
+                    "if (apiObj is {}) throw ArgumentError('{}');",
+                    unknown_class_name(&self.ir),
+                    unknown_send_error(&self.ir)
+                )
+            });
+            Some(
+                guard
+                    .into_iter()
+                    .chain(enu.variants().iter().enumerate().map(|(idx, variant)| {
                         if let IrVariantKind::Value = &variant.kind {
                             format!(
                                 "if (apiObj is {}) {{ wireObj.tag = {}; return; }}",
@@ -35,13 +93,23 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                                     .fields
                                     .iter()
                                     .map(|field| {
-                                        format!(
-                                            "{}.{} = _api2wire_{}(apiObj.{});",
-                                            r,
-                                            field.name.rust_style(),
-                                            field.ty.safe_ident(),
-                                            field.name.dart_style()
-                                        )
+                                        if is_filled_in_place(&field.ty) {
+                                            format!(
+                                                "_api_fill_to_wire_{}(apiObj.{}, {}.{});",
+                                                field.ty.safe_ident(),
+                                                field.name.dart_style(),
+                                                r,
+                                                field.name.rust_style()
+                                            )
+                                        } else {
+                                            format!(
+                                                "{}.{} = _api2wire_{}(apiObj.{});",
+                                                r,
+                                                field.name.rust_style(),
+                                                field.ty.safe_ident(),
+                                                field.name.dart_style()
+                                            )
+                                        }
                                     })
                                     .collect(),
                                 _ => unreachable!(),
@@ -58,7 +126,7 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                                 body.join("\n")
                             )
                         }
-                    })
+                    }))
                     .collect::<Vec<_>>()
                     .join("\n"),
             )
@@ -68,7 +136,28 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
     }
 
     fn wire2api_body(&self) -> String {
-        if self.ir.is_struct {
+        if self.ir.is_trait_object {
+            let enu = self.ir.get(self.context.ir_file);
+            let variants = enu
+                .variants()
+                .iter()
+                .enumerate()
+                .map(|(idx, variant)| {
+                    format!(
+                        "case {}: return _wire2api_{}(raw[1]);",
+                        idx,
+                        trait_object_field(variant).ty.safe_ident()
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "switch (raw[0]) {{
+                    {}
+                    default: throw Exception(\"unreachable\");
+                }}",
+                variants.join("\n"),
+            )
+        } else if self.ir.is_struct {
             let enu = self.ir.get(self.context.ir_file);
             let variants = enu
                 .variants()
@@ -99,15 +188,62 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                     format!("case {}: return {}({});", idx, variant.name, args)
                 })
                 .collect::<Vec<_>>();
+            let default = if enu.is_non_exhaustive {
+                format!("return {}();", unknown_class_name(&self.ir))
+            } else {
+                "throw Exception(\"unreachable\");".to_owned()
+            };
             format!(
                 "switch (raw[0]) {{
                     {}
-                    default: throw Exception(\"unreachable\");
+                    default: {}
                 }}",
                 variants.join("\n"),
+                default,
             )
         } else {
-            format!("return {}.values[raw];", self.ir.name)
+            let enu = self.ir.get(self.context.ir_file);
+            if enu.has_explicit_discriminants() {
+                let variants = enu
+                    .variants()
+                    .iter()
+                    .zip(enu.discriminants())
+                    .map(|(variant, discriminant)| {
+                        format!(
+                            "case {}: return {}.{};",
+                            discriminant,
+                            self.ir.name,
+                            variant.name.rust_style()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let default = if enu.is_non_exhaustive {
+                    format!("return {}.unknown;", self.ir.name)
+                } else {
+                    format!(
+                        "throw Exception(\"Invalid variant for {}: $raw\");",
+                        self.ir.name
+                    )
+                };
+                format!(
+                    "switch (raw as int) {{
+                        {}
+                        default: {}
+                    }}",
+                    variants.join("\n"),
+                    default,
+                )
+            } else if enu.is_non_exhaustive {
+                // `unknown` is the last of the values
+                format!(
+                    "final index = raw as int;
+                    return index < {1} ? {0}.values[index] : {0}.unknown;",
+                    self.ir.name,
+                    enu.variants().len()
+                )
+            } else {
+                format!("return {}.values[raw];", self.ir.name)
+            }
         }
     }
 
@@ -115,74 +251,21 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
         let src = self.ir.get(self.context.ir_file);
 
         let comments = dart_comments(&src.comments);
-        if src.is_struct() {
-            let variants = src
-                .variants()
-                .iter()
-                .map(|variant| {
-                    let args = match &variant.kind {
-                        IrVariantKind::Value => "".to_owned(),
-                        IrVariantKind::Struct(IrStruct {
-                            is_fields_named: false,
-                            fields,
-                            ..
-                        }) => {
-                            let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
-                            let split = optional_boundary_index(&types);
-                            let types = fields
-                                .iter()
-                                .map(|field| {
-                                    format!(
-                                        "{}{} {},",
-                                        dart_comments(&field.comments),
-                                        field.ty.dart_api_type(),
-                                        field.name.dart_style()
-                                    )
-                                })
-                                .collect::<Vec<_>>();
-                            if let Some(idx) = split {
-                                let before = &types[..idx];
-                                let after = &types[idx..];
-                                format!("{}[{}]", before.join(""), after.join(""))
-                            } else {
-                                types.join(",")
-                            }
-                        }
-                        IrVariantKind::Struct(st) => {
-                            let fields = st
-                                .fields
-                                .iter()
-                                .map(|field| {
-                                    format!(
-                                        "{}{}{} {},",
-                                        dart_comments(&field.comments),
-                                        field.ty.dart_required_modifier(),
-                                        field.ty.dart_api_type(),
-                                        field.name.dart_style()
-                                    )
-                                })
-                                .collect::<Vec<_>>();
-                            format!("{{ {} }}", fields.join(""))
-                        }
-                    };
-                    format!(
-                        "{}const factory {}.{}({}) = {};",
-                        dart_comments(&variant.comments),
-                        self.ir.name,
-                        variant.name.dart_style(),
-                        args,
-                        variant.name.rust_style(),
-                    )
-                })
-                .collect::<Vec<_>>();
-            format!(
-                "@freezed
-                class {0} with _${0} {{
-                    {1}
-                }}",
-                self.ir.name,
-                variants.join("\n")
-            )
+        if src.is_trait_object {
+            // Implemented by the classes of the implementors
+            format!("{}abstract class {} {{}}", comments, self.ir.name)
+        } else if src.is_struct() {
+            let json = if self.context.config.dart_json
+                && json_classes(self.context.ir_file).contains(&self.ir.name)
+            {
+                generate_enum_json(src, self.context.config)
+            } else {
+                "".to_string()
+            };
+            match self.context.config.dart_enums_style {
+                DartEnumsStyle::Freezed => self.structs_freezed(src, &json),
+                DartEnumsStyle::Classes => self.structs_classes(src, &json),
+            }
         } else {
             let variants = src
                 .variants()
@@ -194,6 +277,10 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                         variant.name.rust_style()
                     )
                 })
+                .chain(
+                    src.is_non_exhaustive
+                        .then(|| format!("{}unknown,", UNKNOWN_COMMENT)),
+                )
                 .collect::<Vec<_>>()
                 .join("\n");
             format!(
@@ -205,3 +292,339 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
         }
     }
 }
+
+impl TypeEnumRefGenerator<'_> {
+    /// `json` is the JSON methods of the union, if any
+    fn structs_freezed(&self, src: &IrEnum, json: &str) -> String {
+        let variants = src
+            .variants()
+            .iter()
+            .map(|variant| {
+                let args = match &variant.kind {
+                    IrVariantKind::Value => "".to_owned(),
+                    IrVariantKind::Struct(st) => variant_params(st, true, |field| {
+                        format!("{} {}", field.ty.dart_api_type(), field.name.dart_style())
+                    }),
+                };
+                format!(
+                    "{}const factory {}.{}({}) = {};",
+                    dart_comments(&variant.comments),
+                    self.ir.name,
+                    variant.name.dart_style(),
+                    args,
+                    variant.name.rust_style(),
+                )
+            })
+            .chain(src.is_non_exhaustive.then(|| {
+                format!(
+                    "{}const factory {}.unknown() = {};",
+                    UNKNOWN_COMMENT,
+                    self.ir.name,
+                    unknown_class_name(&self.ir)
+                )
+            }))
+            .collect::<Vec<_>>();
+        // freezed only lets a union have methods of its own through a private constructor
+        let private_constructor = if json.is_empty() {
+            "".to_string()
+        } else {
+            format!("const {}._();\n", self.ir.name)
+        };
+        format!(
+            "@freezed
+            class {0} with _${0} {{
+                {1}{2}{3}
+            }}",
+            self.ir.name,
+            private_constructor,
+            variants.join("\n"),
+            json,
+        )
+    }
+
+    /// `json` is the JSON methods of the base class, if any
+    fn structs_classes(&self, src: &IrEnum, json: &str) -> String {
+        let variants = src
+            .variants()
+            .iter()
+            .map(|variant| {
+                let (fields, args) = match &variant.kind {
+                    IrVariantKind::Value => (vec![], "".to_owned()),
+                    IrVariantKind::Struct(st) => (
+                        st.fields
+                            .iter()
+                            .map(|field| {
+                                format!(
+                                    "{}{}final {} {};",
+                                    dart_comments(&field.comments),
+                                    dart_deprecation(&field.deprecation),
+                                    field.ty.dart_api_type(),
+                                    field.name.dart_style()
+                                )
+                            })
+                            .collect(),
+                        variant_params(st, false, |field| {
+                            format!("this.{}", field.name.dart_style())
+                        }),
+                    ),
+                };
+                format!(
+                    "{}class {} extends {} {{
+                        {}
+
+                        const {}({});
+                    }}",
+                    dart_comments(&variant.comments),
+                    variant.name.rust_style(),
+                    self.ir.name,
+                    fields.join("\n"),
+                    variant.name.rust_style(),
+                    args,
+                )
+            })
+            .chain(src.is_non_exhaustive.then(|| {
+                format!(
+                    "{0}class {1} extends {2} {{
+                        const {1}();
+                    }}",
+                    UNKNOWN_COMMENT,
+                    unknown_class_name(&self.ir),
+                    self.ir.name,
+                )
+            }))
+            .collect::<Vec<_>>();
+        format!(
+            "{}abstract class {} {{
+                const {}();{}
+            }}
+
+            {}",
+            dart_comments(&src.comments),
+            self.ir.name,
+            self.ir.name,
+            json,
+            variants.join("\n\n")
+        )
+    }
+
+    /// The exception classes thrown for the enum when a function returns it as its error: a base
+    /// class, only extended here, and a subclass for each variant holding the error
+    pub fn exceptions(&self) -> String {
+        let src = self.ir.get(self.context.ir_file);
+        let base = exception_class_name(&self.ir);
+        let variants = src
+            .variants()
+            .iter()
+            .map(|variant| {
+                // The variants of an enum with data have classes of their own
+                let error_type = if self.ir.is_struct {
+                    variant.name.rust_style()
+                } else {
+                    &self.ir.name
+                };
+                format!(
+                    "{}class {} extends {} {{
+                        @override
+                        final {} error;
+
+                        const {}(this.error) : super._();
+                    }}",
+                    dart_comments(&variant.comments),
+                    variant_exception_class_name(&self.ir, variant),
+                    base,
+                    error_type,
+                    variant_exception_class_name(&self.ir, variant),
+                )
+            })
+            .chain(src.is_non_exhaustive.then(|| {
+                let error_type = if self.ir.is_struct {
+                    unknown_class_name(&self.ir)
+                } else {
+                    self.ir.name.clone()
+                };
+                format!(
+                    "{0}class {1} extends {2} {{
+                        @override
+                        final {3} error;
+
+                        const {1}(this.error) : super._();
+                    }}",
+                    UNKNOWN_COMMENT,
+                    unknown_exception_class_name(&self.ir),
+                    base,
+                    error_type,
+                )
+            }))
+            .collect::<Vec<_>>();
+        format!(
+            "/// Thrown when Rust returns a [{0}] as an error, as the subclass of its variant.
+            abstract class {1} implements Exception {{
+                const {1}._();
+
+                /// The error returned by Rust
+                {0} get error;
+
+                @override
+                String toString() => '{1}($error)';
+            }}
+
+            {2}",
+            self.ir.name,
+            base,
+            variants.join("\n\n"),
+        )
+    }
+
+    /// The function reading the error sent by Rust into the exception of its variant, told apart
+    /// by the tag of its variant, or by the value itself when the enum has no data
+    pub fn wire2api_exception_func(&self) -> String {
+        let src = self.ir.get(self.context.ir_file);
+        let variants = src
+            .variants()
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                if self.ir.is_struct {
+                    format!(
+                        "case {}: return {}(error as {});",
+                        idx,
+                        variant_exception_class_name(&self.ir, variant),
+                        variant.name.rust_style()
+                    )
+                } else {
+                    format!(
+                        "case {}.{}: return {}(error);",
+                        self.ir.name,
+                        variant.name.rust_style(),
+                        variant_exception_class_name(&self.ir, variant)
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        let default = match (src.is_non_exhaustive, self.ir.is_struct) {
+            (false, _) => "throw Exception(\"unreachable\");".to_owned(),
+            (true, true) => format!(
+                "return {}(error as {});",
+                unknown_exception_class_name(&self.ir),
+                unknown_class_name(&self.ir)
+            ),
+            (true, false) => format!("return {}(error);", unknown_exception_class_name(&self.ir)),
+        };
+        format!(
+            "{} _wire2api_{}_exception(dynamic raw) {{
+                final error = _wire2api_{}(raw);
+                switch ({}) {{
+                    {}
+                    default: {}
+                }}
+            }}
+            ",
+            exception_class_name(&self.ir),
+            self.ir.safe_ident(),
+            self.ir.safe_ident(),
+            if self.ir.is_struct { "raw[0]" } else { "error" },
+            variants.join("\n"),
+            default,
+        )
+    }
+}
+
+/// The enum of an error that is thrown as the exception of its variant, if it is one
+pub fn error_enum(error: &IrType) -> Option<&IrTypeEnumRef> {
+    match error {
+        IrType::EnumRef(enum_ref) if !enum_ref.is_trait_object => Some(enum_ref),
+        _ => None,
+    }
+}
+
+/// The documentation of the fallback of a `#[non_exhaustive]` enum
+const UNKNOWN_COMMENT: &str =
+    "/// A variant the `#[non_exhaustive]` Rust enum has gained since this code was generated.
+    /// It cannot be sent to Rust.\n";
+
+/// The class of the fallback of a `#[non_exhaustive]` enum with data, prefixed since the classes of
+/// the variants are not
+fn unknown_class_name(enum_ref: &IrTypeEnumRef) -> String {
+    format!("{}Unknown", enum_ref.name)
+}
+
+fn unknown_exception_class_name(enum_ref: &IrTypeEnumRef) -> String {
+    format!("{}UnknownException", enum_ref.name)
+}
+
+/// Rust has no variant to read the fallback of a `#[non_exhaustive]` enum into
+fn unknown_send_error(enum_ref: &IrTypeEnumRef) -> String {
+    format!(
+        "The unknown variant of {} cannot be sent to Rust",
+        enum_ref.name
+    )
+}
+
+fn exception_class_name(enum_ref: &IrTypeEnumRef) -> String {
+    format!("{}Exception", enum_ref.name)
+}
+
+fn variant_exception_class_name(enum_ref: &IrTypeEnumRef, variant: &IrVariant) -> String {
+    format!("{}{}Exception", enum_ref.name, variant.name.rust_style())
+}
+
+/// The field holding the implementor in a variant of the enum standing for a trait object
+fn trait_object_field(variant: &IrVariant) -> &IrField {
+    match &variant.kind {
+        IrVariantKind::Struct(st) => &st.fields[0],
+        IrVariantKind::Value => unreachable!(),
+    }
+}
+
+/// Parameter list of a variant constructor, where `param` formats each field.
+/// Named fields become named parameters, and trailing optional positional fields
+/// become optional positional parameters. Deprecated fields are annotated here, as
+/// annotations must come before `required`, and so are the comments of the fields when
+/// `documented`, which come first.
+fn variant_params(st: &IrStruct, documented: bool, param: impl Fn(&IrField) -> String) -> String {
+    let comments = |field: &IrField| {
+        if documented {
+            dart_comments(&field.comments)
+        } else {
+            "".to_owned()
+        }
+    };
+    if st.is_fields_named {
+        let fields = st
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}{}{}{},",
+                    comments(field),
+                    dart_deprecation(&field.deprecation),
+                    field.ty.dart_required_modifier(),
+                    param(field)
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("{{ {} }}", fields.join(""))
+    } else {
+        let types = st.fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+        let split = optional_boundary_index(&types);
+        let params = st
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}{}{},",
+                    comments(field),
+                    dart_deprecation(&field.deprecation),
+                    param(field)
+                )
+            })
+            .collect::<Vec<_>>();
+        if let Some(idx) = split {
+            let before = &params[..idx];
+            let after = &params[idx..];
+            format!("{}[{}]", before.join(""), after.join(""))
+        } else {
+            params.join("")
+        }
+    }
+}
@@ -1,20 +1,29 @@
+use crate::generator::dart::is_filled_in_place;
 use crate::generator::dart::ty::*;
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
-type_dart_generator_struct!(TypeGeneralListGenerator, IrTypeGeneralList);
+type_dart_generator_struct!(TypeGeneralListGenerator, IrTypeGeneralList, without_context);
 
-impl TypeDartGeneratorTrait for TypeGeneralListGenerator<'_> {
+impl TypeDartGeneratorTrait for TypeGeneralListGenerator {
     fn api2wire_body(&self) -> Option<String> {
         // NOTE the memory strategy is same as PrimitiveList, see comments there.
+        // Struct-like items are stored inline, while other items, such as strings, are pointers.
+        let fill_item = match &*self.ir.inner {
+            inner if is_filled_in_place(inner) => format!(
+                "_api_fill_to_wire_{}(raw[i], ans.ref.ptr[i]);",
+                inner.safe_ident()
+            ),
+            inner => format!("ans.ref.ptr[i] = _api2wire_{}(raw[i]);", inner.safe_ident()),
+        };
         Some(format!(
             "final ans = inner.new_{}(raw.length);
                 for (var i = 0; i < raw.length; ++i) {{
-                    _api_fill_to_wire_{}(raw[i], ans.ref.ptr[i]);
+                    {}
                 }}
                 return ans;",
             self.ir.safe_ident(),
-            self.ir.inner.safe_ident()
+            fill_item,
         ))
     }
 
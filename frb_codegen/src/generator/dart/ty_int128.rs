@@ -0,0 +1,39 @@
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeInt128Generator, IrTypeInt128, without_context);
+
+impl TypeDartGeneratorTrait for TypeInt128Generator {
+    fn api2wire_body(&self) -> Option<String> {
+        let in_range = if self.ir.is_signed() {
+            "raw.bitLength <= 127"
+        } else {
+            "!raw.isNegative && raw.bitLength <= 128"
+        };
+        // `toSigned` keeps the low 64 bits as a Dart `int`, which the `Uint64` fields store as is
+        Some(format!(
+            "if (!({})) throw ArgumentError.value(raw, 'raw', 'Out of range for {}');
+                final ans = inner.new_{}();
+                ans.ref.hi = (raw >> 64).toSigned(64).toInt();
+                ans.ref.lo = raw.toSigned(64).toInt();
+                return ans;",
+            in_range,
+            self.ir.rust_api_type(),
+            self.ir.safe_ident(),
+        ))
+    }
+
+    fn wire2api_body(&self) -> String {
+        let hi = if self.ir.is_signed() {
+            "BigInt.from(arr[0])"
+        } else {
+            "BigInt.from(arr[0]).toUnsigned(64)"
+        };
+        format!(
+            "final arr = raw as List<int>;
+                return ({} << 64) | BigInt.from(arr[1]).toUnsigned(64);",
+            hi
+        )
+    }
+}
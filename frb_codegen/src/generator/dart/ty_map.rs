@@ -0,0 +1,30 @@
+use crate::generator::dart::list_of_iterable;
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeMapGenerator, IrTypeMap, without_context);
+
+impl TypeDartGeneratorTrait for TypeMapGenerator {
+    fn api2wire_body(&self) -> Option<String> {
+        Some(format!(
+            "final ans = inner.new_{}();
+                ans.ref.keys = _api2wire_{}({});
+                ans.ref.values = _api2wire_{}({});
+                return ans;",
+            self.ir.safe_ident(),
+            self.ir.key_list().safe_ident(),
+            list_of_iterable(&self.ir.key_list(), "raw.keys"),
+            self.ir.value_list().safe_ident(),
+            list_of_iterable(&self.ir.value_list(), "raw.values"),
+        ))
+    }
+
+    fn wire2api_body(&self) -> String {
+        format!(
+            "return Map.fromEntries((raw as List<dynamic>).map((entry) => MapEntry(_wire2api_{}(entry[0]), _wire2api_{}(entry[1]))));",
+            self.ir.key.safe_ident(),
+            self.ir.value.safe_ident(),
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use crate::generator::dart::ty::*;
+use crate::generator::dart::{dart_comments, generate_api_method};
+use crate::ir::*;
+use crate::type_dart_generator_struct;
+use convert_case::{Case, Casing};
+
+type_dart_generator_struct!(TypeOpaqueGenerator, IrTypeOpaque);
+
+impl TypeDartGeneratorTrait for TypeOpaqueGenerator<'_> {
+    fn api2wire_body(&self) -> Option<String> {
+        Some(format!(
+            "if (raw.isDisposed) throw StateError('{} was used after being disposed');
+                final ans = inner.new_{}();
+                ans.ref.ptr = ffi.Pointer.fromAddress(raw.address);
+                return ans;",
+            self.ir.dart_name,
+            self.ir.safe_ident(),
+        ))
+    }
+
+    fn wire2api_body(&self) -> String {
+        if self.ir.has_finalizer {
+            format!(
+                "final ans = {}(bridge: this, address: raw as int);
+                    {}.attach(ans, ffi.Pointer.fromAddress(ans.address), detach: ans);
+                    return ans;",
+                self.ir.dart_name,
+                self.ir.dart_finalizer_name(),
+            )
+        } else {
+            format!(
+                "return {}(bridge: this, address: raw as int);",
+                self.ir.dart_name
+            )
+        }
+    }
+
+    fn structs(&self) -> String {
+        let methods = self
+            .context
+            .ir_file
+            .methods_of(&self.ir.name)
+            .map(|func| generate_api_method(func, func.method.as_ref().unwrap()))
+            .collect::<Vec<_>>();
+
+        // The object is kept alive by Dart while it is being passed to Rust, see `ffi.Finalizable`
+        let (implements, dispose_comment) = if self.ir.has_finalizer {
+            (
+                " implements ffi.Finalizable",
+                "Releases the Rust object without waiting for this object to be garbage collected.",
+            )
+        } else {
+            ("", "Releases the Rust object, which is otherwise leaked.")
+        };
+
+        format!(
+            "{}class {name}{implements} {{
+                final {api} bridge;
+
+                /// The address of the Rust object, which stays alive until [dispose] is called.
+                final int address;
+
+                bool _isDisposed = false;
+
+                {name}({{required this.bridge, required this.address}});
+
+                /// Whether [dispose] was called, after which this object can no longer be passed to Rust.
+                bool get isDisposed => _isDisposed;
+
+                /// {dispose_comment} Calls that are still running keep it alive until they finish.
+                void dispose() {{
+                    if (_isDisposed) return;
+                    _isDisposed = true;
+                    bridge.{drop}(this);
+                }}
+
+                {}
+            }}",
+            dart_comments(&self.ir.comments),
+            methods.join("\n\n"),
+            name = self.ir.dart_name,
+            implements = implements,
+            dispose_comment = dispose_comment,
+            api = self.context.config.dart_api_class_name(),
+            drop = self.ir.drop_func_name().to_case(Case::Camel),
+        )
+    }
+}
@@ -2,24 +2,32 @@ use crate::generator::dart::ty::*;
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
-type_dart_generator_struct!(TypeOptionalGenerator, IrTypeOptional);
+type_dart_generator_struct!(TypeOptionalGenerator, IrTypeOptional, without_context);
 
-impl TypeDartGeneratorTrait for TypeOptionalGenerator<'_> {
+impl TypeDartGeneratorTrait for TypeOptionalGenerator {
     fn api2wire_body(&self) -> Option<String> {
-        Some(format!(
-            "return raw == null ? ffi.nullptr : _api2wire_{}(raw);",
-            self.ir.inner.safe_ident()
-        ))
+        Some(match self.ir.compact_primitive() {
+            Some(prim) => format!(
+                "return inner.new_{}(raw != null, raw ?? {});",
+                self.ir.safe_ident(),
+                dart_zero(prim)
+            ),
+            None => format!(
+                "return raw == null ? ffi.nullptr : _api2wire_{}(raw);",
+                self.ir.inner.safe_ident()
+            ),
+        })
     }
 
     fn api_fill_to_wire_body(&self) -> Option<String> {
-        if !self.ir.needs_initialization() || self.ir.is_list() {
-            return None;
-        }
-        Some(format!(
-            "if (apiObj != null) _api_fill_to_wire_{}(apiObj, wireObj);",
-            self.ir.inner.safe_ident()
-        ))
+        self.ir.compact_primitive().map(|prim| {
+            format!(
+                "wireObj.is_some = apiObj != null ? 1 : 0;
+                wireObj.value = _api2wire_{}(apiObj ?? {});",
+                self.ir.inner.safe_ident(),
+                dart_zero(prim)
+            )
+        })
     }
 
     fn wire2api_body(&self) -> String {
@@ -29,3 +37,12 @@ impl TypeDartGeneratorTrait for TypeOptionalGenerator<'_> {
         )
     }
 }
+
+/// The value sent next to the presence flag of `null`
+fn dart_zero(prim: &IrTypePrimitive) -> &'static str {
+    match prim {
+        IrTypePrimitive::Bool => "false",
+        IrTypePrimitive::F32 | IrTypePrimitive::F64 => "0.0",
+        _ => "0",
+    }
+}
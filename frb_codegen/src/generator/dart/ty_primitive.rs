@@ -3,9 +3,9 @@ use crate::generator::dart::ty::*;
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
-type_dart_generator_struct!(TypePrimitiveGenerator, IrTypePrimitive);
+type_dart_generator_struct!(TypePrimitiveGenerator, IrTypePrimitive, without_context);
 
-impl TypeDartGeneratorTrait for TypePrimitiveGenerator<'_> {
+impl TypeDartGeneratorTrait for TypePrimitiveGenerator {
     fn api2wire_body(&self) -> Option<String> {
         Some(match self.ir {
             IrTypePrimitive::Bool => "return raw ? 1 : 0;".to_owned(),
@@ -3,9 +3,13 @@ use crate::generator::dart::ty::*;
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
-type_dart_generator_struct!(TypePrimitiveListGenerator, IrTypePrimitiveList);
+type_dart_generator_struct!(
+    TypePrimitiveListGenerator,
+    IrTypePrimitiveList,
+    without_context
+);
 
-impl TypeDartGeneratorTrait for TypePrimitiveListGenerator<'_> {
+impl TypeDartGeneratorTrait for TypePrimitiveListGenerator {
     fn api2wire_body(&self) -> Option<String> {
         // NOTE Dart code *only* allocates memory. It never *release* memory by itself.
         // Instead, Rust receives that pointer and now it is in control of Rust.
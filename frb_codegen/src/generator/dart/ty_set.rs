@@ -0,0 +1,24 @@
+use crate::generator::dart::list_of_iterable;
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeSetGenerator, IrTypeSet, without_context);
+
+impl TypeDartGeneratorTrait for TypeSetGenerator {
+    fn api2wire_body(&self) -> Option<String> {
+        Some(format!(
+            "return _api2wire_{}({});",
+            self.ir.list().safe_ident(),
+            list_of_iterable(&self.ir.list(), "raw"),
+        ))
+    }
+
+    /// Dart sets keep the order of the items they are built from
+    fn wire2api_body(&self) -> String {
+        format!(
+            "return Set.of(_wire2api_{}(raw));",
+            self.ir.list().safe_ident()
+        )
+    }
+}
@@ -1,5 +1,8 @@
-use crate::generator::dart::dart_comments;
 use crate::generator::dart::ty::*;
+use crate::generator::dart::{
+    dart_comments, dart_deprecation, generate_api_method, generate_struct_json, is_filled_in_place,
+    json_classes,
+};
 use crate::ir::*;
 use crate::type_dart_generator_struct;
 
@@ -15,13 +18,29 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
         Some(
             s.fields
                 .iter()
-                .map(|field| {
-                    format!(
+                .map(|field| match &field.ty {
+                    ty if is_filled_in_place(ty) => format!(
+                        "_api_fill_to_wire_{}(apiObj.{}, wireObj.{});",
+                        field.ty.safe_ident(),
+                        field.name.dart_style(),
+                        field.name.rust_style()
+                    ),
+                    // ffigen makes the arrays of a struct crossing the wire as is `ffi.Array`s
+                    IrType::Array(array) if s.is_pod => format!(
+                        "if (apiObj.{field}.length != {length}) throw ArgumentError('Expected a list of {length} items, but got ${{apiObj.{field}.length}}');
+                        for (var i = 0; i < {length}; i++) {{
+                            wireObj.{wire_field}[i] = apiObj.{field}[i];
+                        }}",
+                        field = field.name.dart_style(),
+                        wire_field = field.name.rust_style(),
+                        length = array.length,
+                    ),
+                    _ => format!(
                         "wireObj.{} = _api2wire_{}(apiObj.{});",
                         field.name.rust_style(),
                         field.ty.safe_ident(),
                         field.name.dart_style()
-                    )
+                    ),
                 })
                 .collect::<Vec<_>>()
                 .join("\n"),
@@ -35,37 +54,67 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             .iter()
             .enumerate()
             .map(|(idx, field)| {
-                format!(
-                    "{}: _wire2api_{}(arr[{}]),",
-                    field.name.dart_style(),
-                    field.ty.safe_ident(),
-                    idx
-                )
+                let value = format!("_wire2api_{}(arr[{}]),", field.ty.safe_ident(), idx);
+                if s.is_fields_named {
+                    format!("{}: {}", field.name.dart_style(), value)
+                } else {
+                    value
+                }
             })
             .collect::<Vec<_>>()
             .join("\n");
+        let bridge = match (self.has_methods(), s.is_fields_named) {
+            (false, _) => "",
+            (true, true) => "bridge: this,",
+            (true, false) => "this,",
+        };
 
         format!(
             "final arr = raw as List<dynamic>;
                 if (arr.length != {}) throw Exception('unexpected arr length: expect {} but see ${{arr.length}}');
-                return {}({});",
+                return {}({}{});",
             s.fields.len(),
             s.fields.len(),
-            s.name, inner,
+            self.ir.dart_name, bridge, inner,
         )
     }
 
     fn structs(&self) -> String {
         let src = self.ir.get(self.context.ir_file);
 
+        let methods = self
+            .context
+            .ir_file
+            .methods_of(&src.name)
+            .map(|func| generate_api_method(func, func.method.as_ref().unwrap()))
+            .collect::<Vec<_>>();
+        // Methods are called through the API instance that created the object
+        let (bridge_declaration, bridge_param) = if methods.is_empty() {
+            ("".to_string(), "")
+        } else {
+            (
+                format!(
+                    "final {} bridge;\n",
+                    self.context.config.dart_api_class_name()
+                ),
+                if src.is_fields_named {
+                    "required this.bridge,"
+                } else {
+                    "this.bridge,"
+                },
+            )
+        };
+
         let field_declarations = src
             .fields
             .iter()
             .map(|f| {
                 let comments = dart_comments(&f.comments);
                 format!(
-                    "{}final {} {};",
+                    "{}{}{}{} {};",
                     comments,
+                    dart_deprecation(&f.deprecation),
+                    if f.is_final { "final " } else { "" },
                     f.ty.dart_api_type(),
                     f.name.dart_style()
                 )
@@ -73,7 +122,87 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             .collect::<Vec<_>>()
             .join("\n");
 
-        let constructor_params = src
+        let constructor_params = generate_constructor_params(src, bridge_param);
+        // A const constructor needs all the fields to be final, including those of a superclass
+        let is_const = self.context.config.dart_const_constructors
+            && src.fields.iter().all(|f| f.is_final)
+            && src
+                .dart_clauses()
+                .all(|clause| !matches!(dart_clause_keyword(clause), Some("extends" | "with")));
+
+        let value_equality = if self.context.config.dart_value_equality {
+            generate_value_equality(&self.ir.dart_name, src)
+        } else {
+            "".to_string()
+        };
+
+        let copy_with = if self.context.config.dart_copy_with {
+            generate_copy_with(&self.ir.dart_name, src, !methods.is_empty())
+        } else {
+            "".to_string()
+        };
+
+        let json = if self.context.config.dart_json
+            && json_classes(self.context.ir_file).contains(&self.ir.name)
+        {
+            generate_struct_json(&self.ir.dart_name, src, self.context.config)
+        } else {
+            "".to_string()
+        };
+
+        let comments = dart_comments(&src.comments);
+        let annotations = src
+            .dart_annotations()
+            .map(|annotation| format!("{}\n", annotation))
+            .collect::<String>();
+        let mut clauses = src.dart_clauses().cloned().collect::<Vec<_>>();
+        // The classes of implementors stand for the trait objects of the traits too
+        let traits = self.context.ir_file.traits_implemented_by(&src.name);
+        if !traits.is_empty() {
+            match clauses
+                .iter_mut()
+                .find(|clause| dart_clause_keyword(clause) == Some("implements"))
+            {
+                Some(clause) => *clause = format!("{}, {}", clause, traits.join(", ")),
+                None => clauses.push(format!("implements {}", traits.join(", "))),
+            }
+        }
+        let clauses = clauses
+            .iter()
+            .map(|clause| format!(" {}", clause))
+            .collect::<String>();
+
+        format!(
+            "{}{}class {}{} {{
+            {}{}
+
+            {}{}({});
+
+            {}{}{}{}
+        }}",
+            comments,
+            annotations,
+            self.ir.dart_name,
+            clauses,
+            bridge_declaration,
+            field_declarations,
+            if is_const { "const " } else { "" },
+            self.ir.dart_name,
+            constructor_params,
+            methods.join("\n\n"),
+            copy_with,
+            value_equality,
+            json,
+        )
+    }
+}
+
+/// Parameters of the constructor of `src`, after `bridge_param`. Named fields become named
+/// parameters, required unless they are nullable, and the fields of tuple structs become
+/// positional parameters, the trailing nullable ones being optional.
+fn generate_constructor_params(src: &IrStruct, bridge_param: &str) -> String {
+    if src.is_fields_named {
+        let params = src
             .fields
             .iter()
             .map(|f| {
@@ -83,18 +212,140 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
                     f.name.dart_style()
                 )
             })
-            .collect::<Vec<_>>()
-            .join("");
+            .collect::<String>();
+        format!("{{{}{}}}", bridge_param, params)
+    } else {
+        let types = src.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        let params = src
+            .fields
+            .iter()
+            .map(|f| format!("this.{},", f.name.dart_style()))
+            .collect::<Vec<_>>();
+        match optional_boundary_index(&types) {
+            Some(idx) => format!(
+                "{}{}[{}]",
+                bridge_param,
+                params[..idx].join(""),
+                params[idx..].join("")
+            ),
+            None => format!("{}{}", bridge_param, params.join("")),
+        }
+    }
+}
 
-        let comments = dart_comments(&src.comments);
+/// `copyWith` of the class `class_name` of `src`, whose parameters replace the fields they are
+/// given for. Those of nullable fields default to `_CopyWithUnset`, so that `null` can be given to
+/// empty the field, and the `bridge` of classes with methods is passed on.
+fn generate_copy_with(class_name: &str, src: &IrStruct, has_bridge: bool) -> String {
+    let params = src
+        .fields
+        .iter()
+        .map(|field| {
+            let ty = field.ty.dart_api_type();
+            if ty.ends_with('?') {
+                format!(
+                    "Object? {} = const _CopyWithUnset(),",
+                    field.name.dart_style()
+                )
+            } else {
+                format!("{}? {},", ty, field.name.dart_style())
+            }
+        })
+        .collect::<String>();
+    let args = src
+        .fields
+        .iter()
+        .map(|field| {
+            let name = field.name.dart_style();
+            let ty = field.ty.dart_api_type();
+            let value = if ty.ends_with('?') {
+                format!(
+                    "{name} is _CopyWithUnset ? this.{name} : {name} as {ty}",
+                    name = name,
+                    ty = ty
+                )
+            } else {
+                format!("{name} ?? this.{name}", name = name)
+            };
+            if src.is_fields_named {
+                format!("{}: {},", name, value)
+            } else {
+                format!("{},", value)
+            }
+        })
+        .collect::<String>();
+    let bridge = match (has_bridge, src.is_fields_named) {
+        (false, _) => "",
+        (true, true) => "bridge: bridge,",
+        (true, false) => "bridge,",
+    };
+    format!(
+        "
 
-        format!(
-            "{}class {} {{
-            {}
+        {class_name} copyWith({params}) => {class_name}({bridge}{args});",
+        class_name = class_name,
+        params = if params.is_empty() {
+            params
+        } else {
+            format!("{{{}}}", params)
+        },
+        bridge = bridge,
+        args = args,
+    )
+}
 
-            {}({{{}}});
-        }}",
-            comments, self.ir.name, field_declarations, self.ir.name, constructor_params
-        )
+/// `==`, `hashCode` and `toString` of the class `class_name` of `src`, from all its fields. Lists
+/// and maps are compared by their content, and `this` tells the fields apart from `other`.
+fn generate_value_equality(class_name: &str, src: &IrStruct) -> String {
+    let fields = src
+        .fields
+        .iter()
+        .map(|field| field.name.dart_style())
+        .collect::<Vec<_>>();
+    let equals = fields
+        .iter()
+        .map(|field| {
+            format!(
+                " && const DeepCollectionEquality().equals(other.{}, this.{})",
+                field, field
+            )
+        })
+        .collect::<String>();
+    let hashes = fields
+        .iter()
+        .map(|field| format!(", const DeepCollectionEquality().hash({})", field))
+        .collect::<String>();
+    let descriptions = fields
+        .iter()
+        .map(|field| format!("{}: ${}", field, field))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is {class_name} && other.runtimeType == runtimeType{equals};
+
+        @override
+        int get hashCode => Object.hashAll([runtimeType{hashes}]);
+
+        @override
+        String toString() => '{class_name}({descriptions})';",
+        class_name = class_name,
+        equals = equals,
+        hashes = hashes,
+        descriptions = descriptions,
+    )
+}
+
+impl TypeStructRefGenerator<'_> {
+    fn has_methods(&self) -> bool {
+        self.context
+            .ir_file
+            .methods_of(&self.ir.name)
+            .next()
+            .is_some()
     }
 }
@@ -1,43 +1,68 @@
 mod ty;
+mod ty_array;
 mod ty_boxed;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_int128;
+mod ty_map;
+mod ty_opaque;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
+mod ty_set;
 mod ty_struct;
 
 pub use ty::*;
+pub use ty_array::*;
 pub use ty_boxed::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_int128::*;
+pub use ty_map::*;
+pub use ty_opaque::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
+pub use ty_set::*;
 pub use ty_struct::*;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
 
+use crate::config::{AsyncRuntime, NonUtf8Paths, Opts, RustOutputSplit};
 use crate::ir::IrType::*;
 use crate::ir::*;
 use crate::others::*;
+use crate::parser::SourceFile;
 
 pub const HANDLER_NAME: &str = "FLUTTER_RUST_BRIDGE_HANDLER";
+pub const ASYNC_RUNTIME_NAME: &str = "FLUTTER_RUST_BRIDGE_ASYNC_RUNTIME";
 
 pub struct Output {
     pub code: String,
+    /// The names and the code of the submodules of `--rust-output-split`, which `code` declares
+    pub submodules: Vec<(String, String)>,
     pub extern_func_names: Vec<String>,
 }
 
-pub fn generate(ir_file: &IrFile, rust_wire_mod: &str) -> Output {
-    let mut generator = Generator::new();
-    let code = generator.generate(ir_file, rust_wire_mod);
+/// `rust_prelude` is the code given by `--rust-prelude`, if any
+pub fn generate(
+    ir_file: &IrFile,
+    rust_wire_mods: &[String],
+    rust_prelude: Option<&SourceFile>,
+    config: &Opts,
+) -> Output {
+    let mut generator = Generator::new(&config.symbol_prefix);
+    let (code, submodules) = generator.generate(ir_file, rust_wire_mods, rust_prelude, config);
 
+    // Sorted so that the C code listing them only changes when they do
+    let mut extern_func_names = generator.extern_func_collector.names;
+    extern_func_names.sort();
     Output {
         code,
-        extern_func_names: generator.extern_func_collector.names,
+        submodules,
+        extern_func_names,
     }
 }
 
@@ -46,87 +71,209 @@ struct Generator {
 }
 
 impl Generator {
-    fn new() -> Self {
+    fn new(symbol_prefix: &str) -> Self {
         Self {
-            extern_func_collector: ExternFuncCollector::new(),
+            extern_func_collector: ExternFuncCollector::new(symbol_prefix),
         }
     }
 
-    fn generate(&mut self, ir_file: &IrFile, rust_wire_mod: &str) -> String {
+    fn generate(
+        &mut self,
+        ir_file: &IrFile,
+        rust_wire_mods: &[String],
+        rust_prelude: Option<&SourceFile>,
+        config: &Opts,
+    ) -> (String, Vec<(String, String)>) {
         let mut lines: Vec<String> = vec![];
 
         let distinct_input_types = ir_file.distinct_types(true, false);
         let distinct_output_types = ir_file.distinct_types(false, true);
 
-        lines.push(r#"#![allow(non_camel_case_types, unused, clippy::redundant_closure, clippy::useless_conversion, clippy::unit_arg, non_snake_case)]"#.to_string());
+        lines.push(r#"#![allow(non_camel_case_types, unused, clippy::redundant_closure, clippy::useless_conversion, clippy::unit_arg, non_snake_case, deprecated)]"#.to_string());
         lines.push(CODE_HEADER.to_string());
 
         lines.push(String::new());
-        lines.push(format!("use crate::{}::*;", rust_wire_mod));
+        lines.extend(
+            rust_wire_mods
+                .iter()
+                .map(|rust_wire_mod| format!("use crate::{}::*;", rust_wire_mod)),
+        );
         lines.push("use flutter_rust_bridge::*;".to_string());
         lines.push(String::new());
+        if let Some(wire_allocator) = &config.wire_allocator {
+            lines.push(generate_wire_allocator_support(wire_allocator));
+            lines.push(String::new());
+        }
 
         lines.push(self.section_header_comment("imports"));
-        lines.extend(self.generate_imports(
+        let imports = self.generate_imports(
             ir_file,
-            rust_wire_mod,
+            rust_wire_mods,
             &distinct_input_types,
             &distinct_output_types,
-        ));
+        );
+        match rust_prelude {
+            Some(prelude) => lines.extend(imports_not_in_prelude(imports, prelude)),
+            None => lines.extend(imports),
+        }
         lines.push(String::new());
 
-        lines.push(self.section_header_comment("wire functions"));
-        lines.extend(ir_file.funcs.iter().map(|f| self.generate_wire_func(f)));
+        if let Some(prelude) = rust_prelude {
+            lines.push(self.section_header_comment("prelude"));
+            lines.push(prelude.content.clone());
+            lines.push(String::new());
+        }
 
-        lines.push(self.section_header_comment("wire structs"));
-        lines.extend(
+        let mut wire_funcs = Section::new("wire functions");
+        wire_funcs.items.extend(ir_file.funcs.iter().map(|f| {
+            (
+                Some(f.source_index),
+                self.generate_wire_func(f, &config.async_runtime),
+            )
+        }));
+        wire_funcs.extend(
+            ir_file
+                .consts
+                .iter()
+                .filter(|constant| constant.value.is_none())
+                .map(|constant| self.generate_const_getter(constant)),
+        );
+        if !ir_file.init_funcs.is_empty() {
+            wire_funcs.extend(Some(self.generate_init_func(ir_file)));
+        }
+
+        let mut wire_structs = Section::new("wire structs");
+        wire_structs.pinned = true;
+        wire_structs.extend(
             distinct_input_types
                 .iter()
-                .map(|ty| self.generate_wire_struct(ty, ir_file)),
+                .map(|ty| self.generate_wire_struct(ty, ir_file, config.wire_layout_docs)),
         );
-        lines.extend(
+        wire_structs.extend(
             distinct_input_types
                 .iter()
                 .map(|ty| TypeRustGenerator::new(ty.clone(), ir_file).structs()),
         );
 
-        lines.push(self.section_header_comment("allocate functions"));
-        lines.extend(
+        let mut allocate_funcs = Section::new("allocate functions");
+        allocate_funcs.extend(
             distinct_input_types
                 .iter()
                 .map(|f| self.generate_allocate_funcs(f, ir_file)),
         );
 
-        lines.push(self.section_header_comment("impl Wire2Api"));
-        lines.push(self.generate_wire2api_misc().to_string());
-        lines.extend(
+        let mut drop_funcs = Section::new("drop functions");
+        drop_funcs.extend(
+            ir_file
+                .distinct_types(true, true)
+                .iter()
+                .map(|ty| self.generate_drop_funcs(ty, ir_file)),
+        );
+
+        let mut wire2api = Section::new("impl Wire2Api");
+        wire2api.shared = Some(self.generate_wire2api_misc().to_string());
+        wire2api.extend(
             distinct_input_types
                 .iter()
                 .map(|ty| self.generate_wire2api_func(ty, ir_file)),
         );
 
-        lines.push(self.section_header_comment("impl NewWithNullPtr"));
-        lines.push(self.generate_new_with_nullptr_misc().to_string());
-        lines.extend(
+        let mut new_with_nullptr = Section::new("impl NewWithNullPtr");
+        new_with_nullptr.shared = Some(self.generate_new_with_nullptr_misc().to_string());
+        new_with_nullptr.extend(
             distinct_input_types
                 .iter()
                 .map(|ty| self.generate_new_with_nullptr_func(ty, ir_file)),
         );
 
-        lines.push(self.section_header_comment("impl IntoDart"));
-        lines.extend(
+        let mut into_dart = Section::new("impl IntoDart");
+        into_dart.extend(
             distinct_output_types
                 .iter()
                 .map(|ty| self.generate_impl_intodart(ty, ir_file)),
         );
 
+        let sections = vec![
+            wire_funcs,
+            wire_structs,
+            allocate_funcs,
+            drop_funcs,
+            wire2api,
+            new_with_nullptr,
+            into_dart,
+        ];
+        let (sections, submodules) =
+            split_sections(sections, config.rust_output_split, rust_wire_mods);
+        if !submodules.is_empty() {
+            lines.push(self.section_header_comment("submodules"));
+            lines.extend(
+                submodules
+                    .iter()
+                    .map(|(name, _)| format!("mod {0};\nuse {0}::*;", name)),
+            );
+            lines.push(String::new());
+        }
+        // Kept even when empty for the code not to change unless split
+        let is_split = config.rust_output_split != RustOutputSplit::Off;
+        for section in sections {
+            if !is_split || section.shared.is_some() || !section.items.is_empty() {
+                lines.push(self.section_header_comment(section.name));
+                lines.extend(section.code());
+            }
+        }
+        let submodules = submodules
+            .into_iter()
+            .map(|(name, sections)| {
+                let mut lines = vec![
+                    CODE_HEADER.to_string(),
+                    String::new(),
+                    "use super::*;".to_string(),
+                    String::new(),
+                ];
+                for section in sections {
+                    lines.push(self.section_header_comment(section.name));
+                    lines.extend(section.code());
+                }
+                (name, lines.join("\n"))
+            })
+            .collect();
+
         lines.push(self.section_header_comment("executor"));
         lines.push(self.generate_executor(ir_file));
 
+        if ir_file.funcs.iter().any(|f| f.is_async) {
+            lines.push(self.section_header_comment("async runtime"));
+            lines.push(self.generate_async_runtime(&config.async_runtime));
+        }
+
+        let locks = ir_file
+            .funcs
+            .iter()
+            .filter_map(|f| f.lock.as_ref())
+            .map(IrFuncLock::static_name)
+            .collect::<BTreeSet<_>>();
+        if !locks.is_empty() {
+            lines.push(self.section_header_comment("locks"));
+            lines.push(self.generate_locks(&locks));
+        }
+
+        if ir_file.funcs.iter().any(|f| f.cancellation_token.is_some()) {
+            lines.push(self.section_header_comment("cancellation"));
+            lines.push(self.generate_cancel_task_func());
+        }
+
+        if ir_file.funcs.iter().any(|f| !f.callbacks.is_empty()) {
+            lines.push(self.section_header_comment("callbacks"));
+            lines.push(self.generate_callback_reply_funcs(ir_file));
+        }
+
+        lines.push(self.section_header_comment("wire hash"));
+        lines.push(self.generate_wire_hash(ir_file));
+
         lines.push(self.section_header_comment("sync execution mode utility"));
         lines.push(self.generate_sync_execution_mode_utility());
 
-        lines.join("\n")
+        (lines.join("\n"), submodules)
     }
 
     fn section_header_comment(&self, section_name: &str) -> String {
@@ -136,7 +283,7 @@ impl Generator {
     fn generate_imports(
         &self,
         ir_file: &IrFile,
-        rust_wire_mod: &str,
+        rust_wire_mods: &[String],
         distinct_input_types: &[IrType],
         distinct_output_types: &[IrType],
     ) -> impl Iterator<Item = String> {
@@ -151,10 +298,14 @@ impl Generator {
             .chain(output_type_imports)
             // Filter out `None` and unwrap
             .flatten()
-            // Don't include imports from the API file
-            .filter(|import| !import.starts_with(&format!("use crate::{}::", rust_wire_mod)))
-            // de-duplicate
-            .collect::<HashSet<String>>()
+            // Don't include imports from the API files
+            .filter(|import| {
+                !rust_wire_mods.iter().any(|rust_wire_mod| {
+                    import.starts_with(&format!("use crate::{}::", rust_wire_mod))
+                })
+            })
+            // de-duplicate, in an order which does not change from one run to another
+            .collect::<BTreeSet<String>>()
             .into_iter()
     }
 
@@ -172,16 +323,109 @@ impl Generator {
         }
     }
 
+    fn generate_async_runtime(&self, async_runtime: &AsyncRuntime) -> String {
+        match async_runtime {
+            AsyncRuntime::Tokio => format!(
+                "support::lazy_static! {{
+                static ref {}: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+            }}
+            ",
+                ASYNC_RUNTIME_NAME
+            ),
+            AsyncRuntime::AsyncStd | AsyncRuntime::Custom(_) => "".to_string(),
+        }
+    }
+
+    /// The `RwLock`s of `#[frb(lock = "..")]` and `#[frb(read_lock = "..")]`, one for each key
+    fn generate_locks(&self, static_names: &BTreeSet<String>) -> String {
+        // Indented here, since rustfmt may leave the body of the macro as is
+        format!(
+            "support::lazy_static! {{\n{}}}\n",
+            static_names
+                .iter()
+                .map(|name| format!(
+                    "    static ref {}: std::sync::RwLock<()> = Default::default();\n",
+                    name
+                ))
+                .collect::<String>()
+        )
+    }
+
+    /// Called by the Dart `CancellationToken`s given to functions
+    fn generate_cancel_task_func(&mut self) -> String {
+        self.extern_func_collector.generate(
+            "cancel_task",
+            &["id: i64"],
+            None,
+            "cancel::cancel_task(id)",
+        )
+    }
+
+    /// Called by Dart with what the functions given as `DartCallback`s return, one for each
+    /// return type, or when they throw
+    fn generate_callback_reply_funcs(&mut self, ir_file: &IrFile) -> String {
+        let outputs = ir_file
+            .funcs
+            .iter()
+            .flat_map(|func| &func.callbacks)
+            .map(|callback| {
+                (
+                    callback.signature.reply_func_name(),
+                    &callback.signature.output,
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut lines = outputs
+            .into_iter()
+            .map(|(reply_func_name, output)| match output {
+                Primitive(IrTypePrimitive::Unit) => self.extern_func_collector.generate(
+                    &reply_func_name,
+                    &["call_id: i64"],
+                    None,
+                    "callback::reply(call_id, ())",
+                ),
+                _ => self.extern_func_collector.generate(
+                    &reply_func_name,
+                    &[
+                        "call_id: i64",
+                        &format!("value: {}", output.rust_wire_type()),
+                    ],
+                    None,
+                    "callback::reply(call_id, value)",
+                ),
+            })
+            .collect::<Vec<_>>();
+        lines.push(self.extern_func_collector.generate(
+            "fail_callback",
+            &["call_id: i64"],
+            None,
+            "callback::fail(call_id)",
+        ));
+        lines.join("\n")
+    }
+
+    /// The hash of the IR, which Dart reads to check that it was generated from the same API
+    fn generate_wire_hash(&mut self, ir_file: &IrFile) -> String {
+        format!(
+            "pub const WIRE_HASH: u32 = {:#010x};
+            {}",
+            ir_file.wire_hash(),
+            self.extern_func_collector
+                .generate("get_wire_hash", &[], Some("u32"), "WIRE_HASH")
+        )
+    }
+
     fn generate_sync_execution_mode_utility(&mut self) -> String {
         self.extern_func_collector.generate(
             "free_WireSyncReturnStruct",
             &["val: support::WireSyncReturnStruct"],
             None,
-            "unsafe { let _ = support::vec_from_leak_ptr(val.ptr, val.len); }",
+            // Made by `flutter_rust_bridge` whatever the wire allocator
+            "unsafe { let _ = flutter_rust_bridge::support::vec_from_leak_ptr(val.ptr, val.len); }",
         )
     }
 
-    fn generate_wire_func(&mut self, func: &IrFunc) -> String {
+    fn generate_wire_func(&mut self, func: &IrFunc, async_runtime: &AsyncRuntime) -> String {
         let params = [
             if func.mode.has_port_argument() {
                 vec!["port_: i64".to_string()]
@@ -199,21 +443,256 @@ impl Generator {
                     )
                 })
                 .collect::<Vec<_>>(),
+            func.cancellation_token
+                .iter()
+                .map(|token| format!("{}: i64", token.name.rust_style()))
+                .collect::<Vec<_>>(),
+            func.callbacks
+                .iter()
+                .map(|callback| format!("{}: i64", callback.name.rust_style()))
+                .collect::<Vec<_>>(),
         ]
         .concat();
 
+        // The receiver of a method comes before the stream sink
+        let (receiver_param, inputs) = match &func.method {
+            Some(method) => (
+                vec![method
+                    .receiver
+                    .rust_call_arg(&format!("api_{}", func.inputs[0].name.rust_style()))],
+                &func.inputs[1..],
+            ),
+            None => (vec![], &func.inputs[..]),
+        };
+        let mut arg_params = inputs
+            .iter()
+            .map(|field| match &field.ty {
+                Opaque(IrTypeOpaque { shared: false, .. }) => {
+                    format!("&api_{}", field.name.rust_style())
+                }
+                Optional(IrTypeOptional { inner }) if is_lent_opaque(inner) => {
+                    format!("api_{}.as_deref()", field.name.rust_style())
+                }
+                Delegate(IrTypeDelegate::Str | IrTypeDelegate::Slice(_)) => {
+                    format!("&api_{}", field.name.rust_style())
+                }
+                Delegate(IrTypeDelegate::Path(_)) => {
+                    format!("std::path::Path::new(&api_{})", field.name.rust_style())
+                }
+                _ => format!("api_{}", field.name.rust_style()),
+            })
+            .collect::<Vec<_>>();
+        // The arguments which Dart does not give as such, inserted from the first one
+        let mut special_args = func
+            .out_params
+            .iter()
+            .map(|out_param| {
+                (
+                    out_param.position,
+                    format!("&mut api_{}", out_param.name.rust_style()),
+                )
+            })
+            .chain(func.cancellation_token.iter().map(|token| {
+                (
+                    token.position,
+                    format!("api_{}.clone()", token.name.rust_style()),
+                )
+            }))
+            .chain(func.callbacks.iter().map(|callback| {
+                (
+                    callback.position,
+                    format!("api_{}.clone()", callback.name.rust_style()),
+                )
+            }))
+            .collect::<Vec<_>>();
+        special_args.sort_by_key(|(position, _)| *position);
+        for (position, arg) in special_args {
+            arg_params.insert(position, arg);
+        }
         let inner_func_params = [
+            receiver_param,
             match func.mode {
-                IrFuncMode::Normal | IrFuncMode::Sync => vec![],
+                IrFuncMode::Normal | IrFuncMode::Sync | IrFuncMode::Direct => vec![],
                 IrFuncMode::Stream => vec!["task_callback.stream_sink()".to_string()],
             },
-            func.inputs
-                .iter()
-                .map(|field| format!("api_{}", field.name.rust_style()))
-                .collect::<Vec<_>>(),
+            arg_params,
         ]
         .concat();
 
+        // The wire values are converted before the task is spawned, since they are not `Send`
+        let code_wire2api = func
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                let is_mut_receiver = idx == 0
+                    && matches!(&func.method, Some(method) if method.receiver == IrMethodReceiver::RefMut);
+                // Opaque objects are lent to the function, which does not tell what to convert into
+                let annotation = match &field.ty {
+                    Opaque(opaque) => format!(": {}", opaque.rust_api_type()),
+                    optional @ Optional(IrTypeOptional { inner }) if is_lent_opaque(inner) => {
+                        format!(": {}", optional.rust_api_type())
+                    }
+                    Delegate(borrowed @ (IrTypeDelegate::Slice(_) | IrTypeDelegate::Path(_))) => {
+                        format!(": {}", borrowed.get_delegate().rust_api_type())
+                    }
+                    _ => "".to_string(),
+                };
+                let name = field.name.rust_style();
+                let code_value = match &field.ty {
+                    // Borrowed from the `String` taking over the bytes
+                    Delegate(IrTypeDelegate::Str) => {
+                        format!("String::from_utf8({}.wire2api()?)?", name)
+                    }
+                    _ => format!("{}.wire2api()?", name),
+                };
+                format!(
+                    "let {}api_{}{} = {};",
+                    if is_mut_receiver { "mut " } else { "" },
+                    name,
+                    annotation,
+                    code_value
+                )
+            })
+            .collect::<Vec<_>>();
+        let code_wire2api = code_wire2api.concat();
+        // Registered before Dart can cancel the task
+        let code_wire2api = match &func.cancellation_token {
+            Some(token) => format!(
+                "{}let api_{} = cancel::cancellation_token({});",
+                code_wire2api,
+                token.name.rust_style(),
+                token.name.rust_style()
+            ),
+            None => code_wire2api,
+        };
+        let code_wire2api = func
+            .callbacks
+            .iter()
+            .fold(code_wire2api, |code_wire2api, callback| {
+                format!(
+                    "{}let api_{} = callback::DartCallback::new({});",
+                    code_wire2api,
+                    callback.name.rust_style(),
+                    callback.name.rust_style()
+                )
+            });
+
+        let code_call_inner_func = match &func.method {
+            Some(method) => format!(
+                "<{}>::{}({})",
+                method.self_type,
+                method.name,
+                inner_func_params.join(", ")
+            ),
+            None => format!(
+                "{}({})",
+                func.instantiation
+                    .as_ref()
+                    .map_or_else(|| func.name.clone(), IrFuncInstantiation::rust_call_path),
+                inner_func_params.join(", ")
+            ),
+        };
+        let code_call_inner_func = if func.collects_iterator {
+            generate_collect_iterator(func, &code_call_inner_func)
+        } else {
+            code_call_inner_func
+        };
+        // Cancelled `async` functions are dropped, which does not need them to check the token
+        let code_call_inner_func = match &func.cancellation_token {
+            Some(token) if func.is_async => format!(
+                "cancel::until_cancelled(api_{}.clone(), {})",
+                token.name.rust_style(),
+                code_call_inner_func
+            ),
+            _ => code_call_inner_func,
+        };
+        // Async functions are driven to completion on the worker thread running the task
+        let code_call_inner_func = if func.is_async {
+            match async_runtime {
+                AsyncRuntime::Tokio => {
+                    format!("{}.block_on({})", ASYNC_RUNTIME_NAME, code_call_inner_func)
+                }
+                AsyncRuntime::AsyncStd => {
+                    format!("async_std::task::block_on({})", code_call_inner_func)
+                }
+                AsyncRuntime::Custom(block_on) => format!("{}({})", block_on, code_call_inner_func),
+            }
+        } else {
+            code_call_inner_func
+        };
+        // The data borrowed from the receiver outlives neither the closure nor the receiver
+        let code_call_inner_func = match (func.copies_borrowed_output, func.fallible) {
+            (false, _) => code_call_inner_func,
+            (true, false) => format!("ToOwned::to_owned({})", code_call_inner_func),
+            (true, true) => format!("{}.map(ToOwned::to_owned)", code_call_inner_func),
+        };
+        // Once the function returns, its callbacks are closed, since Dart stops listening
+        let code_call_inner_func =
+            func.callbacks
+                .iter()
+                .fold(code_call_inner_func, |code_call_inner_func, callback| {
+                    format!(
+                        "{{ let _api_{}_guard = api_{}.close_on_drop(); {} }}",
+                        callback.name.rust_style(),
+                        callback.name.rust_style(),
+                        code_call_inner_func
+                    )
+                });
+        // Held until the function returns, across the awaits of an `async` function. A function
+        // that panicked while holding it poisons it, which does not stop the next calls.
+        let code_call_inner_func = match &func.lock {
+            Some(lock) => format!(
+                "{{ let _lock_guard = {}.{}().unwrap_or_else(std::sync::PoisonError::into_inner); {} }}",
+                lock.static_name(),
+                if lock.shared { "read" } else { "write" },
+                code_call_inner_func
+            ),
+            None => code_call_inner_func,
+        };
+
+        // Whatever the function returns, a cancelled task ends with `Err(Cancelled)`, which is
+        // merged with the errors of the function
+        let code_custom_error = if func.error_output.is_some() {
+            ".map_err(handler::custom_error)"
+        } else {
+            ""
+        };
+        let code_call_inner_func = match &func.cancellation_token {
+            Some(token) => {
+                let code_checked = if func.is_async {
+                    code_call_inner_func
+                } else {
+                    format!(
+                        "{{ let ans = {}; api_{}.check().map(|()| ans) }}",
+                        code_call_inner_func,
+                        token.name.rust_style()
+                    )
+                };
+                if func.fallible {
+                    format!("{}.and_then(|ans| ans{})", code_checked, code_custom_error)
+                } else {
+                    code_checked
+                }
+            }
+            None => code_call_inner_func,
+        };
+        let fallible = func.fallible || func.cancellation_token.is_some();
+
+        let code_call_inner_func = if func.out_params.is_empty() {
+            code_call_inner_func
+        } else {
+            self.generate_call_with_out_params(func, fallible, &code_call_inner_func)
+        };
+
+        // Custom errors are sent to Dart with their data, in contrast to `anyhow::Error`
+        let code_call_inner_func =
+            if func.error_output.is_some() && func.cancellation_token.is_none() {
+                format!("{}.map_err(handler::custom_error)", code_call_inner_func)
+            } else {
+                code_call_inner_func
+            };
+
         let wrap_info_obj = format!(
             "WrapInfo{{ debug_name: \"{}\", port: {}, mode: FfiCallMode::{} }}",
             func.name,
@@ -225,29 +704,21 @@ impl Generator {
             func.mode.ffi_call_mode(),
         );
 
-        let code_wire2api = func
-            .inputs
-            .iter()
-            .map(|field| {
-                format!(
-                    "let api_{} = {}.wire2api();",
-                    field.name.rust_style(),
-                    field.name.rust_style()
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("");
-
-        let code_call_inner_func = format!("{}({})", func.name, inner_func_params.join(", "));
-
-        let code_call_inner_func_result = if func.fallible {
-            code_call_inner_func
+        let code_call_inner_func_result = if fallible {
+            match generate_output_conversion(func, "ans") {
+                Some(code) => format!("{}.map(|ans| {})", code_call_inner_func, code),
+                None => code_call_inner_func,
+            }
         } else {
-            format!("Ok({})", code_call_inner_func)
+            format!(
+                "Ok({})",
+                generate_output_conversion(func, &code_call_inner_func)
+                    .unwrap_or(code_call_inner_func)
+            )
         };
 
         let (handler_func_name, return_type, code_closure) = match func.mode {
-            IrFuncMode::Sync => (
+            IrFuncMode::Sync | IrFuncMode::Direct => (
                 "wrap_sync",
                 Some("support::WireSyncReturnStruct"),
                 format!(
@@ -261,7 +732,7 @@ impl Generator {
                 None,
                 format!(
                     "{}
-                    move |task_callback| {}
+                    Ok(move |task_callback: rust2dart::TaskCallback| {})
                     ",
                     code_wire2api, code_call_inner_func_result,
                 ),
@@ -286,17 +757,138 @@ impl Generator {
         )
     }
 
-    fn generate_wire_struct(&mut self, ty: &IrType, ir_file: &IrFile) -> String {
+    /// Calls the function with its out-parameters, starting from their default value, then gives
+    /// them back along with what it returns, as `output` stands for.
+    fn generate_call_with_out_params(
+        &self,
+        func: &IrFunc,
+        fallible: bool,
+        code_call_inner_func: &str,
+    ) -> String {
+        let code_declare = func
+            .out_params
+            .iter()
+            .map(|out_param| {
+                format!(
+                    "let mut api_{}: {} = Default::default();",
+                    out_param.name.rust_style(),
+                    out_param.ty.rust_api_type()
+                )
+            })
+            .collect::<String>();
+        let out_values = func
+            .out_params
+            .iter()
+            .map(|out_param| format!("api_{}", out_param.name.rust_style()))
+            .collect::<Vec<_>>();
+        let values = if func.out_params_only {
+            out_values
+        } else {
+            [vec!["ans".to_string()], out_values].concat()
+        };
+        let code_output = if values.len() == 1 {
+            values[0].clone()
+        } else {
+            format!("({})", values.join(", "))
+        };
+        match (func.out_params_only, fallible) {
+            (true, true) => format!(
+                "{{ {} {}.map(|()| {}) }}",
+                code_declare, code_call_inner_func, code_output
+            ),
+            (false, true) => format!(
+                "{{ {} {}.map(|ans| {}) }}",
+                code_declare, code_call_inner_func, code_output
+            ),
+            (true, false) => format!(
+                "{{ {} {}; {} }}",
+                code_declare, code_call_inner_func, code_output
+            ),
+            (false, false) => format!(
+                "{{ {} let ans = {}; {} }}",
+                code_declare, code_call_inner_func, code_output
+            ),
+        }
+    }
+
+    /// The extern function running the functions marked `#[frb(init)]`, only the first time it is
+    /// called. Their panics are sent to Dart like those of the other functions.
+    fn generate_init_func(&mut self, ir_file: &IrFile) -> String {
+        self.extern_func_collector.generate(
+            &format!("wire_{}", INIT_FUNC_NAME),
+            &["port_: i64"],
+            None,
+            &format!(
+                "{handler_name}.wrap(
+                    WrapInfo {{
+                        debug_name: \"{name}\",
+                        port: Some(port_),
+                        mode: FfiCallMode::Normal,
+                    }},
+                    move || Ok(move |_: rust2dart::TaskCallback| {{
+                        static INIT: std::sync::Once = std::sync::Once::new();
+                        INIT.call_once(|| {{ {calls} }});
+                        Ok(())
+                    }}),
+                )",
+                handler_name = HANDLER_NAME,
+                name = INIT_FUNC_NAME,
+                calls = ir_file
+                    .init_funcs
+                    .iter()
+                    .map(|func| format!("{}();", func))
+                    .collect::<String>(),
+            ),
+        )
+    }
+
+    /// The extern function reading a constant whose value Dart cannot be given as a literal.
+    /// Strings are returned like the bytes of `SyncReturn<Vec<u8>>`, which Dart decodes.
+    fn generate_const_getter(&mut self, constant: &IrConst) -> String {
+        let (return_type, body) = match &constant.ty {
+            Delegate(IrTypeDelegate::String) => (
+                "support::WireSyncReturnStruct".to_string(),
+                format!(
+                    "let (ptr, len) = support::into_leak_vec_ptr({}.as_bytes().to_vec());
+                    support::WireSyncReturnStruct {{ ptr, len, success: true }}",
+                    constant.name
+                ),
+            ),
+            Delegate(IrTypeDelegate::Char) => {
+                ("u32".to_string(), format!("{} as u32", constant.name))
+            }
+            ty => (ty.rust_wire_type(), constant.name.clone()),
+        };
+        self.extern_func_collector.generate(
+            &constant.wire_func_name(),
+            &[],
+            Some(&return_type),
+            &body,
+        )
+    }
+
+    fn generate_wire_struct(&mut self, ty: &IrType, ir_file: &IrFile, layout_docs: bool) -> String {
         // println!("generate_wire_struct: {:?}", ty);
-        if let Some(fields) = TypeRustGenerator::new(ty.clone(), ir_file).wire_struct_fields() {
+        if let Some(mut fields) = TypeRustGenerator::new(ty.clone(), ir_file).wire_struct_fields() {
+            let docs = if layout_docs {
+                let docs = generate_wire_layout_docs(ty, &fields);
+                let count = fields.len();
+                for (idx, field) in fields.iter_mut().enumerate() {
+                    *field = format!("/// Field {} of {}\n{}", idx, count, field);
+                }
+                docs
+            } else {
+                "".to_string()
+            };
             format!(
                 r###"
-                #[repr(C)]
+                {}#[repr(C)]
                 #[derive(Clone)]
                 pub struct {} {{
                     {}
                 }}
                 "###,
+                docs,
                 ty.rust_wire_type(),
                 fields.join(",\n"),
             )
@@ -310,20 +902,24 @@ impl Generator {
         TypeRustGenerator::new(ty.clone(), ir_file).allocate_funcs(&mut self.extern_func_collector)
     }
 
+    fn generate_drop_funcs(&mut self, ty: &IrType, ir_file: &IrFile) -> String {
+        TypeRustGenerator::new(ty.clone(), ir_file).drop_funcs(&mut self.extern_func_collector)
+    }
+
     fn generate_wire2api_misc(&self) -> &'static str {
         r"pub trait Wire2Api<T> {
-            fn wire2api(self) -> T;
+            fn wire2api(self) -> support::anyhow::Result<T>;
         }
         
         impl<T, S> Wire2Api<Option<T>> for *mut S
             where
                 *mut S: Wire2Api<T>
         {
-            fn wire2api(self) -> Option<T> {
+            fn wire2api(self) -> support::anyhow::Result<Option<T>> {
                 if self.is_null() {
-                    None
+                    Ok(None)
                 } else {
-                    Some(self.wire2api())
+                    self.wire2api().map(Some)
                 }
             }
         }
@@ -335,7 +931,7 @@ impl Generator {
         if let Some(body) = TypeRustGenerator::new(ty.clone(), ir_file).wire2api_body() {
             format!(
                 "impl Wire2Api<{}> for {} {{
-            fn wire2api(self) -> {} {{
+            fn wire2api(self) -> support::anyhow::Result<{}> {{
                 {}
             }}
         }}
@@ -374,6 +970,263 @@ impl Generator {
     }
 }
 
+/// Collects the iterator returned by the call into the `Vec` Dart receives, from the future of an
+/// `async` function as well. The iterator may borrow the arguments, so it is collected at once.
+fn generate_collect_iterator(func: &IrFunc, code_call_inner_func: &str) -> String {
+    let code_collect = if func.fallible {
+        "ans.map(|ans| ans.collect::<Vec<_>>())"
+    } else {
+        "ans.collect::<Vec<_>>()"
+    };
+    if func.is_async {
+        format!(
+            "async move {{ let ans = {}.await; {} }}",
+            code_call_inner_func, code_collect
+        )
+    } else {
+        format!("{{ let ans = {}; {} }}", code_call_inner_func, code_collect)
+    }
+}
+
+/// Converts the value returned by the function into what is sent to Dart, when it is not sent as
+/// such. A direct call gives the bytes of its primitive, in the order of the platform.
+fn generate_output_conversion(func: &IrFunc, value: &str) -> Option<String> {
+    match (&func.mode, &func.output) {
+        (IrFuncMode::Normal, output) if needs_into_dart_conversion(output) => Some(format!(
+            "{{ use support::IntoDart; {} }}",
+            generate_into_dart(output, value)
+        )),
+        (IrFuncMode::Direct, Primitive(primitive)) => Some(format!(
+            "SyncReturn({})",
+            match primitive {
+                IrTypePrimitive::Unit => format!("{{ let () = {}; Vec::new() }}", value),
+                IrTypePrimitive::Bool => format!("vec![{} as u8]", value),
+                _ => format!("{}.to_ne_bytes().to_vec()", value),
+            }
+        )),
+        _ => None,
+    }
+}
+
+/// The imports of the API types that the prelude does not import itself, which would be imported
+/// twice otherwise. An item of another path imported by the prelude under the name of an API type
+/// would hide it, so it is rejected.
+fn imports_not_in_prelude(
+    imports: impl Iterator<Item = String>,
+    prelude: &SourceFile,
+) -> impl Iterator<Item = String> {
+    let mut prelude_names = Vec::new();
+    for item in &prelude.ast.items {
+        if let syn::Item::Use(item_use) = item {
+            collect_use_names(&item_use.tree, "", &mut prelude_names);
+        }
+    }
+    let path = prelude.path.clone();
+    imports.filter(move |import| {
+        let item_use = syn::parse_str::<syn::ItemUse>(import)
+            .unwrap_or_else(|e| panic!("invalid generated import `{}`: {}", import, e));
+        let mut names = Vec::new();
+        collect_use_names(&item_use.tree, "", &mut names);
+        names.iter().all(|(name, item_path)| {
+            match prelude_names
+                .iter()
+                .find(|(prelude_name, _)| prelude_name == name)
+            {
+                Some((_, prelude_path)) if prelude_path == item_path => false,
+                Some((_, prelude_path)) => panic!(
+                    "`{}` is imported from `{}` by the Rust prelude {}, but the API uses `{}`",
+                    name, prelude_path, path, item_path
+                ),
+                None => true,
+            }
+        })
+    })
+}
+
+/// The names brought into scope by `tree`, along with the paths they stand for, glob imports
+/// bringing no name of their own
+fn collect_use_names(tree: &syn::UseTree, prefix: &str, names: &mut Vec<(String, String)>) {
+    match tree {
+        syn::UseTree::Path(path) => {
+            collect_use_names(&path.tree, &format!("{}{}::", prefix, path.ident), names)
+        }
+        // `use a::b::{self}` imports `b`
+        syn::UseTree::Name(name) if name.ident == "self" => {
+            let path = prefix.trim_end_matches("::");
+            let name = path.rsplit("::").next().unwrap_or(path);
+            names.push((name.to_owned(), path.to_owned()));
+        }
+        syn::UseTree::Name(name) => {
+            names.push((name.ident.to_string(), format!("{}{}", prefix, name.ident)))
+        }
+        syn::UseTree::Rename(rename) => names.push((
+            rename.rename.to_string(),
+            format!("{}{}", prefix, rename.ident),
+        )),
+        syn::UseTree::Glob(_) => {}
+        syn::UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_use_names(tree, prefix, names);
+            }
+        }
+    }
+}
+
+/// A section of the generated code, whose items can be moved to the submodules of
+/// `--rust-output-split`
+struct Section {
+    name: &'static str,
+    /// The code shared by the items, which stays in the top module
+    shared: Option<String>,
+    /// The items, with the position of the Rust input file they belong to, if any
+    items: Vec<(Option<usize>, String)>,
+    /// Whether the items stay in the top module, as the wire structs do for their private fields
+    /// to be readable from every submodule
+    pinned: bool,
+}
+
+impl Section {
+    fn new(name: &'static str) -> Self {
+        Section {
+            name,
+            shared: None,
+            items: Vec::new(),
+            pinned: false,
+        }
+    }
+
+    /// Adds items belonging to no input file
+    fn extend(&mut self, items: impl IntoIterator<Item = String>) {
+        self.items
+            .extend(items.into_iter().map(|item| (None, item)));
+    }
+
+    fn code(self) -> impl Iterator<Item = String> {
+        self.shared
+            .into_iter()
+            .chain(self.items.into_iter().map(|(_, item)| item))
+    }
+}
+
+/// Adds `item` of the section `name` to `sections`, after the items of the last section if it is
+/// the same one
+fn push_item(sections: &mut Vec<Section>, name: &'static str, item: (Option<usize>, String)) {
+    match sections.last_mut() {
+        Some(section) if section.name == name => section.items.push(item),
+        _ => {
+            let mut section = Section::new(name);
+            section.items.push(item);
+            sections.push(section);
+        }
+    }
+}
+
+/// The sections left in the top module, and the submodules holding the items moved out of them,
+/// by name: the wire functions of each input file in a submodule named after its module, or all
+/// the items but the pinned ones cut into `part_1`, `part_2` and so on
+fn split_sections(
+    mut sections: Vec<Section>,
+    split: RustOutputSplit,
+    rust_wire_mods: &[String],
+) -> (Vec<Section>, Vec<(String, Vec<Section>)>) {
+    // Many types have nothing to add to a section, which only matters once split
+    let drop_empty_items = |sections: &mut Vec<Section>| {
+        for section in sections {
+            section.items.retain(|(_, item)| !item.trim().is_empty());
+        }
+    };
+    match split {
+        RustOutputSplit::Off => (sections, vec![]),
+        RustOutputSplit::Modules => {
+            drop_empty_items(&mut sections);
+            let mut submodules = BTreeMap::<usize, Vec<Section>>::new();
+            for section in &mut sections {
+                let (moved, kept) = std::mem::take(&mut section.items)
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|(source_index, _)| source_index.is_some());
+                section.items = kept;
+                for item in moved {
+                    let sections = submodules.entry(item.0.unwrap()).or_default();
+                    push_item(sections, section.name, item);
+                }
+            }
+            let submodules = submodules
+                .into_iter()
+                .map(|(source_index, sections)| {
+                    (rust_wire_mods[source_index].replace("::", "_"), sections)
+                })
+                .collect();
+            (sections, submodules)
+        }
+        RustOutputSplit::Lines(max_lines) => {
+            drop_empty_items(&mut sections);
+            let mut submodules: Vec<Vec<Section>> = Vec::new();
+            let mut lines = 0;
+            for section in sections.iter_mut().filter(|section| !section.pinned) {
+                for item in std::mem::take(&mut section.items) {
+                    let item_lines = item.1.lines().count();
+                    if submodules.is_empty() || (lines > 0 && lines + item_lines > max_lines) {
+                        submodules.push(Vec::new());
+                        lines = 0;
+                    }
+                    push_item(submodules.last_mut().unwrap(), section.name, item);
+                    lines += item_lines;
+                }
+            }
+            let submodules = submodules
+                .into_iter()
+                .enumerate()
+                .map(|(idx, sections)| (format!("part_{}", idx + 1), sections))
+                .collect();
+            (sections, submodules)
+        }
+    }
+}
+
+/// A `support` module shadowing the one of `flutter_rust_bridge`, whose allocation functions are
+/// those of `wire_allocator` instead
+fn generate_wire_allocator_support(wire_allocator: &str) -> String {
+    format!(
+        "mod support {{
+            pub use flutter_rust_bridge::support::*;
+            use flutter_rust_bridge::support::WireAllocator;
+
+            pub fn new_leak_vec_ptr<T: Clone>(fill: T, length: i32) -> *mut T {{
+                <{0} as WireAllocator>::new_leak_vec_ptr(fill, length)
+            }}
+
+            pub unsafe fn vec_from_leak_ptr<T>(ptr: *mut T, len: i32) -> Vec<T> {{
+                <{0} as WireAllocator>::vec_from_leak_ptr(ptr, len)
+            }}
+
+            pub fn new_leak_box_ptr<T>(t: T) -> *mut T {{
+                <{0} as WireAllocator>::new_leak_box_ptr(t)
+            }}
+
+            pub unsafe fn box_from_leak_ptr<T>(ptr: *mut T) -> Box<T> {{
+                <{0} as WireAllocator>::box_from_leak_ptr(ptr)
+            }}
+        }}",
+        wire_allocator
+    )
+}
+
+/// The documentation of the wire struct of `ty`, listing its `fields` in the order given by
+/// [TypeRustGeneratorTrait::wire_struct_fields]. cbindgen copies it into the C header.
+fn generate_wire_layout_docs(ty: &IrType, fields: &[String]) -> String {
+    let lines = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| format!("/// {}. `{}`\n", idx, field))
+        .collect::<String>();
+    format!(
+        "/// Wire layout of `{}`, with {} fields in this order:\n///\n{}",
+        ty.rust_api_type(),
+        fields.len(),
+        lines
+    )
+}
+
 pub fn generate_import(api_type: &IrType, ir_file: &IrFile) -> Option<String> {
     TypeRustGenerator::new(api_type.clone(), ir_file).imports()
 }
@@ -384,30 +1237,260 @@ pub fn generate_list_allocate_func(
     list: &impl IrTypeTrait,
     inner: &IrType,
 ) -> String {
+    // Items passed by value, such as timestamps, have no null pointer to start from
+    let fill = match inner {
+        _ if !inner.rust_ptr_modifier().is_empty() || inner.rust_wire_is_pointer() => format!(
+            "<{}{}>::new_with_null_ptr()",
+            inner.rust_ptr_modifier(),
+            inner.rust_wire_type()
+        ),
+        StructRef(_)
+        | EnumRef(IrTypeEnumRef {
+            is_struct: true, ..
+        })
+        | Optional(_) => format!("<{}>::new_with_null_ptr()", inner.rust_wire_type()),
+        _ => "Default::default()".to_string(),
+    };
     collector.generate(
         &format!("new_{}", safe_ident),
         &["len: i32"],
-        Some(&[
-            list.rust_wire_modifier().as_str(),
-            list.rust_wire_type().as_str()
-        ].concat()),
+        Some(
+            &[
+                list.rust_wire_modifier().as_str(),
+                list.rust_wire_type().as_str(),
+            ]
+            .concat(),
+        ),
         &format!(
-            "let wrap = {} {{ ptr: support::new_leak_vec_ptr(<{}{}>::new_with_null_ptr(), len), len }};
+            "let wrap = {} {{ ptr: support::new_leak_vec_ptr({}, len), len }};
                 support::new_leak_box_ptr(wrap)",
             list.rust_wire_type(),
-            inner.rust_ptr_modifier(),
-            inner.rust_wire_type()
+            fill,
         ),
     )
 }
 
+/// The placeholder value of a wire field before the Dart side fills it in.
+pub fn generate_wire_field_init(ty: &IrType) -> &'static str {
+    match ty {
+        _ if ty.rust_wire_is_pointer() => "core::ptr::null_mut()",
+        StructRef(_)
+        | EnumRef(IrTypeEnumRef {
+            is_struct: true, ..
+        })
+        | Optional(_) => "NewWithNullPtr::new_with_null_ptr()",
+        _ => "Default::default()",
+    }
+}
+
+/// Whether `ty` is an opaque object that functions borrow, rather than hold as `Arc<T>`
+fn is_lent_opaque(ty: &IrType) -> bool {
+    matches!(ty, Opaque(IrTypeOpaque { shared: false, .. }))
+}
+
+/// Whether values of this type are not `IntoDart` themselves. Std types such as maps and boxes
+/// can't implement the trait in the generated crate, so they are converted where they are used.
+pub fn needs_into_dart_conversion(ty: &IrType) -> bool {
+    match ty {
+        Map(_)
+        | Set(_)
+        | Array(_)
+        | Int128(_)
+        | Delegate(
+            IrTypeDelegate::String
+            | IrTypeDelegate::Time(_)
+            | IrTypeDelegate::Uuid(_)
+            | IrTypeDelegate::Char
+            | IrTypeDelegate::BoolList
+            | IrTypeDelegate::Cow(_)
+            | IrTypeDelegate::Str
+            | IrTypeDelegate::Slice(_)
+            | IrTypeDelegate::BoxedSlice(_)
+            | IrTypeDelegate::PathBuf(_)
+            | IrTypeDelegate::Path(_)
+            | IrTypeDelegate::NonZero(..)
+            | IrTypeDelegate::Json,
+        ) => true,
+        StructRef(struct_ref) => {
+            struct_ref.tuple_fields.is_some() || struct_ref.foreign_rust_type.is_some()
+        }
+        Opaque(opaque) => opaque.shared,
+        Boxed(boxed) => boxed.exist_in_real_api || needs_into_dart_conversion(&boxed.inner),
+        // A list is only `IntoDart` when its items are Dart objects, which lists of primitives or
+        // strings are not until they are converted
+        GeneralList(list) => {
+            matches!(
+                &*list.inner,
+                PrimitiveList(_) | GeneralList(_) | Delegate(IrTypeDelegate::StringList)
+            ) || needs_into_dart_conversion(&list.inner)
+        }
+        Optional(opt) => needs_into_dart_conversion(&opt.inner),
+        _ => false,
+    }
+}
+
+/// Expression converting `expr`, a value of type `ty`, into a `DartCObject`.
+pub fn generate_into_dart(ty: &IrType, expr: &str) -> String {
+    match ty {
+        // Entries are sent as `[key, value]` pairs, in the iteration order of the map
+        Map(map) => format!(
+            "{}.into_iter().map(|(k, v)| vec![{}, {}].into_dart()).collect::<Vec<_>>().into_dart()",
+            expr,
+            generate_into_dart(&map.key, "k"),
+            generate_into_dart(&map.value, "v"),
+        ),
+        // Sets are sent like lists, sorted for the order of a `HashSet` not to vary between runs
+        Set(set) => {
+            let items = match set.kind {
+                IrTypeSetKind::HashSet => format!(
+                    "{{ let mut items = {}.into_iter().collect::<Vec<_>>(); items.sort(); items }}",
+                    expr
+                ),
+                IrTypeSetKind::BTreeSet => format!("{}.into_iter().collect::<Vec<_>>()", expr),
+            };
+            generate_into_dart(&set.list(), &items)
+        }
+        // Arrays are sent like lists, as standard types can't implement `IntoDart`
+        Array(array) => generate_into_dart(&array.list(), &format!("Vec::from({})", expr)),
+        // Tuples are sent like the structs standing for them, as a list of their fields
+        StructRef(IrTypeStructRef {
+            tuple_fields: Some(fields),
+            ..
+        }) => format!(
+            "{{ let tuple = {}; vec![{}].into_dart() }}",
+            expr,
+            fields
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| generate_into_dart(ty, &format!("tuple.{}", idx)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        // Sent as the struct standing for the type of another crate
+        StructRef(IrTypeStructRef {
+            name,
+            foreign_rust_type: Some(_),
+            ..
+        }) => format!("{}::from({}).into_dart()", name, expr),
+        // Dart holds a reference of its own
+        Opaque(IrTypeOpaque { shared: true, .. }) => {
+            format!("support::opaque_shared_into_dart({})", expr)
+        }
+        // Sent as the high and low halves, as `i64`s in the two's complement of their bits
+        Int128(_) => format!(
+            "{{ let value = {}; vec![(value >> 64) as i64, value as i64].into_dart() }}",
+            expr
+        ),
+        // Dart durations are limited to the microseconds an `i64` can hold
+        Delegate(IrTypeDelegate::Time(IrTypeDelegateTime::Duration)) => format!(
+            "{{ let duration = {}; <i64 as std::convert::TryFrom<_>>::try_from(duration.as_micros()).unwrap_or_else(|_| panic!(\"{{:?}} is too long for a Dart Duration\", duration)).into_dart() }}",
+            expr
+        ),
+        // Sent as its bytes, which Dart decodes from the buffer of Rust instead of a copy of them
+        Delegate(IrTypeDelegate::String) => format!(
+            "ZeroCopyBuffer({}.into_bytes()).into_dart()",
+            expr
+        ),
+        Delegate(IrTypeDelegate::Uuid(_)) => format!("{}.as_bytes().to_vec().into_dart()", expr),
+        Delegate(IrTypeDelegate::Char) => format!("({} as u32).into_dart()", expr),
+        // Writing a `serde_json::Value` cannot fail, its map keys being strings
+        Delegate(json @ IrTypeDelegate::Json) => generate_into_dart(
+            &json.get_delegate(),
+            &format!("serde_json::to_string(&{}).unwrap()", expr),
+        ),
+        Delegate(IrTypeDelegate::NonZero(inner, _)) => {
+            generate_into_dart(inner, &format!("{}.get()", expr))
+        }
+        // Sent as a `Uint8List` rather than a list of Dart objects
+        Delegate(IrTypeDelegate::BoolList) => format!(
+            "{}.into_iter().map(u8::from).collect::<Vec<_>>().into_dart()",
+            expr
+        ),
+        Delegate(boxed_slice @ IrTypeDelegate::BoxedSlice(_)) => generate_into_dart(
+            &boxed_slice.get_delegate(),
+            &format!("Vec::from({})", expr),
+        ),
+        Delegate(cow @ IrTypeDelegate::Cow(_)) => {
+            generate_into_dart(&cow.get_delegate(), &format!("{}.into_owned()", expr))
+        }
+        // Borrowed data that is returned lives in the program, but Dart needs a copy of its own
+        Delegate(IrTypeDelegate::Str) => generate_into_dart(
+            &Delegate(IrTypeDelegate::String),
+            &format!("{}.to_owned()", expr),
+        ),
+        Delegate(slice @ IrTypeDelegate::Slice(_)) => {
+            generate_into_dart(&slice.get_delegate(), &format!("{}.to_vec()", expr))
+        }
+        // Paths hold the bytes of the OS, which are not always valid UTF-8
+        Delegate(
+            IrTypeDelegate::PathBuf(NonUtf8Paths::Lossy) | IrTypeDelegate::Path(NonUtf8Paths::Lossy),
+        ) => generate_into_dart(
+            &Delegate(IrTypeDelegate::String),
+            &format!(
+                "std::path::Path::to_string_lossy({}.as_ref()).into_owned()",
+                expr
+            ),
+        ),
+        Delegate(IrTypeDelegate::PathBuf(_) | IrTypeDelegate::Path(_)) => format!(
+            "{{ let path = {}; {} }}",
+            expr,
+            generate_into_dart(
+                &Delegate(IrTypeDelegate::String),
+                "std::path::Path::to_str(path.as_ref()).unwrap_or_else(|| panic!(\"{:?} is not valid UTF-8, so it cannot be sent to Dart\", path)).to_owned()",
+            )
+        ),
+        // `timestamp_micros` is missing from older versions of chrono
+        Delegate(IrTypeDelegate::Time(_)) => format!(
+            "{{ let time = {}; (time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64).into_dart() }}",
+            expr
+        ),
+        GeneralList(list) if needs_into_dart_conversion(ty) => format!(
+            "{}.into_iter().map(|e| {}).collect::<Vec<_>>().into_dart()",
+            expr,
+            generate_into_dart(&list.inner, "e"),
+        ),
+        Optional(opt) if needs_into_dart_conversion(&opt.inner) => format!(
+            "{}.map(|e| {}).into_dart()",
+            expr,
+            generate_into_dart(&opt.inner, "e"),
+        ),
+        Boxed(IrTypeBoxed {
+            exist_in_real_api: true,
+            pointer: IrTypeBoxedPointer::Box,
+            inner,
+        }) => generate_into_dart(inner, &format!("(*{})", expr)),
+        // Clones the value unless nothing else shares it
+        Boxed(IrTypeBoxed {
+            exist_in_real_api: true,
+            pointer,
+            inner,
+        }) => generate_into_dart(
+            inner,
+            &format!(
+                "{}::try_unwrap({}).unwrap_or_else(|shared| (*shared).clone())",
+                pointer.rust_path(),
+                expr
+            ),
+        ),
+        Boxed(boxed) if needs_into_dart_conversion(&boxed.inner) => {
+            generate_into_dart(&boxed.inner, expr)
+        }
+        _ => format!("{}.into_dart()", expr),
+    }
+}
+
 pub struct ExternFuncCollector {
+    /// Put before the name of each function, for the symbols of several bridges not to collide
+    symbol_prefix: String,
     names: Vec<String>,
 }
 
 impl ExternFuncCollector {
-    fn new() -> Self {
-        ExternFuncCollector { names: vec![] }
+    fn new(symbol_prefix: &str) -> Self {
+        ExternFuncCollector {
+            symbol_prefix: symbol_prefix.to_string(),
+            names: vec![],
+        }
     }
 
     fn generate(
@@ -417,7 +1500,8 @@ impl ExternFuncCollector {
         return_type: Option<&str>,
         body: &str,
     ) -> String {
-        self.names.push(func_name.to_string());
+        let func_name = format!("{}{}", self.symbol_prefix, func_name);
+        self.names.push(func_name.clone());
 
         format!(
             r#"
@@ -3,6 +3,7 @@ use enum_dispatch::enum_dispatch;
 
 #[enum_dispatch]
 pub trait TypeRustGeneratorTrait {
+    /// The body of `wire2api`, giving `Err` for the values which the Rust type cannot hold.
     fn wire2api_body(&self) -> Option<String>;
 
     fn wire_struct_fields(&self) -> Option<Vec<String>> {
@@ -17,6 +18,11 @@ pub trait TypeRustGeneratorTrait {
         "".to_string()
     }
 
+    /// Extern functions through which Dart releases what it owns.
+    fn drop_funcs(&self, _collector: &mut ExternFuncCollector) -> String {
+        "".to_string()
+    }
+
     fn impl_intodart(&self) -> String {
         "".to_string()
     }
@@ -44,33 +50,50 @@ macro_rules! type_rust_generator_struct {
             pub context: TypeGeneratorContext<'a>,
         }
     };
+    // For the types generated without looking up others in the IR file
+    ($cls:ident, $ir_cls:ty, without_context) => {
+        #[derive(Debug, Clone)]
+        pub struct $cls {
+            pub ir: $ir_cls,
+        }
+    };
 }
 
 #[enum_dispatch(TypeRustGeneratorTrait)]
 #[derive(Debug, Clone)]
 pub enum TypeRustGenerator<'a> {
-    Primitive(TypePrimitiveGenerator<'a>),
-    Delegate(TypeDelegateGenerator<'a>),
-    PrimitiveList(TypePrimitiveListGenerator<'a>),
+    Primitive(TypePrimitiveGenerator),
+    Delegate(TypeDelegateGenerator),
+    PrimitiveList(TypePrimitiveListGenerator),
     Optional(TypeOptionalGenerator<'a>),
     GeneralList(TypeGeneralListGenerator<'a>),
     StructRef(TypeStructRefGenerator<'a>),
     Boxed(TypeBoxedGenerator<'a>),
     EnumRef(TypeEnumRefGenerator<'a>),
+    Map(TypeMapGenerator),
+    Set(TypeSetGenerator<'a>),
+    Opaque(TypeOpaqueGenerator),
+    Array(TypeArrayGenerator<'a>),
+    Int128(TypeInt128Generator),
 }
 
 impl<'a> TypeRustGenerator<'a> {
     pub fn new(ty: IrType, ir_file: &'a IrFile) -> Self {
         let context = TypeGeneratorContext { ir_file };
         match ty {
-            Primitive(ir) => TypePrimitiveGenerator { ir, context }.into(),
-            Delegate(ir) => TypeDelegateGenerator { ir, context }.into(),
-            PrimitiveList(ir) => TypePrimitiveListGenerator { ir, context }.into(),
+            Primitive(_) => TypePrimitiveGenerator.into(),
+            Delegate(ir) => TypeDelegateGenerator { ir }.into(),
+            PrimitiveList(ir) => TypePrimitiveListGenerator { ir }.into(),
             Optional(ir) => TypeOptionalGenerator { ir, context }.into(),
             GeneralList(ir) => TypeGeneralListGenerator { ir, context }.into(),
             StructRef(ir) => TypeStructRefGenerator { ir, context }.into(),
             Boxed(ir) => TypeBoxedGenerator { ir, context }.into(),
             EnumRef(ir) => TypeEnumRefGenerator { ir, context }.into(),
+            Map(ir) => TypeMapGenerator { ir }.into(),
+            Set(ir) => TypeSetGenerator { ir, context }.into(),
+            Opaque(ir) => TypeOpaqueGenerator { ir }.into(),
+            Array(ir) => TypeArrayGenerator { ir, context }.into(),
+            Int128(ir) => TypeInt128Generator { ir }.into(),
         }
     }
 }
@@ -0,0 +1,24 @@
+use crate::generator::rust::generate_import;
+use crate::generator::rust::ty::*;
+use crate::ir::*;
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeArrayGenerator, IrTypeArray);
+
+impl TypeRustGeneratorTrait for TypeArrayGenerator<'_> {
+    fn wire2api_body(&self) -> Option<String> {
+        Some(format!(
+            "let vec: {} = self.wire2api()?;
+            std::convert::TryInto::try_into(vec).map_err(|vec: {}| {{
+                support::anyhow::anyhow!(\"Expected a list of {} items, but got {{}}\", vec.len())
+            }})",
+            self.ir.list().rust_api_type(),
+            self.ir.list().rust_api_type(),
+            self.ir.length,
+        ))
+    }
+
+    fn imports(&self) -> Option<String> {
+        generate_import(&self.ir.inner, self.context.ir_file)
+    }
+}
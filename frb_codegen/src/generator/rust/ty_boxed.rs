@@ -1,6 +1,5 @@
 use crate::generator::rust::ty::*;
 use crate::generator::rust::{generate_import, ExternFuncCollector};
-use crate::ir::IrType::Primitive;
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
@@ -11,26 +10,50 @@ impl TypeRustGeneratorTrait for TypeBoxedGenerator<'_> {
         let IrTypeBoxed {
             inner: box_inner,
             exist_in_real_api,
+            pointer,
         } = &self.ir;
+        if self.ir.reuses_inner_pointer() {
+            return Some(format!(
+                "Wire2Api::<{}>::wire2api(self).map(Into::into)",
+                box_inner.rust_api_type()
+            ));
+        }
         Some(match (box_inner.as_ref(), exist_in_real_api) {
-            (IrType::Primitive(_), false) => "unsafe { *support::box_from_leak_ptr(self) }".into(),
-            (IrType::Primitive(_), true) => "unsafe { support::box_from_leak_ptr(self) }".into(),
+            (IrType::Primitive(_), false) => "Ok(unsafe { *support::box_from_leak_ptr(self) })".into(),
+            (IrType::Primitive(_), true) if *pointer == IrTypeBoxedPointer::Box => {
+                "Ok(unsafe { support::box_from_leak_ptr(self) })".into()
+            }
+            (IrType::Primitive(_), true) => {
+                "Ok(unsafe { support::box_from_leak_ptr(self) }.into())".into()
+            }
+            (IrType::Delegate(_) | IrType::EnumRef(_), _)
+                if self.ir.inner_primitive().is_some() =>
+            {
+                format!(
+                    "let wrap = unsafe {{ support::box_from_leak_ptr(self) }};
+                Wire2Api::<{}>::wire2api(*wrap).map(Into::into)",
+                    box_inner.rust_api_type()
+                )
+            }
             _ => {
-                "let wrap = unsafe { support::box_from_leak_ptr(self) }; (*wrap).wire2api().into()"
+                "let wrap = unsafe { support::box_from_leak_ptr(self) }; (*wrap).wire2api().map(Into::into)"
                     .into()
             }
         })
     }
 
     fn allocate_funcs(&self, collector: &mut ExternFuncCollector) -> String {
-        match &*self.ir.inner {
-            Primitive(prim) => collector.generate(
+        if self.ir.reuses_inner_pointer() {
+            return String::new();
+        }
+        match (self.ir.inner_primitive(), &*self.ir.inner) {
+            (Some(prim), _) => collector.generate(
                 &format!("new_{}", self.ir.safe_ident()),
                 &[&format!("value: {}", prim.rust_wire_type())],
                 Some(&format!("*mut {}", prim.rust_wire_type())),
                 "support::new_leak_box_ptr(value)",
             ),
-            inner => collector.generate(
+            (None, inner) => collector.generate(
                 &format!("new_{}", self.ir.safe_ident()),
                 &[],
                 Some(&[self.ir.rust_wire_modifier(), self.ir.rust_wire_type()].concat()),
@@ -1,3 +1,4 @@
+use crate::config::NonZeroInts;
 use crate::generator::rust::ty::*;
 use crate::generator::rust::{
     generate_list_allocate_func, ExternFuncCollector, TypeGeneralListGenerator,
@@ -5,19 +6,82 @@ use crate::generator::rust::{
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
-type_rust_generator_struct!(TypeDelegateGenerator, IrTypeDelegate);
+type_rust_generator_struct!(TypeDelegateGenerator, IrTypeDelegate, without_context);
 
-impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
+impl TypeRustGeneratorTrait for TypeDelegateGenerator {
     fn wire2api_body(&self) -> Option<String> {
         Some(match &self.ir {
-            IrTypeDelegate::String => "let vec: Vec<u8> = self.wire2api();
-            String::from_utf8_lossy(&vec).into_owned()"
+            // Borrowed in the wire function, from the bytes, the `Vec` or the `String` it owns
+            IrTypeDelegate::Str | IrTypeDelegate::Slice(_) | IrTypeDelegate::Path(_) => {
+                return None
+            }
+            // Takes over the bytes, which `utf8.encode` wrote unless they were sent some other way
+            IrTypeDelegate::String => "let vec: Vec<u8> = self.wire2api()?;
+            Ok(String::from_utf8(vec)?)"
                 .into(),
             IrTypeDelegate::SyncReturnVecU8 => "/*unsupported*/".into(),
             IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
-                "ZeroCopyBuffer(self.wire2api())".into()
+                "self.wire2api().map(ZeroCopyBuffer)".into()
             }
             IrTypeDelegate::StringList => TypeGeneralListGenerator::WIRE2API_BODY.to_string(),
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => {
+                "let micros: u64 = std::convert::TryInto::try_into(self).map_err(|_| {
+                    support::anyhow::anyhow!(\"{} microseconds is not a valid Duration, which cannot be negative\", self)
+                })?;
+                Ok(std::time::Duration::from_micros(micros))"
+                    .into()
+            }
+            IrTypeDelegate::Time(time) => format!(
+                "let (secs, nsecs) = (self.div_euclid(1_000_000), (self.rem_euclid(1_000_000) * 1_000) as u32);
+                {}.ok_or_else(|| support::anyhow::anyhow!(\"the timestamp {{}} is out of range\", self))",
+                match time {
+                    IrTypeDelegateTime::Naive => "chrono::NaiveDateTime::from_timestamp_opt(secs, nsecs)",
+                    IrTypeDelegateTime::Utc => {
+                        "chrono::TimeZone::timestamp_opt(&chrono::Utc, secs, nsecs).single()"
+                    }
+                    IrTypeDelegateTime::Local => {
+                        "chrono::TimeZone::timestamp_opt(&chrono::Local, secs, nsecs).single()"
+                    }
+                    IrTypeDelegateTime::Duration => unreachable!(),
+                }
+            ),
+            IrTypeDelegate::Uuid(_) => "let bytes: Vec<u8> = self.wire2api()?;
+            let bytes: [u8; 16] = std::convert::TryInto::try_into(bytes).map_err(|bytes: Vec<u8>| {
+                support::anyhow::anyhow!(\"a UUID has 16 bytes, not {}\", bytes.len())
+            })?;
+            Ok(uuid::Uuid::from_bytes(bytes))"
+                .into(),
+            // Dart checks the code point already, unless it was sent some other way
+            IrTypeDelegate::Char => "char::from_u32(self).ok_or_else(|| support::anyhow::anyhow!(\"{:#x} is not a Unicode scalar value\", self))"
+                .into(),
+            // Converted by the impl of the primitive itself
+            IrTypeDelegate::BigInt64(_) => return None,
+            IrTypeDelegate::BoolList => "let bytes: Vec<u8> = self.wire2api()?;
+            Ok(bytes.into_iter().map(|byte| byte != 0).collect())"
+                .into(),
+            // The wire type of a `Vec<u8>` is also that of a `String` or a `Vec<bool>`
+            ty @ IrTypeDelegate::BoxedSlice(_) => format!(
+                "let vec: {} = self.wire2api()?;
+                Ok(vec.into_boxed_slice())",
+                ty.get_delegate().rust_api_type()
+            ),
+            IrTypeDelegate::Cow(_) => "self.wire2api().map(std::borrow::Cow::Owned)".into(),
+            IrTypeDelegate::PathBuf(_) => "let string: String = self.wire2api()?;
+            Ok(std::path::PathBuf::from(string))"
+                .into(),
+            // Written by `jsonEncode`, unless the value was sent some other way
+            IrTypeDelegate::Json => "let string: String = self.wire2api()?;
+            serde_json::from_str(&string).map_err(support::anyhow::Error::from)"
+                .into(),
+            // Dart checks for zero already, unless the value was sent some other way
+            ty @ IrTypeDelegate::NonZero(_, NonZeroInts::Error) => format!(
+                "{0}::new(self).ok_or_else(|| support::anyhow::anyhow!(\"zero is not a valid {0}\"))",
+                ty.rust_api_type()
+            ),
+            ty @ IrTypeDelegate::NonZero(_, NonZeroInts::Clamp) => format!(
+                "Ok({0}::new(self).unwrap_or_else(|| {0}::new(1).unwrap()))",
+                ty.rust_api_type()
+            ),
         })
     }
 
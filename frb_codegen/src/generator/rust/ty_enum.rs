@@ -1,5 +1,5 @@
 use crate::generator::rust::ty::*;
-use crate::generator::rust::ExternFuncCollector;
+use crate::generator::rust::{generate_into_dart, generate_wire_field_init, ExternFuncCollector};
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
@@ -8,14 +8,40 @@ type_rust_generator_struct!(TypeEnumRefGenerator, IrTypeEnumRef);
 impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
     fn wire2api_body(&self) -> Option<String> {
         let enu = self.ir.get(self.context.ir_file);
-        Some(if self.ir.is_struct {
+        Some(if self.ir.is_trait_object {
+            let variants = enu
+                .variants()
+                .iter()
+                .enumerate()
+                .map(|(idx, variant)| {
+                    format!(
+                        "{} => unsafe {{
+                            let ans = support::box_from_leak_ptr(self.kind);
+                            let ans = support::box_from_leak_ptr(ans.{});
+                            let value: {} = ans.field0.wire2api()?;
+                            Ok(Box::new(value))
+                        }}",
+                        idx,
+                        variant.name,
+                        trait_object_implementor(variant).rust_api_type(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "match self.tag {{
+                        {}
+                        _ => unreachable!(),
+                    }}",
+                variants.join("\n"),
+            )
+        } else if self.ir.is_struct {
             let variants = enu
                 .variants()
                 .iter()
                 .enumerate()
                 .map(|(idx, variant)| match &variant.kind {
                     IrVariantKind::Value => {
-                        format!("{} => {}::{},", idx, enu.name, variant.name)
+                        format!("{} => Ok({}::{}),", idx, enu.name, variant.name)
                     }
                     IrVariantKind::Struct(st) => {
                         let fields: Vec<_> = st
@@ -23,9 +49,9 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                             .iter()
                             .map(|field| {
                                 if st.is_fields_named {
-                                    format!("{0}: ans.{0}.wire2api()", field.name.rust_style())
+                                    format!("{0}: ans.{0}.wire2api()?", field.name.rust_style())
                                 } else {
-                                    format!("ans.{}.wire2api()", field.name.rust_style())
+                                    format!("ans.{}.wire2api()?", field.name.rust_style())
                                 }
                             })
                             .collect();
@@ -34,7 +60,7 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                             "{} => unsafe {{
                                         let ans = support::box_from_leak_ptr(self.kind);
                                         let ans = support::box_from_leak_ptr(ans.{2});
-                                        {}::{2}{3}{4}{5}
+                                        Ok({}::{2}{3}{4}{5})
                                     }}",
                             idx,
                             enu.name,
@@ -57,8 +83,10 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
             let variants = enu
                 .variants()
                 .iter()
-                .enumerate()
-                .map(|(idx, variant)| format!("{} => {}::{},", idx, enu.name, variant.name))
+                .zip(enu.discriminants())
+                .map(|(variant, discriminant)| {
+                    format!("{} => Ok({}::{}),", discriminant, enu.name, variant.name)
+                })
                 .collect::<Vec<_>>()
                 .join("\n");
             format!(
@@ -131,7 +159,40 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
     fn impl_intodart(&self) -> String {
         let src = self.ir.get(self.context.ir_file);
 
-        if self.ir.is_struct {
+        if self.ir.is_trait_object {
+            // The implementor is found by comparing `TypeId`s, the pointer is then cast back to it
+            let variants = src
+                .variants()
+                .iter()
+                .enumerate()
+                .map(|(idx, variant)| {
+                    let implementor = trait_object_implementor(variant).rust_api_type();
+                    format!(
+                        "if type_id == std::any::TypeId::of::<{0}>() {{
+                            let value = unsafe {{ Box::from_raw(Box::into_raw(self) as *mut {0}) }};
+                            return vec![{1}.into_dart(), {2}].into_dart();
+                        }}",
+                        implementor,
+                        idx,
+                        generate_into_dart(trait_object_implementor(variant), "(*value)"),
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "impl support::IntoDart for {0} {{
+                fn into_dart(self) -> support::DartCObject {{
+                    let type_id = std::any::Any::type_id(&*self);
+                    {1}
+                    panic!(\"Only the types listed by `#[frb(implementors = \\\"..\\\")]` on `{2}` can cross the bridge as `{0}`\")
+                }}
+            }}
+            impl support::IntoDartExceptPrimitive for {0} {{}}
+            ",
+                self.ir.rust_api_type(),
+                variants.join("\n"),
+                self.ir.name,
+            )
+        } else if self.ir.is_struct {
             let variants = src
                 .variants()
                 .iter()
@@ -146,7 +207,7 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                             let fields = Some(tag)
                                 .into_iter()
                                 .chain(s.fields.iter().map(|field| {
-                                    format!("{}.into_dart()", field.name.rust_style())
+                                    generate_into_dart(&field.ty, field.name.rust_style())
                                 }))
                                 .collect::<Vec<_>>();
                             let pattern = s
@@ -175,16 +236,20 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                     }}.into_dart()
                 }}
             }}
+            impl support::IntoDartExceptPrimitive for {} {{}}
             ",
                 self.ir.name,
-                variants.join("\n")
+                variants.join("\n"),
+                self.ir.name,
             )
         } else {
             let variants = src
                 .variants()
                 .iter()
-                .enumerate()
-                .map(|(idx, variant)| format!("Self::{} => {},", variant.name, idx))
+                .zip(src.discriminants())
+                .map(|(variant, discriminant)| {
+                    format!("Self::{} => {},", variant.name, discriminant)
+                })
                 .collect::<Vec<_>>()
                 .join("\n");
             format!(
@@ -195,8 +260,9 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                     }}.into_dart()
                 }}
             }}
+            impl support::IntoDartExceptPrimitive for {} {{}}
             ",
-                self.ir.name, variants
+                self.ir.name, variants, self.ir.name
             )
         }
     }
@@ -206,44 +272,56 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
             return "".to_string();
         }
 
-        fn init_of(ty: &IrType) -> &str {
-            if ty.rust_wire_is_pointer() {
-                "core::ptr::null_mut()"
-            } else {
-                "Default::default()"
-            }
-        }
-
         let src = self.ir.get(self.context.ir_file);
 
-        let inflators = src
+        // The data of each variant is boxed with null pointers and default values, which Dart fills
+        // in after setting the tag, so the union only holds a pointer whatever the variant
+        let variants = src
             .variants()
             .iter()
             .filter_map(|variant| {
-                let typ = format!("{}_{}", self.ir.name, variant.name);
-                let body: Vec<_> = if let IrVariantKind::Struct(st) = &variant.kind {
-                    st.fields
-                        .iter()
-                        .map(|field| format!("{}: {}", field.name.rust_style(), init_of(&field.ty)))
-                        .collect()
-                } else {
-                    return None;
+                let st = match &variant.kind {
+                    IrVariantKind::Struct(st) => st,
+                    IrVariantKind::Value => return None,
                 };
-                Some(collector.generate(
+                let typ = format!("{}_{}", self.ir.name, variant.name);
+                let fields = st
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}: {}",
+                            field.name.rust_style(),
+                            generate_wire_field_init(&field.ty)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let inflator = collector.generate(
                     &format!("inflate_{}", typ),
                     &[],
                     Some(&format!("*mut {}Kind", self.ir.name)),
                     &format!(
                         "support::new_leak_box_ptr({}Kind {{
-                        {}: support::new_leak_box_ptr({} {{
-                            {}
-                        }})
-                    }})",
+                            {}: support::new_leak_box_ptr({}::new_with_null_ptr())
+                        }})",
                         self.ir.name,
                         variant.name.rust_style(),
                         typ,
-                        body.join(",")
                     ),
+                );
+                Some(format!(
+                    "impl NewWithNullPtr for {} {{
+                        fn new_with_null_ptr() -> Self {{
+                            Self {{
+                                {}
+                            }}
+                        }}
+                    }}
+
+                    {}",
+                    typ,
+                    fields.join(","),
+                    inflator
                 ))
             })
             .collect::<Vec<_>>();
@@ -256,9 +334,10 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                     }}
                 }}
             }}
+
             {}",
             self.ir.rust_wire_type(),
-            inflators.join("\n\n")
+            variants.join("\n\n")
         )
     }
 
@@ -267,3 +346,14 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
         Some(format!("use {};", api_enum.path.join("::")))
     }
 }
+
+/// The type held by a variant of the enum standing for a trait object, which implements the trait
+fn trait_object_implementor(variant: &IrVariant) -> &IrType {
+    match &variant.kind {
+        IrVariantKind::Struct(st) => match &st.fields[0].ty {
+            IrType::Boxed(boxed) => &boxed.inner,
+            ty => ty,
+        },
+        IrVariantKind::Value => unreachable!(),
+    }
+}
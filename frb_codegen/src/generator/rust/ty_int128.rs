@@ -0,0 +1,37 @@
+use crate::generator::rust::ty::*;
+use crate::generator::rust::ExternFuncCollector;
+use crate::ir::*;
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeInt128Generator, IrTypeInt128, without_context);
+
+impl TypeRustGeneratorTrait for TypeInt128Generator {
+    fn wire2api_body(&self) -> Option<String> {
+        // Shifting the zero-extended high half puts its top bit in the sign bit of an `i128`
+        Some(format!(
+            "let wrap = unsafe {{ support::box_from_leak_ptr(self) }};
+            Ok(((wrap.hi as {ty}) << 64) | wrap.lo as {ty})",
+            ty = self.ir.rust_api_type(),
+        ))
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        Some(vec!["hi: u64".to_string(), "lo: u64".to_string()])
+    }
+
+    fn allocate_funcs(&self, collector: &mut ExternFuncCollector) -> String {
+        collector.generate(
+            &format!("new_{}", self.ir.safe_ident()),
+            &[],
+            Some(&format!(
+                "{}{}",
+                self.ir.rust_wire_modifier(),
+                self.ir.rust_wire_type()
+            )),
+            &format!(
+                "support::new_leak_box_ptr({} {{ hi: 0, lo: 0 }})",
+                self.ir.rust_wire_type()
+            ),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use crate::generator::rust::ty::*;
+use crate::generator::rust::ExternFuncCollector;
+use crate::ir::*;
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeMapGenerator, IrTypeMap, without_context);
+
+impl TypeRustGeneratorTrait for TypeMapGenerator {
+    fn wire2api_body(&self) -> Option<String> {
+        Some(format!(
+            "let wrap = unsafe {{ support::box_from_leak_ptr(self) }};
+            let keys: {} = wrap.keys.wire2api()?;
+            let values: {} = wrap.values.wire2api()?;
+            Ok(keys.into_iter().zip(values).collect())",
+            self.ir.key_list().rust_api_type(),
+            self.ir.value_list().rust_api_type(),
+        ))
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        let key_list = self.ir.key_list();
+        let value_list = self.ir.value_list();
+        Some(vec![
+            format!(
+                "keys: {}{}",
+                key_list.rust_wire_modifier(),
+                key_list.rust_wire_type()
+            ),
+            format!(
+                "values: {}{}",
+                value_list.rust_wire_modifier(),
+                value_list.rust_wire_type()
+            ),
+        ])
+    }
+
+    fn allocate_funcs(&self, collector: &mut ExternFuncCollector) -> String {
+        collector.generate(
+            &format!("new_{}", self.ir.safe_ident()),
+            &[],
+            Some(&format!(
+                "{}{}",
+                self.ir.rust_wire_modifier(),
+                self.ir.rust_wire_type()
+            )),
+            &format!(
+                "support::new_leak_box_ptr({}::new_with_null_ptr())",
+                self.ir.rust_wire_type()
+            ),
+        )
+    }
+
+    fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
+        format!(
+            r#"impl NewWithNullPtr for {} {{
+                    fn new_with_null_ptr() -> Self {{
+                        Self {{ keys: core::ptr::null_mut(), values: core::ptr::null_mut() }}
+                    }}
+                }}
+            "#,
+            self.ir.rust_wire_type(),
+        )
+    }
+}
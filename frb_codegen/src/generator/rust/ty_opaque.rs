@@ -0,0 +1,74 @@
+use crate::generator::rust::ty::*;
+use crate::generator::rust::ExternFuncCollector;
+use crate::ir::*;
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeOpaqueGenerator, IrTypeOpaque, without_context);
+
+impl TypeRustGeneratorTrait for TypeOpaqueGenerator {
+    fn wire2api_body(&self) -> Option<String> {
+        Some(format!(
+            "let wrap = unsafe {{ support::box_from_leak_ptr(self) }};
+            Ok(unsafe {{ support::opaque_from_dart::<{}>(wrap.ptr) }})",
+            self.ir.name
+        ))
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        Some(vec!["ptr: *const core::ffi::c_void".to_string()])
+    }
+
+    fn allocate_funcs(&self, collector: &mut ExternFuncCollector) -> String {
+        collector.generate(
+            &format!("new_{}", self.ir.safe_ident()),
+            &[],
+            Some(&format!(
+                "{}{}",
+                self.ir.rust_wire_modifier(),
+                self.ir.rust_wire_type()
+            )),
+            &format!(
+                "support::new_leak_box_ptr({}::new_with_null_ptr())",
+                self.ir.rust_wire_type()
+            ),
+        )
+    }
+
+    fn drop_funcs(&self, collector: &mut ExternFuncCollector) -> String {
+        collector.generate(
+            &self.ir.drop_func_name(),
+            &["ptr: *const core::ffi::c_void"],
+            None,
+            &format!("unsafe {{ support::drop_opaque::<{}>(ptr) }}", self.ir.name),
+        )
+    }
+
+    fn impl_intodart(&self) -> String {
+        format!(
+            "impl support::IntoDart for {} {{
+                fn into_dart(self) -> support::DartCObject {{
+                    support::opaque_into_dart(self)
+                }}
+            }}
+            impl support::IntoDartExceptPrimitive for {} {{}}
+            ",
+            self.ir.name, self.ir.name,
+        )
+    }
+
+    fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
+        format!(
+            r#"impl NewWithNullPtr for {} {{
+                    fn new_with_null_ptr() -> Self {{
+                        Self {{ ptr: core::ptr::null() }}
+                    }}
+                }}
+            "#,
+            self.ir.rust_wire_type(),
+        )
+    }
+
+    fn imports(&self) -> Option<String> {
+        Some(format!("use {};", self.ir.path.join("::")))
+    }
+}
@@ -1,5 +1,5 @@
-use crate::generator::rust::generate_import;
 use crate::generator::rust::ty::*;
+use crate::generator::rust::{generate_import, ExternFuncCollector};
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
@@ -7,7 +7,52 @@ type_rust_generator_struct!(TypeOptionalGenerator, IrTypeOptional);
 
 impl TypeRustGeneratorTrait for TypeOptionalGenerator<'_> {
     fn wire2api_body(&self) -> Option<String> {
-        None
+        self.ir
+            .compact_primitive()
+            .map(|_| "self.is_some.then(|| self.value.wire2api()).transpose()".to_owned())
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        self.ir.compact_primitive().map(|prim| {
+            vec![
+                "is_some: bool".to_owned(),
+                format!("value: {}", prim.rust_wire_type()),
+            ]
+        })
+    }
+
+    fn allocate_funcs(&self, collector: &mut ExternFuncCollector) -> String {
+        // Returned by value, so that an argument is not behind a pointer either
+        match self.ir.compact_primitive() {
+            Some(prim) => collector.generate(
+                &format!("new_{}", self.ir.safe_ident()),
+                &[
+                    "is_some: bool",
+                    &format!("value: {}", prim.rust_wire_type()),
+                ],
+                Some(&self.ir.rust_wire_type()),
+                &format!("{} {{ is_some, value }}", self.ir.rust_wire_type()),
+            ),
+            None => "".to_string(),
+        }
+    }
+
+    fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
+        match self.ir.compact_primitive() {
+            Some(_) => format!(
+                "impl NewWithNullPtr for {} {{
+                    fn new_with_null_ptr() -> Self {{
+                        Self {{
+                            is_some: false,
+                            value: Default::default(),
+                        }}
+                    }}
+                }}
+                ",
+                self.ir.rust_wire_type()
+            ),
+            None => "".to_string(),
+        }
     }
 
     fn imports(&self) -> Option<String> {
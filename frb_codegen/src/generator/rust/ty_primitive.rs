@@ -1,11 +1,10 @@
 use crate::generator::rust::ty::*;
-use crate::ir::*;
-use crate::type_rust_generator_struct;
 
-type_rust_generator_struct!(TypePrimitiveGenerator, IrTypePrimitive);
+#[derive(Debug, Clone)]
+pub struct TypePrimitiveGenerator;
 
-impl TypeRustGeneratorTrait for TypePrimitiveGenerator<'_> {
+impl TypeRustGeneratorTrait for TypePrimitiveGenerator {
     fn wire2api_body(&self) -> Option<String> {
-        Some("self".into())
+        Some("Ok(self)".into())
     }
 }
@@ -3,15 +3,19 @@ use crate::generator::rust::ExternFuncCollector;
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
-type_rust_generator_struct!(TypePrimitiveListGenerator, IrTypePrimitiveList);
+type_rust_generator_struct!(
+    TypePrimitiveListGenerator,
+    IrTypePrimitiveList,
+    without_context
+);
 
-impl TypeRustGeneratorTrait for TypePrimitiveListGenerator<'_> {
+impl TypeRustGeneratorTrait for TypePrimitiveListGenerator {
     fn wire2api_body(&self) -> Option<String> {
         Some(
-            "unsafe {
+            "Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }"
+        })"
             .into(),
         )
     }
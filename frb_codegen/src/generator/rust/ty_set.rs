@@ -0,0 +1,20 @@
+use crate::generator::rust::generate_import;
+use crate::generator::rust::ty::*;
+use crate::ir::*;
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeSetGenerator, IrTypeSet);
+
+impl TypeRustGeneratorTrait for TypeSetGenerator<'_> {
+    fn wire2api_body(&self) -> Option<String> {
+        Some(format!(
+            "let vec: {} = self.wire2api()?;
+            Ok(vec.into_iter().collect())",
+            self.ir.list().rust_api_type(),
+        ))
+    }
+
+    fn imports(&self) -> Option<String> {
+        generate_import(&self.ir.inner, self.context.ir_file)
+    }
+}
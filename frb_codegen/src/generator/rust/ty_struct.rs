@@ -1,5 +1,5 @@
 use crate::generator::rust::ty::*;
-use crate::generator::rust::ExternFuncCollector;
+use crate::generator::rust::{generate_into_dart, generate_wire_field_init, ExternFuncCollector};
 use crate::ir::*;
 use crate::type_rust_generator_struct;
 
@@ -8,35 +8,79 @@ type_rust_generator_struct!(TypeStructRefGenerator, IrTypeStructRef);
 impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
     fn wire2api_body(&self) -> Option<String> {
         let api_struct = self.ir.get(self.context.ir_file);
-        let fields_str = &api_struct
-            .fields
-            .iter()
-            .map(|field| {
-                format!(
-                    "{} self.{}.wire2api()",
-                    if api_struct.is_fields_named {
-                        field.name.rust_style().to_string() + ": "
-                    } else {
-                        String::new()
-                    },
-                    field.name.rust_style()
+        if api_struct.is_pod {
+            return Some("Ok(self)".to_owned());
+        }
+        if self.ir.tuple_fields.is_some() {
+            let fields_str = api_struct
+                .fields
+                .iter()
+                .map(|field| format!("self.{}.wire2api()?,", field.name.rust_style()))
+                .collect::<String>();
+            return Some(format!("Ok(({}))", fields_str));
+        }
+        let fields_str = if api_struct.is_fields_named {
+            api_struct
+                .fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}: self.{}.wire2api()?",
+                        field.name.rust_style(),
+                        field.name.rust_style()
+                    )
+                })
+                .chain(
+                    api_struct
+                        .skipped_fields
+                        .iter()
+                        .map(|name| format!("{}: Default::default()", name.rust_style())),
                 )
-            })
-            .collect::<Vec<_>>()
-            .join(",");
+                .collect::<Vec<_>>()
+                .join(",")
+        } else {
+            // Skipped fields keep their place among the fields of a tuple struct
+            (0..api_struct.fields.len() + api_struct.skipped_fields.len())
+                .map(|idx| {
+                    let name = format!("field{}", idx);
+                    if api_struct.fields.iter().any(|field| field.name.raw == name) {
+                        format!("self.{}.wire2api()?", name)
+                    } else {
+                        "Default::default()".to_owned()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
 
         let (left, right) = api_struct.brackets_pair();
-        Some(format!(
-            "{}{}{}{}",
-            self.ir.rust_api_type(),
-            left,
-            fields_str,
-            right
-        ))
+        let api_struct_expr = |name: &str| format!("{}{}{}{}", name, left, fields_str, right);
+        let expr = match &self.ir.foreign_rust_type {
+            // A mirrored type has the same fields
+            Some(foreign_rust_type) if api_struct.is_mirror => api_struct_expr(foreign_rust_type),
+            Some(foreign_rust_type) => format!(
+                "{}::from({})",
+                foreign_rust_type,
+                api_struct_expr(&api_struct.name)
+            ),
+            // The type arguments of a generic struct are inferred, `Response<User> { .. }` is not
+            // an expression
+            None => api_struct_expr(
+                api_struct
+                    .path
+                    .as_ref()
+                    .and_then(|path| path.last())
+                    .unwrap_or(&api_struct.name),
+            ),
+        };
+        Some(format!("Ok({})", expr))
     }
 
     fn wire_struct_fields(&self) -> Option<Vec<String>> {
         let s = self.ir.get(self.context.ir_file);
+        if s.is_pod {
+            return None;
+        }
         Some(
             s.fields
                 .iter()
@@ -52,21 +96,80 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
         )
     }
 
+    fn structs(&self) -> String {
+        // The C header names the struct itself after the wire type, see `bindgen_rust_to_dart`
+        if self.ir.get(self.context.ir_file).is_pod {
+            format!(
+                "pub type {} = {};",
+                self.ir.rust_wire_type(),
+                self.ir.rust_api_type()
+            )
+        } else {
+            "".to_string()
+        }
+    }
+
     fn impl_intodart(&self) -> String {
+        // Tuples are standard types, so they are converted where they are used instead
+        if self.ir.tuple_fields.is_some() {
+            return "".to_string();
+        }
         let src = self.ir.get(self.context.ir_file);
 
         let body = src
             .fields
             .iter()
             .map(|field| {
-                format!(
-                    "self.{}.into_dart()",
-                    field.name_rust_style(src.is_fields_named)
+                generate_into_dart(
+                    &field.ty,
+                    &format!("self.{}", field.name_rust_style(src.is_fields_named)),
                 )
             })
             .collect::<Vec<_>>()
             .join(",\n");
 
+        // The type of another crate is converted into the struct where it is used
+        let rust_type = match &self.ir.foreign_rust_type {
+            Some(_) => self.ir.name.clone(),
+            None => self.ir.rust_api_type(),
+        };
+        let impl_from_mirrored = match &self.ir.foreign_rust_type {
+            Some(foreign_rust_type) if src.is_mirror => {
+                let fields = if src.is_fields_named {
+                    src.fields
+                        .iter()
+                        .map(|field| field.name.rust_style().to_owned())
+                        .chain(
+                            src.skipped_fields
+                                .iter()
+                                .map(|name| name.rust_style().to_owned()),
+                        )
+                        .map(|name| format!("{0}: value.{0}", name))
+                        .collect::<Vec<_>>()
+                } else {
+                    (0..src.fields.len() + src.skipped_fields.len())
+                        .map(|idx| format!("value.{}", idx))
+                        .collect()
+                };
+                let (left, right) = src.brackets_pair();
+                format!(
+                    "impl From<{}> for {} {{
+                        fn from(value: {}) -> Self {{
+                            {}{}{}{}
+                        }}
+                    }}
+                    ",
+                    foreign_rust_type,
+                    self.ir.name,
+                    foreign_rust_type,
+                    self.ir.name,
+                    left,
+                    fields.join(","),
+                    right
+                )
+            }
+            _ => "".to_string(),
+        };
         format!(
             "impl support::IntoDart for {} {{
                 fn into_dart(self) -> support::DartCObject {{
@@ -76,13 +179,25 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
                 }}
             }}
             impl support::IntoDartExceptPrimitive for {} {{}}
-            ",
-            self.ir.name, body, self.ir.name,
+            {}",
+            rust_type, body, rust_type, impl_from_mirrored,
         )
     }
 
     fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
         let src = self.ir.get(self.context.ir_file);
+        if src.is_pod {
+            // Zeros are valid for all the primitives, unlike `Default` for arrays of more than 32
+            return format!(
+                "impl NewWithNullPtr for {} {{
+                    fn new_with_null_ptr() -> Self {{
+                        unsafe {{ std::mem::zeroed() }}
+                    }}
+                }}
+                ",
+                self.ir.rust_wire_type(),
+            );
+        }
 
         let body = {
             src.fields
@@ -91,11 +206,7 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
                     format!(
                         "{}: {},",
                         field.name.rust_style(),
-                        if field.ty.rust_wire_is_pointer() {
-                            "core::ptr::null_mut()"
-                        } else {
-                            "Default::default()"
-                        }
+                        generate_wire_field_init(&field.ty)
                     )
                 })
                 .collect::<Vec<_>>()
@@ -115,13 +226,9 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
 
     fn imports(&self) -> Option<String> {
         let api_struct = self.ir.get(self.context.ir_file);
-        if api_struct.path.is_some() {
-            Some(format!(
-                "use {};",
-                api_struct.path.as_ref().unwrap().join("::")
-            ))
-        } else {
-            None
-        }
+        api_struct
+            .path
+            .as_ref()
+            .map(|path| format!("use {};", path.join("::")))
     }
 }
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct IrComment(String);
 
 impl IrComment {
@@ -9,18 +11,71 @@ impl IrComment {
 
 impl From<&str> for IrComment {
     fn from(input: &str) -> Self {
+        let input = input.replace("\r\n", "\n").replace('\r', "\n");
         if input.contains('\n') {
             // Dart's formatter has issues with block comments
             // so we convert them ahead of time.
-            let formatted = input
-                .split('\n')
-                .into_iter()
-                .map(|e| format!("///{}", e))
-                .collect::<Vec<_>>()
-                .join("\n");
-            Self(formatted)
+            Self(
+                unindent_block_comment(&input)
+                    .iter()
+                    .map(|line| {
+                        if line.is_empty() {
+                            "///".to_string()
+                        } else {
+                            format!("/// {}", line)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
         } else {
             Self(format!("///{}", input))
         }
     }
 }
+
+/// Strips what only makes sense inside a Rust block comment, the way rustdoc does: blank lines
+/// around the text, a leading `*` on every line and the common indentation. Dart would otherwise
+/// render indented lines as code and `*` as list items.
+fn unindent_block_comment(input: &str) -> Vec<&str> {
+    // Text right after the opening `/**` has no comparable indentation
+    let is_first_line_inline = !input
+        .split('\n')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .is_empty();
+    let mut lines = input.split('\n').collect::<Vec<_>>();
+    while lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let is_decorated = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.trim_start().starts_with('*'));
+    if is_decorated {
+        for line in &mut lines {
+            *line = line.trim_start().strip_prefix('*').unwrap_or_default();
+        }
+    }
+
+    let indent = lines
+        .iter()
+        .skip(is_first_line_inline as usize)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| match idx {
+            0 if is_first_line_inline => line.trim(),
+            _ => line.get(indent..).unwrap_or_default().trim_end(),
+        })
+        .collect()
+}
@@ -0,0 +1,25 @@
+use crate::ir::*;
+use serde::Serialize;
+
+/// A public `const` or `static` of an input file, whose type is a number, a boolean, a `char` or a
+/// string
+#[derive(Debug, Clone, Serialize)]
+pub struct IrConst {
+    pub name: String,
+    /// The name of the Dart constant or getter, given by `#[frb(name = "..")]` or derived from the
+    /// Rust name
+    pub dart_name: String,
+    pub ty: IrType,
+    /// The Dart literal of the value, when it is a literal in Rust too. Other values are read
+    /// through an extern function.
+    pub value: Option<String>,
+    pub comments: Vec<IrComment>,
+    pub deprecation: Option<IrDeprecation>,
+}
+
+impl IrConst {
+    /// The extern function returning the value, when it is not a literal
+    pub fn wire_func_name(&self) -> String {
+        format!("wire_const_{}", self.name)
+    }
+}
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+/// A `#[deprecated]` attribute, with its `note` if any.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrDeprecation {
+    pub note: Option<String>,
+}
@@ -1,10 +1,18 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrField {
     pub ty: IrType,
     pub name: IrIdent,
     pub comments: Vec<IrComment>,
+    pub deprecation: Option<IrDeprecation>,
+    /// The Dart literal of the value given by `#[frb(default = "..")]` to an argument, which can
+    /// then be omitted in Dart.
+    pub default: Option<String>,
+    /// Whether the field of the Dart class is `final`, which `#[frb(non_final)]` turns off for the
+    /// fields of structs.
+    pub is_final: bool,
 }
 
 impl IrField {
@@ -1,15 +1,29 @@
 use crate::ir::*;
-use std::collections::{HashMap, HashSet};
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 pub type IrStructPool = HashMap<String, IrStruct>;
 pub type IrEnumPool = HashMap<String, IrEnum>;
 
-#[derive(Debug, Clone)]
+/// The name of the generated function running the functions marked `#[frb(init)]`
+pub const INIT_FUNC_NAME: &str = "init";
+
+/// The name of the method of the API class tearing down the bridge, which the base class of the
+/// generated implementation provides
+pub const DISPOSE_FUNC_NAME: &str = "dispose";
+
+#[derive(Debug, Clone, Serialize)]
 pub struct IrFile {
     pub funcs: Vec<IrFunc>,
+    pub consts: Vec<IrConst>,
+    #[serde(serialize_with = "serialize_sorted")]
     pub struct_pool: IrStructPool,
+    #[serde(serialize_with = "serialize_sorted")]
     pub enum_pool: IrEnumPool,
     pub has_executor: bool,
+    /// The functions marked `#[frb(init)]`, which the generated `init` runs once
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub init_funcs: Vec<String>,
 }
 
 impl IrFile {
@@ -28,10 +42,42 @@ impl IrFile {
             }
             if include_func_output {
                 func.output.visit_types(f, self);
+                if let Some(error_output) = &func.error_output {
+                    error_output.visit_types(f, self);
+                }
             }
         }
     }
 
+    /// The functions that are methods of the given struct
+    pub fn methods_of<'a>(&'a self, struct_name: &'a str) -> impl Iterator<Item = &'a IrFunc> {
+        self.funcs.iter().filter(move |func| {
+            func.method
+                .as_ref()
+                .is_some_and(|method| method.struct_name == struct_name)
+        })
+    }
+
+    /// The traits of the trait objects that can be the given struct, by name
+    pub fn traits_implemented_by(&self, struct_name: &str) -> Vec<&str> {
+        let mut names = self
+            .enum_pool
+            .values()
+            .filter(|enu| {
+                enu.is_trait_object
+                    && enu
+                        .variants()
+                        .iter()
+                        .any(|variant| variant.name.raw == struct_name)
+            })
+            .map(|enu| enu.name.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// The types used by the functions, each once, sorted by [IrTypeTrait::safe_ident] which
+    /// tells them apart, so that the generated code does not depend on the order of the functions
     pub fn distinct_types(
         &self,
         include_func_inputs: bool,
@@ -58,4 +104,38 @@ impl IrFile {
 
         ans
     }
+
+    /// A hash of the IR, which the generated Rust and Dart compare at runtime to tell whether they
+    /// were generated from the same API. Comments and deprecations are left out, since they do
+    /// not change what crosses the wire. The hash is FNV-1a, which unlike `DefaultHasher` stays
+    /// the same from one Rust version to another, for the generated code not to change with it.
+    pub fn wire_hash(&self) -> u32 {
+        let mut ir = serde_json::to_value(self).expect("the IR can always be serialized");
+        strip_docs(&mut ir);
+        ir.to_string().bytes().fold(0x811c9dc5, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+        })
+    }
+}
+
+fn strip_docs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(object) => {
+            object.remove("comments");
+            object.remove("deprecation");
+            object.values_mut().for_each(strip_docs);
+        }
+        serde_json::Value::Array(array) => array.iter_mut().for_each(strip_docs),
+        _ => {}
+    }
+}
+
+/// Serializes a pool ordered by name, so that the output does not change from one run to another
+fn serialize_sorted<S: Serializer, V: Serialize>(
+    pool: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    pool.iter()
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
 }
@@ -1,47 +1,241 @@
+use crate::config::DartNamesStyle;
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrFunc {
     pub name: String,
     pub inputs: Vec<IrField>,
     pub output: IrType,
+    /// The `E` of a function returning `Result<T, E>`, unless it is an `anyhow::Error` that is
+    /// only reported to Dart as a message.
+    pub error_output: Option<IrType>,
     pub fallible: bool,
+    pub is_async: bool,
     pub mode: IrFuncMode,
     pub comments: Vec<IrComment>,
+    pub deprecation: Option<IrDeprecation>,
+    /// The name given by `#[frb(name = "..")]`, which names the Dart method instead for a method.
+    /// The Rust function keeps its name.
+    pub dart_name: Option<String>,
+    /// Set if the function is a method of a struct, in which case the receiver is the first input.
+    pub method: Option<IrMethod>,
+    /// The arguments marked `#[frb(out)]`, which the function fills in through a `&mut T` rather
+    /// than Dart giving them. They are part of `output`, after the value returned by the function.
+    pub out_params: Vec<IrOutParam>,
+    /// Whether the function itself returns `()`, `output` then being made of the out-parameters
+    /// only: the value of the only one, or a tuple of them all.
+    pub out_params_only: bool,
+    /// `#[frb(extension)]`, which makes the function an extension method in Dart as well, called on
+    /// its first argument
+    pub is_extension: bool,
+    /// The `CancellationToken` argument, which makes the task of the function cancellable from
+    /// Dart
+    pub cancellation_token: Option<IrCancellationToken>,
+    /// The `DartCallback` arguments, Dart functions which the function can call while it runs
+    pub callbacks: Vec<IrCallback>,
+    /// Whether the function returns an `impl Iterator<Item = T>`, which the wrapper collects into
+    /// the `Vec<T>` of `output`
+    pub collects_iterator: bool,
+    /// Set by the transformer, like the style of the names of the arguments
+    #[serde(skip)]
+    pub dart_names_style: DartNamesStyle,
+    /// Whether the method returns a reference borrowed from its receiver, which the wrapper copies
+    /// with `ToOwned` into the `output` while the receiver is alive
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub copies_borrowed_output: bool,
+    /// The lock of `#[frb(lock = "..")]` or `#[frb(read_lock = "..")]` held by the wrapper while
+    /// the function runs, which leaves the wire as it is
+    #[serde(skip)]
+    pub lock: Option<IrFuncLock>,
+    /// Set if the function is an instantiation of a generic function given by
+    /// `#[frb(instantiate = "..")]`, which the wrapper calls with its type arguments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<IrFuncInstantiation>,
+    /// The position of the Rust input file declaring the function, whose submodule holds the wire
+    /// function when the generated Rust code is split by module
+    #[serde(skip)]
+    pub source_index: usize,
 }
 
 impl IrFunc {
     pub fn wire_func_name(&self) -> String {
         format!("wire_{}", self.name)
     }
+
+    /// The name of the function of the Dart API
+    pub fn dart_func_name(&self) -> String {
+        match (&self.dart_name, &self.method) {
+            (Some(dart_name), None) => dart_name.clone(),
+            _ => self.dart_names_style.convert(&self.name),
+        }
+    }
+
+    /// The name of the method of the Dart class, given the method this function stands for
+    pub fn dart_method_name(&self, method: &IrMethod) -> String {
+        self.dart_name
+            .clone()
+            .unwrap_or_else(|| self.dart_names_style.convert(&method.name))
+    }
+}
+
+/// A method declared in an `impl` block of a struct
+#[derive(Debug, Clone, Serialize)]
+pub struct IrMethod {
+    pub struct_name: String,
+    /// The Rust type the method is called on, e.g. `Response<User>` for the instantiation
+    /// `ResponseUser` of a generic struct
+    pub self_type: String,
+    pub name: String,
+    pub receiver: IrMethodReceiver,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum IrMethodReceiver {
+    /// `&self`
+    Ref,
+    /// `&mut self`
+    RefMut,
+    /// `self`
+    Value,
+}
+
+impl IrMethodReceiver {
+    /// How the receiver, stored in the variable `name`, is passed to the method
+    pub fn rust_call_arg(&self, name: &str) -> String {
+        match self {
+            Self::Ref => format!("&{}", name),
+            Self::RefMut => format!("&mut {}", name),
+            Self::Value => name.to_string(),
+        }
+    }
+}
+
+/// An argument marked `#[frb(out)]`, filled in by the function from `T::default()`
+#[derive(Debug, Clone, Serialize)]
+pub struct IrOutParam {
+    pub name: IrIdent,
+    /// The `T` of the `&mut T`
+    pub ty: IrType,
+    /// The position among the arguments of the Rust function, not counting the receiver
+    pub position: usize,
+}
+
+/// The `CancellationToken` argument of a function, which Dart gives as an optional
+/// `CancellationToken` of its own
+#[derive(Debug, Clone, Serialize)]
+pub struct IrCancellationToken {
+    pub name: IrIdent,
+    /// The position among the arguments of the Rust function, not counting the receiver
+    pub position: usize,
+}
+
+/// A lock shared by the functions naming the same key, generated as a `RwLock` in the Rust code
+#[derive(Debug, Clone, Serialize)]
+pub struct IrFuncLock {
+    /// A snake_case identifier
+    pub key: String,
+    /// Whether the function only reads the state, `#[frb(read_lock = "..")]` letting such
+    /// functions run at the same time
+    pub shared: bool,
+}
+
+impl IrFuncLock {
+    /// The static holding the `RwLock`
+    pub fn static_name(&self) -> String {
+        format!("FLUTTER_RUST_BRIDGE_LOCK_{}", self.key.to_uppercase())
+    }
+}
+
+/// The type arguments a generic function is called with, the function being named after them
+#[derive(Debug, Clone, Serialize)]
+pub struct IrFuncInstantiation {
+    /// The name of the generic Rust function
+    pub generic_name: String,
+    /// In the order of the type parameters
+    pub type_args: Vec<String>,
+}
+
+impl IrFuncInstantiation {
+    /// The path calling the generic function with the type arguments
+    pub fn rust_call_path(&self) -> String {
+        format!("{}::<{}>", self.generic_name, self.type_args.join(", "))
+    }
+}
+
+/// A `DartCallback<fn(..) -> R>` argument of a function, given by Dart as a Dart function
+#[derive(Debug, Clone, Serialize)]
+pub struct IrCallback {
+    pub name: IrIdent,
+    pub signature: IrCallbackSignature,
+    /// The position among the arguments of the Rust function, not counting the receiver
+    pub position: usize,
+}
+
+/// The `fn(..) -> R` of a `DartCallback`
+#[derive(Debug, Clone, Serialize)]
+pub struct IrCallbackSignature {
+    /// The arguments, numbers, booleans or strings, named if the `fn` type names them
+    pub inputs: Vec<(Option<IrIdent>, IrType)>,
+    /// A primitive, `()` if the function returns nothing
+    pub output: IrType,
+}
+
+impl IrCallbackSignature {
+    /// The type of the Dart function, e.g. `int Function(int a, int b)`
+    pub fn dart_api_type(&self) -> String {
+        let params = self
+            .inputs
+            .iter()
+            .map(|(name, ty)| match name {
+                Some(name) => format!("{} {}", ty.dart_api_type(), name.dart_style()),
+                None => ty.dart_api_type(),
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{} Function({})",
+            self.output.dart_api_type(),
+            params.join(", ")
+        )
+    }
+
+    /// The extern function Dart gives the value its function returns to
+    pub fn reply_func_name(&self) -> String {
+        format!("reply_callback_{}", self.output.safe_ident())
+    }
 }
 
 /// Represents a function's output type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrFuncOutput {
-    ResultType(IrType),
+    ResultType(IrType, Option<IrType>),
     Type(IrType),
 }
 
 /// Represents the type of an argument to a function
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrFuncArg {
     StreamSinkType(IrType),
+    CancellationToken,
+    Callback(IrCallbackSignature),
     Type(IrType),
 }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialOrd, PartialEq)]
 pub enum IrFuncMode {
     Normal,
     Sync,
     Stream,
+    /// `#[frb(sync)]`, which returns a primitive in the bytes of a [IrFuncMode::Sync] call, so
+    /// that its errors are reported the same way.
+    Direct,
 }
 
 impl IrFuncMode {
     pub fn dart_return_type(&self, inner: &str) -> String {
         match self {
             Self::Normal => format!("Future<{}>", inner),
-            Self::Sync => inner.to_string(),
+            Self::Sync | Self::Direct => inner.to_string(),
             Self::Stream => format!("Stream<{}>", inner),
         }
     }
@@ -49,12 +243,12 @@ impl IrFuncMode {
     pub fn ffi_call_mode(&self) -> &'static str {
         match self {
             Self::Normal => "Normal",
-            Self::Sync => "Sync",
+            Self::Sync | Self::Direct => "Sync",
             Self::Stream => "Stream",
         }
     }
 
     pub fn has_port_argument(&self) -> bool {
-        self != &Self::Sync
+        !matches!(self, Self::Sync | Self::Direct)
     }
 }
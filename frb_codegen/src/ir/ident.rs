@@ -1,8 +1,14 @@
-use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+use crate::config::DartNamesStyle;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct IrIdent {
     pub raw: String,
+    /// Set by the transformer for the names Dart converts, left out of the wire hash since the
+    /// Dart names do not bind the data
+    #[serde(skip)]
+    pub dart_names_style: DartNamesStyle,
 }
 
 impl std::fmt::Display for IrIdent {
@@ -13,7 +19,10 @@ impl std::fmt::Display for IrIdent {
 
 impl IrIdent {
     pub fn new(raw: String) -> IrIdent {
-        IrIdent { raw }
+        IrIdent {
+            raw,
+            dart_names_style: DartNamesStyle::default(),
+        }
     }
 
     pub fn rust_style(&self) -> &str {
@@ -21,6 +30,6 @@ impl IrIdent {
     }
 
     pub fn dart_style(&self) -> String {
-        self.raw.to_case(Case::Camel)
+        self.dart_names_style.convert(&self.raw)
     }
 }
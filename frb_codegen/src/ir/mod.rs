@@ -1,29 +1,43 @@
 mod comment;
+mod constant;
+mod deprecation;
 mod field;
 mod file;
 mod func;
 mod ident;
 mod ty;
+mod ty_array;
 mod ty_boxed;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_int128;
+mod ty_map;
+mod ty_opaque;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
+mod ty_set;
 mod ty_struct;
 
 pub use comment::*;
+pub use constant::*;
+pub use deprecation::*;
 pub use field::*;
 pub use file::*;
 pub use func::*;
 pub use ident::*;
 pub use ty::*;
+pub use ty_array::*;
 pub use ty_boxed::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_int128::*;
+pub use ty_map::*;
+pub use ty_opaque::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
+pub use ty_set::*;
 pub use ty_struct::*;
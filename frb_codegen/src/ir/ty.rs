@@ -1,10 +1,11 @@
 use crate::ir::*;
 use enum_dispatch::enum_dispatch;
+use serde::Serialize;
 use IrType::*;
 
 /// Remark: "Ty" instead of "Type", since "type" is a reserved word in Rust.
 #[enum_dispatch(IrTypeTrait)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrType {
     Primitive(IrTypePrimitive),
     Delegate(IrTypeDelegate),
@@ -14,6 +15,11 @@ pub enum IrType {
     StructRef(IrTypeStructRef),
     Boxed(IrTypeBoxed),
     EnumRef(IrTypeEnumRef),
+    Map(IrTypeMap),
+    Set(IrTypeSet),
+    Opaque(IrTypeOpaque),
+    Array(IrTypeArray),
+    Int128(IrTypeInt128),
 }
 
 impl IrType {
@@ -25,6 +31,18 @@ impl IrType {
         self.visit_children_types(f, ir_file);
     }
 
+    /// The type of a `Vec` of this type, as the parser would produce it.
+    pub fn list_of(inner: IrType) -> IrType {
+        match inner {
+            Primitive(IrTypePrimitive::Bool) => Delegate(IrTypeDelegate::BoolList),
+            Primitive(primitive) => PrimitiveList(IrTypePrimitiveList { primitive }),
+            Delegate(IrTypeDelegate::String) => Delegate(IrTypeDelegate::StringList),
+            others => GeneralList(IrTypeGeneralList {
+                inner: Box::new(others),
+            }),
+        }
+    }
+
     #[inline]
     pub fn dart_required_modifier(&self) -> &'static str {
         match self {
@@ -37,7 +55,26 @@ impl IrType {
     #[inline]
     pub fn rust_ptr_modifier(&self) -> &'static str {
         match self {
-            Optional(_) | Delegate(IrTypeDelegate::String) => "*mut ",
+            Optional(opt) if opt.compact_primitive().is_some() => "",
+            Optional(_)
+            | Delegate(
+                IrTypeDelegate::String
+                | IrTypeDelegate::Uuid(_)
+                | IrTypeDelegate::StringList
+                | IrTypeDelegate::BoolList
+                | IrTypeDelegate::BoxedSlice(_)
+                | IrTypeDelegate::Cow(IrTypeDelegateCow::Str)
+                | IrTypeDelegate::PathBuf(_)
+                | IrTypeDelegate::Path(_)
+                | IrTypeDelegate::Json,
+            )
+            | PrimitiveList(_)
+            | GeneralList(_)
+            | Map(_)
+            | Opaque(_)
+            | Int128(_) => "*mut ",
+            Array(array) => array.list().rust_ptr_modifier(),
+            Set(set) => set.list().rust_ptr_modifier(),
             _ => "",
         }
     }
@@ -76,9 +113,9 @@ pub fn optional_boundary_index(types: &[&IrType]) -> Option<usize> {
         .enumerate()
         .find(|ty| matches!(ty.1, Optional(_)))
         .and_then(|(idx, _)| {
-            (&types[idx..])
+            types[idx..]
                 .iter()
                 .all(|ty| matches!(ty, Optional(_)))
-                .then(|| idx)
+                .then_some(idx)
         })
 }
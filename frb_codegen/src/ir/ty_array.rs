@@ -0,0 +1,46 @@
+use crate::ir::*;
+use serde::Serialize;
+
+/// A fixed-size array `[T; N]`, which crosses the wire as a list of `length` items.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrTypeArray {
+    pub inner: Box<IrType>,
+    pub length: usize,
+}
+
+impl IrTypeArray {
+    /// The list type holding the items on the wire.
+    pub fn list(&self) -> IrType {
+        IrType::list_of(*self.inner.clone())
+    }
+}
+
+impl IrTypeTrait for IrTypeArray {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        self.list().visit_types(f, ir_file);
+    }
+
+    fn safe_ident(&self) -> String {
+        format!("{}_array_{}", self.inner.safe_ident(), self.length)
+    }
+
+    fn dart_api_type(&self) -> String {
+        self.list().dart_api_type()
+    }
+
+    fn dart_wire_type(&self) -> String {
+        self.list().dart_wire_type()
+    }
+
+    fn rust_api_type(&self) -> String {
+        format!("[{}; {}]", self.inner.rust_api_type(), self.length)
+    }
+
+    fn rust_wire_type(&self) -> String {
+        self.list().rust_wire_type()
+    }
+
+    fn rust_wire_is_pointer(&self) -> bool {
+        self.list().rust_wire_is_pointer()
+    }
+}
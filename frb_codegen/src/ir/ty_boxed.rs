@@ -1,13 +1,57 @@
-use crate::ir::IrType::Primitive;
+use crate::ir::IrType::{Delegate, EnumRef, Primitive};
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypeBoxed {
     /// if false, means that we automatically add it when transforming it - it does not exist in real api.
     pub exist_in_real_api: bool,
+    /// The pointer of the real API, `Box` when it does not exist in it
+    pub pointer: IrTypeBoxedPointer,
     pub inner: Box<IrType>,
 }
 
+/// `Arc` and `Rc` are sent like `Box`, by value: the inner value is cloned into Dart unless it is
+/// not shared anymore
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum IrTypeBoxedPointer {
+    Box,
+    /// `std::sync::Arc`
+    Arc,
+    /// `std::rc::Rc`
+    Rc,
+}
+
+impl IrTypeBoxedPointer {
+    pub fn rust_path(&self) -> &'static str {
+        match self {
+            IrTypeBoxedPointer::Box => "Box",
+            IrTypeBoxedPointer::Arc => "std::sync::Arc",
+            IrTypeBoxedPointer::Rc => "std::rc::Rc",
+        }
+    }
+}
+
+impl IrTypeBoxed {
+    /// The primitive the inner value is sent as, if it is passed by value like one
+    pub fn inner_primitive(&self) -> Option<IrTypePrimitive> {
+        match &*self.inner {
+            Primitive(prim) => Some(prim.clone()),
+            Delegate(delegate) => delegate.primitive(),
+            EnumRef(IrTypeEnumRef {
+                is_struct: false, ..
+            }) => Some(IrTypePrimitive::I32),
+            _ => None,
+        }
+    }
+
+    /// Whether the value is sent as the inner one, which is a pointer already. Boxes of the real
+    /// API are still allocated on their own.
+    pub fn reuses_inner_pointer(&self) -> bool {
+        self.pointer != IrTypeBoxedPointer::Box && self.inner.rust_wire_is_pointer()
+    }
+}
+
 impl IrTypeTrait for IrTypeBoxed {
     fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
         self.inner.visit_types(f, ir_file);
@@ -15,7 +59,12 @@ impl IrTypeTrait for IrTypeBoxed {
 
     fn safe_ident(&self) -> String {
         format!(
-            "box_{}{}",
+            "{}_{}{}",
+            match self.pointer {
+                IrTypeBoxedPointer::Box => "box",
+                IrTypeBoxedPointer::Arc => "arc",
+                IrTypeBoxedPointer::Rc => "rc",
+            },
             if self.exist_in_real_api {
                 ""
             } else {
@@ -30,7 +79,10 @@ impl IrTypeTrait for IrTypeBoxed {
     }
 
     fn dart_wire_type(&self) -> String {
-        let wire_type = if let Primitive(prim) = &*self.inner {
+        if self.reuses_inner_pointer() {
+            return self.inner.dart_wire_type();
+        }
+        let wire_type = if let Some(prim) = self.inner_primitive() {
             prim.dart_native_type().to_owned()
         } else {
             self.inner.dart_wire_type()
@@ -40,7 +92,11 @@ impl IrTypeTrait for IrTypeBoxed {
 
     fn rust_api_type(&self) -> String {
         if self.exist_in_real_api {
-            format!("Box<{}>", self.inner.rust_api_type())
+            format!(
+                "{}<{}>",
+                self.pointer.rust_path(),
+                self.inner.rust_api_type()
+            )
         } else {
             self.inner.rust_api_type()
         }
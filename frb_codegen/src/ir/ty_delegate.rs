@@ -1,20 +1,75 @@
+use crate::config::{DartUuidType, NonUtf8Paths, NonZeroInts};
 use crate::ir::*;
+use serde::Serialize;
 
 /// types that delegate to another type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrTypeDelegate {
     String,
+    /// `&str`, sent like a `String`. It is borrowed from the bytes received as an argument, and
+    /// copied when returned as `&'static str`.
+    Str,
+    /// `&[T]` of the given element type, sent like a `Vec<T>`. It is borrowed from the `Vec`
+    /// received as an argument, and copied when returned as `&'static [T]`.
+    Slice(Box<IrType>),
     StringList,
     SyncReturnVecU8,
     ZeroCopyBufferVecPrimitive(IrTypePrimitive),
+    /// A `chrono` timestamp, sent as microseconds since the Unix epoch, or a duration, sent as
+    /// microseconds
+    Time(IrTypeDelegateTime),
+    /// `uuid::Uuid`, sent as its 16 bytes
+    Uuid(DartUuidType),
+    /// `char`, sent as its code point
+    Char,
+    /// `i64` or `u64` seen by Dart as a `BigInt` with `--dart-int64-type big-int`, sent as is
+    BigInt64(IrTypePrimitive),
+    /// `Vec<bool>`, sent as one byte per value, either 0 or 1
+    BoolList,
+    /// `Box<[T]>` of the given element type, sent like the `Vec<T>` it is converted from and into
+    BoxedSlice(Box<IrType>),
+    /// `Cow<str>` or `Cow<[T]>`, sent like the `String` or `Vec<T>` it owns when converted
+    Cow(IrTypeDelegateCow),
+    /// `std::path::PathBuf`, sent like a `String`, converted as told when it is not valid UTF-8
+    PathBuf(NonUtf8Paths),
+    /// `&std::path::Path`, sent like a `PathBuf`. It is borrowed from the `String` received as
+    /// an argument, and copied when returned as `&'static Path`.
+    Path(NonUtf8Paths),
+    /// `std::num::NonZeroU32` or another `NonZero*` integer, sent like the integer, which is
+    /// either a primitive or a `BigInt64`. A zero is converted as told.
+    NonZero(Box<IrType>, NonZeroInts),
+    /// `serde_json::Value`, sent as a `String` of its JSON, which Dart decodes into an `Object?`
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum IrTypeDelegateCow {
+    /// `Cow<str>`, which owns a `String`
+    Str,
+    /// `Cow<[T]>` of the given element type, which owns a `Vec<T>`
+    Slice(Box<IrType>),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum IrTypeDelegateTime {
+    /// `chrono::NaiveDateTime`, whose time zone is chosen by `--naive-date-time-zone`
+    Naive,
+    /// `chrono::DateTime<chrono::Utc>`
+    Utc,
+    /// `chrono::DateTime<chrono::Local>`
+    Local,
+    /// `std::time::Duration`
+    Duration,
 }
 
 impl IrTypeDelegate {
     pub fn get_delegate(&self) -> IrType {
         match self {
-            IrTypeDelegate::String => IrType::PrimitiveList(IrTypePrimitiveList {
-                primitive: IrTypePrimitive::U8,
-            }),
+            IrTypeDelegate::String | IrTypeDelegate::Str => {
+                IrType::PrimitiveList(IrTypePrimitiveList {
+                    primitive: IrTypePrimitive::U8,
+                })
+            }
             IrTypeDelegate::SyncReturnVecU8 => IrType::PrimitiveList(IrTypePrimitiveList {
                 primitive: IrTypePrimitive::U8,
             }),
@@ -24,6 +79,34 @@ impl IrTypeDelegate {
                 })
             }
             IrTypeDelegate::StringList => IrType::Delegate(IrTypeDelegate::String),
+            IrTypeDelegate::Time(_) => IrType::Primitive(IrTypePrimitive::I64),
+            IrTypeDelegate::Uuid(_) => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: IrTypePrimitive::U8,
+            }),
+            IrTypeDelegate::Char => IrType::Primitive(IrTypePrimitive::U32),
+            IrTypeDelegate::BigInt64(primitive) => IrType::Primitive(primitive.clone()),
+            IrTypeDelegate::BoolList => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: IrTypePrimitive::U8,
+            }),
+            IrTypeDelegate::Cow(IrTypeDelegateCow::Str)
+            | IrTypeDelegate::PathBuf(_)
+            | IrTypeDelegate::Path(_)
+            | IrTypeDelegate::Json => IrType::Delegate(IrTypeDelegate::String),
+            IrTypeDelegate::Slice(elem)
+            | IrTypeDelegate::BoxedSlice(elem)
+            | IrTypeDelegate::Cow(IrTypeDelegateCow::Slice(elem)) => {
+                IrType::list_of((**elem).clone())
+            }
+            IrTypeDelegate::NonZero(inner, _) => (**inner).clone(),
+        }
+    }
+
+    /// The primitive the value is sent as, if it is passed by value like one
+    pub fn primitive(&self) -> Option<IrTypePrimitive> {
+        match self.get_delegate() {
+            IrType::Primitive(prim) => Some(prim),
+            IrType::Delegate(delegate) => delegate.primitive(),
+            _ => None,
         }
     }
 }
@@ -36,21 +119,53 @@ impl IrTypeTrait for IrTypeDelegate {
     fn safe_ident(&self) -> String {
         match self {
             IrTypeDelegate::String => "String".to_owned(),
+            IrTypeDelegate::Str => "str".to_owned(),
             IrTypeDelegate::StringList => "StringList".to_owned(),
             IrTypeDelegate::SyncReturnVecU8 => "SyncReturnVecU8".to_owned(),
             IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
                 "ZeroCopyBuffer_".to_owned() + &self.get_delegate().dart_api_type()
             }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => "Duration".to_owned(),
+            IrTypeDelegate::Time(time) => format!("Chrono_{:?}", time),
+            IrTypeDelegate::Uuid(_) => "Uuid".to_owned(),
+            IrTypeDelegate::Char => "Char".to_owned(),
+            IrTypeDelegate::BigInt64(_) => "BigInt_".to_owned() + &self.get_delegate().safe_ident(),
+            IrTypeDelegate::BoolList => "BoolList".to_owned(),
+            IrTypeDelegate::Slice(_) => "Slice_".to_owned() + &self.get_delegate().safe_ident(),
+            IrTypeDelegate::BoxedSlice(_) => {
+                "BoxedSlice_".to_owned() + &self.get_delegate().safe_ident()
+            }
+            IrTypeDelegate::Cow(_) => "Cow_".to_owned() + &self.get_delegate().safe_ident(),
+            IrTypeDelegate::PathBuf(_) => "PathBuf".to_owned(),
+            IrTypeDelegate::Path(_) => "Path".to_owned(),
+            IrTypeDelegate::NonZero(..) => {
+                "NonZero_".to_owned() + &self.get_delegate().safe_ident()
+            }
+            IrTypeDelegate::Json => "Json".to_owned(),
         }
     }
 
     fn dart_api_type(&self) -> String {
         match self {
-            IrTypeDelegate::String => "String".to_string(),
+            IrTypeDelegate::String | IrTypeDelegate::Str => "String".to_string(),
             IrTypeDelegate::StringList => "List<String>".to_owned(),
             IrTypeDelegate::SyncReturnVecU8 | IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
                 self.get_delegate().dart_api_type()
             }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => "Duration".to_owned(),
+            IrTypeDelegate::Time(_) => "DateTime".to_owned(),
+            IrTypeDelegate::Uuid(DartUuidType::Bytes) => "Uint8List".to_owned(),
+            IrTypeDelegate::Uuid(DartUuidType::UuidValue) => "UuidValue".to_owned(),
+            IrTypeDelegate::Char => "int".to_owned(),
+            IrTypeDelegate::BigInt64(_) => "BigInt".to_owned(),
+            IrTypeDelegate::BoolList => "List<bool>".to_owned(),
+            IrTypeDelegate::Json => "Object?".to_owned(),
+            IrTypeDelegate::Slice(_)
+            | IrTypeDelegate::BoxedSlice(_)
+            | IrTypeDelegate::Cow(_)
+            | IrTypeDelegate::PathBuf(_)
+            | IrTypeDelegate::Path(_)
+            | IrTypeDelegate::NonZero(..) => self.get_delegate().dart_api_type(),
         }
     }
 
@@ -64,11 +179,40 @@ impl IrTypeTrait for IrTypeDelegate {
     fn rust_api_type(&self) -> String {
         match self {
             IrTypeDelegate::String => "String".to_owned(),
+            IrTypeDelegate::Str => "&str".to_owned(),
+            IrTypeDelegate::Slice(elem) => format!("&[{}]", elem.rust_api_type()),
+            IrTypeDelegate::BoxedSlice(elem) => format!("Box<[{}]>", elem.rust_api_type()),
             IrTypeDelegate::SyncReturnVecU8 => "SyncReturn<Vec<u8>>".to_string(),
             IrTypeDelegate::StringList => "Vec<String>".to_owned(),
             IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
                 format!("ZeroCopyBuffer<{}>", self.get_delegate().rust_api_type())
             }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Naive) => "chrono::NaiveDateTime".to_owned(),
+            IrTypeDelegate::Time(IrTypeDelegateTime::Utc) => {
+                "chrono::DateTime<chrono::Utc>".to_owned()
+            }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Local) => {
+                "chrono::DateTime<chrono::Local>".to_owned()
+            }
+            IrTypeDelegate::Time(IrTypeDelegateTime::Duration) => "std::time::Duration".to_owned(),
+            IrTypeDelegate::Uuid(_) => "uuid::Uuid".to_owned(),
+            IrTypeDelegate::Char => "char".to_owned(),
+            IrTypeDelegate::BigInt64(_) => self.get_delegate().rust_api_type(),
+            IrTypeDelegate::BoolList => "Vec<bool>".to_owned(),
+            // Arguments are given an owned value, which can live as long as needed
+            IrTypeDelegate::Cow(IrTypeDelegateCow::Str) => {
+                "std::borrow::Cow<'static, str>".to_owned()
+            }
+            IrTypeDelegate::Cow(IrTypeDelegateCow::Slice(elem)) => {
+                format!("std::borrow::Cow<'static, [{}]>", elem.rust_api_type())
+            }
+            IrTypeDelegate::PathBuf(_) => "std::path::PathBuf".to_owned(),
+            IrTypeDelegate::Path(_) => "&std::path::Path".to_owned(),
+            IrTypeDelegate::NonZero(..) => format!(
+                "std::num::NonZero{}",
+                self.get_delegate().rust_api_type().to_uppercase()
+            ),
+            IrTypeDelegate::Json => "serde_json::Value".to_owned(),
         }
     }
 
@@ -1,11 +1,15 @@
 use crate::ir::IrType::{EnumRef, StructRef};
 use crate::ir::*;
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypeEnumRef {
     pub name: String,
     pub is_struct: bool,
+    /// Whether this is `Box<dyn Trait>` rather than an enum, with a variant for each of the types
+    /// implementing the trait
+    pub is_trait_object: bool,
 }
 
 impl IrTypeEnumRef {
@@ -40,7 +44,11 @@ impl IrTypeTrait for IrTypeEnumRef {
         }
     }
     fn rust_api_type(&self) -> String {
-        self.name.to_string()
+        if self.is_trait_object {
+            format!("Box<dyn {}>", self.name)
+        } else {
+            self.name.to_string()
+        }
     }
     fn rust_wire_type(&self) -> String {
         if self.is_struct {
@@ -51,12 +59,21 @@ impl IrTypeTrait for IrTypeEnumRef {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrEnum {
     pub name: String,
     pub path: Vec<String>,
     pub comments: Vec<IrComment>,
+    /// Whether this stands for the trait objects of a trait, whose variants are named after the
+    /// types implementing it and hold them in `field0`
+    pub is_trait_object: bool,
+    /// Whether the enum is `#[non_exhaustive]`, Dart then having a fallback for the variants it
+    /// does not know of
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_non_exhaustive: bool,
+    #[serde(rename = "variants")]
     _variants: Vec<IrVariant>,
+    #[serde(rename = "is_struct")]
     _is_struct: bool,
 }
 
@@ -74,6 +91,7 @@ impl IrEnum {
                     is_struct: true, ..
                 }) => IrType::Boxed(IrTypeBoxed {
                     exist_in_real_api: false,
+                    pointer: IrTypeBoxedPointer::Box,
                     inner: Box::new(ty),
                 }),
                 _ => ty,
@@ -108,6 +126,8 @@ impl IrEnum {
             name,
             path,
             comments,
+            is_trait_object: false,
+            is_non_exhaustive: false,
             _variants: variants,
             _is_struct,
         }
@@ -117,19 +137,46 @@ impl IrEnum {
         &self._variants
     }
 
+    pub fn variants_mut(&mut self) -> &mut [IrVariant] {
+        &mut self._variants
+    }
+
     pub fn is_struct(&self) -> bool {
         self._is_struct
     }
+
+    /// Whether a variant is given its discriminant, such as `Low = 1`
+    pub fn has_explicit_discriminants(&self) -> bool {
+        self._variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some())
+    }
+
+    /// The discriminant of each variant, which the variants without one get like in Rust, by
+    /// adding one to the previous
+    pub fn discriminants(&self) -> Vec<i64> {
+        let mut next = 0;
+        self._variants
+            .iter()
+            .map(|variant| {
+                let discriminant = variant.discriminant.unwrap_or(next);
+                next = discriminant + 1;
+                discriminant
+            })
+            .collect()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrVariant {
     pub name: IrIdent,
     pub comments: Vec<IrComment>,
+    /// The value given to the variant in Rust, e.g. `1` in `Low = 1`
+    pub discriminant: Option<i64>,
     pub kind: IrVariantKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrVariantKind {
     Value,
     Struct(IrStruct),
@@ -1,6 +1,7 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypeGeneralList {
     pub inner: Box<IrType>,
 }
@@ -0,0 +1,60 @@
+use crate::ir::*;
+use serde::Serialize;
+
+/// A 128-bit integer, which crosses the wire as its high and low 64-bit halves since neither C
+/// nor Dart's `int` can hold it.
+#[derive(Debug, Clone, Serialize)]
+pub enum IrTypeInt128 {
+    I128,
+    U128,
+}
+
+impl IrTypeInt128 {
+    pub fn try_from_rust_str(s: &str) -> Option<Self> {
+        match s {
+            "i128" => Some(IrTypeInt128::I128),
+            "u128" => Some(IrTypeInt128::U128),
+            _ => None,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, IrTypeInt128::I128)
+    }
+}
+
+impl IrTypeTrait for IrTypeInt128 {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, _f: &mut F, _ir_file: &IrFile) {}
+
+    fn safe_ident(&self) -> String {
+        self.rust_api_type()
+    }
+
+    fn dart_api_type(&self) -> String {
+        "BigInt".to_string()
+    }
+
+    fn dart_wire_type(&self) -> String {
+        format!("ffi.Pointer<{}>", self.rust_wire_type())
+    }
+
+    fn rust_api_type(&self) -> String {
+        match self {
+            IrTypeInt128::I128 => "i128",
+            IrTypeInt128::U128 => "u128",
+        }
+        .to_string()
+    }
+
+    fn rust_wire_type(&self) -> String {
+        match self {
+            IrTypeInt128::I128 => "wire_int_128",
+            IrTypeInt128::U128 => "wire_uint_128",
+        }
+        .to_string()
+    }
+
+    fn rust_wire_is_pointer(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,79 @@
+use crate::ir::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IrTypeMap {
+    pub kind: IrTypeMapKind,
+    pub key: Box<IrType>,
+    pub value: Box<IrType>,
+}
+
+#[derive(Debug, Clone, Serialize, Copy, PartialEq, Eq)]
+pub enum IrTypeMapKind {
+    HashMap,
+    BTreeMap,
+}
+
+impl IrTypeMap {
+    /// Maps cross the wire as a list of keys along with a list of values of the same length.
+    pub fn key_list(&self) -> IrType {
+        IrType::list_of(*self.key.clone())
+    }
+
+    pub fn value_list(&self) -> IrType {
+        IrType::list_of(*self.value.clone())
+    }
+}
+
+impl IrTypeTrait for IrTypeMap {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        self.key_list().visit_types(f, ir_file);
+        self.value_list().visit_types(f, ir_file);
+    }
+
+    fn safe_ident(&self) -> String {
+        let kind = match self.kind {
+            IrTypeMapKind::HashMap => "hash_map",
+            IrTypeMapKind::BTreeMap => "btree_map",
+        };
+        format!(
+            "{}_{}_{}",
+            kind,
+            self.key.safe_ident(),
+            self.value.safe_ident()
+        )
+    }
+
+    fn dart_api_type(&self) -> String {
+        format!(
+            "Map<{}, {}>",
+            self.key.dart_api_type(),
+            self.value.dart_api_type()
+        )
+    }
+
+    fn dart_wire_type(&self) -> String {
+        format!("ffi.Pointer<wire_{}>", self.safe_ident())
+    }
+
+    fn rust_api_type(&self) -> String {
+        let kind = match self.kind {
+            IrTypeMapKind::HashMap => "HashMap",
+            IrTypeMapKind::BTreeMap => "BTreeMap",
+        };
+        format!(
+            "std::collections::{}<{}, {}>",
+            kind,
+            self.key.rust_api_type(),
+            self.value.rust_api_type()
+        )
+    }
+
+    fn rust_wire_type(&self) -> String {
+        format!("wire_{}", self.safe_ident())
+    }
+
+    fn rust_wire_is_pointer(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,59 @@
+use crate::ir::*;
+use convert_case::{Case, Casing};
+use serde::Serialize;
+
+/// A struct marked with `#[frb(opaque)]`. It stays in Rust, and Dart only holds its address.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrTypeOpaque {
+    pub name: String,
+    /// The name of the Dart class, the Rust name unless given by `#[frb(name = "..")]`
+    pub dart_name: String,
+    pub path: Vec<String>,
+    pub comments: Vec<IrComment>,
+    /// Whether Dart releases the object once it is garbage collected, unless it is marked
+    /// `#[frb(opaque, manual_dispose)]`.
+    pub has_finalizer: bool,
+    /// Whether the API holds the object as `Arc<T>`, the very `Arc` held by Dart, rather than as
+    /// `T`
+    pub shared: bool,
+}
+
+impl IrTypeOpaque {
+    /// The extern function releasing the reference Dart holds on an object.
+    pub fn drop_func_name(&self) -> String {
+        format!("drop_opaque_{}", self.name)
+    }
+
+    /// The Dart field of the API implementation holding the finalizer.
+    pub fn dart_finalizer_name(&self) -> String {
+        format!("_{}Finalizer", self.safe_ident().to_case(Case::Camel))
+    }
+}
+
+impl IrTypeTrait for IrTypeOpaque {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, _f: &mut F, _ir_file: &IrFile) {}
+
+    fn safe_ident(&self) -> String {
+        format!("opaque_{}", self.name.to_case(Case::Snake))
+    }
+
+    fn dart_api_type(&self) -> String {
+        self.dart_name.to_string()
+    }
+
+    fn dart_wire_type(&self) -> String {
+        format!("ffi.Pointer<{}>", self.rust_wire_type())
+    }
+
+    fn rust_api_type(&self) -> String {
+        format!("std::sync::Arc<{}>", self.name)
+    }
+
+    fn rust_wire_type(&self) -> String {
+        format!("wire_{}", self.name)
+    }
+
+    fn rust_wire_is_pointer(&self) -> bool {
+        true
+    }
+}
@@ -1,17 +1,27 @@
 use crate::ir::IrType::*;
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypeOptional {
     pub inner: Box<IrType>,
 }
 
 impl IrTypeOptional {
+    /// Sent inline, as a presence flag next to the value, rather than behind a pointer.
     pub fn new_prim(prim: IrTypePrimitive) -> Self {
+        Self {
+            inner: Box::new(Primitive(prim)),
+        }
+    }
+
+    /// Sent behind a pointer, for the optionals nested in another, whose `None` is a null pointer.
+    pub fn new_boxed_prim(prim: IrTypePrimitive) -> Self {
         Self {
             inner: Box::new(Boxed(IrTypeBoxed {
                 inner: Box::new(Primitive(prim)),
                 exist_in_real_api: false,
+                pointer: IrTypeBoxedPointer::Box,
             })),
         }
     }
@@ -22,20 +32,12 @@ impl IrTypeOptional {
         }
     }
 
-    pub fn is_primitive(&self) -> bool {
-        matches!(&*self.inner, Boxed(boxed) if matches!(*boxed.inner, IrType::Primitive(_)))
-    }
-
-    pub fn is_list(&self) -> bool {
-        matches!(&*self.inner, GeneralList(_) | PrimitiveList(_))
-    }
-
-    pub fn is_delegate(&self) -> bool {
-        matches!(&*self.inner, Delegate(_))
-    }
-
-    pub fn needs_initialization(&self) -> bool {
-        !(self.is_primitive() || self.is_delegate())
+    /// The primitive of an optional sent inline, see [IrTypeOptional::new_prim].
+    pub fn compact_primitive(&self) -> Option<&IrTypePrimitive> {
+        match &*self.inner {
+            Primitive(prim) => Some(prim),
+            _ => None,
+        }
     }
 }
 
@@ -44,19 +46,32 @@ impl IrTypeTrait for IrTypeOptional {
         format!("opt_{}", self.inner.safe_ident())
     }
     fn rust_wire_type(&self) -> String {
-        self.inner.rust_wire_type()
+        match self.compact_primitive() {
+            Some(_) => format!("wire_{}", self.safe_ident()),
+            None => self.inner.rust_wire_type(),
+        }
     }
     fn rust_api_type(&self) -> String {
         format!("Option<{}>", self.inner.rust_api_type())
     }
     fn dart_wire_type(&self) -> String {
-        self.inner.dart_wire_type()
+        match self.compact_primitive() {
+            Some(_) => self.rust_wire_type(),
+            None => self.inner.dart_wire_type(),
+        }
     }
+    /// `Option<Option<T>>` is flattened into `T?`, so that both `None` and `Some(None)` become
+    /// `null` in Dart, and `null` becomes `None` in Rust.
     fn dart_api_type(&self) -> String {
-        format!("{}?", self.inner.dart_api_type())
+        match &*self.inner {
+            Optional(inner) => inner.dart_api_type(),
+            // Nullable already, `None` and the JSON `null` both being `null` in Dart
+            inner @ Delegate(IrTypeDelegate::Json) => inner.dart_api_type(),
+            inner => format!("{}?", inner.dart_api_type()),
+        }
     }
     fn rust_wire_is_pointer(&self) -> bool {
-        true
+        self.compact_primitive().is_none()
     }
 
     fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
@@ -1,6 +1,7 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrTypePrimitive {
     U8,
     I8,
@@ -1,7 +1,8 @@
 use crate::ir::*;
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypePrimitiveList {
     pub primitive: IrTypePrimitive,
 }
@@ -0,0 +1,60 @@
+use crate::ir::*;
+use serde::Serialize;
+
+/// A `HashSet<T>` or a `BTreeSet<T>`, which crosses the wire as a list of its items.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrTypeSet {
+    pub kind: IrTypeSetKind,
+    pub inner: Box<IrType>,
+}
+
+#[derive(Debug, Clone, Serialize, Copy, PartialEq, Eq)]
+pub enum IrTypeSetKind {
+    HashSet,
+    BTreeSet,
+}
+
+impl IrTypeSet {
+    /// The list type holding the items on the wire.
+    pub fn list(&self) -> IrType {
+        IrType::list_of(*self.inner.clone())
+    }
+}
+
+impl IrTypeTrait for IrTypeSet {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        self.list().visit_types(f, ir_file);
+    }
+
+    fn safe_ident(&self) -> String {
+        let kind = match self.kind {
+            IrTypeSetKind::HashSet => "hash_set",
+            IrTypeSetKind::BTreeSet => "btree_set",
+        };
+        format!("{}_{}", kind, self.inner.safe_ident())
+    }
+
+    fn dart_api_type(&self) -> String {
+        format!("Set<{}>", self.inner.dart_api_type())
+    }
+
+    fn dart_wire_type(&self) -> String {
+        self.list().dart_wire_type()
+    }
+
+    fn rust_api_type(&self) -> String {
+        let kind = match self.kind {
+            IrTypeSetKind::HashSet => "HashSet",
+            IrTypeSetKind::BTreeSet => "BTreeSet",
+        };
+        format!("std::collections::{}<{}>", kind, self.inner.rust_api_type())
+    }
+
+    fn rust_wire_type(&self) -> String {
+        self.list().rust_wire_type()
+    }
+
+    fn rust_wire_is_pointer(&self) -> bool {
+        self.list().rust_wire_is_pointer()
+    }
+}
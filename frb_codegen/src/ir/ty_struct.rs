@@ -1,19 +1,102 @@
 use crate::ir::*;
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrTypeStructRef {
     pub name: String,
+    /// The name of the Dart class, the Rust name unless given by `#[frb(name = "..")]`
+    pub dart_name: String,
+    /// The element types, when this is the hidden struct standing for a Rust tuple
+    pub tuple_fields: Option<Vec<IrType>>,
+    /// The names of the elements given by `#[frb(returns = "..")]`, when the tuple is returned by a
+    /// function naming them, the struct being then named after the function
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tuple_field_names: Option<Vec<String>>,
+    /// The Rust type, when this is an instantiation of a generic struct, which is named after its
+    /// type arguments, e.g. `Response<User>` for `ResponseUser`
+    pub generic_rust_type: Option<String>,
+    /// The type of another crate the struct stands for through `#[frb(convert = "..")]` or
+    /// `#[frb(mirror = "..")]`, which crosses the bridge as the struct
+    pub foreign_rust_type: Option<String>,
 }
 
 impl IrTypeStructRef {
     pub fn get<'a>(&self, f: &'a IrFile) -> &'a IrStruct {
         &f.struct_pool[&self.name]
     }
+
+    /// Refers to the hidden struct of the tuple made of `fields`, which is named after them
+    pub fn for_tuple(fields: Vec<IrType>) -> Self {
+        let name = fields
+            .iter()
+            .map(|field| field.safe_ident().to_case(Case::UpperCamel))
+            .collect::<String>();
+        let name = format!("Tuple{}", name);
+        IrTypeStructRef {
+            dart_name: name.clone(),
+            name,
+            tuple_fields: Some(fields),
+            tuple_field_names: None,
+            generic_rust_type: None,
+            foreign_rust_type: None,
+        }
+    }
+
+    /// Gives names to the elements of a tuple, which then gets a hidden struct of its own named
+    /// `name` rather than the one shared by the tuples of the same types
+    pub fn with_tuple_field_names(self, name: String, field_names: Vec<String>) -> Self {
+        IrTypeStructRef {
+            dart_name: name.clone(),
+            name,
+            tuple_field_names: Some(field_names),
+            ..self
+        }
+    }
+
+    /// The hidden struct of a tuple, with fields `field0`, `field1`, .. unless they are named
+    pub fn tuple_struct(&self) -> Option<IrStruct> {
+        let fields = self.tuple_fields.as_ref()?;
+        Some(IrStruct {
+            name: self.name.clone(),
+            path: None,
+            fields: fields
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| IrField {
+                    name: IrIdent::new(match &self.tuple_field_names {
+                        Some(names) => names[idx].clone(),
+                        None => format!("field{}", idx),
+                    }),
+                    ty: ty.clone(),
+                    comments: vec![],
+                    deprecation: None,
+                    default: None,
+                    is_final: true,
+                })
+                .collect(),
+            skipped_fields: vec![],
+            is_fields_named: true,
+            is_pod: false,
+            is_mirror: false,
+            comments: vec![IrComment::from(
+                format!(" The Rust tuple `{}`.", self.rust_api_type()).as_str(),
+            )],
+            dart_metadata: vec![],
+            dart_imports: vec![],
+        })
+    }
 }
 
 impl IrTypeTrait for IrTypeStructRef {
     fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        // The hidden struct of a tuple is only added to the pool by the transformer
+        if let Some(fields) = &self.tuple_fields {
+            for ty in fields {
+                ty.visit_types(f, ir_file);
+            }
+            return;
+        }
         for field in &self.get(ir_file).fields {
             field.ty.visit_types(f, ir_file);
         }
@@ -23,7 +106,7 @@ impl IrTypeTrait for IrTypeStructRef {
         self.dart_api_type().to_case(Case::Snake)
     }
     fn dart_api_type(&self) -> String {
-        self.name.to_string()
+        self.dart_name.to_string()
     }
 
     fn dart_wire_type(&self) -> String {
@@ -31,7 +114,21 @@ impl IrTypeTrait for IrTypeStructRef {
     }
 
     fn rust_api_type(&self) -> String {
-        self.name.to_string()
+        match &self.tuple_fields {
+            Some(fields) => {
+                let fields = fields.iter().map(IrType::rust_api_type).collect::<Vec<_>>();
+                if fields.len() == 1 {
+                    format!("({},)", fields[0])
+                } else {
+                    format!("({})", fields.join(", "))
+                }
+            }
+            None => self
+                .foreign_rust_type
+                .clone()
+                .or_else(|| self.generic_rust_type.clone())
+                .unwrap_or_else(|| self.name.to_string()),
+        }
     }
 
     fn rust_wire_type(&self) -> String {
@@ -39,13 +136,27 @@ impl IrTypeTrait for IrTypeStructRef {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrStruct {
     pub name: String,
     pub path: Option<Vec<String>>,
     pub fields: Vec<IrField>,
+    /// The fields marked `#[frb(skip)]` and the zero-sized ones such as `PhantomData<T>`, which
+    /// stay in Rust and are set to their `Default` when the struct comes from Dart
+    pub skipped_fields: Vec<IrIdent>,
     pub is_fields_named: bool,
+    /// Whether the struct is `#[repr(C)]` and only made of primitives and arrays of them, so that
+    /// it is its own wire type rather than being converted field by field
+    pub is_pod: bool,
+    /// Whether the struct is marked `#[frb(mirror = "..")]`, so that the type of another crate it
+    /// stands for is built from its fields, rather than converted from and into it with `From`
+    pub is_mirror: bool,
     pub comments: Vec<IrComment>,
+    /// Given by `#[frb(dart_metadata = "..")]`, the annotations of the Dart class and the
+    /// `extends`, `with` and `implements` clauses of its declaration
+    pub dart_metadata: Vec<String>,
+    /// Given by `#[frb(dart_import = "..")]`, the URIs of the Dart libraries the metadata needs
+    pub dart_imports: Vec<String>,
 }
 
 impl IrStruct {
@@ -56,4 +167,31 @@ impl IrStruct {
             ('(', ')')
         }
     }
+
+    /// The annotations among the Dart metadata
+    pub fn dart_annotations(&self) -> impl Iterator<Item = &String> {
+        self.dart_metadata
+            .iter()
+            .filter(|metadata| dart_clause_keyword(metadata).is_none())
+    }
+
+    /// The clauses among the Dart metadata, in the order they must appear in the declaration
+    pub fn dart_clauses(&self) -> impl Iterator<Item = &String> {
+        DART_CLAUSE_KEYWORDS.iter().flat_map(move |keyword| {
+            self.dart_metadata
+                .iter()
+                .filter(move |metadata| dart_clause_keyword(metadata) == Some(*keyword))
+        })
+    }
+}
+
+const DART_CLAUSE_KEYWORDS: [&str; 3] = ["extends", "with", "implements"];
+
+/// The keyword of a clause of a Dart class declaration, such as `extends` in `extends Base`
+pub fn dart_clause_keyword(metadata: &str) -> Option<&'static str> {
+    let keyword = metadata.split_whitespace().next()?;
+    DART_CLAUSE_KEYWORDS
+        .iter()
+        .copied()
+        .find(|clause| *clause == keyword && metadata.len() > keyword.len())
 }
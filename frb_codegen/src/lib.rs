@@ -0,0 +1,577 @@
+//! The code generator of `flutter_rust_bridge`, which can also be called from a build script:
+//!
+//! ```no_run
+//! use flutter_rust_bridge_codegen::config::{self, RawOpts};
+//!
+//! let config = config::parse(RawOpts {
+//!     rust_input: vec!["src/api.rs".to_owned()],
+//!     dart_output: "../lib/bridge_generated.dart".to_owned(),
+//!     ..Default::default()
+//! });
+//! flutter_rust_bridge_codegen::generate(&config).unwrap();
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use log::{debug, error, info, warn};
+use pathdiff::diff_paths;
+
+use crate::cache::Cache;
+use crate::commands::ensure_tools_available;
+use crate::config::{Opts, RustOutputSplit};
+use crate::ir::*;
+use crate::others::*;
+use crate::outputs::Outputs;
+use crate::timings::Timings;
+use crate::utils::*;
+
+mod cache;
+mod commands;
+pub mod config;
+mod generator;
+mod ir;
+mod others;
+mod outputs;
+mod parser;
+mod source_graph;
+mod timings;
+mod transformer;
+mod utils;
+mod watch;
+
+/// Generates the code for `config` as the command line does, doing nothing when neither the
+/// inputs nor the options changed since the last run, unless forced
+pub fn generate(config: &Opts) -> Result<()> {
+    run(config, format!("{:?}", config))
+}
+
+/// The error of a check finding generated files which are not up to date
+#[derive(Debug)]
+pub struct OutdatedError {
+    /// The files which would be written, or written differently
+    pub paths: Vec<String>,
+}
+
+impl fmt::Display for OutdatedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} generated file(s) are not up to date: {}",
+            self.paths.len(),
+            self.paths.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for OutdatedError {}
+
+/// Runs the code generator, `raw_opts` being the options as given, which are remembered to
+/// tell whether the next run can be skipped
+#[doc(hidden)]
+pub fn run(config: &Opts, raw_opts: String) -> Result<()> {
+    let cache = Cache::new(config, raw_opts);
+    let is_fresh = !config.force && !config.dry_run && !config.check && cache.is_fresh()?;
+    if is_fresh {
+        info!("Nothing changed since the last run, skipping (use --force to regenerate anyway)");
+        if !config.watch {
+            return Ok(());
+        }
+    }
+
+    ensure_tools_available(&config.llvm_path, config.force);
+
+    if config.watch {
+        watch::watch(config, !is_fresh, || generate_once(config, &cache))
+    } else {
+        generate_once(config, &cache)
+    }
+}
+
+/// Runs all the phases of the code generation
+fn generate_once(config: &Opts, cache: &Cache) -> Result<()> {
+    let rust_output_dir = parent_dir(&config.rust_output_path)?;
+    let dart_output_dir = parent_dir(&config.dart_output_path)?;
+    let outputs = Outputs::new(config.dry_run || config.check)?;
+    let mut timings = Timings::new();
+
+    let sources = config
+        .rust_input_paths
+        .iter()
+        .map(|path| read_source_file(path, "Rust input file"))
+        .collect::<Result<Vec<_>>>()?;
+    timings.phase_done(
+        "Parse source code to AST",
+        &[
+            ("files", sources.len()),
+            (
+                "lines",
+                sources
+                    .iter()
+                    .map(|source| source.content.lines().count())
+                    .sum(),
+            ),
+        ],
+    );
+
+    let raw_ir_file = parser::parse(&sources, config)?;
+    debug!("parsed functions: {:?}", &raw_ir_file);
+    timings.phase_done(
+        "Parse AST to IR",
+        &[
+            ("functions", raw_ir_file.funcs.len()),
+            (
+                "structs and enums",
+                raw_ir_file.struct_pool.len() + raw_ir_file.enum_pool.len(),
+            ),
+        ],
+    );
+
+    let ir_file = transformer::transform(raw_ir_file, config.dart_names_style);
+    debug!("transformed functions: {:?}", &ir_file);
+    if let Some(dump_ir_path) = &config.dump_ir_path {
+        let ir_json =
+            serde_json::to_string_pretty(&ir_file).context("Failed to serialize the IR")?;
+        outputs.write(dump_ir_path, ir_json + "\n")?;
+    }
+    timings.phase_done(
+        "Transform IR",
+        &[("types", ir_file.distinct_types(true, true).len())],
+    );
+
+    let rust_prelude = config
+        .rust_prelude_path
+        .as_ref()
+        .map(|path| read_source_file(path, "Rust prelude"))
+        .transpose()?;
+    let generated_rust = generator::rust::generate(
+        &ir_file,
+        &config
+            .rust_input_paths
+            .iter()
+            .map(|path| mod_from_rust_path(path, &config.rust_crate_dir))
+            .collect::<Vec<_>>(),
+        rust_prelude.as_ref(),
+        config,
+    );
+    if config.rust_output_split != RustOutputSplit::Off {
+        // Rust refuses a module found both at `foo.rs` and `foo/mod.rs`
+        let single_file_path = parent_dir(&config.rust_output_path)?.with_extension("rs");
+        if single_file_path.exists() {
+            return Err(anyhow!(
+                "{:?} is in the way of the split Rust output {}, remove it",
+                single_file_path,
+                config.rust_output_path
+            ));
+        }
+    }
+    outputs.create_parent_dir(&config.rust_output_path)?;
+    let rust_submodules = generated_rust
+        .submodules
+        .iter()
+        .map(|(name, code)| (config.rust_submodule_path(name), code.as_str()))
+        .collect::<Vec<_>>();
+    let rust_lines = generated_rust.code.lines().count()
+        + rust_submodules
+            .iter()
+            .map(|(_, code)| code.lines().count())
+            .sum::<usize>();
+    outputs.write(&config.rust_output_path, &generated_rust.code)?;
+    for (path, code) in &rust_submodules {
+        outputs.write(path, code)?;
+    }
+    timings.phase_done(
+        "Generate Rust code",
+        &[("lines", rust_lines), ("submodules", rust_submodules.len())],
+    );
+
+    let dart_header = config
+        .dart_header_path
+        .as_ref()
+        .map(|path| {
+            fs::read_to_string(path).with_context(|| format!("Failed to read Dart header {}", path))
+        })
+        .transpose()?;
+    let generated_dart = generator::dart::generate(&ir_file, dart_header.as_deref(), config);
+    timings.phase_done(
+        "Generate Dart code",
+        &[(
+            "lines",
+            generated_dart.decl_code.body.lines().count()
+                + generated_dart.impl_code.body.lines().count(),
+        )],
+    );
+
+    for path in Some(&config.rust_output_path)
+        .into_iter()
+        .chain(rust_submodules.iter().map(|(path, _)| path))
+    {
+        commands::format_rust(&outputs.path(path), rust_output_dir, &config.rust_formatter);
+    }
+    timings.phase_done("Format Rust code", &[]);
+
+    let lib_with_mod = if config.skip_add_mod_to_lib {
+        None
+    } else {
+        others::try_lib_with_mod(&config.rust_crate_dir, &config.rust_output_path)
+            .map(|(path, content)| (path.to_string_lossy().into_owned(), content))
+    };
+    if let Some((lib_path, lib_content)) = &lib_with_mod {
+        if !outputs.is_dry_run() {
+            info!("Inject the module of the generated code into {}", lib_path);
+        }
+        outputs.write(lib_path, lib_content)?;
+    }
+
+    // Structs which are their own wire type, only aliased by it when passed from Dart
+    let c_struct_renames = ir_file
+        .distinct_types(true, false)
+        .iter()
+        .filter_map(|ty| match ty {
+            IrType::StructRef(struct_ref) if struct_ref.get(&ir_file).is_pod => {
+                Some((ty.rust_api_type(), ty.rust_wire_type()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let c_struct_names = ir_file
+        .distinct_types(true, true)
+        .iter()
+        .filter_map(|ty| {
+            if let IrType::StructRef(_)
+            | IrType::EnumRef(IrTypeEnumRef {
+                is_struct: true, ..
+            }) = ty
+            {
+                let wire_type = ty.rust_wire_type();
+                Some(
+                    c_struct_renames
+                        .iter()
+                        .find(|(_, alias)| *alias == wire_type)
+                        .map_or(wire_type, |(name, _)| name.clone()),
+                )
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let temp_dart_wire_file =
+        tempfile::NamedTempFile::new().context("Failed to create a temporary Dart file")?;
+    let temp_bindgen_c_output_file = tempfile::Builder::new()
+        .suffix(".h")
+        .tempfile()
+        .context("Failed to create a temporary C header")?;
+    let dart_wire_path = match &config.dart_wire_output_path {
+        Some(dart_wire_output_path) => {
+            outputs.create_parent_dir(dart_wire_output_path)?;
+            outputs.path(dart_wire_output_path)
+        }
+        None => temp_dart_wire_file.path().to_string_lossy().into_owned(),
+    };
+    let bindgen = || {
+        commands::bindgen_rust_to_dart(
+            &config.rust_crate_dir,
+            &temp_bindgen_c_output_file.path().to_string_lossy(),
+            &dart_wire_path,
+            &config.dart_ffigen_class_name(),
+            config.dart_wire_output_path.is_some(),
+            &config.symbol_prefix,
+            c_struct_names,
+            &c_struct_renames,
+            &config.llvm_path[..],
+            &config.llvm_compiler_opts,
+        );
+    };
+    if outputs.is_dry_run() {
+        // cbindgen reads the crate, which has to contain the generated code meanwhile
+        let rust_code = fs::read_to_string(outputs.path(&config.rust_output_path))
+            .context("Failed to read the generated Rust code")?
+            + DUMMY_WIRE_CODE_FOR_BINDGEN;
+        let rust_submodule_codes = rust_submodules
+            .iter()
+            .map(|(path, _)| {
+                fs::read_to_string(outputs.path(path))
+                    .with_context(|| format!("Failed to read the generated Rust code {}", path))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut files = vec![(config.rust_output_path.as_str(), rust_code.as_str())];
+        files.extend(
+            rust_submodules
+                .iter()
+                .zip(&rust_submodule_codes)
+                .map(|((path, _), code)| (path.as_str(), code.as_str())),
+        );
+        files.extend(
+            lib_with_mod
+                .iter()
+                .map(|(path, content)| (path.as_str(), content.as_str())),
+        );
+        with_temporary_files(&files, bindgen);
+    } else {
+        with_changed_file(
+            &config.rust_output_path,
+            DUMMY_WIRE_CODE_FOR_BINDGEN,
+            bindgen,
+        );
+    }
+    timings.phase_done("Run cbindgen and ffigen", &[]);
+
+    let effective_func_names = [
+        generated_rust.extern_func_names,
+        EXTRA_EXTERN_FUNC_NAMES.to_vec(),
+    ]
+    .concat();
+    let c_dummy_code = generator::c::generate_dummy(&effective_func_names, &config.symbol_prefix);
+    outputs.create_parent_dir(&config.c_output_path)?;
+    let bindgen_c_output = fs::read_to_string(&temp_bindgen_c_output_file)
+        .context("Failed to read the C header generated by cbindgen")?;
+    outputs.write(
+        &config.c_output_path,
+        bindgen_c_output.clone() + "\n" + &c_dummy_code,
+    )?;
+
+    outputs.create_parent_dir(&config.dart_output_path)?;
+    let generated_dart_wire_code_raw = fs::read_to_string(&dart_wire_path)
+        .context("Failed to read the Dart code generated by ffigen")?;
+    let generated_dart_wire = match &config.dart_wire_output_path {
+        Some(dart_wire_output_path) => {
+            // The import goes to the library when the code is part of one
+            let importing_dir = match &config.dart_part_of_path {
+                Some(library_path) => parent_dir(library_path)?,
+                None => dart_output_dir,
+            };
+            import_standalone_dart_wire(
+                &relative_path(dart_wire_output_path, importing_dir)?,
+                &config.dart_wire_class_name(),
+                &config.dart_ffigen_class_name(),
+            )
+        }
+        None => extract_dart_wire_content(&modify_dart_wire_content(
+            &generated_dart_wire_code_raw,
+            &config.dart_wire_class_name(),
+        )),
+    };
+
+    let checked_dart_wire_code = match &config.dart_wire_output_path {
+        Some(_) => &generated_dart_wire_code_raw,
+        None => &generated_dart_wire.body,
+    };
+    if let Err(issues) = sanity_check(
+        checked_dart_wire_code,
+        &config.dart_ffigen_class_name(),
+        &bindgen_c_output,
+        &effective_func_names,
+    ) {
+        for issue in &issues {
+            error!("{}", issue);
+        }
+        return Err(anyhow!(
+            "The Dart code generated by ffigen has {} problem(s), see above",
+            issues.len()
+        ));
+    }
+
+    let generated_dart_decl_all = generated_dart.decl_code;
+    let generated_dart_impl_all = &generated_dart.impl_code + &generated_dart_wire;
+    if let Some(library_path) = &config.dart_part_of_path {
+        let mut directives = (&generated_dart_decl_all + &generated_dart_impl_all).directives();
+        let parts = match &config.dart_decl_output_path {
+            Some(dart_decl_output_path) => vec![
+                (dart_decl_output_path, generated_dart_decl_all),
+                (&config.dart_output_path, generated_dart_impl_all),
+            ],
+            None => vec![(
+                &config.dart_output_path,
+                generated_dart_decl_all + &generated_dart_impl_all,
+            )],
+        };
+        for (path, code) in parts {
+            let library = relative_path(library_path, parent_dir(path)?)?;
+            outputs.write(
+                path,
+                (&generated_dart.file_prelude + &code.as_part_of(&library)).to_text(),
+            )?;
+            directives.push(format!(
+                "part '{}';",
+                relative_path(path, parent_dir(library_path)?)?
+            ));
+        }
+        check_dart_library(library_path, &directives);
+    } else if let Some(dart_decl_output_path) = &config.dart_decl_output_path {
+        let impl_import_decl = DartBasicCode {
+            import: format!(
+                "import \"{}\";",
+                relative_path(dart_decl_output_path, dart_output_dir)?
+            ),
+            part: String::new(),
+            body: String::new(),
+        };
+        outputs.write(
+            dart_decl_output_path,
+            (&generated_dart.file_prelude + &generated_dart_decl_all).to_text(),
+        )?;
+        outputs.write(
+            &config.dart_output_path,
+            (&generated_dart.file_prelude + &impl_import_decl + &generated_dart_impl_all).to_text(),
+        )?;
+        if let Some(dart_barrel_output_path) = &config.dart_barrel_output_path {
+            let barrel_dir = parent_dir(dart_barrel_output_path)?;
+            outputs.create_parent_dir(dart_barrel_output_path)?;
+            outputs.write(
+                dart_barrel_output_path,
+                generator::dart::generate_barrel(
+                    &relative_path(dart_decl_output_path, barrel_dir)?,
+                    &relative_path(&config.dart_output_path, barrel_dir)?,
+                    &config.dart_api_impl_class_name(),
+                ),
+            )?;
+        }
+    } else {
+        outputs.write(
+            &config.dart_output_path,
+            (&generated_dart.file_prelude + &generated_dart_decl_all + &generated_dart_impl_all)
+                .to_text(),
+        )?;
+    }
+    timings.phase_done("Assemble C and Dart code", &[]);
+
+    commands::format_dart(
+        &outputs.path(&config.dart_output_path),
+        config.dart_format_line_length,
+        &config.dart_formatter,
+    );
+    for path in [
+        &config.dart_decl_output_path,
+        &config.dart_barrel_output_path,
+        &config.dart_wire_output_path,
+    ]
+    .iter()
+    .copied()
+    .flatten()
+    {
+        commands::format_dart(
+            &outputs.path(path),
+            config.dart_format_line_length,
+            &config.dart_formatter,
+        );
+    }
+    timings.phase_done("Format Dart code", &[]);
+
+    if config.timings {
+        println!("{}", timings.to_json());
+    }
+
+    if outputs.is_dry_run() {
+        let c_output_path = Some(&config.c_output_path).filter(|_| !config.c_output_is_temporary);
+        let paths = Some(&config.rust_output_path)
+            .into_iter()
+            .chain(rust_submodules.iter().map(|(path, _)| path))
+            .chain(c_output_path)
+            .chain(lib_with_mod.iter().map(|(path, _)| path))
+            .chain(Some(&config.dart_output_path))
+            .chain(
+                [
+                    &config.dart_decl_output_path,
+                    &config.dart_barrel_output_path,
+                    &config.dart_wire_output_path,
+                    &config.dump_ir_path,
+                ]
+                .iter()
+                .copied()
+                .flatten(),
+            )
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        info!(
+            "{} of {} function(s) and {} type(s), nothing was written:",
+            if config.check { "Check" } else { "Dry run" },
+            ir_file.funcs.len(),
+            ir_file.struct_pool.len() + ir_file.enum_pool.len()
+        );
+        let outdated_paths = outputs.report(&paths, config.diff || config.check)?;
+        if config.check && !outdated_paths.is_empty() {
+            return Err(OutdatedError {
+                paths: outdated_paths,
+            }
+            .into());
+        }
+        return Ok(());
+    }
+
+    cache.store()?;
+
+    info!("Success! Now go and use it :)");
+    Ok(())
+}
+
+/// Warns about the directives of the generated code that are missing from the library it is part of
+fn check_dart_library(library_path: &str, directives: &[String]) {
+    let library = match fs::read_to_string(library_path) {
+        // Either quote can be used
+        Ok(library) => library.replace('"', "'"),
+        Err(_) => {
+            warn!(
+                "The Dart library {} does not exist, it needs:\n{}",
+                library_path,
+                directives.join("\n")
+            );
+            return;
+        }
+    };
+    let missing = directives
+        .iter()
+        .filter(|directive| !library.contains(directive.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        warn!(
+            "The Dart library {} lacks directives needed by the generated code:\n{}",
+            library_path,
+            missing.join("\n")
+        );
+    }
+}
+
+/// The path of `path` relative to the directory `dir`, as written in Dart URIs
+fn relative_path(path: &str, dir: &Path) -> Result<String> {
+    diff_paths(path, dir)
+        .and_then(|path| path.to_str().map(str::to_owned))
+        .ok_or_else(|| {
+            anyhow!(
+                "Failed to express the path of {} relative to {:?}",
+                path,
+                dir
+            )
+        })
+}
+
+fn parent_dir(path: &str) -> Result<&Path> {
+    Path::new(path)
+        .parent()
+        .ok_or_else(|| anyhow!("{} has no parent directory", path))
+}
+
+/// Reads and parses an input file, pointing at the syntax error if any
+/// `description` tells what the file is for in the errors
+fn read_source_file(path: &str, description: &str) -> Result<parser::SourceFile> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {} {}", description, path))?;
+    let ast = syn::parse_file(&content).map_err(|e| {
+        let start = e.span().start();
+        anyhow!(
+            "Failed to parse {}:{}:{}: {}",
+            path,
+            start.line,
+            start.column + 1,
+            e
+        )
+    })?;
+    Ok(parser::SourceFile {
+        path: path.to_owned(),
+        content,
+        ast,
+    })
+}
@@ -1,10 +1,11 @@
+use std::fmt;
 use std::fs;
 use std::ops::Add;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Result};
 use lazy_static::lazy_static;
-use log::{error, info, warn};
+use log::warn;
 use pathdiff::diff_paths;
 use regex::RegexBuilder;
 
@@ -57,6 +58,30 @@ pub fn modify_dart_wire_content(content_raw: &str, dart_wire_class_name: &str) -
     content.to_string()
 }
 
+/// Uses the wire class that ffigen generated to a separate file as is, by extending it with the
+/// base class expected by `flutter_rust_bridge`
+pub fn import_standalone_dart_wire(
+    dart_wire_uri: &str,
+    dart_wire_class_name: &str,
+    dart_ffigen_class_name: &str,
+) -> DartBasicCode {
+    DartBasicCode {
+        import: format!(
+            "import 'dart:ffi' as ffi;
+            import '{}';",
+            dart_wire_uri
+        ),
+        part: String::new(),
+        body: format!(
+            "class {wire} extends {ffigen} implements FlutterRustBridgeWireBase {{
+                {wire}(ffi.DynamicLibrary dynamicLibrary) : super(dynamicLibrary);
+            }}",
+            wire = dart_wire_class_name,
+            ffigen = dart_ffigen_class_name,
+        ),
+    }
+}
+
 #[derive(Default)]
 pub struct DartBasicCode {
     pub import: String,
@@ -88,6 +113,28 @@ impl DartBasicCode {
     pub fn to_text(&self) -> String {
         format!("{}\n{}\n{}", self.import, self.part, self.body)
     }
+
+    /// The code as a part of `library`, whose URI is relative to the file of the part. The library
+    /// has to contain the directives of the code instead.
+    pub fn as_part_of(&self, library: &str) -> DartBasicCode {
+        DartBasicCode {
+            import: "".to_string(),
+            part: format!("part of '{}';", library),
+            body: self.body.clone(),
+        }
+    }
+
+    /// The import and part directives, without duplicates
+    pub fn directives(&self) -> Vec<String> {
+        let mut directives = Vec::new();
+        for line in self.import.lines().chain(self.part.lines()) {
+            let line = line.trim();
+            if !line.is_empty() && !directives.iter().any(|directive| directive == line) {
+                directives.push(line.to_string());
+            }
+        }
+        directives
+    }
 }
 
 pub fn extract_dart_wire_content(content: &str) -> DartBasicCode {
@@ -107,27 +154,118 @@ pub fn extract_dart_wire_content(content: &str) -> DartBasicCode {
     }
 }
 
-pub fn sanity_check(generated_dart_wire_code: &str, dart_wire_class_name: &str) {
-    if !generated_dart_wire_code.contains(dart_wire_class_name) {
-        error!(
-            "Nothing is generated for dart wire class. \
-            Maybe you forget to put code like `mod the_generated_bridge_code;` to your `lib.rs`?"
-        );
+/// A problem of the Dart wire code generated by ffigen, which would not compile or not work
+#[derive(Debug)]
+pub struct SanityIssue {
+    pub message: String,
+    /// The code the problem is about
+    pub snippet: String,
+}
+
+impl fmt::Display for SanityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}\n{}", self.message, indent_snippet(&self.snippet))
+    }
+}
+
+/// Checks the Dart wire code generated by ffigen from the C header, reporting all the problems
+/// found at once
+pub fn sanity_check(
+    generated_dart_wire_code: &str,
+    dart_wire_class_name: &str,
+    c_header: &str,
+    extern_func_names: &[String],
+) -> Result<(), Vec<SanityIssue>> {
+    if generated_dart_wire_code.trim().is_empty() {
+        return Err(vec![SanityIssue {
+            message: "Nothing is generated for the dart wire code.".to_string(),
+            snippet: format!("{:?}", generated_dart_wire_code),
+        }]);
+    }
+
+    let mut issues = Vec::new();
+    let lines = generated_dart_wire_code.lines().collect::<Vec<_>>();
+    let class_declaration = format!("class {} ", dart_wire_class_name);
+    if !generated_dart_wire_code.contains(&class_declaration) {
+        issues.push(SanityIssue {
+            message: format!(
+                "Nothing is generated for dart wire class {}.",
+                dart_wire_class_name
+            ),
+            snippet: lines_around(&lines, 0),
+        });
+    }
+
+    let missing_funcs = extern_func_names
+        .iter()
+        .filter(|name| !generated_dart_wire_code.contains(&format!(" {}(", name)))
+        .collect::<Vec<_>>();
+    if !missing_funcs.is_empty() && missing_funcs.len() == extern_func_names.len() {
+        issues.push(SanityIssue {
+            message: "The dart wire code lacks all the functions of the API. \
+                Maybe you forget to put code like `mod the_generated_bridge_code;` to your `lib.rs`?"
+                .to_string(),
+            snippet: lines_around(&lines, 0),
+        });
+    } else {
+        for name in missing_funcs {
+            let c_declaration = c_header
+                .lines()
+                .find(|line| line.contains(&format!(" {}(", name)))
+                .unwrap_or("(not in the C header either)");
+            issues.push(SanityIssue {
+                message: format!("The dart wire code lacks the function {}.", name),
+                snippet: c_declaration.to_string(),
+            });
+        }
+    }
+
+    // Provided by `flutter_rust_bridge`, so a second declaration would conflict with it
+    for (idx, line) in lines.iter().enumerate() {
+        if line.contains("class WireSyncReturnStruct ") {
+            issues.push(SanityIssue {
+                message: "The dart wire code declares WireSyncReturnStruct, which \
+                    `flutter_rust_bridge` already declares."
+                    .to_string(),
+                snippet: lines_around(&lines, idx),
+            });
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
     }
 }
 
-pub fn try_add_mod_to_lib(rust_crate_dir: &str, rust_output_path: &str) {
-    if let Err(e) = auto_add_mod_to_lib_core(rust_crate_dir, rust_output_path) {
+/// The lines of code next to the one at `idx`
+fn lines_around(lines: &[&str], idx: usize) -> String {
+    lines[idx.saturating_sub(2)..(idx + 3).min(lines.len())].join("\n")
+}
+
+fn indent_snippet(snippet: &str) -> String {
+    snippet
+        .lines()
+        .map(|line| format!("    | {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The path and new content of `lib.rs` when it lacks the module of the generated code
+pub fn try_lib_with_mod(rust_crate_dir: &str, rust_output_path: &str) -> Option<(PathBuf, String)> {
+    lib_with_mod(rust_crate_dir, rust_output_path).unwrap_or_else(|e| {
         warn!(
             "auto_add_mod_to_lib fail, the generated code may or may not have problems. \
             Please ensure you have add code like `mod the_generated_bridge_code;` to your `lib.rs`. \
             Details: {}",
             e
         );
-    }
+        None
+    })
 }
 
-pub fn auto_add_mod_to_lib_core(rust_crate_dir: &str, rust_output_path: &str) -> Result<()> {
+fn lib_with_mod(rust_crate_dir: &str, rust_output_path: &str) -> Result<Option<(PathBuf, String)>> {
     let path_src_folder = Path::new(rust_crate_dir).join("src");
     let rust_output_path_relative_to_src_folder =
         diff_paths(rust_output_path, path_src_folder.clone()).ok_or_else(|| {
@@ -138,6 +276,15 @@ pub fn auto_add_mod_to_lib_core(rust_crate_dir: &str, rust_output_path: &str) ->
             )
         })?;
 
+    // `foo/mod.rs` is the module `foo`
+    let rust_output_path_relative_to_src_folder = if rust_output_path_relative_to_src_folder
+        .file_stem()
+        .is_some_and(|stem| stem == "mod")
+    {
+        rust_output_path_relative_to_src_folder.with_file_name("")
+    } else {
+        rust_output_path_relative_to_src_folder
+    };
     let mod_name = rust_output_path_relative_to_src_folder
         .file_stem()
         .ok_or_else(|| anyhow!(""))?
@@ -150,15 +297,10 @@ pub fn auto_add_mod_to_lib_core(rust_crate_dir: &str, rust_output_path: &str) ->
     let path_lib_rs = path_src_folder.join("lib.rs");
 
     let raw_content_lib_rs = fs::read_to_string(path_lib_rs.clone())?;
-    if !raw_content_lib_rs.contains(&expect_code) {
-        info!("Inject `{}` into {:?}", &expect_code, &path_lib_rs);
-
-        let comments = " /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */";
-        let modified_content_lib_rs =
-            format!("{}{}\n{}", expect_code, comments, raw_content_lib_rs);
-
-        fs::write(&path_lib_rs, modified_content_lib_rs).unwrap();
+    if raw_content_lib_rs.contains(&expect_code) {
+        return Ok(None);
     }
-
-    Ok(())
+    let comments = " /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */";
+    let modified_content_lib_rs = format!("{}{}\n{}", expect_code, comments, raw_content_lib_rs);
+    Ok(Some((path_lib_rs, modified_content_lib_rs)))
 }
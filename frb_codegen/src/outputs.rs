@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use log::info;
+use tempfile::TempDir;
+
+use crate::commands;
+
+/// Where the generated files are written: at their own paths, or for a dry run in a temporary
+/// directory, to be compared with the files at their own paths in the end.
+pub struct Outputs {
+    staging_dir: Option<TempDir>,
+}
+
+impl Outputs {
+    pub fn new(dry_run: bool) -> Result<Self> {
+        let staging_dir = if dry_run {
+            Some(tempfile::tempdir().context("Failed to create a temporary directory")?)
+        } else {
+            None
+        };
+        Ok(Outputs { staging_dir })
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.staging_dir.is_some()
+    }
+
+    /// Where the file standing for `path` is written
+    pub fn path(&self, path: &str) -> String {
+        match &self.staging_dir {
+            // In a directory named after the whole parent path, for outputs of different
+            // directories not to collide, while files of a directory stay side by side, as the
+            // submodules of a split Rust output have to for rustfmt to find them
+            Some(staging_dir) => {
+                let path = Path::new(path);
+                let dir_name = path
+                    .parent()
+                    .map(|dir| dir.to_string_lossy().replace(['/', '\\', ':'], "_"))
+                    .unwrap_or_default();
+                staging_dir
+                    .path()
+                    .join(format!("_{}", dir_name))
+                    .join(path.file_name().unwrap_or_default())
+                    .to_string_lossy()
+                    .into_owned()
+            }
+            None => path.to_owned(),
+        }
+    }
+
+    pub fn create_parent_dir(&self, path: &str) -> Result<()> {
+        if self.is_dry_run() {
+            return Ok(());
+        }
+        let dir = super::parent_dir(path)?;
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create directory {:?}", dir))
+    }
+
+    pub fn write(&self, path: &str, content: impl AsRef<[u8]>) -> Result<()> {
+        let staged_path = self.path(path);
+        if self.is_dry_run() {
+            let dir = super::parent_dir(&staged_path)?;
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create directory {:?}", dir))?;
+        }
+        fs::write(staged_path, content).with_context(|| format!("Failed to write {}", path))
+    }
+
+    /// Tells, for a dry run, how each of the files at `paths` would change, with a diff for those
+    /// which would if `show_diff`, returning them
+    pub fn report(&self, paths: &[&str], show_diff: bool) -> Result<Vec<String>> {
+        let mut changed_paths = Vec::new();
+        for path in paths {
+            let staged_path = self.path(path);
+            let content = fs::read(&staged_path)
+                .with_context(|| format!("Failed to read the staged {}", path))?;
+            let status = match fs::read(path) {
+                Ok(content_original) if content_original == content => "unchanged",
+                Ok(_) => "changed",
+                Err(_) => "new",
+            };
+            info!("  {} ({} bytes, {})", path, content.len(), status);
+            if status == "unchanged" {
+                continue;
+            }
+            changed_paths.push(path.to_string());
+            if show_diff {
+                let original_path = if Path::new(path).exists() {
+                    path
+                } else {
+                    "/dev/null"
+                };
+                print!(
+                    "{}",
+                    commands::diff_files(original_path, &staged_path, path)
+                );
+            }
+        }
+        Ok(changed_paths)
+    }
+}
@@ -0,0 +1,102 @@
+use log::debug;
+use quote::quote;
+use syn::*;
+
+use crate::config::ActiveCfg;
+
+/// The file without the functions, constants and `impl` blocks that `#[cfg(..)]` disables, nor
+/// the disabled methods of the `impl` blocks left
+pub fn strip_disabled_items(file: &File, active_cfg: &ActiveCfg) -> File {
+    let mut file = file.clone();
+    file.items.retain(|item| match item {
+        Item::Fn(ItemFn { attrs, .. })
+        | Item::Impl(ItemImpl { attrs, .. })
+        | Item::Const(ItemConst { attrs, .. })
+        | Item::Static(ItemStatic { attrs, .. }) => is_enabled(attrs, active_cfg),
+        _ => true,
+    });
+    for item in &mut file.items {
+        if let Item::Impl(item_impl) = item {
+            item_impl.items.retain(|item| match item {
+                ImplItem::Method(method) => is_enabled(&method.attrs, active_cfg),
+                _ => true,
+            });
+        }
+    }
+    file
+}
+
+/// Whether none of the `#[cfg(..)]` of the attributes is known not to hold
+fn is_enabled(attrs: &[Attribute], active_cfg: &ActiveCfg) -> bool {
+    let values = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .map(|attr| {
+            let value = match attr.parse_meta() {
+                Ok(Meta::List(MetaList { nested, .. })) if nested.len() == 1 => {
+                    evaluate(&nested[0], active_cfg)
+                }
+                _ => None,
+            };
+            if value.is_none() {
+                debug!(
+                    "Keeping the item under `{}`, which depends on what is not known",
+                    quote!(#attr)
+                );
+            }
+            value
+        });
+    all(values) != Some(false)
+}
+
+/// The value of a `cfg` predicate, `None` when it depends on what is not known
+fn evaluate(predicate: &NestedMeta, active_cfg: &ActiveCfg) -> Option<bool> {
+    let meta = match predicate {
+        NestedMeta::Meta(meta) => meta,
+        NestedMeta::Lit(_) => return None,
+    };
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Str(lit),
+            ..
+        }) if path.is_ident("feature") => Some(active_cfg.features.contains(&lit.value())),
+        Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Str(lit),
+            ..
+        }) if path.is_ident("target_os") => {
+            let target_os = active_cfg.target_os.as_ref()?;
+            Some(*target_os == lit.value())
+        }
+        // The bridge is built without the tests, so what only they have cannot be called
+        Meta::Path(path) if path.is_ident("test") => Some(false),
+        Meta::List(MetaList { path, nested, .. }) if path.is_ident("not") && nested.len() == 1 => {
+            evaluate(&nested[0], active_cfg).map(|value| !value)
+        }
+        Meta::List(MetaList { path, nested, .. }) if path.is_ident("all") => all(nested
+            .iter()
+            .map(|predicate| evaluate(predicate, active_cfg))),
+        // One holds unless all do not
+        Meta::List(MetaList { path, nested, .. }) if path.is_ident("any") => {
+            let negated = nested
+                .iter()
+                .map(|predicate| evaluate(predicate, active_cfg).map(|value| !value));
+            all(negated).map(|value| !value)
+        }
+        _ => None,
+    }
+}
+
+/// Whether all the values hold: not when one does not, and unknown when one is otherwise
+fn all(values: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+    let mut all = Some(true);
+    for value in values {
+        match value {
+            Some(false) => return Some(false),
+            Some(true) => {}
+            None => all = None,
+        }
+    }
+    all
+}
@@ -1,75 +1,431 @@
+mod cfg;
 mod ty;
 
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::string::String;
 
-use log::debug;
+use convert_case::{Case, Casing};
+use log::{debug, warn};
 use quote::quote;
+use syn::parse::Parser as _;
+use syn::punctuated::Punctuated;
 use syn::*;
 
+use crate::config::{ActiveCfg, ArcStructs, DartNamesStyle, ItemFilter, Opts};
 use crate::ir::*;
 
 use crate::generator::rust::HANDLER_NAME;
 use crate::parser::ty::TypeParser;
-use crate::source_graph::Crate;
+use crate::source_graph::{Crate, Enum, Struct};
 
 const STREAM_SINK_IDENT: &str = "StreamSink";
+const CANCELLATION_TOKEN_IDENT: &str = "CancellationToken";
+const DART_CALLBACK_IDENT: &str = "DartCallback";
 const RESULT_IDENT: &str = "Result";
+/// The name of the argument holding the receiver of a method
+const METHOD_RECEIVER_NAME: &str = "that";
 
-pub fn parse(source_rust_content: &str, file: File, manifest_path: &str) -> IrFile {
-    let crate_map = Crate::new(manifest_path);
+/// The structs and enums that can be named from an input file, by the name they are known under
+type VisibleTypes<'a> = (HashMap<String, &'a Struct>, HashMap<String, &'a Enum>);
+
+/// A Rust input file
+pub struct SourceFile {
+    pub path: String,
+    pub content: String,
+    pub ast: File,
+}
+
+/// Parses the input files into a single [IrFile], whose types may be defined anywhere in the crate
+pub fn parse(sources: &[SourceFile], config: &Opts) -> anyhow::Result<IrFile> {
+    let crate_map = Crate::new(&config.manifest_path)?;
 
-    let src_fns = extract_fns_from_file(&file);
     let src_structs = crate_map.root_module.collect_structs_to_vec();
     let src_enums = crate_map.root_module.collect_enums_to_vec();
+    let src_traits = crate_map.root_module.collect_traits_to_vec();
+    let source_modules = sources
+        .iter()
+        .map(|source| {
+            let module = crate_map.find_module_by_file(Path::new(&source.path));
+            if module.is_none() {
+                warn!(
+                    "{} is not a module of the crate, the types it uses are looked up by name",
+                    source.path
+                );
+            }
+            module
+        })
+        .collect::<Vec<_>>();
+    // Names are resolved as in each input file, falling back to any type of the crate with that name
+    let visible_types = source_modules
+        .iter()
+        .map(|module| match module {
+            Some(module) => (
+                crate_map.visible_structs(module),
+                crate_map.visible_enums(module),
+            ),
+            None => (HashMap::new(), HashMap::new()),
+        })
+        .collect::<Vec<_>>();
+    let item_scopes = sources
+        .iter()
+        .zip(&source_modules)
+        .map(|(source, module)| {
+            let is_public = module.is_none_or(|module| crate_map.is_module_public(module));
+            if config.item_filter.public_modules_only && !is_public {
+                warn!(
+                    "All the items of {} are left out by public_modules_only, since its module is not public from the crate root",
+                    source.path
+                );
+            }
+            ItemScope {
+                module_path: module.map(|module| module.module_path.join("::")),
+                is_public,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let arc_handle_structs = match config.arc_structs {
+        ArcStructs::Clone => HashSet::new(),
+        ArcStructs::Handle => collect_arc_handle_structs(sources, &src_structs, &src_enums),
+    };
+
+    let parser = Parser::new(
+        TypeParser::new(
+            src_structs,
+            src_enums,
+            src_traits,
+            crate_map.dependency_libs.clone(),
+            config.dart_uuid_type,
+            config.dart_int64_type,
+            config.non_utf8_paths,
+            config.non_zero_ints,
+            arc_handle_structs,
+        ),
+        &config.item_filter,
+        &config.active_cfg,
+    );
+    Ok(parser.parse(sources, visible_types, item_scopes))
+}
 
-    let parser = Parser::new(TypeParser::new(src_structs, src_enums));
-    parser.parse(source_rust_content, src_fns)
+/// Where the items of an input file are, for the item filter
+struct ItemScope {
+    /// Such as `crate::api`, unless the file is not a module of the crate
+    module_path: Option<String>,
+    /// Whether the module and the modules containing it are all declared `pub`
+    is_public: bool,
 }
 
 struct Parser<'a> {
     type_parser: TypeParser<'a>,
+    item_filter: &'a ItemFilter,
+    active_cfg: &'a ActiveCfg,
+    item_scope: ItemScope,
+    /// The paths of the items the filter was asked about, to tell which patterns match nothing
+    item_paths: Vec<String>,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(type_parser: TypeParser<'a>) -> Self {
-        Parser { type_parser }
+    pub fn new(
+        type_parser: TypeParser<'a>,
+        item_filter: &'a ItemFilter,
+        active_cfg: &'a ActiveCfg,
+    ) -> Self {
+        Parser {
+            type_parser,
+            item_filter,
+            active_cfg,
+            item_scope: ItemScope {
+                module_path: None,
+                is_public: true,
+            },
+            item_paths: Vec::new(),
+        }
+    }
+
+    /// Whether the item of the given name, or path within the current file, passes the filter
+    fn keeps_item(&mut self, name: &str) -> bool {
+        let path = match &self.item_scope.module_path {
+            Some(module_path) => format!("{}::{}", module_path, name),
+            None => name.to_owned(),
+        };
+        let keeps = self.item_filter.keeps(&path, self.item_scope.is_public);
+        self.item_paths.push(path);
+        keeps
+    }
+
+    fn warn_unmatched_item_patterns(&self) {
+        let filter = self.item_filter;
+        for (option, patterns) in [
+            ("include_items", &filter.include),
+            ("exclude_items", &filter.exclude),
+        ] {
+            for pattern in patterns {
+                if !self.item_paths.iter().any(|path| pattern.matches(path)) {
+                    warn!(
+                        "The {} pattern `{}` matches no function, method nor constant of the inputs",
+                        option, pattern.pattern
+                    );
+                }
+            }
+        }
     }
 }
 
 impl<'a> Parser<'a> {
-    fn parse(mut self, source_rust_content: &str, src_fns: Vec<&ItemFn>) -> IrFile {
-        let funcs = src_fns.iter().map(|f| self.parse_function(f)).collect();
+    fn parse(
+        mut self,
+        sources: &[SourceFile],
+        visible_types: Vec<VisibleTypes<'a>>,
+        item_scopes: Vec<ItemScope>,
+    ) -> IrFile {
+        let mut funcs = Vec::new();
+        let mut consts = Vec::new();
+        let mut init_funcs = Vec::new();
+        // The wire functions of all files live in the same generated file
+        let mut func_paths = HashMap::new();
+        let mut const_paths = HashMap::new();
+        for (source_index, ((source, (structs, enums)), item_scope)) in sources
+            .iter()
+            .zip(visible_types)
+            .zip(item_scopes)
+            .enumerate()
+        {
+            self.type_parser.enter_file(structs, enums);
+            self.item_scope = item_scope;
+            let ast = cfg::strip_disabled_items(&source.ast, self.active_cfg);
+            let source_fns: Vec<_> = extract_fns_from_file(&ast)
+                .into_iter()
+                .filter(|f| self.keeps_item(&f.sig.ident.to_string()))
+                .collect();
+            let mut source_funcs: Vec<_> = source_fns
+                .iter()
+                .flat_map(|f| self.parse_free_function(f))
+                .collect();
+            for item_impl in extract_impls_from_file(&ast) {
+                source_funcs.extend(self.parse_impl(item_impl));
+            }
+            for func in &mut source_funcs {
+                func.source_index = source_index;
+            }
+            for func in &source_funcs {
+                if let Some(other_path) = func_paths.insert(func.name.clone(), &source.path) {
+                    panic!(
+                        "Function `{}` is defined in both {} and {}, but functions of all input files share the same namespace",
+                        func.name, other_path, source.path
+                    );
+                }
+            }
+            funcs.extend(source_funcs);
+            init_funcs.extend(extract_init_fns_from_file(&ast, |name| {
+                self.keeps_item(name)
+            }));
+
+            let source_consts = extract_consts_from_file(&ast, |name| self.keeps_item(name));
+            for constant in &source_consts {
+                if let Some(other_path) = const_paths.insert(constant.name.clone(), &source.path) {
+                    panic!(
+                        "Constant `{}` is defined in both {} and {}, but constants of all input files share the same namespace",
+                        constant.name, other_path, source.path
+                    );
+                }
+            }
+            consts.extend(source_consts);
+        }
 
-        let has_executor = source_rust_content.contains(HANDLER_NAME);
+        self.warn_unmatched_item_patterns();
+        check_dart_func_names(&funcs);
+        check_dart_const_names(&consts, &funcs);
+        if !init_funcs.is_empty() {
+            if let Some(func) = funcs
+                .iter()
+                .find(|func| func.name == INIT_FUNC_NAME || func.dart_func_name() == INIT_FUNC_NAME)
+            {
+                panic!(
+                    "Function `{}` takes the name of the generated `{}`, which runs the functions marked `#[frb(init)]`",
+                    func.name, INIT_FUNC_NAME
+                );
+            }
+        }
+        if let Some(func) = funcs
+            .iter()
+            .find(|func| func.dart_func_name() == DISPOSE_FUNC_NAME)
+        {
+            panic!(
+                "Function `{}` takes the name of the generated `{}`, which tears down the bridge",
+                func.name, DISPOSE_FUNC_NAME
+            );
+        }
+        if let Some(constant) = consts
+            .iter()
+            .find(|constant| constant.value.is_none() && constant.dart_name == DISPOSE_FUNC_NAME)
+        {
+            panic!(
+                "Constant `{}` takes the name of the generated `{}`, which tears down the bridge",
+                constant.name, DISPOSE_FUNC_NAME
+            );
+        }
+
+        let has_executor = sources
+            .iter()
+            .any(|source| source.content.contains(HANDLER_NAME));
 
         let (struct_pool, enum_pool) = self.type_parser.consume();
 
         IrFile {
             funcs,
+            consts,
             struct_pool,
             enum_pool,
             has_executor,
+            init_funcs,
         }
     }
 
     /// Attempts to parse the type from the return part of a function signature. There is a special
     /// case for top-level `Result` types.
     pub fn try_parse_fn_output_type(&mut self, ty: &syn::Type) -> Option<IrFuncOutput> {
+        if let syn::Type::Reference(reference) = ty {
+            return Some(IrFuncOutput::Type(self.parse_static_output_type(reference)));
+        }
+        if let Some(item) = iterator_item(ty) {
+            return Some(IrFuncOutput::Type(self.parse_iterator_output_type(item)));
+        }
+        if let Some((iterator, error)) = result_of_iterator(ty) {
+            let ok = self.parse_iterator_output_type(iterator_item(iterator).unwrap());
+            let error = error
+                .and_then(ty::SupportedInnerType::try_from_syn_type)
+                .and_then(|error| self.type_parser.convert_to_ir_type(error));
+            self.type_parser.take_unresolved_type();
+            return Some(IrFuncOutput::ResultType(ok, error));
+        }
+        if let Some((reference, error)) = result_of_reference(ty) {
+            let ok = self.parse_static_output_type(reference);
+            let error = error
+                .and_then(ty::SupportedInnerType::try_from_syn_type)
+                .and_then(|error| self.type_parser.convert_to_ir_type(error));
+            self.type_parser.take_unresolved_type();
+            return Some(IrFuncOutput::ResultType(ok, error));
+        }
         let inner = ty::SupportedInnerType::try_from_syn_type(ty)?;
 
+        // Only the names left unresolved by this type are worth reporting
+        self.type_parser.take_unresolved_type();
         match inner {
             ty::SupportedInnerType::Path(ty::SupportedPathType {
                 ident,
-                generic: Some(generic),
-            }) if ident == RESULT_IDENT => Some(IrFuncOutput::ResultType(
-                self.type_parser.convert_to_ir_type(*generic)?,
-            )),
+                mut generics,
+            }) if ident == RESULT_IDENT && !generics.is_empty() => {
+                let ok = self.type_parser.convert_to_ir_type(generics.remove(0))?;
+                // Error types that cannot cross the bridge, such as `anyhow::Error`, are reported
+                // as messages instead
+                let error = generics
+                    .pop()
+                    .and_then(|error| self.type_parser.convert_to_ir_type(error));
+                self.type_parser.take_unresolved_type();
+                Some(IrFuncOutput::ResultType(ok, error))
+            }
             _ => Some(IrFuncOutput::Type(
                 self.type_parser.convert_to_ir_type(inner)?,
             )),
         }
     }
 
+    /// The type of an `impl Iterator<Item = T>` output, which the wrapper collects into a `Vec<T>`
+    /// for it to be sent as a list
+    fn parse_iterator_output_type(&mut self, item: &syn::Type) -> IrType {
+        self.type_parser.parse_type(&syn::parse_quote!(Vec<#item>))
+    }
+
+    /// The type of a borrowed output, which can only be data living as long as the program, since
+    /// the function returns before it is sent. The data is then copied to be sent like a `String`
+    /// or a `Vec<T>`.
+    fn parse_static_output_type(&mut self, reference: &syn::TypeReference) -> IrType {
+        let is_static = matches!(&reference.lifetime, Some(lifetime) if lifetime.ident == "static");
+        if is_static && reference.mutability.is_none() {
+            match &*reference.elem {
+                syn::Type::Path(syn::TypePath { qself: None, path }) if path.is_ident("str") => {
+                    return IrType::Delegate(IrTypeDelegate::Str);
+                }
+                syn::Type::Path(syn::TypePath { qself: None, path }) if is_std_path(path) => {
+                    return self.type_parser.borrowed_path_type();
+                }
+                syn::Type::Slice(syn::TypeSlice { elem, .. }) => {
+                    return IrType::Delegate(IrTypeDelegate::Slice(Box::new(
+                        self.type_parser.parse_type(elem),
+                    )));
+                }
+                _ => {}
+            }
+        }
+        panic!(
+            "Function output type `{}` is a reference, but only `&'static str`, `&'static [T]` and `&'static Path` can be returned, as copies, besides the references into the receiver of a method taking `&self`. Return an owned value instead.",
+            type_to_string(&syn::Type::Reference(reference.clone()))
+        );
+    }
+
+    /// The `fn(..) -> R` of a `DartCallback`, whose arguments can be numbers, booleans and strings,
+    /// which Dart receives as they are, and whose return type a number, a boolean or `()`
+    fn parse_callback_signature(&mut self, segment: &PathSegment) -> IrCallbackSignature {
+        let bare_fn = match &segment.arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. })
+                if args.len() == 1 =>
+            {
+                match args.first() {
+                    Some(GenericArgument::Type(Type::BareFn(bare_fn))) => Some(bare_fn),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+        .unwrap_or_else(|| {
+            panic!("`DartCallback` takes the signature of the Dart function as a function pointer type, e.g. `DartCallback<fn(i32, i32) -> i32>`")
+        });
+        let inputs = bare_fn
+            .inputs
+            .iter()
+            .map(|input| {
+                let ty = self.type_parser.parse_type(&input.ty);
+                if matches!(ty, IrType::Delegate(IrTypeDelegate::BigInt64(_))) {
+                    panic!(
+                        "`DartCallback` argument type `{}` is not supported with `--dart-int64-type big-int` for now",
+                        type_to_string(&input.ty)
+                    );
+                }
+                if !matches!(
+                    ty,
+                    IrType::Delegate(IrTypeDelegate::String) | IrType::Primitive(_)
+                ) || matches!(ty, IrType::Primitive(IrTypePrimitive::Unit))
+                {
+                    panic!(
+                        "`DartCallback` argument type `{}` is not supported, only numbers, booleans and `String`s can be given to Dart functions for now",
+                        type_to_string(&input.ty)
+                    );
+                }
+                let name = input
+                    .name
+                    .as_ref()
+                    .map(|(name, _)| IrIdent::new(name.to_string()));
+                (name, ty)
+            })
+            .collect();
+        let output = match &bare_fn.output {
+            ReturnType::Default => IrType::Primitive(IrTypePrimitive::Unit),
+            ReturnType::Type(_, ty) => match self.type_parser.parse_type(ty) {
+                primitive @ IrType::Primitive(_) => primitive,
+                IrType::Delegate(IrTypeDelegate::BigInt64(_)) => panic!(
+                    "`DartCallback` return type `{}` is not supported with `--dart-int64-type big-int` for now",
+                    type_to_string(ty)
+                ),
+                _ => panic!(
+                    "`DartCallback` return type `{}` is not supported, Dart functions can only return numbers, booleans or nothing for now",
+                    type_to_string(ty)
+                ),
+            },
+        };
+        IrCallbackSignature { inputs, output }
+    }
+
     /// Attempts to parse the type from an argument of a function signature. There is a special
     /// case for top-level `StreamSink` types.
     pub fn try_parse_fn_arg_type(&mut self, ty: &syn::Type) -> Option<IrFuncArg> {
@@ -91,34 +447,293 @@ impl<'a> Parser<'a> {
                         }
                         _ => None,
                     }
+                } else if last_segment.ident == CANCELLATION_TOKEN_IDENT {
+                    Some(IrFuncArg::CancellationToken)
+                } else if last_segment.ident == DART_CALLBACK_IDENT {
+                    Some(IrFuncArg::Callback(
+                        self.parse_callback_signature(last_segment),
+                    ))
+                } else if let Some(elem) = optional_reference_elem(last_segment) {
+                    // Optional opaque objects are lent like the others, as `Option<&T>`
+                    match self.type_parser.parse_type(elem) {
+                        ty @ IrType::Opaque(IrTypeOpaque { shared: false, .. }) => Some(
+                            IrFuncArg::Type(IrType::Optional(IrTypeOptional::new_ptr(ty))),
+                        ),
+                        _ => None,
+                    }
                 } else {
-                    Some(IrFuncArg::Type(self.type_parser.parse_type(ty)))
+                    let ty = self.type_parser.parse_type(ty);
+                    match &ty {
+                        IrType::Opaque(opaque @ IrTypeOpaque { shared: false, .. }) => panic!(
+                            "Opaque type `{}` can only be passed by reference, as `&{}`",
+                            opaque.name, opaque.name
+                        ),
+                        IrType::Optional(IrTypeOptional { inner }) => {
+                            if let IrType::Opaque(opaque @ IrTypeOpaque { shared: false, .. }) =
+                                &**inner
+                            {
+                                panic!(
+                                    "Opaque type `{}` can only be passed by reference, as `Option<&{}>`",
+                                    opaque.name, opaque.name
+                                );
+                            }
+                        }
+                        _ => {}
+                    }
+                    Some(IrFuncArg::Type(ty))
                 }
             }
+            // Strings, slices and paths are borrowed from the values received, and opaque objects stay
+            // in Rust, so they are only lent to functions
+            syn::Type::Reference(syn::TypeReference {
+                mutability: None,
+                elem,
+                ..
+            }) => match &**elem {
+                syn::Type::Path(syn::TypePath { qself: None, path }) if path.is_ident("str") => {
+                    Some(IrFuncArg::Type(IrType::Delegate(IrTypeDelegate::Str)))
+                }
+                syn::Type::Path(syn::TypePath { qself: None, path }) if is_std_path(path) => {
+                    Some(IrFuncArg::Type(self.type_parser.borrowed_path_type()))
+                }
+                syn::Type::Slice(syn::TypeSlice { elem, .. }) => {
+                    Some(IrFuncArg::Type(IrType::Delegate(IrTypeDelegate::Slice(
+                        Box::new(self.type_parser.parse_type(elem)),
+                    ))))
+                }
+                _ => match self.type_parser.parse_type(elem) {
+                    ty @ IrType::Opaque(_) => Some(IrFuncArg::Type(ty)),
+                    _ => None,
+                },
+            },
+            syn::Type::Array(_) | syn::Type::Tuple(_) => {
+                Some(IrFuncArg::Type(self.type_parser.parse_type(ty)))
+            }
             _ => None,
         }
     }
 
-    fn parse_function(&mut self, func: &ItemFn) -> IrFunc {
-        debug!("parse_function function name: {:?}", func.sig.ident);
+    /// Parses the public methods of an `impl` block. Associated functions without a receiver are
+    /// not exposed, nor are the methods marked `#[frb(skip)]` or those of a skipped type.
+    fn parse_impl(&mut self, item_impl: &ItemImpl) -> Vec<IrFunc> {
+        if has_frb_flag(&item_impl.attrs, "skip")
+            || self.type_parser.is_skipped_type(&item_impl.self_ty)
+        {
+            return vec![];
+        }
+        // Methods are filtered before their type is parsed, for that of an impl block whose methods
+        // are all left out not to reach the API
+        let type_name = match &*item_impl.self_ty {
+            Type::Path(TypePath { path, .. }) => path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        }
+        .unwrap_or_else(|| type_to_string(&item_impl.self_ty));
+        let methods: Vec<_> = item_impl
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Method(method) => match &method.vis {
+                    Visibility::Public(_)
+                        if method.sig.receiver().is_some()
+                            && !has_frb_flag(&method.attrs, "skip") =>
+                    {
+                        Some(method)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .filter(|method| self.keeps_item(&format!("{}::{}", type_name, method.sig.ident)))
+            .collect();
+        if methods.is_empty() {
+            return vec![];
+        }
+        if item_impl.generics.type_params().next().is_some() {
+            panic!(
+                "Methods of generic `impl` blocks such as `impl {}` are not supported, implement them for the type arguments used instead",
+                type_to_string(&item_impl.self_ty)
+            );
+        }
+
+        let self_ty = self.type_parser.parse_type(&item_impl.self_ty);
+        let struct_name = match &self_ty {
+            IrType::StructRef(IrTypeStructRef { name, .. })
+            | IrType::Opaque(IrTypeOpaque { name, .. }) => name.clone(),
+            _ => panic!(
+                "Methods are only supported on structs, but found `impl {}`",
+                type_to_string(&item_impl.self_ty)
+            ),
+        };
+
+        methods
+            .into_iter()
+            .map(|method| {
+                self.parse_function(&method.sig, &method.attrs, Some((&struct_name, &self_ty)))
+            })
+            .collect()
+    }
+
+    /// `owner` is the name and type of the struct when parsing a method.
+    /// The functions standing for a free function: the function itself, or each instantiation of
+    /// a generic function given by `#[frb(instantiate = "..")]`
+    fn parse_free_function(&mut self, item_fn: &ItemFn) -> Vec<IrFunc> {
+        let sig = &item_fn.sig;
+        let params = sig
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+        if params.is_empty() {
+            return vec![self.parse_function(sig, &item_fn.attrs, None)];
+        }
+
+        let hints = frb_values(&item_fn.attrs, "instantiate").collect::<Vec<_>>();
+        if hints.is_empty() {
+            panic!(
+                "Function `{}` is generic, so the types it is called with have to be given by `#[frb(instantiate = \"{}\")]`, repeated for each instantiation",
+                sig.ident,
+                params.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+            );
+        }
+        let mut names = HashSet::new();
+        hints
+            .iter()
+            .map(|hint| {
+                let type_args = Punctuated::<Type, Token![,]>::parse_terminated
+                    .parse_str(hint)
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "`#[frb(instantiate = \"{}\")]` of function `{}` is not a list of types: {}",
+                            hint, sig.ident, err
+                        )
+                    })
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                if type_args.len() != params.len() {
+                    panic!(
+                        "`#[frb(instantiate = \"{}\")]` of function `{}` gives {} types, but the function has {} type parameter{}",
+                        hint,
+                        sig.ident,
+                        type_args.len(),
+                        params.len(),
+                        if params.len() == 1 { "" } else { "s" }
+                    );
+                }
+                let suffix = instantiation_suffix(&type_args);
+                let instance_sig = instantiate_signature(sig, &suffix, &params, &type_args);
+                if !names.insert(suffix.clone()) {
+                    panic!(
+                        "Function `{}` is instantiated several times with `{}`",
+                        sig.ident, hint
+                    );
+                }
+                let mut func = self.parse_function(&instance_sig, &item_fn.attrs, None);
+                // Each instantiation needs a Dart name of its own
+                if let Some(dart_name) = &func.dart_name {
+                    func.dart_name = Some(
+                        format!("{}_{}", dart_name, suffix).to_case(Case::Camel),
+                    );
+                }
+                func.instantiation = Some(IrFuncInstantiation {
+                    generic_name: sig.ident.to_string(),
+                    type_args: type_args.iter().map(type_to_string).collect(),
+                });
+                func
+            })
+            .collect()
+    }
+
+    fn parse_function(
+        &mut self,
+        sig: &Signature,
+        attrs: &[Attribute],
+        owner: Option<(&str, &IrType)>,
+    ) -> IrFunc {
+        debug!("parse_function function name: {:?}", sig.ident);
+
+        if sig.generics.type_params().next().is_some() {
+            panic!(
+                "Method `{}` is generic, which is only supported for free functions, through `#[frb(instantiate = \"..\")]`",
+                sig.ident
+            );
+        }
 
-        let sig = &func.sig;
-        let func_name = sig.ident.to_string();
+        let func_name = match owner {
+            Some((struct_name, _)) => format!("{}_{}", struct_name.to_case(Case::Snake), sig.ident),
+            None => sig.ident.to_string(),
+        };
 
         let mut inputs = Vec::new();
         let mut output = None;
         let mut mode = None;
+        let mut error_output = None;
         let mut fallible = true;
+        let mut method = None;
+        let mut out_params = Vec::new();
+        let mut arg_position = 0;
+        let mut cancellation_token = None;
+        let mut collects_iterator = false;
+        let mut callbacks = Vec::new();
 
         for sig_input in &sig.inputs {
-            if let FnArg::Typed(ref pat_type) = sig_input {
+            if let (FnArg::Receiver(receiver), Some((struct_name, self_ty))) = (sig_input, owner) {
+                let receiver = match (&receiver.reference, &receiver.mutability) {
+                    (Some(_), Some(_)) => IrMethodReceiver::RefMut,
+                    (Some(_), None) => IrMethodReceiver::Ref,
+                    (None, _) => IrMethodReceiver::Value,
+                };
+                if matches!(self_ty, IrType::Opaque(_)) && receiver != IrMethodReceiver::Ref {
+                    panic!(
+                        "Method `{}` of opaque type `{}` can only take `&self`, consider interior mutability instead",
+                        sig.ident, struct_name
+                    );
+                }
+                method = Some(IrMethod {
+                    struct_name: struct_name.to_string(),
+                    self_type: match self_ty {
+                        IrType::StructRef(struct_ref) => struct_ref.rust_api_type(),
+                        _ => struct_name.to_string(),
+                    },
+                    name: sig.ident.to_string(),
+                    receiver,
+                });
+                inputs.push(IrField {
+                    name: IrIdent::new(METHOD_RECEIVER_NAME.to_string()),
+                    ty: self_ty.clone(),
+                    comments: vec![],
+                    deprecation: None,
+                    default: None,
+                    is_final: true,
+                });
+            } else if let FnArg::Typed(ref pat_type) = sig_input {
                 let name = if let Pat::Ident(ref pat_ident) = *pat_type.pat {
                     format!("{}", pat_ident.ident)
                 } else {
                     panic!("unexpected pat_type={:?}", pat_type)
                 };
 
+                arg_position += 1;
+                if has_frb_flag(&pat_type.attrs, "out") {
+                    out_params.push(IrOutParam {
+                        ty: self.parse_out_param_type(&pat_type.ty, &name),
+                        name: IrIdent::new(name),
+                        position: arg_position - 1,
+                    });
+                    continue;
+                }
+
                 match self.try_parse_fn_arg_type(&pat_type.ty).unwrap_or_else(|| {
+                    if let syn::Type::Reference(syn::TypeReference {
+                        mutability: Some(_),
+                        ..
+                    }) = &*pat_type.ty
+                    {
+                        panic!(
+                            "Argument `{}: {}` is a mutable reference, which is only supported as an out-parameter marked `#[frb(out)]`",
+                            name,
+                            type_to_string(&pat_type.ty)
+                        );
+                    }
                     panic!(
                         "Failed to parse function argument type `{}`",
                         type_to_string(&pat_type.ty)
@@ -127,12 +742,36 @@ impl<'a> Parser<'a> {
                     IrFuncArg::StreamSinkType(ty) => {
                         output = Some(ty);
                         mode = Some(IrFuncMode::Stream);
+                        // The sink is passed first, apart from the other arguments
+                        arg_position -= 1;
+                    }
+                    IrFuncArg::CancellationToken => {
+                        if cancellation_token.is_some() {
+                            panic!(
+                                "Function `{}` takes several `CancellationToken`s, while one is enough",
+                                func_name
+                            );
+                        }
+                        cancellation_token = Some(IrCancellationToken {
+                            name: IrIdent::new(name),
+                            position: arg_position - 1,
+                        });
                     }
+                    IrFuncArg::Callback(signature) => callbacks.push(IrCallback {
+                        name: IrIdent::new(name),
+                        signature,
+                        position: arg_position - 1,
+                    }),
                     IrFuncArg::Type(ty) => {
+                        let default = frb_value(&pat_type.attrs, "default")
+                            .map(|value| dart_default_literal(&ty, &value, &name));
                         inputs.push(IrField {
                             name: IrIdent::new(name),
                             ty,
                             comments: extract_comments(&pat_type.attrs),
+                            deprecation: None,
+                            default,
+                            is_final: true,
                         });
                     }
                 }
@@ -141,16 +780,32 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // A method borrowing its receiver can return a reference into it, copied before the
+        // receiver is dropped
+        let borrows_receiver =
+            matches!(&method, Some(method) if method.receiver != IrMethodReceiver::Value);
+        let mut copies_borrowed_output = false;
         if output.is_none() {
             output = Some(match &sig.output {
                 ReturnType::Type(_, ty) => {
+                    let owned_ty = borrows_receiver
+                        .then(|| owned_borrowed_output_type(ty))
+                        .flatten();
+                    copies_borrowed_output = owned_ty.is_some();
+                    let ty = owned_ty.as_ref().unwrap_or(ty);
+                    collects_iterator =
+                        iterator_item(ty).is_some() || result_of_iterator(ty).is_some();
                     match self.try_parse_fn_output_type(ty).unwrap_or_else(|| {
                         panic!(
-                            "Failed to parse function output type `{}`",
-                            type_to_string(ty)
+                            "{}",
+                            self.type_parser
+                                .unsupported_type_message("function output type", ty)
                         )
                     }) {
-                        IrFuncOutput::ResultType(ty) => ty,
+                        IrFuncOutput::ResultType(ty, error) => {
+                            error_output = error;
+                            ty
+                        }
                         IrFuncOutput::Type(ty) => {
                             fallible = false;
                             ty
@@ -169,27 +824,475 @@ impl<'a> Parser<'a> {
                     IrFuncMode::Normal
                 },
             );
+        } else {
+            // The return value of a stream function is ignored, only whether it can fail matters
+            fallible = match &sig.output {
+                ReturnType::Type(_, ty) => match self.try_parse_fn_output_type(ty) {
+                    Some(IrFuncOutput::ResultType(_, error)) => {
+                        error_output = error;
+                        true
+                    }
+                    _ => false,
+                },
+                ReturnType::Default => false,
+            };
+        }
+
+        if mode == Some(IrFuncMode::Sync) && error_output.is_some() {
+            panic!(
+                "Function `{}` returns a custom error type, which is not supported with SyncReturn",
+                func_name
+            );
         }
 
-        // let comments = func.attrs.iter().filter_map(extract_comments).collect();
+        let mut out_params_only = false;
+        if !out_params.is_empty() {
+            if matches!(mode, Some(IrFuncMode::Sync | IrFuncMode::Stream)) {
+                panic!(
+                    "Function `{}` has out-parameters, which are not supported with SyncReturn nor StreamSink",
+                    func_name
+                );
+            }
+            // The value returned by the function comes first, then the out-parameters in order
+            let own_output = output.take().unwrap();
+            out_params_only = matches!(own_output, IrType::Primitive(IrTypePrimitive::Unit));
+            let mut fields = if out_params_only {
+                vec![]
+            } else {
+                vec![own_output]
+            };
+            fields.extend(out_params.iter().map(|field| field.ty.clone()));
+            output = Some(if fields.len() == 1 {
+                fields.pop().unwrap()
+            } else {
+                IrType::StructRef(IrTypeStructRef::for_tuple(fields))
+            });
+        }
+
+        if let Some(names) = frb_value(attrs, "returns") {
+            // Named after the function as Dart calls it, prefixed by the type of a method
+            let class_name = format!(
+                "{}{}Output",
+                owner.map_or("", |(struct_name, _)| struct_name),
+                extract_dart_name(attrs)
+                    .unwrap_or_else(|| sig.ident.to_string())
+                    .to_case(Case::UpperCamel)
+            );
+            output = Some(self.name_returned_values(
+                &func_name,
+                class_name,
+                output.take().unwrap(),
+                &names,
+            ));
+        }
+
+        if has_frb_flag(attrs, "sync") {
+            mode = Some(check_direct_func(
+                &func_name,
+                mode.as_ref(),
+                output.as_ref(),
+                sig.asyncness.is_some(),
+            ));
+        }
+
+        if cancellation_token.is_some()
+            && matches!(mode, Some(IrFuncMode::Sync | IrFuncMode::Direct))
+        {
+            panic!(
+                "Function `{}` takes a `CancellationToken`, but synchronous functions cannot be cancelled, since Dart waits for them to return",
+                func_name
+            );
+        }
+
+        // The calls block the task until Dart replies, which it cannot do while waiting for the
+        // function to return
+        if !callbacks.is_empty() && mode != Some(IrFuncMode::Normal) {
+            panic!(
+                "Function `{}` takes a `DartCallback`, which is only supported by functions returning to Dart through a `Future` for now, not by synchronous functions nor streams",
+                func_name
+            );
+        }
+
+        let is_extension = has_frb_flag(attrs, "extension");
+        if is_extension {
+            check_extension_func(&func_name, &inputs, method.is_some());
+        }
+
+        let lock = extract_lock(&func_name, attrs);
+
+        let mut comments = extract_comments(attrs);
+        if copies_borrowed_output {
+            if !comments.is_empty() {
+                comments.push(IrComment::from(""));
+            }
+            comments.push(IrComment::from(
+                " Returns a copy of the data the Rust method borrows, made on each call at a cost growing with its size.",
+            ));
+        }
 
         IrFunc {
             name: func_name,
             inputs,
             output: output.expect("unsupported output"),
+            error_output,
             fallible,
+            is_async: sig.asyncness.is_some(),
             mode: mode.expect("unsupported mode"),
-            comments: extract_comments(&func.attrs),
+            comments,
+            deprecation: extract_deprecation(attrs),
+            dart_name: extract_dart_name(attrs),
+            method,
+            out_params,
+            out_params_only,
+            is_extension,
+            cancellation_token,
+            collects_iterator,
+            copies_borrowed_output,
+            callbacks,
+            dart_names_style: DartNamesStyle::default(),
+            lock,
+            instantiation: None,
+            source_index: 0,
+        }
+    }
+
+    /// The tuple returned by a function marked `#[frb(returns = "a, b")]`, its elements being named
+    /// as listed, which becomes a Dart class of its own
+    fn name_returned_values(
+        &mut self,
+        func_name: &str,
+        class_name: String,
+        output: IrType,
+        names: &str,
+    ) -> IrType {
+        let struct_ref = match output {
+            IrType::StructRef(
+                struct_ref @ IrTypeStructRef {
+                    tuple_fields: Some(_),
+                    ..
+                },
+            ) => struct_ref,
+            ty => panic!(
+                "Function `{}` is marked `#[frb(returns = \"{}\")]`, but returns `{}`, while only the values of a tuple can be named",
+                func_name,
+                names,
+                ty.rust_api_type()
+            ),
+        };
+        let field_names = names
+            .split(',')
+            .map(|name| name.trim().to_owned())
+            .collect::<Vec<_>>();
+        let count = struct_ref.tuple_fields.as_ref().unwrap().len();
+        if field_names.len() != count {
+            panic!(
+                "Function `{}` returns {} values, but `#[frb(returns = \"{}\")]` names {}",
+                func_name,
+                count,
+                names,
+                field_names.len()
+            );
+        }
+        for (idx, name) in field_names.iter().enumerate() {
+            if syn::parse_str::<Ident>(name).is_err() {
+                panic!(
+                    "`#[frb(returns = \"{}\")]` of function `{}` names a value `{}`, which is not an identifier",
+                    names, func_name, name
+                );
+            }
+            if field_names[..idx].contains(name) {
+                panic!(
+                    "`#[frb(returns = \"{}\")]` of function `{}` names several values `{}`",
+                    names, func_name, name
+                );
+            }
+        }
+        self.type_parser
+            .check_dart_type_name(&format!("{}()", func_name), &class_name);
+        IrType::StructRef(struct_ref.with_tuple_field_names(class_name, field_names))
+    }
+
+    /// The type filled in by an argument marked `#[frb(out)]`, which has to be a `&mut T` of a type
+    /// that can be returned. The function starts from `T::default()`.
+    fn parse_out_param_type(&mut self, ty: &syn::Type, name: &str) -> IrType {
+        let elem = match ty {
+            syn::Type::Reference(syn::TypeReference {
+                mutability: Some(_),
+                elem,
+                ..
+            }) => elem,
+            _ => panic!(
+                "Out-parameter `{}` must be a mutable reference such as `&mut Vec<u8>`, but found `{}`",
+                name,
+                type_to_string(ty)
+            ),
+        };
+        if let syn::Type::Slice(_) = &**elem {
+            panic!(
+                "Out-parameter `{}` must be a `&mut Vec<T>` rather than `{}`, since Dart has no buffer to lend",
+                name,
+                type_to_string(ty)
+            );
+        }
+        match self.type_parser.parse_type(elem) {
+            IrType::Opaque(opaque) => panic!(
+                "Out-parameter `{}` cannot be of opaque type `{}`, return the object instead",
+                name, opaque.name
+            ),
+            ty => ty,
+        }
+    }
+}
+
+/// Checks that a function marked `#[frb(sync)]` can return its value directly, which only the
+/// primitives can be, as the bytes of a sync return.
+fn check_direct_func(
+    func_name: &str,
+    mode: Option<&IrFuncMode>,
+    output: Option<&IrType>,
+    is_async: bool,
+) -> IrFuncMode {
+    if mode == Some(&IrFuncMode::Stream) {
+        panic!(
+            "Function `{}` is marked `#[frb(sync)]`, but streams need the port",
+            func_name
+        );
+    }
+    if is_async {
+        panic!(
+            "Function `{}` is marked `#[frb(sync)]`, but an `async fn` cannot be called synchronously",
+            func_name
+        );
+    }
+    match output {
+        Some(IrType::Primitive(_)) => IrFuncMode::Direct,
+        _ => panic!(
+            "Function `{}` is marked `#[frb(sync)]`, but returns `{}`, while only numbers, booleans and `()` can be returned without the port",
+            func_name,
+            output.map_or_else(String::new, IrType::rust_api_type)
+        ),
+    }
+}
+
+/// Checks that the first argument of a function marked `#[frb(extension)]` is of a type Dart
+/// classes can be extended with, which only primitives and strings are meant to be.
+fn check_extension_func(func_name: &str, inputs: &[IrField], is_method: bool) {
+    if is_method {
+        panic!(
+            "Method `{}` cannot be marked `#[frb(extension)]`, since it is already called on its struct",
+            func_name
+        );
+    }
+    let receiver = inputs.first().unwrap_or_else(|| {
+        panic!(
+            "Function `{}` is marked `#[frb(extension)]`, but has no argument to be called on",
+            func_name
+        )
+    });
+    let is_valid_target = match &receiver.ty {
+        IrType::Primitive(IrTypePrimitive::Unit) => false,
+        IrType::Primitive(_) => true,
+        IrType::Delegate(IrTypeDelegate::String | IrTypeDelegate::Str) => true,
+        _ => false,
+    };
+    if !is_valid_target {
+        panic!(
+            "Function `{}` is marked `#[frb(extension)]`, but its first argument `{}` is of type `{}`, while only numbers, booleans and strings can be extended",
+            func_name,
+            receiver.name.raw,
+            receiver.ty.rust_api_type()
+        );
+    }
+    if receiver.default.is_some() {
+        panic!(
+            "Argument `{}` of function `{}` cannot have a default value, since the extension method is called on it",
+            receiver.name.raw, func_name
+        );
+    }
+}
+
+/// Panics when two functions, or two methods of a struct, have the same name in Dart, which can
+/// happen when renaming them.
+fn check_dart_func_names(funcs: &[IrFunc]) {
+    let mut names = HashMap::new();
+    for func in funcs {
+        let mut keys = vec![(None, func.dart_func_name())];
+        if let Some(method) = &func.method {
+            keys.push((Some(&method.struct_name), func.dart_method_name(method)));
+        }
+        for key in keys {
+            if let Some(other) = names.insert(key.clone(), &func.name) {
+                panic!(
+                    "Functions `{}` and `{}` are both named `{}` in Dart",
+                    other, func.name, key.1
+                );
+            }
         }
     }
 }
 
+/// The names of the structs used as `Arc<T>` by the functions and methods of the inputs or by the
+/// types of the crate, which are opaque with `--arc-structs handle` wherever they are used
+fn collect_arc_handle_structs(
+    sources: &[SourceFile],
+    src_structs: &HashMap<String, &Struct>,
+    src_enums: &HashMap<String, &Enum>,
+) -> HashSet<String> {
+    let sigs = sources.iter().flat_map(|source| {
+        extract_fns_from_file(&source.ast)
+            .into_iter()
+            .map(|item_fn| &item_fn.sig)
+            .chain(
+                extract_impls_from_file(&source.ast)
+                    .into_iter()
+                    .flat_map(|item_impl| &item_impl.items)
+                    .filter_map(|item| match item {
+                        ImplItem::Method(method) => Some(&method.sig),
+                        _ => None,
+                    }),
+            )
+    });
+    let sig_types = sigs.flat_map(|sig| {
+        sig.inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+                FnArg::Receiver(_) => None,
+            })
+            .chain(match &sig.output {
+                ReturnType::Type(_, ty) => Some(&**ty),
+                ReturnType::Default => None,
+            })
+    });
+    let field_types = src_structs
+        .values()
+        .flat_map(|src_struct| &src_struct.src.fields)
+        .chain(
+            src_enums
+                .values()
+                .flat_map(|src_enum| &src_enum.src.variants)
+                .flat_map(|variant| &variant.fields),
+        )
+        .map(|field| &field.ty);
+
+    let mut names = HashSet::new();
+    for ty in sig_types.chain(field_types) {
+        collect_arc_type_names(ty, &mut names);
+    }
+    names.retain(|name| src_structs.contains_key(name));
+    names
+}
+
+/// Adds the names `T` of all the `Arc<T>` found in `ty`
+fn collect_arc_type_names(ty: &Type, names: &mut HashSet<String>) {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            for segment in &path.segments {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(arg) = arg {
+                            if let (true, Type::Path(TypePath { qself: None, path })) =
+                                (segment.ident == "Arc", arg)
+                            {
+                                names.insert(path.segments.last().unwrap().ident.to_string());
+                            }
+                            collect_arc_type_names(arg, names);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(TypeReference { elem, .. })
+        | Type::Array(TypeArray { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Group(TypeGroup { elem, .. }) => collect_arc_type_names(elem, names),
+        Type::Tuple(TypeTuple { elems, .. }) => {
+            for elem in elems {
+                collect_arc_type_names(elem, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The words of the type arguments of an instantiation in snake_case, e.g. `vec_u8` for `Vec<u8>`
+fn instantiation_suffix(type_args: &[Type]) -> String {
+    type_args
+        .iter()
+        .flat_map(|ty| {
+            type_to_string(ty)
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    // Keeps the digits of primitives such as `i32` with their letter
+                    if word.chars().any(|c| c.is_ascii_uppercase()) {
+                        word.to_case(Case::Snake)
+                    } else {
+                        word.to_owned()
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// The signature of a generic function called with the given type arguments, named after the
+/// suffix of their words, e.g. `parse_i32` for `parse::<i32>`
+fn instantiate_signature(
+    sig: &Signature,
+    suffix: &str,
+    params: &[Ident],
+    type_args: &[Type],
+) -> Signature {
+    let mut instance_sig = sig.clone();
+    instance_sig.ident = Ident::new(&format!("{}_{}", sig.ident, suffix), sig.ident.span());
+    // Bounds are left to the Rust compiler, which checks them when the wrapper calls the function
+    instance_sig.generics = Generics::default();
+    let tokens = substitute_idents(
+        quote!(#instance_sig {}),
+        &params.iter().zip(type_args).collect::<HashMap<_, _>>(),
+    );
+    parse2::<ItemFn>(tokens)
+        .expect("a signature stays valid with types in place of its type parameters")
+        .sig
+}
+
+/// The tokens with the identifiers of the map replaced by the tokens of their types
+fn substitute_idents(
+    tokens: proc_macro2::TokenStream,
+    types: &HashMap<&Ident, &Type>,
+) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if types.contains_key(&ident) => {
+                let ty = types[&ident];
+                quote!(#ty)
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut substituted = proc_macro2::Group::new(
+                    group.delimiter(),
+                    substitute_idents(group.stream(), types),
+                );
+                substituted.set_span(group.span());
+                quote!(#substituted)
+            }
+            token => quote!(#token),
+        })
+        .collect()
+}
+
 fn extract_fns_from_file(file: &File) -> Vec<&ItemFn> {
     let mut src_fns = Vec::new();
 
     for item in file.items.iter() {
         if let Item::Fn(ref item_fn) = item {
-            if let Visibility::Public(_) = &item_fn.vis {
+            if matches!(item_fn.vis, Visibility::Public(_))
+                && !has_frb_flag(&item_fn.attrs, "skip")
+                && !has_frb_flag(&item_fn.attrs, "init")
+            {
                 src_fns.push(item_fn);
             }
         }
@@ -198,6 +1301,307 @@ fn extract_fns_from_file(file: &File) -> Vec<&ItemFn> {
     src_fns
 }
 
+/// The names of the functions of a file marked `#[frb(init)]` that `keep` accepts. Dart does not
+/// call them itself, so they take no argument and return nothing.
+fn extract_init_fns_from_file(file: &File, mut keep: impl FnMut(&str) -> bool) -> Vec<String> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(item_fn) if has_frb_flag(&item_fn.attrs, "init") => Some(item_fn),
+            _ => None,
+        })
+        .filter(|item_fn| keep(&item_fn.sig.ident.to_string()))
+        .map(|item_fn| {
+            let sig = &item_fn.sig;
+            if !matches!(item_fn.vis, Visibility::Public(_)) {
+                panic!(
+                    "Function `{}` is marked `#[frb(init)]`, but is not public, so the generated code cannot call it",
+                    sig.ident
+                );
+            }
+            if sig.asyncness.is_some()
+                || !sig.inputs.is_empty()
+                || !matches!(sig.output, ReturnType::Default)
+            {
+                panic!(
+                    "Function `{}` is marked `#[frb(init)]`, but only functions taking no argument and returning nothing can be run at initialization",
+                    sig.ident
+                );
+            }
+            sig.ident.to_string()
+        })
+        .collect()
+}
+
+/// The public constants and statics of a file that `keep` accepts, but for mutable statics, which
+/// cannot be read safely. Those of types Dart cannot get are skipped with a warning.
+fn extract_consts_from_file(file: &File, mut keep: impl FnMut(&str) -> bool) -> Vec<IrConst> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Const(ItemConst {
+                attrs,
+                vis: Visibility::Public(_),
+                ident,
+                ty,
+                expr,
+                ..
+            })
+            | Item::Static(ItemStatic {
+                attrs,
+                vis: Visibility::Public(_),
+                mutability: None,
+                ident,
+                ty,
+                expr,
+                ..
+            }) if !has_frb_flag(attrs, "skip") && keep(&ident.to_string()) => {
+                parse_const(ident, ty, expr, attrs)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_const(ident: &Ident, ty: &Type, expr: &Expr, attrs: &[Attribute]) -> Option<IrConst> {
+    let name = ident.to_string();
+    let ty = match const_type(ty) {
+        Some(ty) => ty,
+        None => {
+            warn!(
+                "Constant `{}` is skipped, since its type `{}` is not a number, a boolean, a `char` or a string (mark it `#[frb(skip)]` to silence this warning)",
+                name,
+                type_to_string(ty)
+            );
+            return None;
+        }
+    };
+    let value = rust_literal_value(expr).and_then(|value| dart_literal(&ty, &value));
+    Some(IrConst {
+        dart_name: extract_dart_name(attrs).unwrap_or_else(|| name.to_case(Case::Camel)),
+        name,
+        ty,
+        value,
+        comments: extract_comments(attrs),
+        deprecation: extract_deprecation(attrs),
+    })
+}
+
+/// The reference `T` and the error type `E`, if any, of an output type `Result<&T, E>`
+fn result_of_reference(ty: &Type) -> Option<(&TypeReference, Option<&Type>)> {
+    match result_types(ty)? {
+        (Type::Reference(reference), error) => Some((reference, error)),
+        _ => None,
+    }
+}
+
+/// The `impl Iterator<Item = T>` and the error type `E`, if any, of an output type
+/// `Result<impl Iterator<Item = T>, E>`
+fn result_of_iterator(ty: &Type) -> Option<(&Type, Option<&Type>)> {
+    let (ok, error) = result_types(ty)?;
+    iterator_item(ok).map(|_| (ok, error))
+}
+
+/// The owned type of an output borrowed from the receiver of a method, `&T` or `Result<&T, E>`
+/// with a lifetime other than `'static`, as given by `ToOwned`: `String` for `&str`, `Vec<T>` for
+/// `&[T]`, `PathBuf` for `&Path` and `T` otherwise
+fn owned_borrowed_output_type(ty: &Type) -> Option<Type> {
+    if let Type::Reference(reference) = ty {
+        return owned_reference_type(reference);
+    }
+    match result_types(ty)? {
+        (Type::Reference(reference), error) => {
+            let ok = owned_reference_type(reference)?;
+            Some(match error {
+                Some(error) => parse_quote!(Result<#ok, #error>),
+                None => parse_quote!(Result<#ok>),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn owned_reference_type(reference: &TypeReference) -> Option<Type> {
+    let is_static = matches!(&reference.lifetime, Some(lifetime) if lifetime.ident == "static");
+    if is_static || reference.mutability.is_some() {
+        return None;
+    }
+    Some(match &*reference.elem {
+        Type::Path(TypePath { qself: None, path }) if path.is_ident("str") => parse_quote!(String),
+        Type::Path(TypePath { qself: None, path }) if is_std_path(path) => parse_quote!(PathBuf),
+        Type::Slice(TypeSlice { elem, .. }) => parse_quote!(Vec<#elem>),
+        elem => elem.clone(),
+    })
+}
+
+/// The `T` of `impl Iterator<Item = T>`, whatever the other bounds
+fn iterator_item(ty: &Type) -> Option<&Type> {
+    let bounds = match ty {
+        Type::ImplTrait(TypeImplTrait { bounds, .. }) => bounds,
+        _ => return None,
+    };
+    bounds.iter().find_map(|bound| {
+        let segment = match bound {
+            TypeParamBound::Trait(TraitBound { path, .. }) => path.segments.last()?,
+            _ => return None,
+        };
+        if segment.ident != "Iterator" {
+            return None;
+        }
+        match &segment.arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                args.iter().find_map(|arg| match arg {
+                    GenericArgument::Binding(Binding { ident, ty, .. }) if ident == "Item" => {
+                        Some(ty)
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        }
+    })
+}
+
+/// The `T` and the `E`, if any, of an output type `Result<T, E>`
+fn result_types(ty: &Type) -> Option<(&Type, Option<&Type>)> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != RESULT_IDENT {
+        return None;
+    }
+    let mut types = match &segment.arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+            args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+        }
+        _ => return None,
+    };
+    Some((types.next()?, types.next()))
+}
+
+/// `T` if `segment` is `Option<&T>`
+fn optional_reference_elem(segment: &PathSegment) -> Option<&Type> {
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. })
+            if args.len() == 1 =>
+        {
+            match args.first() {
+                Some(GenericArgument::Type(Type::Reference(TypeReference {
+                    mutability: None,
+                    elem,
+                    ..
+                }))) => Some(elem),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The type of a constant, among those whose values can be read from Dart
+fn const_type(ty: &Type) -> Option<IrType> {
+    match ty {
+        Type::Reference(TypeReference {
+            mutability: None,
+            elem,
+            ..
+        }) if matches!(&**elem, Type::Path(TypePath { path, .. }) if path.is_ident("str")) => {
+            Some(IrType::Delegate(IrTypeDelegate::String))
+        }
+        Type::Path(TypePath { qself: None, path }) => {
+            let ident = path.segments.last()?.ident.to_string();
+            match ident.as_str() {
+                "String" => Some(IrType::Delegate(IrTypeDelegate::String)),
+                "char" => Some(IrType::Delegate(IrTypeDelegate::Char)),
+                _ => IrTypePrimitive::try_from_rust_str(&ident)
+                    .filter(|prim| !matches!(prim, IrTypePrimitive::Unit))
+                    .map(IrType::Primitive),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The value of a literal expression, possibly negated, as expected by [dart_literal]
+fn rust_literal_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Int(int) => Some(int.base10_digits().to_owned()),
+            Lit::Float(float) => Some(float.base10_digits().to_owned()),
+            Lit::Bool(bool) => Some(bool.value.to_string()),
+            Lit::Str(str) => Some(str.value()),
+            Lit::Char(char) => Some(char.value().to_string()),
+            _ => None,
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            }) => rust_literal_value(expr).map(|value| format!("-{}", value)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Panics when two constants are named the same in Dart, or when a getter is named like a function
+/// of the API class it belongs to as well
+fn check_dart_const_names(consts: &[IrConst], funcs: &[IrFunc]) {
+    let mut names = HashMap::new();
+    for constant in consts {
+        if let Some(other) = names.insert(&constant.dart_name, &constant.name) {
+            panic!(
+                "Constants `{}` and `{}` are both named `{}` in Dart",
+                other, constant.name, constant.dart_name
+            );
+        }
+        if constant.value.is_some() {
+            continue;
+        }
+        if let Some(func) = funcs
+            .iter()
+            .find(|func| func.method.is_none() && func.dart_func_name() == constant.dart_name)
+        {
+            panic!(
+                "Constant `{}` and function `{}` are both named `{}` in Dart",
+                constant.name, func.name, constant.dart_name
+            );
+        }
+    }
+}
+
+/// Whether `path` names `std::path::Path`, as `Path` or by its full path
+fn is_std_path(path: &syn::Path) -> bool {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    segments == ["Path"] || segments == ["std", "path", "Path"]
+}
+
+fn extract_impls_from_file(file: &File) -> Vec<&ItemImpl> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            // Trait implementations are not part of the API
+            Item::Impl(item_impl) if item_impl.trait_.is_none() => Some(item_impl),
+            _ => None,
+        })
+        .collect()
+}
+
 fn extract_comments(attrs: &[Attribute]) -> Vec<IrComment> {
     attrs
         .iter()
@@ -212,7 +1616,298 @@ fn extract_comments(attrs: &[Attribute]) -> Vec<IrComment> {
         .collect()
 }
 
+/// Reads `#[deprecated]`, `#[deprecated = "note"]` and `#[deprecated(note = "note", ..)]`.
+fn extract_deprecation(attrs: &[Attribute]) -> Option<IrDeprecation> {
+    attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("deprecated"))
+        .map(|attr| {
+            let note = match attr.parse_meta() {
+                Ok(Meta::NameValue(MetaNameValue {
+                    lit: Lit::Str(lit), ..
+                })) => Some(lit.value()),
+                Ok(Meta::List(MetaList { nested, .. })) => {
+                    nested.iter().find_map(|meta| match meta {
+                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(lit),
+                            ..
+                        })) if path.is_ident("note") => Some(lit.value()),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            };
+            IrDeprecation { note }
+        })
+}
+
+/// The items of all the `#[frb(..)]` attributes.
+fn frb_metas(attrs: &[Attribute]) -> impl Iterator<Item = NestedMeta> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "frb")
+        })
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.into_iter().collect(),
+            _ => vec![],
+        })
+}
+
+/// Whether the attributes contain `#[frb(flag)]`, `flag` being a bare identifier.
+fn has_frb_flag(attrs: &[Attribute], flag: &str) -> bool {
+    frb_metas(attrs)
+        .any(|meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(flag)))
+}
+
+/// Whether the attributes contain `#[repr(C)]`, possibly among other representation hints.
+fn has_repr_c(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .any(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.iter().any(
+                |meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C")),
+            ),
+            _ => false,
+        })
+}
+
+/// Whether the attributes contain `#[derive(name)]`, possibly among other derives.
+fn has_derive(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .any(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.iter().any(|meta| {
+                matches!(meta, NestedMeta::Meta(Meta::Path(path))
+                    if path.segments.last().is_some_and(|segment| segment.ident == name))
+            }),
+            _ => false,
+        })
+}
+
+/// The lock of a function marked `#[frb(lock = "key")]` or `#[frb(read_lock = "key")]`
+fn extract_lock(func_name: &str, attrs: &[Attribute]) -> Option<IrFuncLock> {
+    let (key, shared) = match (frb_value(attrs, "lock"), frb_value(attrs, "read_lock")) {
+        (None, None) => return None,
+        (Some(key), None) => (key, false),
+        (None, Some(key)) => (key, true),
+        (Some(_), Some(_)) => panic!(
+            "Function `{}` is marked both `#[frb(lock = \"..\")]` and `#[frb(read_lock = \"..\")]`, while it can only take one lock",
+            func_name
+        ),
+    };
+    let is_snake_case = key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !is_snake_case {
+        panic!(
+            "The lock `{}` of function `{}` must be named by a snake_case identifier, such as `app_state`",
+            key, func_name
+        );
+    }
+    Some(IrFuncLock { key, shared })
+}
+
+/// The value of `#[frb(key = "value")]` in the attributes, if any.
+fn frb_value(attrs: &[Attribute], key: &str) -> Option<String> {
+    frb_values(attrs, key).next()
+}
+
+/// The values of all the `#[frb(key = "value")]` in the attributes, for keys that can be repeated.
+fn frb_values<'a>(attrs: &'a [Attribute], key: &'a str) -> impl Iterator<Item = String> + 'a {
+    frb_metas(attrs).filter_map(move |meta| match meta {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Str(lit),
+            ..
+        })) if path.is_ident(key) => Some(lit.value()),
+        _ => None,
+    })
+}
+
+/// The metadata given to a Dart class by `#[frb(dart_metadata = "..")]`, each being either an
+/// annotation such as `@immutable` or an `extends`, `with` or `implements` clause.
+fn extract_dart_metadata(attrs: &[Attribute]) -> Vec<String> {
+    let metadata = frb_values(attrs, "dart_metadata")
+        .map(|value| value.trim().to_owned())
+        .collect::<Vec<_>>();
+    for value in &metadata {
+        match dart_clause_keyword(value) {
+            Some(keyword)
+                if metadata
+                    .iter()
+                    .filter(|other| dart_clause_keyword(other) == Some(keyword))
+                    .count()
+                    > 1 =>
+            {
+                panic!(
+                    "Only one `{}` clause can be given by `#[frb(dart_metadata = \"..\")]`, list the types in it instead",
+                    keyword
+                );
+            }
+            None if !value.starts_with('@') => panic!(
+                "`#[frb(dart_metadata = \"{}\")]` is neither an annotation nor an `extends`, `with` or `implements` clause",
+                value
+            ),
+            _ => {}
+        }
+    }
+    metadata
+}
+
+/// The URIs of the Dart libraries given by `#[frb(dart_import = "..")]`, such as
+/// `package:my_app/model.dart`.
+fn extract_dart_imports(attrs: &[Attribute]) -> Vec<String> {
+    frb_values(attrs, "dart_import")
+        .map(|uri| {
+            let uri = uri.trim().to_owned();
+            if uri.is_empty() || uri.contains(['\'', '"']) {
+                panic!("`#[frb(dart_import = \"{}\")]` is not a Dart URI", uri);
+            }
+            uri
+        })
+        .collect()
+}
+
+/// The types given by `#[frb(implementors = "A, B")]` on a trait, which are all the types its
+/// trait objects can be when crossing the bridge.
+fn extract_implementors(attrs: &[Attribute]) -> Option<Vec<Type>> {
+    let value = frb_value(attrs, "implementors")?;
+    let implementors = Punctuated::<Type, Token![,]>::parse_terminated
+        .parse_str(&value)
+        .unwrap_or_else(|err| {
+            panic!(
+                "`#[frb(implementors = \"{}\")]` is not a list of types: {}",
+                value, err
+            )
+        });
+    Some(implementors.into_iter().collect())
+}
+
+/// The name given to an item by `#[frb(name = "dartName")]`, which replaces the one derived from
+/// the Rust name in Dart only.
+fn extract_dart_name(attrs: &[Attribute]) -> Option<String> {
+    let name = frb_value(attrs, "name")?;
+    // A leading `_` would make the item private to the file it is generated in
+    let is_public_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if !is_public_identifier {
+        panic!(
+            "`#[frb(name = \"{}\")]` is not a public Dart identifier",
+            name
+        );
+    }
+    Some(name)
+}
+
+/// The Dart literal of `value`, given to the argument `arg` of type `ty` by
+/// `#[frb(default = "..")]`. Strings are given without quotes.
+fn dart_default_literal(ty: &IrType, value: &str, arg: &str) -> String {
+    if matches!(ty, IrType::Delegate(IrTypeDelegate::BigInt64(_))) {
+        panic!(
+            "`{}: {}` cannot have a default value with `--dart-int64-type big-int`, since a `BigInt` cannot be a Dart `const`",
+            arg,
+            ty.rust_api_type()
+        );
+    }
+    if !matches!(
+        ty,
+        IrType::Primitive(_) | IrType::Delegate(IrTypeDelegate::String | IrTypeDelegate::Str)
+    ) {
+        panic!(
+            "Only numbers, booleans and strings can have a default value, not `{}: {}`",
+            arg,
+            ty.rust_api_type()
+        );
+    }
+    dart_literal(ty, value).unwrap_or_else(|| {
+        panic!(
+            "`{}` is not a valid default value for argument `{}` of type `{}`",
+            value,
+            arg,
+            ty.rust_api_type()
+        )
+    })
+}
+
+/// The Dart literal of `value` if it is a valid value of `ty`, given as in Rust but without the
+/// quotes of strings and `char`s.
+fn dart_literal(ty: &IrType, value: &str) -> Option<String> {
+    match ty {
+        IrType::Primitive(IrTypePrimitive::Bool) => {
+            value.parse::<bool>().ok().map(|_| value.to_owned())
+        }
+        // Dart only has 64-bit doubles, so they must also fit the 32 bits Rust gets
+        IrType::Primitive(IrTypePrimitive::F32) => value
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(|value| format!("{:?}", value)),
+        IrType::Primitive(IrTypePrimitive::F64) => value
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(|value| format!("{:?}", value)),
+        IrType::Primitive(IrTypePrimitive::Unit) => None,
+        IrType::Primitive(prim) => value
+            .parse::<i128>()
+            .ok()
+            .filter(|int| int_range(prim).contains(int))
+            .map(|int| int.to_string()),
+        IrType::Delegate(IrTypeDelegate::String | IrTypeDelegate::Str) => Some(format!(
+            "'{}'",
+            value
+                .replace('\\', "\\\\")
+                .replace('\'', "\\'")
+                .replace('$', "\\$")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+        )),
+        // Code points, as for the values crossing the bridge
+        IrType::Delegate(IrTypeDelegate::Char) => {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some((c as u32).to_string()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The values of an integer type that Dart can represent, since its integers are `i64`s
+fn int_range(prim: &IrTypePrimitive) -> std::ops::RangeInclusive<i128> {
+    match prim {
+        IrTypePrimitive::U8 => u8::MIN.into()..=u8::MAX.into(),
+        IrTypePrimitive::I8 => i8::MIN.into()..=i8::MAX.into(),
+        IrTypePrimitive::U16 => u16::MIN.into()..=u16::MAX.into(),
+        IrTypePrimitive::I16 => i16::MIN.into()..=i16::MAX.into(),
+        IrTypePrimitive::U32 => u32::MIN.into()..=u32::MAX.into(),
+        IrTypePrimitive::I32 => i32::MIN.into()..=i32::MAX.into(),
+        IrTypePrimitive::U64 => 0..=i64::MAX.into(),
+        _ => i64::MIN.into()..=i64::MAX.into(),
+    }
+}
+
 /// syn -> string https://github.com/dtolnay/syn/issues/294
 fn type_to_string(ty: &Type) -> String {
-    quote!(#ty).to_string().replace(' ', "")
+    // Spaces are only kept between words, as in `&mut T`
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut string = String::new();
+    for token in quote!(#ty).to_string().split(' ') {
+        if string.ends_with(is_word_char) && token.starts_with(is_word_char) {
+            string.push(' ');
+        }
+        string.push_str(token);
+    }
+    string
 }
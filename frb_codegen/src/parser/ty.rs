@@ -1,78 +1,265 @@
 use std::collections::{HashMap, HashSet};
 use std::string::String;
 
+use convert_case::{Case, Casing};
+use log::warn;
 use syn::*;
 
+use crate::config::{DartInt64Type, DartUuidType, NonUtf8Paths, NonZeroInts};
 use crate::ir::IrType::*;
 use crate::ir::*;
 
-use crate::source_graph::{Enum, Struct};
+use crate::source_graph::{Crate, DependencyLib, Enum, Struct, Trait};
 
-use crate::parser::{extract_comments, type_to_string};
+use crate::parser::{
+    extract_comments, extract_dart_imports, extract_dart_metadata, extract_dart_name,
+    extract_deprecation, extract_implementors, frb_value, has_derive, has_frb_flag, has_repr_c,
+    rust_literal_value, type_to_string,
+};
 
 pub struct TypeParser<'a> {
+    crate_structs: HashMap<String, &'a Struct>,
+    crate_enums: HashMap<String, &'a Enum>,
+    /// The types of the crate, by the name they are known under in the input file being parsed
     src_structs: HashMap<String, &'a Struct>,
     src_enums: HashMap<String, &'a Enum>,
+    /// The traits of the crate, by name
+    src_traits: HashMap<String, &'a Trait>,
+    /// The paths of the types parsed so far, since each of their names must stand for one type
+    type_paths: HashMap<String, Vec<String>>,
+    /// The Rust names of the types parsed so far, by the name of their Dart class
+    dart_type_names: HashMap<String, String>,
+    dart_uuid_type: DartUuidType,
+    dart_int64_type: DartInt64Type,
+    non_utf8_paths: NonUtf8Paths,
+    non_zero_ints: NonZeroInts,
+    /// The structs which are opaque without being marked so, since they are shared through an
+    /// `Arc` with `--arc-structs handle`
+    arc_handle_structs: HashSet<String>,
+    /// The last name that was neither a supported type nor a type of the crate
+    unresolved_type: Option<String>,
+    /// The type arguments of the instantiation of a generic struct whose fields are being parsed,
+    /// by the name of their parameter
+    type_args: HashMap<String, IrType>,
 
     parsing_or_parsed_struct_names: HashSet<String>,
     struct_pool: IrStructPool,
 
     parsed_enums: HashSet<String>,
     enum_pool: IrEnumPool,
+
+    /// The libraries the crate depends on, whose source tells the fields of mirrored types
+    dependency_libs: HashMap<String, DependencyLib>,
+    dependency_crates: HashMap<String, Crate>,
 }
 
 impl<'a> TypeParser<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         src_structs: HashMap<String, &'a Struct>,
         src_enums: HashMap<String, &'a Enum>,
+        src_traits: HashMap<String, &'a Trait>,
+        dependency_libs: HashMap<String, DependencyLib>,
+        dart_uuid_type: DartUuidType,
+        dart_int64_type: DartInt64Type,
+        non_utf8_paths: NonUtf8Paths,
+        non_zero_ints: NonZeroInts,
+        arc_handle_structs: HashSet<String>,
     ) -> Self {
         TypeParser {
+            crate_structs: src_structs.clone(),
+            crate_enums: src_enums.clone(),
             src_structs,
             src_enums,
+            src_traits,
+            type_paths: HashMap::new(),
+            dart_type_names: HashMap::new(),
+            dart_uuid_type,
+            dart_int64_type,
+            non_utf8_paths,
+            non_zero_ints,
+            arc_handle_structs,
+            unresolved_type: None,
+            type_args: HashMap::new(),
             struct_pool: HashMap::new(),
             enum_pool: HashMap::new(),
             parsing_or_parsed_struct_names: HashSet::new(),
             parsed_enums: HashSet::new(),
+            dependency_libs,
+            dependency_crates: HashMap::new(),
+        }
+    }
+
+    /// The type of a `&Path`, which is only parsed as a reference, for `Path` not to stand for the
+    /// types of the crate with that name
+    pub fn borrowed_path_type(&self) -> IrType {
+        IrType::Delegate(IrTypeDelegate::Path(self.non_utf8_paths))
+    }
+
+    /// The type of an integer of 64 bits is chosen by `--dart-int64-type`
+    fn convert_primitive_to_ir_type(&self, primitive: IrTypePrimitive) -> IrType {
+        match (primitive, self.dart_int64_type) {
+            (primitive @ (IrTypePrimitive::I64 | IrTypePrimitive::U64), DartInt64Type::BigInt) => {
+                Delegate(IrTypeDelegate::BigInt64(primitive))
+            }
+            (primitive, _) => Primitive(primitive),
         }
     }
 
     pub fn consume(self) -> (IrStructPool, IrEnumPool) {
         (self.struct_pool, self.enum_pool)
     }
+
+    /// Resolves the names of the types as in the input file about to be parsed, given the types
+    /// it declares or imports
+    pub fn enter_file(
+        &mut self,
+        visible_structs: HashMap<String, &'a Struct>,
+        visible_enums: HashMap<String, &'a Enum>,
+    ) {
+        self.src_structs = self.crate_structs.clone();
+        self.src_structs.extend(visible_structs);
+        self.src_enums = self.crate_enums.clone();
+        self.src_enums.extend(visible_enums);
+    }
+
+    /// Panics when the name of a type already stood for another type, since the Dart classes and
+    /// the wire types are named after the Rust type
+    fn check_type_path(&mut self, name: &str, path: &[String]) {
+        match self.type_paths.get(name) {
+            Some(other) if other != path => panic!(
+                "`{}` stands for both `{}` and `{}`, types crossing the bridge must have distinct names",
+                name,
+                other.join("::"),
+                path.join("::")
+            ),
+            Some(_) => {}
+            None => {
+                self.type_paths.insert(name.to_owned(), path.to_vec());
+            }
+        }
+    }
+
+    /// Panics when the Dart class of a type was already given to another type, which can happen
+    /// when renaming them
+    pub fn check_dart_type_name(&mut self, name: &str, dart_name: &str) {
+        match self.dart_type_names.get(dart_name) {
+            Some(other) if other != name => panic!(
+                "`{}` and `{}` are both named `{}` in Dart",
+                other, name, dart_name
+            ),
+            Some(_) => {}
+            None => {
+                self.dart_type_names
+                    .insert(dart_name.to_owned(), name.to_owned());
+            }
+        }
+    }
+
+    /// Whether `ty` names a struct or an enum of the crate marked `#[frb(skip)]`
+    pub fn is_skipped_type(&self, ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Path(syn::TypePath { path, .. }) => path
+                .segments
+                .last()
+                .is_some_and(|segment| self.is_skipped_type_name(&segment.ident.to_string())),
+            _ => false,
+        }
+    }
+
+    fn is_skipped_type_name(&self, name: &str) -> bool {
+        let struct_attrs = self.src_structs.get(name).map(|src| &src.src.attrs);
+        let enum_attrs = self.src_enums.get(name).map(|src| &src.src.attrs);
+        struct_attrs
+            .or(enum_attrs)
+            .is_some_and(|attrs| has_frb_flag(attrs, "skip"))
+    }
+
+    pub fn take_unresolved_type(&mut self) -> Option<String> {
+        self.unresolved_type.take()
+    }
+
+    /// The message of the error for a type that failed to parse, naming the type that could not be
+    /// found in the crate if any
+    pub fn unsupported_type_message(&mut self, kind: &str, ty: &syn::Type) -> String {
+        match self.take_unresolved_type() {
+            Some(name) => format!(
+                "Failed to parse {} `{}`: `{}` is neither a supported type nor a struct or an enum of the crate, whose modules must all be declared with `mod`",
+                kind,
+                type_to_string(ty),
+                name
+            ),
+            None => format!("Failed to parse {} `{}`", kind, type_to_string(ty)),
+        }
+    }
 }
 
 /// Generic intermediate representation of a type that can appear inside a function signature.
 pub enum SupportedInnerType {
-    /// Path types with up to 1 generic type argument on the final segment. All segments before
-    /// the last segment are ignored. The generic type argument must also be a valid
-    /// `SupportedInnerType`.
+    /// Path types with any number of generic type arguments on the final segment. All segments
+    /// before the last segment are ignored. The generic type arguments must also be valid
+    /// `SupportedInnerType`s.
     Path(SupportedPathType),
+    /// Fixed-size arrays `[T; N]`, with `N` an integer literal.
+    Array(Box<SupportedInnerType>, usize),
+    /// Tuples with at least one element.
+    Tuple(Vec<SupportedInnerType>),
     /// The unit type `()`.
     Unit,
+    /// Trait objects `dyn Trait`, whose lifetimes are ignored.
+    DynTrait(syn::Ident),
+    /// Slices `[T]`.
+    Slice(Box<SupportedInnerType>),
 }
 
 impl std::fmt::Display for SupportedInnerType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::Path(p) => write!(f, "{}", p),
+            Self::Array(inner, length) => write!(f, "[{}; {}]", inner, length),
+            Self::Tuple(elems) if elems.len() == 1 => write!(f, "({},)", elems[0]),
+            Self::Tuple(elems) => write!(
+                f,
+                "({})",
+                elems
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             Self::Unit => write!(f, "()"),
+            Self::DynTrait(ident) => write!(f, "dyn {}", ident),
+            Self::Slice(elem) => write!(f, "[{}]", elem),
         }
     }
 }
 
-/// Represents a named type, with an optional path and up to 1 generic type argument.
+/// Represents a named type, with an optional path and its generic type arguments.
 pub struct SupportedPathType {
     pub ident: syn::Ident,
-    pub generic: Option<Box<SupportedInnerType>>,
+    pub generics: Vec<SupportedInnerType>,
+}
+
+impl SupportedPathType {
+    /// Whether this is the plain type `ident`, without any generic argument.
+    fn is_plain(&self, ident: &str) -> bool {
+        self.ident == ident && self.generics.is_empty()
+    }
 }
 
 impl std::fmt::Display for SupportedPathType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let ident = self.ident.to_string();
-        if let Some(generic) = &self.generic {
-            write!(f, "{}<{}>", ident, generic)
-        } else {
+        if self.generics.is_empty() {
             write!(f, "{}", ident)
+        } else {
+            let generics = self
+                .generics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "{}<{}>", ident, generics)
         }
     }
 }
@@ -87,27 +274,67 @@ impl SupportedInnerType {
                 match last_segment.arguments {
                     syn::PathArguments::None => Some(SupportedInnerType::Path(SupportedPathType {
                         ident: last_segment.ident,
-                        generic: None,
+                        generics: vec![],
                     })),
                     syn::PathArguments::AngleBracketed(a) => {
-                        let generic = match a.args.into_iter().next() {
-                            Some(syn::GenericArgument::Type(t)) => {
-                                Some(Box::new(SupportedInnerType::try_from_syn_type(&t)?))
-                            }
-                            _ => None,
-                        };
+                        // Lifetimes and const arguments carry no type information, skip them
+                        let generics = a
+                            .args
+                            .into_iter()
+                            .filter_map(|arg| match arg {
+                                syn::GenericArgument::Type(t) => Some(t),
+                                _ => None,
+                            })
+                            .map(|t| SupportedInnerType::try_from_syn_type(&t))
+                            .collect::<Option<Vec<_>>>()?;
 
                         Some(SupportedInnerType::Path(SupportedPathType {
                             ident: last_segment.ident,
-                            generic,
+                            generics,
                         }))
                     }
                     _ => None,
                 }
             }
+            syn::Type::Array(syn::TypeArray {
+                elem,
+                len:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(length),
+                        ..
+                    }),
+                ..
+            }) => Some(SupportedInnerType::Array(
+                Box::new(SupportedInnerType::try_from_syn_type(elem)?),
+                length.base10_parse().ok()?,
+            )),
+            syn::Type::Slice(syn::TypeSlice { elem, .. }) => Some(SupportedInnerType::Slice(
+                Box::new(SupportedInnerType::try_from_syn_type(elem)?),
+            )),
             syn::Type::Tuple(syn::TypeTuple { elems, .. }) if elems.is_empty() => {
                 Some(SupportedInnerType::Unit)
             }
+            syn::Type::Tuple(syn::TypeTuple { elems, .. }) => Some(SupportedInnerType::Tuple(
+                elems
+                    .iter()
+                    .map(SupportedInnerType::try_from_syn_type)
+                    .collect::<Option<Vec<_>>>()?,
+            )),
+            syn::Type::TraitObject(syn::TypeTraitObject { bounds, .. }) => {
+                let traits = bounds
+                    .iter()
+                    .filter_map(|bound| match bound {
+                        syn::TypeParamBound::Trait(bound) => Some(bound),
+                        syn::TypeParamBound::Lifetime(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+                match traits.as_slice() {
+                    [bound] => Some(SupportedInnerType::DynTrait(
+                        bound.path.segments.last()?.ident.clone(),
+                    )),
+                    _ => None,
+                }
+            }
             _ => None,
         }
     }
@@ -118,90 +345,207 @@ impl<'a> TypeParser<'a> {
         let supported_type = SupportedInnerType::try_from_syn_type(ty)
             .unwrap_or_else(|| panic!("Unsupported type `{}`", type_to_string(ty)));
 
-        self.convert_to_ir_type(supported_type)
-            .unwrap_or_else(|| panic!("parse_type failed for ty={}", type_to_string(ty)))
+        self.unresolved_type = None;
+        match self.convert_to_ir_type(supported_type) {
+            Some(ir_type) => ir_type,
+            None => panic!("{}", self.unsupported_type_message("type", ty)),
+        }
     }
 
     /// Converts an inner type into an `IrType` if possible.
     pub fn convert_to_ir_type(&mut self, ty: SupportedInnerType) -> Option<IrType> {
         match ty {
             SupportedInnerType::Path(p) => self.convert_path_to_ir_type(p),
+            SupportedInnerType::Array(inner, length) => {
+                self.convert_to_ir_type(*inner).map(|inner| {
+                    Array(IrTypeArray {
+                        inner: Box::new(inner),
+                        length,
+                    })
+                })
+            }
+            SupportedInnerType::Tuple(elems) => elems
+                .into_iter()
+                .map(|elem| self.convert_to_ir_type(elem))
+                .collect::<Option<Vec<_>>>()
+                .map(|fields| StructRef(IrTypeStructRef::for_tuple(fields))),
             SupportedInnerType::Unit => Some(IrType::Primitive(IrTypePrimitive::Unit)),
+            // Trait objects are only supported behind a `Box`, and slices behind a `Cow`
+            SupportedInnerType::DynTrait(_) | SupportedInnerType::Slice(_) => None,
         }
     }
 
     /// Converts a path type into an `IrType` if possible.
     pub fn convert_path_to_ir_type(&mut self, p: SupportedPathType) -> Option<IrType> {
-        let p_as_str = format!("{}", &p);
-        let ident_string = &p.ident.to_string();
-        if let Some(generic) = p.generic {
+        let SupportedPathType {
+            ident,
+            mut generics,
+        } = p;
+        let ident_string = &ident.to_string();
+        if generics.is_empty() {
+            if let Some(ty) = self.type_args.get(ident_string) {
+                return Some(ty.clone());
+            }
+        } else if let Some(src_struct) = self.src_structs.get(ident_string).copied() {
+            return self.convert_generic_struct_to_ir_type(
+                SupportedPathType { ident, generics },
+                src_struct,
+            );
+        }
+        if generics.len() == 2 {
+            let value = generics.pop().unwrap();
+            let key = generics.pop().unwrap();
+            let kind = match ident_string.as_str() {
+                "HashMap" => IrTypeMapKind::HashMap,
+                "BTreeMap" => IrTypeMapKind::BTreeMap,
+                _ => return None,
+            };
+            Some(Map(IrTypeMap {
+                kind,
+                key: Box::new(self.convert_to_ir_type(key)?),
+                value: Box::new(self.convert_to_ir_type(value)?),
+            }))
+        } else if let Some(generic) = generics.pop() {
+            if !generics.is_empty() {
+                return None;
+            }
             match ident_string.as_str() {
                 "SyncReturn" => {
                     // Special-case SyncReturn<Vec<u8>>. SyncReturn for any other type is not
                     // supported.
-                    match *generic {
-                        SupportedInnerType::Path(SupportedPathType {
-                            ident,
-                            generic: Some(generic),
-                        }) if ident == "Vec" => match *generic {
-                            SupportedInnerType::Path(SupportedPathType {
-                                ident,
-                                generic: None,
-                            }) if ident == "u8" => {
-                                Some(IrType::Delegate(IrTypeDelegate::SyncReturnVecU8))
+                    match generic {
+                        SupportedInnerType::Path(SupportedPathType { ident, generics })
+                            if ident == "Vec" =>
+                        {
+                            match generics.as_slice() {
+                                [SupportedInnerType::Path(inner)] if inner.is_plain("u8") => {
+                                    Some(IrType::Delegate(IrTypeDelegate::SyncReturnVecU8))
+                                }
+                                _ => None,
                             }
-                            _ => None,
-                        },
+                        }
                         _ => None,
                     }
                 }
-                "Vec" => {
-                    // Special-case Vec<String> as StringList
-                    if matches!(*generic, SupportedInnerType::Path(SupportedPathType { ref ident, .. }) if ident == "String")
-                    {
-                        Some(IrType::Delegate(IrTypeDelegate::StringList))
-                    } else {
-                        self.convert_to_ir_type(*generic).map(|inner| match inner {
-                            Primitive(primitive) => {
-                                PrimitiveList(IrTypePrimitiveList { primitive })
-                            }
-                            others => GeneralList(IrTypeGeneralList {
-                                inner: Box::new(others),
-                            }),
-                        })
-                    }
-                }
+                "DateTime" => match generic {
+                    SupportedInnerType::Path(tz) if tz.is_plain("Utc") => Some(IrType::Delegate(
+                        IrTypeDelegate::Time(IrTypeDelegateTime::Utc),
+                    )),
+                    SupportedInnerType::Path(tz) if tz.is_plain("Local") => Some(IrType::Delegate(
+                        IrTypeDelegate::Time(IrTypeDelegateTime::Local),
+                    )),
+                    _ => None,
+                },
+                // Vec<String> and Vec<bool> are special-cased as StringList and BoolList
+                "Vec" => self.convert_to_ir_type(generic).map(IrType::list_of),
                 "ZeroCopyBuffer" => {
-                    let inner = self.convert_to_ir_type(*generic);
-                    if let Some(IrType::PrimitiveList(IrTypePrimitiveList { primitive })) = inner {
-                        Some(IrType::Delegate(
-                            IrTypeDelegate::ZeroCopyBufferVecPrimitive(primitive),
-                        ))
-                    } else {
-                        None
-                    }
+                    let primitive = match self.convert_to_ir_type(generic)? {
+                        IrType::PrimitiveList(IrTypePrimitiveList { primitive }) => primitive,
+                        // Still a typed list with `--dart-int64-type big-int`, since the buffer is
+                        // sent as is
+                        IrType::GeneralList(IrTypeGeneralList { inner }) => match *inner {
+                            Delegate(IrTypeDelegate::BigInt64(primitive)) => primitive,
+                            _ => return None,
+                        },
+                        _ => return None,
+                    };
+                    Some(IrType::Delegate(
+                        IrTypeDelegate::ZeroCopyBufferVecPrimitive(primitive),
+                    ))
                 }
-                "Box" => self.convert_to_ir_type(*generic).map(|inner| {
-                    Boxed(IrTypeBoxed {
+                "Box" => match generic {
+                    SupportedInnerType::DynTrait(ident) => {
+                        self.convert_trait_object_to_ir_type(ident)
+                    }
+                    SupportedInnerType::Slice(elem) => self
+                        .convert_to_ir_type(*elem)
+                        .map(|elem| Delegate(IrTypeDelegate::BoxedSlice(Box::new(elem)))),
+                    generic => self.convert_to_ir_type(generic).map(|inner| {
+                        Boxed(IrTypeBoxed {
+                            exist_in_real_api: true,
+                            pointer: IrTypeBoxedPointer::Box,
+                            inner: Box::new(inner),
+                        })
+                    }),
+                },
+                // Opaque objects live in an `Arc` already, which Dart then holds itself
+                "Arc" => self.convert_to_ir_type(generic).map(|inner| match inner {
+                    Opaque(opaque) => Opaque(IrTypeOpaque {
+                        shared: true,
+                        ..opaque
+                    }),
+                    inner => Boxed(IrTypeBoxed {
                         exist_in_real_api: true,
+                        pointer: IrTypeBoxedPointer::Arc,
                         inner: Box::new(inner),
-                    })
+                    }),
                 }),
-                "Option" => {
-                    // Disallow nested Option
-                    if matches!(*generic, SupportedInnerType::Path(SupportedPathType { ref ident, .. }) if ident == "Option")
-                    {
+                "Rc" => self.convert_to_ir_type(generic).map(|inner| {
+                    if let Opaque(opaque) = &inner {
                         panic!(
-                            "Nested optionals without indirection are not supported. (Option<Option<{}>>)",
-                            p_as_str
+                            "Opaque type `{}` lives in an `Arc`, so it cannot cross the bridge in an `Rc`",
+                            opaque.name
                         );
                     }
-                    self.convert_to_ir_type(*generic).map(|inner| match inner {
+                    Boxed(IrTypeBoxed {
+                        exist_in_real_api: true,
+                        pointer: IrTypeBoxedPointer::Rc,
+                        inner: Box::new(inner),
+                    })
+                }),
+                // Converted into the owned type, whether the value is borrowed or not
+                "Cow" => match generic {
+                    SupportedInnerType::Path(inner) if inner.is_plain("str") => {
+                        Some(Delegate(IrTypeDelegate::Cow(IrTypeDelegateCow::Str)))
+                    }
+                    SupportedInnerType::Slice(elem) => self.convert_to_ir_type(*elem).map(|elem| {
+                        Delegate(IrTypeDelegate::Cow(IrTypeDelegateCow::Slice(Box::new(
+                            elem,
+                        ))))
+                    }),
+                    _ => None,
+                },
+                "HashSet" | "BTreeSet" => {
+                    let kind = if ident_string == "HashSet" {
+                        IrTypeSetKind::HashSet
+                    } else {
+                        IrTypeSetKind::BTreeSet
+                    };
+                    self.convert_to_ir_type(generic).map(|inner| {
+                        Set(IrTypeSet {
+                            kind,
+                            inner: Box::new(inner),
+                        })
+                    })
+                }
+                "Option" => {
+                    // Nested optionals stay nested in Rust, but Dart only sees one level of nullability
+                    self.convert_to_ir_type(generic).map(|inner| match inner {
+                        // `()` has no value to send next to the presence flag
+                        Primitive(IrTypePrimitive::Unit) => {
+                            IrType::Optional(IrTypeOptional::new_boxed_prim(IrTypePrimitive::Unit))
+                        }
                         Primitive(prim) => IrType::Optional(IrTypeOptional::new_prim(prim)),
-                        st @ StructRef(_) => {
+                        // The `None` of the outer optional is a null pointer to the inner one
+                        IrType::Optional(opt) if opt.compact_primitive().is_some() => {
+                            let prim = opt.compact_primitive().unwrap().clone();
+                            IrType::Optional(IrTypeOptional::new_ptr(IrType::Optional(
+                                IrTypeOptional::new_boxed_prim(prim),
+                            )))
+                        }
+                        // Enums without data are sent as an `i32`, which needs a box to be null
+                        st @ (StructRef(_)
+                        | EnumRef(_)
+                        | Delegate(
+                            IrTypeDelegate::Time(_)
+                            | IrTypeDelegate::Char
+                            | IrTypeDelegate::BigInt64(_)
+                            | IrTypeDelegate::NonZero(..),
+                        )) => {
                             IrType::Optional(IrTypeOptional::new_ptr(Boxed(IrTypeBoxed {
                                 inner: Box::new(st),
                                 exist_in_real_api: false,
+                                pointer: IrTypeBoxedPointer::Box,
                             })))
                         }
                         other => IrType::Optional(IrTypeOptional::new_ptr(other)),
@@ -211,24 +555,79 @@ impl<'a> TypeParser<'a> {
             }
         } else {
             IrTypePrimitive::try_from_rust_str(ident_string)
-                .map(Primitive)
+                .map(|primitive| self.convert_primitive_to_ir_type(primitive))
+                .or_else(|| IrTypeInt128::try_from_rust_str(ident_string).map(Int128))
                 .or_else(|| {
                     if ident_string == "String" {
                         Some(IrType::Delegate(IrTypeDelegate::String))
-                    } else if self.src_structs.contains_key(ident_string) {
-                        if !self.parsing_or_parsed_struct_names.contains(ident_string) {
-                            self.parsing_or_parsed_struct_names
-                                .insert(ident_string.to_owned());
-                            let api_struct = self.parse_struct_core(&p.ident);
-                            self.struct_pool.insert(ident_string.to_owned(), api_struct);
+                    } else if ident_string == "NaiveDateTime" {
+                        Some(IrType::Delegate(IrTypeDelegate::Time(
+                            IrTypeDelegateTime::Naive,
+                        )))
+                    } else if ident_string == "Duration" {
+                        Some(IrType::Delegate(IrTypeDelegate::Time(
+                            IrTypeDelegateTime::Duration,
+                        )))
+                    } else if ident_string == "char" {
+                        Some(IrType::Delegate(IrTypeDelegate::Char))
+                    } else if ident_string == "Uuid" {
+                        Some(IrType::Delegate(IrTypeDelegate::Uuid(self.dart_uuid_type)))
+                    } else if ident_string == "PathBuf" {
+                        Some(IrType::Delegate(IrTypeDelegate::PathBuf(self.non_utf8_paths)))
+                    } else if let Some(primitive) = non_zero_primitive(ident_string) {
+                        Some(IrType::Delegate(IrTypeDelegate::NonZero(
+                            Box::new(self.convert_primitive_to_ir_type(primitive)),
+                            self.non_zero_ints,
+                        )))
+                    } else if self.is_skipped_type_name(ident_string) {
+                        panic!(
+                            "`{}` is marked `#[frb(skip)]`, so it cannot cross the bridge",
+                            ident_string
+                        );
+                    } else if let Some(src_struct) = self
+                        .src_structs
+                        .get(ident_string)
+                        .copied()
+                        .filter(|src_struct| {
+                            has_frb_flag(&src_struct.src.attrs, "opaque")
+                                || self.arc_handle_structs.contains(ident_string)
+                        })
+                    {
+                        self.check_type_path(ident_string, &src_struct.path);
+                        let dart_name = extract_dart_name(&src_struct.src.attrs)
+                            .unwrap_or_else(|| ident_string.to_owned());
+                        self.check_dart_type_name(ident_string, &dart_name);
+                        if !extract_dart_metadata(&src_struct.src.attrs).is_empty() {
+                            panic!(
+                                "`#[frb(dart_metadata = \"..\")]` is not supported on opaque types such as `{}`",
+                                ident_string
+                            );
                         }
-
-                        Some(StructRef(IrTypeStructRef {
+                        Some(Opaque(IrTypeOpaque {
                             name: ident_string.to_owned(),
+                            dart_name,
+                            path: src_struct.path.clone(),
+                            comments: extract_comments(&src_struct.src.attrs),
+                            has_finalizer: !has_frb_flag(&src_struct.src.attrs, "manual_dispose"),
+                            shared: false,
                         }))
-                    } else if self.src_enums.contains_key(ident_string) {
+                    } else if let Some((src_struct, foreign_rust_type)) =
+                        self.converted_struct(ident_string)
+                    {
+                        Some(self.convert_struct_to_ir_type(src_struct, Some(foreign_rust_type)))
+                    } else if let Some(src_struct) = self.src_structs.get(ident_string).copied() {
+                        if let Some((_, foreign_rust_type)) = foreign_type_attr(src_struct) {
+                            panic!(
+                                "`{}` stands for `{}`, so the API must use `{}` instead",
+                                ident_string, foreign_rust_type, foreign_rust_type
+                            );
+                        }
+                        Some(self.convert_struct_to_ir_type(src_struct, None))
+                    } else if let Some(src_enum) = self.src_enums.get(ident_string).copied() {
+                        self.check_type_path(ident_string, &src_enum.path);
+                        self.check_dart_type_name(ident_string, ident_string);
                         if self.parsed_enums.insert(ident_string.to_owned()) {
-                            let enu = self.parse_enum_core(&p.ident);
+                            let enu = self.parse_enum_core(&ident);
                             self.enum_pool.insert(ident_string.to_owned(), enu);
                         }
 
@@ -239,8 +638,13 @@ impl<'a> TypeParser<'a> {
                                 .get(ident_string)
                                 .map(IrEnum::is_struct)
                                 .unwrap_or(true),
+                            is_trait_object: false,
                         }))
+                    } else if ident_string == "Value" {
+                        // Looked up after the types of the crate, which keep the name
+                        Some(IrType::Delegate(IrTypeDelegate::Json))
                     } else {
+                        self.unresolved_type = Some(ident_string.to_owned());
                         None
                     }
                 })
@@ -249,11 +653,388 @@ impl<'a> TypeParser<'a> {
 }
 
 impl<'a> TypeParser<'a> {
+    /// Refers to the instantiation of a generic struct with the type arguments of `p`, which is a
+    /// struct of its own named after them, e.g. `ResponseUser` for `Response<User>`.
+    fn convert_generic_struct_to_ir_type(
+        &mut self,
+        p: SupportedPathType,
+        src_struct: &'a Struct,
+    ) -> Option<IrType> {
+        let ident_string = p.ident.to_string();
+        let attrs = &src_struct.src.attrs;
+        if has_frb_flag(attrs, "skip") {
+            panic!(
+                "`{}` is marked `#[frb(skip)]`, so it cannot cross the bridge",
+                ident_string
+            );
+        }
+        if has_frb_flag(attrs, "opaque") {
+            panic!("Generic opaque types such as `{}` are not supported", p);
+        }
+        let params = src_struct
+            .src
+            .generics
+            .type_params()
+            .map(|param| param.ident.to_string())
+            .collect::<Vec<_>>();
+        if params.len() != p.generics.len() {
+            panic!(
+                "`{}` has {} type parameters, but is used as `{}`",
+                ident_string,
+                params.len(),
+                p
+            );
+        }
+        let args = p
+            .generics
+            .into_iter()
+            .map(|arg| self.convert_to_ir_type(arg))
+            .collect::<Option<Vec<_>>>()?;
+
+        let suffix = args
+            .iter()
+            .map(|arg| arg.safe_ident().to_case(Case::UpperCamel))
+            .collect::<String>();
+        let name = format!("{}{}", ident_string, suffix);
+        let rust_type = format!(
+            "{}<{}>",
+            ident_string,
+            args.iter()
+                .map(IrType::rust_api_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        self.check_type_path(&ident_string, &src_struct.path);
+        // Instantiations must not be named like another type, or like each other
+        let mut path = src_struct.path.clone();
+        *path.last_mut().unwrap() = rust_type.clone();
+        self.check_type_path(&name, &path);
+        let dart_name = extract_dart_name(attrs).unwrap_or(ident_string) + &suffix;
+        self.check_dart_type_name(&rust_type, &dart_name);
+
+        let struct_ref = StructRef(IrTypeStructRef {
+            name: name.clone(),
+            dart_name,
+            tuple_fields: None,
+            tuple_field_names: None,
+            generic_rust_type: Some(rust_type),
+            foreign_rust_type: None,
+        });
+        if self.parsing_or_parsed_struct_names.insert(name.clone()) {
+            let type_args = params.into_iter().zip(args).collect();
+            let api_struct = IrStruct {
+                name: name.clone(),
+                ..self.parse_struct_core(&p.ident, type_args, struct_ref.clone())
+            };
+            self.struct_pool.insert(name, api_struct);
+        }
+        Some(struct_ref)
+    }
+
+    /// Refers to a struct of the crate, parsing it the first time, or to the type of another crate
+    /// it stands for.
+    fn convert_struct_to_ir_type(
+        &mut self,
+        src_struct: &'a Struct,
+        foreign_rust_type: Option<String>,
+    ) -> IrType {
+        let name = src_struct.ident.to_string();
+        self.check_type_path(&name, &src_struct.path);
+        let dart_name = extract_dart_name(&src_struct.src.attrs).unwrap_or_else(|| name.clone());
+        self.check_dart_type_name(&name, &dart_name);
+        let struct_ref = StructRef(IrTypeStructRef {
+            name: name.clone(),
+            dart_name,
+            tuple_fields: None,
+            tuple_field_names: None,
+            generic_rust_type: None,
+            foreign_rust_type: foreign_rust_type.clone(),
+        });
+        if !self.parsing_or_parsed_struct_names.contains(&name) {
+            self.parsing_or_parsed_struct_names.insert(name.clone());
+            if let (Some(foreign_rust_type), true) = (
+                &foreign_rust_type,
+                frb_value(&src_struct.src.attrs, "mirror").is_some(),
+            ) {
+                self.check_mirrored_fields(src_struct, foreign_rust_type);
+            }
+            let api_struct =
+                self.parse_struct_core(&src_struct.ident, HashMap::new(), struct_ref.clone());
+            self.struct_pool.insert(name, api_struct);
+        }
+        struct_ref
+    }
+
+    /// The struct marked `#[frb(convert = "path::Name")]` or `#[frb(mirror = "path::Name")]` for
+    /// the type of another crate called `name`, along with its path.
+    fn converted_struct(&self, name: &str) -> Option<(&'a Struct, String)> {
+        let mut converted = self
+            .crate_structs
+            .values()
+            .copied()
+            .filter_map(|src_struct| {
+                let (key, value) = foreign_type_attr(src_struct)?;
+                let path = syn::parse_str::<syn::Path>(&value).unwrap_or_else(|err| {
+                    panic!(
+                        "`#[frb({} = \"{}\")]` on `{}` is not a path: {}",
+                        key, value, src_struct.ident, err
+                    )
+                });
+                (path.segments.last()?.ident == name).then(|| (src_struct, value.replace(' ', "")))
+            })
+            .collect::<Vec<_>>();
+        converted.sort_by_key(|(src_struct, _)| src_struct.ident.to_string());
+        match converted.as_slice() {
+            [] => None,
+            [(src_struct, foreign_rust_type)] => {
+                if self.src_structs.contains_key(name) || self.src_enums.contains_key(name) {
+                    panic!(
+                        "`{}` is both a type of the crate and `{}`, which `{}` stands for, they must have distinct names",
+                        name, foreign_rust_type, src_struct.ident
+                    );
+                }
+                if !src_struct.src.generics.params.is_empty() {
+                    panic!(
+                        "Generic structs such as `{}` cannot stand for a type of another crate",
+                        src_struct.ident
+                    );
+                }
+                Some((src_struct, foreign_rust_type.clone()))
+            }
+            _ => panic!(
+                "`{}` all stand for a type named `{}`, only one struct can",
+                converted
+                    .iter()
+                    .map(|(src_struct, _)| src_struct.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("`, `"),
+                name
+            ),
+        }
+    }
+
+    /// Panics when the fields of a struct marked `#[frb(mirror = "..")]` are not those of the type
+    /// it mirrors, as far as the source of the crate of that type tells.
+    fn check_mirrored_fields(&mut self, src_struct: &Struct, foreign_rust_type: &str) {
+        let path = foreign_rust_type.split("::").collect::<Vec<_>>();
+        let lib = match self.dependency_libs.get(path[0]) {
+            Some(lib) if path.len() > 1 => lib.clone(),
+            _ => {
+                warn!(
+                    "`{}` is not the path of a type of a dependency, so the fields of `{}` were not checked against it",
+                    foreign_rust_type, src_struct.ident
+                );
+                return;
+            }
+        };
+        let dependency = self
+            .dependency_crates
+            .entry(path[0].to_owned())
+            .or_insert_with(|| Crate::from_root_src_file(lib.root_src_file));
+        let structs = dependency.root_module.collect_structs_to_vec();
+        let mirrored = match structs.get(*path.last().unwrap()) {
+            Some(mirrored) => &mirrored.src,
+            None => {
+                warn!(
+                    "`{}` was not found in the source of `{}`, so the fields of `{}` were not checked against it",
+                    foreign_rust_type, path[0], src_struct.ident
+                );
+                return;
+            }
+        };
+
+        let problem = match (&src_struct.src.fields, &mirrored.fields) {
+            (Fields::Named(fields), Fields::Named(mirrored_fields)) => {
+                let names = |fields: &FieldsNamed| {
+                    fields
+                        .named
+                        .iter()
+                        .map(|field| field.ident.as_ref().unwrap().to_string())
+                        .collect::<Vec<_>>()
+                };
+                let (names, mirrored_names) = (names(fields), names(mirrored_fields));
+                let missing = mirrored_names
+                    .iter()
+                    .filter(|name| !names.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let extra = names
+                    .iter()
+                    .filter(|name| !mirrored_names.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if !missing.is_empty() {
+                    Some(format!("lacks the fields `{}`", missing.join("`, `")))
+                } else if !extra.is_empty() {
+                    Some(format!(
+                        "has the fields `{}`, which `{}` does not have",
+                        extra.join("`, `"),
+                        foreign_rust_type
+                    ))
+                } else {
+                    None
+                }
+            }
+            (Fields::Unnamed(fields), Fields::Unnamed(mirrored_fields))
+                if fields.unnamed.len() != mirrored_fields.unnamed.len() =>
+            {
+                Some(format!(
+                    "has {} fields while `{}` has {}",
+                    fields.unnamed.len(),
+                    foreign_rust_type,
+                    mirrored_fields.unnamed.len()
+                ))
+            }
+            (Fields::Unnamed(_), Fields::Unnamed(_)) => None,
+            _ => Some(format!(
+                "does not have the same kind of fields as `{}`, named or positional",
+                foreign_rust_type
+            )),
+        }
+        .or_else(|| {
+            mirrored
+                .fields
+                .iter()
+                .enumerate()
+                .find(|(_, field)| !matches!(field.vis, Visibility::Public(_)))
+                .map(|(idx, field)| {
+                    format!(
+                        "the field `{}` of `{}` is not public, so it cannot be built",
+                        field
+                            .ident
+                            .as_ref()
+                            .map_or_else(|| idx.to_string(), ToString::to_string),
+                        foreign_rust_type
+                    )
+                })
+        });
+        if let Some(problem) = problem {
+            panic!(
+                "`{}` mirrors `{}`, but {}",
+                src_struct.ident, foreign_rust_type, problem
+            );
+        }
+    }
+
+    /// Refers to `Box<dyn Trait>`, which crosses the bridge as an enum with a variant for each of
+    /// the types listed by `#[frb(implementors = "..")]` on the trait.
+    fn convert_trait_object_to_ir_type(&mut self, ident: syn::Ident) -> Option<IrType> {
+        let name = ident.to_string();
+        let src_trait = match self.src_traits.get(&name).copied() {
+            Some(src_trait) => src_trait,
+            None => {
+                self.unresolved_type = Some(name);
+                return None;
+            }
+        };
+        let attrs = &src_trait.src.attrs;
+        let implementors = extract_implementors(attrs).unwrap_or_else(|| {
+            panic!(
+                "`Box<dyn {0}>` can only cross the bridge if the types implementing `{0}` are listed by `#[frb(implementors = \"..\")]` on it",
+                name
+            )
+        });
+        if implementors.is_empty() {
+            panic!(
+                "`#[frb(implementors = \"..\")]` lists no type for `{}`",
+                name
+            );
+        }
+        // The concrete type of a trait object is told from its `TypeId`
+        let extends_any = src_trait.src.supertraits.iter().any(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Any"),
+            syn::TypeParamBound::Lifetime(_) => false,
+        });
+        if !extends_any {
+            panic!(
+                "`{0}` must have `std::any::Any` as a supertrait, as in `trait {0}: std::any::Any`, for `Box<dyn {0}>` to cross the bridge",
+                name
+            );
+        }
+        self.check_type_path(&name, &src_trait.path);
+        self.check_dart_type_name(&name, &name);
+
+        if self.parsed_enums.insert(name.clone()) {
+            let variants = implementors
+                .iter()
+                .map(|implementor| {
+                    let ty = self.parse_type(implementor);
+                    let struct_name = match &ty {
+                        StructRef(IrTypeStructRef {
+                            name,
+                            tuple_fields: None,
+                            ..
+                        }) => name.clone(),
+                        _ => panic!(
+                            "The implementors of `{}` must be structs, but `{}` is not",
+                            name,
+                            type_to_string(implementor)
+                        ),
+                    };
+                    IrVariant {
+                        name: IrIdent::new(struct_name.clone()),
+                        comments: vec![],
+                        discriminant: None,
+                        kind: IrVariantKind::Struct(IrStruct {
+                            name: struct_name,
+                            path: None,
+                            fields: vec![IrField {
+                                name: IrIdent::new("field0".to_owned()),
+                                ty,
+                                comments: vec![],
+                                deprecation: None,
+                                default: None,
+                                is_final: true,
+                            }],
+                            skipped_fields: vec![],
+                            is_fields_named: false,
+                            is_pod: false,
+                            is_mirror: false,
+                            comments: vec![],
+                            dart_metadata: vec![],
+                            dart_imports: vec![],
+                        }),
+                    }
+                })
+                .collect();
+            let mut enu = IrEnum::new(
+                name.clone(),
+                src_trait.path.clone(),
+                extract_comments(attrs),
+                variants,
+            );
+            enu.is_trait_object = true;
+            self.enum_pool.insert(name.clone(), enu);
+        }
+
+        Some(EnumRef(IrTypeEnumRef {
+            name,
+            is_struct: true,
+            is_trait_object: true,
+        }))
+    }
+
     fn parse_enum_core(&mut self, ident: &syn::Ident) -> IrEnum {
         let src_enum = self.src_enums[&ident.to_string()];
         let name = src_enum.ident.to_string();
         let path = src_enum.path.clone();
         let comments = extract_comments(&src_enum.src.attrs);
+        // The fields of the variants only see `Self`, e.g. in `Box<Self>`, which is an enum with
+        // fields since it has one
+        let mut type_args = HashMap::new();
+        type_args.insert(
+            "Self".to_owned(),
+            EnumRef(IrTypeEnumRef {
+                name: name.clone(),
+                is_struct: true,
+                is_trait_object: false,
+            }),
+        );
+        let outer_type_args = std::mem::replace(&mut self.type_args, type_args);
         let variants = src_enum
             .src
             .variants
@@ -261,6 +1042,9 @@ impl<'a> TypeParser<'a> {
             .map(|variant| IrVariant {
                 name: IrIdent::new(variant.ident.to_string()),
                 comments: extract_comments(&variant.attrs),
+                discriminant: variant.discriminant.as_ref().map(|(_, expr)| {
+                    parse_discriminant(expr, &src_enum.ident.to_string(), &variant.ident)
+                }),
                 kind: match variant.fields.iter().next() {
                     None => IrVariantKind::Value,
                     Some(Field {
@@ -272,22 +1056,40 @@ impl<'a> TypeParser<'a> {
                         IrVariantKind::Struct(IrStruct {
                             name: variant_ident,
                             path: None,
+                            skipped_fields: vec![],
                             is_fields_named: field_ident.is_some(),
+                            is_pod: false,
+                            is_mirror: false,
                             comments: extract_comments(attrs),
+                            dart_metadata: vec![],
+                            dart_imports: vec![],
                             fields: variant
                                 .fields
                                 .iter()
                                 .enumerate()
-                                .map(|(idx, field)| IrField {
-                                    name: IrIdent::new(
-                                        field
-                                            .ident
-                                            .as_ref()
-                                            .map(ToString::to_string)
-                                            .unwrap_or_else(|| format!("field{}", idx)),
-                                    ),
-                                    ty: self.parse_type(&field.ty),
-                                    comments: extract_comments(&field.attrs),
+                                .map(|(idx, field)| {
+                                    for flag in ["skip", "non_final"] {
+                                        if has_frb_flag(&field.attrs, flag) {
+                                            panic!(
+                                                "`#[frb({})]` is only supported on fields of structs, not on those of variant `{}::{}`",
+                                                flag, src_enum.ident, variant.ident
+                                            );
+                                        }
+                                    }
+                                    IrField {
+                                        name: IrIdent::new(
+                                            field
+                                                .ident
+                                                .as_ref()
+                                                .map(ToString::to_string)
+                                                .unwrap_or_else(|| format!("field{}", idx)),
+                                        ),
+                                        ty: self.parse_type(&field.ty),
+                                        comments: extract_comments(&field.attrs),
+                                        deprecation: extract_deprecation(&field.attrs),
+                                        default: None,
+                                        is_final: true,
+                                    }
                                 })
                                 .collect(),
                         })
@@ -295,12 +1097,44 @@ impl<'a> TypeParser<'a> {
                 },
             })
             .collect();
-        IrEnum::new(name, path, comments, variants)
+        self.type_args = outer_type_args;
+        let mut enu = IrEnum::new(name, path, comments, variants);
+        enu.is_non_exhaustive = src_enum
+            .src
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("non_exhaustive"));
+        // The fallback of Dart is named `unknown`
+        if enu.is_non_exhaustive {
+            if let Some(variant) = enu
+                .variants()
+                .iter()
+                .find(|variant| variant.name.raw.eq_ignore_ascii_case("unknown"))
+            {
+                panic!(
+                    "the variant `{}` of the `#[non_exhaustive]` enum `{}` has the name of the fallback for unknown variants in Dart",
+                    variant.name, enu.name
+                );
+            }
+        }
+        enu
     }
 
-    fn parse_struct_core(&mut self, ident: &syn::Ident) -> IrStruct {
+    /// `type_args` are the type arguments of the instantiation being parsed, if the struct is
+    /// generic. `self_type` refers to that instantiation, for fields such as `Vec<Self>`.
+    fn parse_struct_core(
+        &mut self,
+        ident: &syn::Ident,
+        mut type_args: HashMap<String, IrType>,
+        self_type: IrType,
+    ) -> IrStruct {
         let src_struct = self.src_structs[&ident.to_string()];
+        let is_generic = !type_args.is_empty();
+        type_args.insert("Self".to_owned(), self_type);
+        // The fields of another struct do not see these type arguments
+        let outer_type_args = std::mem::replace(&mut self.type_args, type_args);
         let mut fields = Vec::new();
+        let mut skipped_fields = Vec::new();
 
         let (is_fields_named, struct_fields) = match &src_struct.src.fields {
             Fields::Named(FieldsNamed { named, .. }) => (true, named),
@@ -313,23 +1147,126 @@ impl<'a> TypeParser<'a> {
                 .ident
                 .as_ref()
                 .map_or(format!("field{}", idx), ToString::to_string);
+            // The type of a skipped field does not have to be supported. Zero-sized fields, such as
+            // `PhantomData<T>` markers, only mean something to the Rust compiler
+            if has_frb_flag(&field.attrs, "skip") || is_zero_sized_type(&field.ty) {
+                skipped_fields.push(IrIdent::new(field_name));
+                continue;
+            }
             let field_type = self.parse_type(&field.ty);
             fields.push(IrField {
                 name: IrIdent::new(field_name),
                 ty: field_type,
                 comments: extract_comments(&field.attrs),
+                deprecation: extract_deprecation(&field.attrs),
+                default: None,
+                is_final: !has_frb_flag(&field.attrs, "non_final"),
             });
         }
 
+        self.type_args = outer_type_args;
+
         let name = src_struct.ident.to_string();
         let path = Some(src_struct.path.clone());
         let comments = extract_comments(&src_struct.src.attrs);
+        // Any other field falls back to converting the struct field by field. `Clone` is needed
+        // to allocate lists of it, and named fields since cbindgen names the others `_0`, `_1`..
+        let is_pod = has_repr_c(&src_struct.src.attrs)
+            && foreign_type_attr(src_struct).is_none()
+            && has_derive(&src_struct.src.attrs, "Clone")
+            && is_fields_named
+            && !is_generic
+            && skipped_fields.is_empty()
+            && !fields.is_empty()
+            && fields.iter().all(|field| {
+                let ty = match &field.ty {
+                    Array(array) => &*array.inner,
+                    ty => ty,
+                };
+                matches!(ty, Primitive(primitive) if !matches!(primitive, IrTypePrimitive::Unit))
+            });
         IrStruct {
             name,
             path,
             fields,
+            skipped_fields,
             is_fields_named,
+            is_pod,
+            is_mirror: frb_value(&src_struct.src.attrs, "mirror").is_some(),
             comments,
+            dart_metadata: extract_dart_metadata(&src_struct.src.attrs),
+            dart_imports: extract_dart_imports(&src_struct.src.attrs),
+        }
+    }
+}
+
+/// The key and the value of `#[frb(convert = "..")]` or `#[frb(mirror = "..")]` on a struct, which
+/// stands for the type of another crate given by the value
+/// The integer of `std::num::NonZeroU32` and the like, named after it
+fn non_zero_primitive(ident: &str) -> Option<IrTypePrimitive> {
+    let primitive =
+        IrTypePrimitive::try_from_rust_str(&ident.strip_prefix("NonZero")?.to_lowercase())?;
+    match primitive {
+        IrTypePrimitive::F32
+        | IrTypePrimitive::F64
+        | IrTypePrimitive::Bool
+        | IrTypePrimitive::Unit => None,
+        integer => Some(integer),
+    }
+}
+
+fn foreign_type_attr(src_struct: &Struct) -> Option<(&'static str, String)> {
+    let attrs = &src_struct.src.attrs;
+    match (frb_value(attrs, "convert"), frb_value(attrs, "mirror")) {
+        (Some(_), Some(_)) => panic!(
+            "`{}` cannot be marked both `#[frb(convert = \"..\")]` and `#[frb(mirror = \"..\")]`",
+            src_struct.ident
+        ),
+        (Some(convert), None) => Some(("convert", convert)),
+        (None, Some(mirror)) => Some(("mirror", mirror)),
+        (None, None) => None,
+    }
+}
+
+/// Whether `ty` is `PhantomData<T>`, `PhantomPinned`, `()` or an array without items, or a tuple
+/// of those, which hold no value to send
+fn is_zero_sized_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { path, .. }) => {
+            path.segments.last().is_some_and(|segment| {
+                segment.ident == "PhantomData" || segment.ident == "PhantomPinned"
+            })
+        }
+        syn::Type::Tuple(tuple) => tuple.elems.iter().all(is_zero_sized_type),
+        syn::Type::Array(syn::TypeArray { elem, len, .. }) => {
+            let is_empty = matches!(len, syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(length),
+                ..
+            }) if length.base10_digits() == "0");
+            is_empty || is_zero_sized_type(elem)
         }
+        syn::Type::Paren(paren) => is_zero_sized_type(&paren.elem),
+        _ => false,
+    }
+}
+
+/// The value of the explicit discriminant `expr` of a variant, which is sent to Dart as an `i32`
+fn parse_discriminant(expr: &Expr, enum_name: &str, variant: &Ident) -> i64 {
+    let value = rust_literal_value(expr)
+        .and_then(|value| value.parse::<i64>().ok())
+        .unwrap_or_else(|| {
+            panic!(
+                "The discriminant of `{}::{}` must be an integer literal, not `{}`",
+                enum_name,
+                variant,
+                quote::quote!(#expr)
+            )
+        });
+    if !(i32::MIN as i64..=i32::MAX as i64).contains(&value) {
+        panic!(
+            "The discriminant of `{}::{}` is {}, which does not fit the `i32` it is sent as",
+            enum_name, variant, value
+        );
     }
+    value
 }
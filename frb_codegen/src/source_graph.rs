@@ -8,28 +8,41 @@
       file
 */
 
-use std::{collections::HashMap, fmt::Debug, fs, path::PathBuf};
-
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
 use cargo_metadata::MetadataCommand;
-use log::debug;
-use syn::{Ident, ItemEnum, ItemStruct, UseTree};
+use log::{debug, warn};
+use syn::{Ident, ItemEnum, ItemStruct, ItemTrait, UseTree};
 
-/// Represents a crate, including a map of its modules, imports, structs and
-/// enums.
+/// Represents a crate, including a map of its modules, imports, structs,
+/// enums and traits.
 #[derive(Debug, Clone)]
 pub struct Crate {
-    pub name: String,
-    pub manifest_path: PathBuf,
-    pub root_src_file: PathBuf,
     pub root_module: Module,
+    /// The libraries of the packages the crate depends on, by the name they are used under in Rust
+    pub dependency_libs: HashMap<String, DependencyLib>,
+}
+
+/// Where to find the source of a library the crate depends on
+#[derive(Debug, Clone)]
+pub struct DependencyLib {
+    pub root_src_file: PathBuf,
 }
 
 impl Crate {
-    pub fn new(manifest_path: &str) -> Self {
+    pub fn new(manifest_path: &str) -> Result<Self> {
         let mut cmd = MetadataCommand::new();
-        cmd.manifest_path(&manifest_path);
+        cmd.manifest_path(manifest_path);
 
-        let metadata = cmd.exec().unwrap();
+        let metadata = cmd
+            .exec()
+            .with_context(|| format!("Failed to read the metadata of {}", manifest_path))?;
 
         let root_package = metadata.root_package().unwrap();
         let root_src_file = {
@@ -53,20 +66,41 @@ impl Crate {
             }
         };
 
+        let mut result = Crate::from_root_src_file(root_src_file);
+        result.dependency_libs = metadata
+            .packages
+            .iter()
+            .filter(|package| package.id != root_package.id)
+            .flat_map(|package| package.targets.iter())
+            .filter(|target| target.kind.iter().any(|kind| kind.ends_with("lib")))
+            .map(|target| {
+                (
+                    target.name.replace('-', "_"),
+                    DependencyLib {
+                        root_src_file: target.src_path.clone().into(),
+                    },
+                )
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    /// The crate whose root module is in `root_src_file`, without its dependencies
+    pub fn from_root_src_file(root_src_file: PathBuf) -> Self {
         let source_rust_content = fs::read_to_string(&root_src_file).unwrap();
         let file_ast = syn::parse_file(&source_rust_content).unwrap();
 
         let mut result = Crate {
-            name: root_package.name.clone(),
-            manifest_path: fs::canonicalize(manifest_path).unwrap(),
-            root_src_file: root_src_file.clone(),
             root_module: Module {
                 visibility: Visibility::Public,
+                dir: root_src_file.parent().unwrap().to_owned(),
                 file_path: root_src_file,
                 module_path: vec!["crate".to_string()],
                 source: Some(ModuleSource::File(file_ast)),
                 scope: None,
             },
+            dependency_libs: HashMap::new(),
         };
 
         result.resolve();
@@ -78,6 +112,144 @@ impl Crate {
     pub fn resolve(&mut self) {
         self.root_module.resolve();
     }
+
+    /// The files of all the modules, including those of modules whose file is missing
+    pub fn module_files(&self) -> Vec<PathBuf> {
+        let mut ans = Vec::new();
+        self.root_module.collect_files(&mut ans);
+        ans.dedup();
+        ans
+    }
+
+    /// The module whose source is the given file, if it is part of the crate
+    pub fn find_module_by_file(&self, file_path: &Path) -> Option<&Module> {
+        let file_path = fs::canonicalize(file_path).ok()?;
+        self.root_module.find_module_by_file(&file_path)
+    }
+
+    /// Whether the given module is declared `pub`, as are all the modules containing it
+    pub fn is_module_public(&self, module: &Module) -> bool {
+        let mut current = &self.root_module;
+        for name in &module.module_path[1..] {
+            let child = current.scope.as_ref().and_then(|scope| {
+                scope
+                    .modules
+                    .iter()
+                    .find(|child| child.module_path.last() == Some(name))
+            });
+            match child {
+                Some(child) if matches!(child.visibility, Visibility::Public) => current = child,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// The structs that can be named from the given module, by the name they are known under there
+    pub fn visible_structs<'a>(&'a self, module: &'a Module) -> HashMap<String, &'a Struct> {
+        self.visible_items(module, |scope| &scope.structs, |item| &item.ident)
+    }
+
+    /// The enums that can be named from the given module, by the name they are known under there
+    pub fn visible_enums<'a>(&'a self, module: &'a Module) -> HashMap<String, &'a Enum> {
+        self.visible_items(module, |scope| &scope.enums, |item| &item.ident)
+    }
+
+    /// Items declared in the module win over those imported by name, which win over those
+    /// imported by a glob, as in Rust.
+    fn visible_items<'a, T>(
+        &'a self,
+        module: &'a Module,
+        items_of: fn(&ModuleScope) -> &Vec<T>,
+        ident_of: fn(&T) -> &Ident,
+    ) -> HashMap<String, &'a T> {
+        let mut ans = HashMap::new();
+        let imports = module
+            .scope
+            .as_ref()
+            .map(|scope| &scope.imports[..])
+            .unwrap_or_default();
+        for import in imports.iter().filter(|import| is_glob(&import.path)) {
+            let path = module.absolute_import_path(&import.path);
+            if let Some(scope) = self
+                .find_module(&path[..path.len() - 1], 0)
+                .and_then(|m| m.scope.as_ref())
+            {
+                for item in items_of(scope) {
+                    ans.insert(ident_of(item).to_string(), item);
+                }
+            }
+        }
+        for import in imports.iter().filter(|import| !is_glob(&import.path)) {
+            let path = module.absolute_import_path(&import.path);
+            if let Some(item) = self.find_item(&path, items_of, ident_of, 0) {
+                ans.insert(path.last().unwrap().clone(), item);
+            }
+        }
+        if let Some(scope) = &module.scope {
+            for item in items_of(scope) {
+                ans.insert(ident_of(item).to_string(), item);
+            }
+        }
+        ans
+    }
+
+    /// Follows the absolute path of a module, going through re-exports
+    fn find_module(&self, path: &[String], depth: usize) -> Option<&Module> {
+        if depth > MAX_REEXPORT_DEPTH || path.first().map(String::as_str) != Some("crate") {
+            return None;
+        }
+        let mut module = &self.root_module;
+        for (idx, name) in path.iter().enumerate().skip(1) {
+            let scope = module.scope.as_ref()?;
+            module = match scope
+                .modules
+                .iter()
+                .find(|m| m.module_path.last() == Some(name))
+            {
+                Some(child) => child,
+                None => {
+                    let reexport = module.find_import(name)?;
+                    let mut reexport = module.absolute_import_path(reexport);
+                    reexport.extend_from_slice(&path[idx + 1..]);
+                    return self.find_module(&reexport, depth + 1);
+                }
+            };
+        }
+        Some(module)
+    }
+
+    /// Follows the absolute path of a struct or an enum, going through re-exports
+    fn find_item<'a, T>(
+        &'a self,
+        path: &[String],
+        items_of: fn(&ModuleScope) -> &Vec<T>,
+        ident_of: fn(&T) -> &Ident,
+        depth: usize,
+    ) -> Option<&'a T> {
+        let (name, module_path) = path.split_last()?;
+        let module = self.find_module(module_path, depth)?;
+        let scope = module.scope.as_ref()?;
+        items_of(scope)
+            .iter()
+            .find(|item| ident_of(item) == name)
+            .or_else(|| {
+                let reexport = module.find_import(name)?;
+                self.find_item(
+                    &module.absolute_import_path(reexport),
+                    items_of,
+                    ident_of,
+                    depth + 1,
+                )
+            })
+    }
+}
+
+/// Bounds the chains of `pub use` followed, in case some are circular
+const MAX_REEXPORT_DEPTH: usize = 16;
+
+fn is_glob(path: &[String]) -> bool {
+    path.last().map(String::as_str) == Some("*")
 }
 
 /// Mirrors syn::Visibility, but can be created without a token
@@ -101,7 +273,6 @@ fn syn_vis_to_visibility(vis: &syn::Visibility) -> Visibility {
 #[derive(Debug, Clone)]
 pub struct Import {
     pub path: Vec<String>,
-    pub visibility: Visibility,
 }
 
 #[derive(Debug, Clone)]
@@ -148,17 +319,39 @@ impl Debug for Enum {
     }
 }
 
+#[derive(Clone)]
+pub struct Trait {
+    pub ident: Ident,
+    pub src: ItemTrait,
+    pub visibility: Visibility,
+    pub path: Vec<String>,
+}
+
+impl Debug for Trait {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Trait")
+            .field("ident", &self.ident)
+            .field("src", &"omitted")
+            .field("visibility", &self.visibility)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ModuleScope {
     pub modules: Vec<Module>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
+    pub traits: Vec<Trait>,
     pub imports: Vec<Import>,
 }
 
 #[derive(Clone)]
 pub struct Module {
     pub visibility: Visibility,
+    /// Directory of the files of the modules declared in this one with `mod name;`
+    pub dir: PathBuf,
     pub file_path: PathBuf,
     pub module_path: Vec<String>,
     pub source: Option<ModuleSource>,
@@ -183,11 +376,12 @@ impl Module {
         self.resolve_imports();
     }
 
-    /// Maps out modules, structs and enums within the scope of this module
+    /// Maps out modules, structs, enums and traits within the scope of this module
     fn resolve_modules(&mut self) {
         let mut scope_modules = Vec::new();
         let mut scope_structs = Vec::new();
         let mut scope_enums = Vec::new();
+        let mut scope_traits = Vec::new();
 
         let items = match self.source.as_ref().unwrap() {
             ModuleSource::File(file) => &file.items,
@@ -220,6 +414,18 @@ impl Module {
                         },
                     });
                 }
+                syn::Item::Trait(item_trait) => {
+                    scope_traits.push(Trait {
+                        ident: item_trait.ident.clone(),
+                        src: item_trait.clone(),
+                        visibility: syn_vis_to_visibility(&item_trait.vis),
+                        path: {
+                            let mut path = self.module_path.clone();
+                            path.push(item_trait.ident.to_string());
+                            path
+                        },
+                    });
+                }
                 syn::Item::Mod(item_mod) => {
                     let ident = item_mod.ident.clone();
 
@@ -230,6 +436,7 @@ impl Module {
                         Some(content) => {
                             let mut child_module = Module {
                                 visibility: syn_vis_to_visibility(&item_mod.vis),
+                                dir: self.dir.join(ident.to_string()),
                                 file_path: self.file_path.clone(),
                                 module_path,
                                 source: Some(ModuleSource::ModuleInFile(content.1.clone())),
@@ -241,17 +448,13 @@ impl Module {
                             child_module
                         }
                         None => {
-                            let folder_path =
-                                self.file_path.parent().unwrap().join(ident.to_string());
-                            let folder_exists = folder_path.exists();
-
-                            let file_path = if folder_exists {
-                                folder_path.join("mod.rs")
+                            // `name.rs`, whose modules are in `name/`, or `name/mod.rs`
+                            let flat_file_path = self.dir.join(ident.to_string() + ".rs");
+                            let mod_file_path = self.dir.join(ident.to_string()).join("mod.rs");
+                            let file_path = if !flat_file_path.exists() && mod_file_path.exists() {
+                                mod_file_path
                             } else {
-                                self.file_path
-                                    .parent()
-                                    .unwrap()
-                                    .join(ident.to_string() + ".rs")
+                                flat_file_path
                             };
 
                             let file_exists = file_path.exists();
@@ -263,11 +466,18 @@ impl Module {
                                     syn::parse_file(&source_rust_content).unwrap(),
                                 ))
                             } else {
+                                // Possibly a module that only exists for other `cfg`s
+                                warn!(
+                                    "The file of module `{}` was not found at {:?}, its types will not be available",
+                                    module_path.join("::"),
+                                    file_path
+                                );
                                 None
                             };
 
                             let mut child_module = Module {
                                 visibility: syn_vis_to_visibility(&item_mod.vis),
+                                dir: self.dir.join(ident.to_string()),
                                 file_path,
                                 module_path,
                                 source,
@@ -290,6 +500,7 @@ impl Module {
             modules: scope_modules,
             enums: scope_enums,
             structs: scope_structs,
+            traits: scope_traits,
             imports: vec![], // Will be filled in by resolve_imports()
         });
     }
@@ -307,17 +518,75 @@ impl Module {
                 let flattened_imports = flatten_use_tree(&item_use.tree);
 
                 for import in flattened_imports {
-                    imports.push(Import {
-                        path: import,
-                        visibility: syn_vis_to_visibility(&item_use.vis),
-                    });
+                    imports.push(Import { path: import });
                 }
             }
         }
     }
 
+    fn collect_files(&self, container: &mut Vec<PathBuf>) {
+        container.push(self.file_path.clone());
+        if let Some(scope) = &self.scope {
+            for scope_module in &scope.modules {
+                scope_module.collect_files(container);
+            }
+        }
+    }
+
+    /// The module whose source is the given canonical file
+    fn find_module_by_file(&self, file_path: &Path) -> Option<&Module> {
+        if matches!(self.source, Some(ModuleSource::File(_))) && self.file_path == file_path {
+            return Some(self);
+        }
+        self.scope
+            .as_ref()?
+            .modules
+            .iter()
+            .find_map(|module| module.find_module_by_file(file_path))
+    }
+
+    /// The import of this module bringing the given name in scope
+    fn find_import(&self, name: &str) -> Option<&[String]> {
+        self.scope
+            .as_ref()?
+            .imports
+            .iter()
+            .find(|import| import.path.last().map(String::as_str) == Some(name))
+            .map(|import| &import.path[..])
+    }
+
+    /// Turns the path of an import into a path starting with `crate`, unless it is from another
+    /// crate
+    fn absolute_import_path(&self, path: &[String]) -> Vec<String> {
+        match path.first().map(String::as_str) {
+            Some("crate") => path.to_vec(),
+            Some("self") => [&self.module_path[..], &path[1..]].concat(),
+            Some("super") => {
+                let supers = path.iter().take_while(|name| *name == "super").count();
+                let parent_len = self.module_path.len().saturating_sub(supers).max(1);
+                [&self.module_path[..parent_len], &path[supers..]].concat()
+            }
+            // Paths relative to this module, such as those of the modules declared in it
+            Some(name)
+                if self.scope.as_ref().is_some_and(|scope| {
+                    scope
+                        .modules
+                        .iter()
+                        .any(|m| m.module_path.last().map(String::as_str) == Some(name))
+                }) =>
+            {
+                [&self.module_path[..], path].concat()
+            }
+            _ => path.to_vec(),
+        }
+    }
+
     pub fn collect_structs<'a>(&'a self, container: &mut HashMap<String, &'a Struct>) {
-        let scope = self.scope.as_ref().unwrap();
+        // Modules whose file is missing have no scope
+        let scope = match &self.scope {
+            Some(scope) => scope,
+            None => return,
+        };
         for scope_struct in &scope.structs {
             container.insert(scope_struct.ident.to_string(), scope_struct);
         }
@@ -333,7 +602,10 @@ impl Module {
     }
 
     pub fn collect_enums<'a>(&'a self, container: &mut HashMap<String, &'a Enum>) {
-        let scope = self.scope.as_ref().unwrap();
+        let scope = match &self.scope {
+            Some(scope) => scope,
+            None => return,
+        };
         for scope_enum in &scope.enums {
             container.insert(scope_enum.ident.to_string(), scope_enum);
         }
@@ -347,179 +619,57 @@ impl Module {
         self.collect_enums(&mut ans);
         ans
     }
+
+    pub fn collect_traits<'a>(&'a self, container: &mut HashMap<String, &'a Trait>) {
+        let scope = match &self.scope {
+            Some(scope) => scope,
+            None => return,
+        };
+        for scope_trait in &scope.traits {
+            container.insert(scope_trait.ident.to_string(), scope_trait);
+        }
+        for scope_module in &scope.modules {
+            scope_module.collect_traits(container);
+        }
+    }
+
+    pub fn collect_traits_to_vec(&self) -> HashMap<String, &Trait> {
+        let mut ans = HashMap::new();
+        self.collect_traits(&mut ans);
+        ans
+    }
 }
 
-fn flatten_use_tree_rename_abort_warning(use_tree: &UseTree) {
-    debug!("WARNING: flatten_use_tree() found an import rename (use a::b as c). flatten_use_tree() will now abort.");
+fn flatten_use_tree_rename_warning(use_tree: &UseTree) {
+    debug!("WARNING: flatten_use_tree() found an import rename (use a::b as c), which it ignores.");
     debug!("WARNING: This happened while parsing {:?}", use_tree);
-    debug!("WARNING: This use statement will be ignored.");
 }
 
 /// Takes a use tree and returns a flat list of use paths (list of string tokens)
 ///
 /// Example:
-///     use a::{b::c, d::e};
+///     use a::{b::c, d::{e, f::*}};
 /// becomes
 ///     [
 ///         ["a", "b", "c"],
-///         ["a", "d", "e"]
+///         ["a", "d", "e"],
+///         ["a", "d", "f", "*"]
 ///     ]
 ///
 /// Warning: As of writing, import renames (import a::b as c) are silently
 /// ignored.
 fn flatten_use_tree(use_tree: &UseTree) -> Vec<Vec<String>> {
-    // Vec<(path, is_complete)>
-    let mut result = vec![(vec![], false)];
-
-    let mut counter: usize = 0;
-
-    loop {
-        counter += 1;
-
-        if counter > 10000 {
-            panic!("flatten_use_tree: Use statement complexity limit exceeded. This is probably a bug.");
-        }
-
-        // If all paths are complete, break from the loop
-        if result.iter().all(|result_item| result_item.1) {
-            break;
-        }
-
-        let mut items_to_push = Vec::new();
-
-        for path_tuple in &mut result {
-            let path = &mut path_tuple.0;
-            let is_complete = &mut path_tuple.1;
-
-            if *is_complete {
-                continue;
-            }
-
-            let mut tree_cursor = use_tree;
-
-            for path_item in path.iter() {
-                match tree_cursor {
-                    UseTree::Path(use_path) => {
-                        let ident = use_path.ident.to_string();
-                        if *path_item != ident {
-                            panic!("This ident did not match the one we already collected. This is a bug.");
-                        }
-                        tree_cursor = use_path.tree.as_ref();
-                    }
-                    UseTree::Group(use_group) => {
-                        let mut moved_tree_cursor = false;
-
-                        for tree in use_group.items.iter() {
-                            match tree {
-                                UseTree::Path(use_path) => {
-                                    if path_item == &use_path.ident.to_string() {
-                                        tree_cursor = use_path.tree.as_ref();
-                                        moved_tree_cursor = true;
-                                        break;
-                                    }
-                                }
-                                // Since we're not matching UseTree::Group here, a::b::{{c}, {d}} might
-                                // break. But also why would anybody do that
-                                _ => unreachable!(),
-                            }
-                        }
-
-                        if !moved_tree_cursor {
-                            unreachable!();
-                        }
-                    }
-                    _ => unreachable!(),
-                }
-            }
-
-            match tree_cursor {
-                UseTree::Name(use_name) => {
-                    path.push(use_name.ident.to_string());
-                    *is_complete = true;
-                }
-                UseTree::Path(use_path) => {
-                    path.push(use_path.ident.to_string());
-                }
-                UseTree::Glob(_) => {
-                    path.push("*".to_string());
-                    *is_complete = true;
-                }
-                UseTree::Group(use_group) => {
-                    // We'll modify the first one in-place, and make clones for
-                    // all subsequent ones
-                    let mut first: bool = true;
-                    // Capture the path in this state, since we're about to
-                    // modify it
-                    let path_copy = path.clone();
-                    for tree in use_group.items.iter() {
-                        let mut new_path_tuple = if first {
-                            None
-                        } else {
-                            let new_path = path_copy.clone();
-                            items_to_push.push((new_path, false));
-                            Some(items_to_push.iter_mut().last().unwrap())
-                        };
-
-                        match tree {
-                            UseTree::Path(use_path) => {
-                                let ident = use_path.ident.to_string();
-
-                                if first {
-                                    path.push(ident);
-                                } else {
-                                    new_path_tuple.unwrap().0.push(ident);
-                                }
-                            }
-                            UseTree::Name(use_name) => {
-                                let ident = use_name.ident.to_string();
-
-                                if first {
-                                    path.push(ident);
-                                    *is_complete = true;
-                                } else {
-                                    let path_tuple = new_path_tuple.as_mut().unwrap();
-                                    path_tuple.0.push(ident);
-                                    path_tuple.1 = true;
-                                }
-                            }
-                            UseTree::Glob(_) => {
-                                if first {
-                                    path.push("*".to_string());
-                                    *is_complete = true;
-                                } else {
-                                    let path_tuple = new_path_tuple.as_mut().unwrap();
-                                    path_tuple.0.push("*".to_string());
-                                    path_tuple.1 = true;
-                                }
-                            }
-                            UseTree::Group(_) => {
-                                panic!(
-                                    "Directly-nested use groups ({}) are not supported by flutter_rust_bridge. Use {} instead.",
-                                    "use a::{{b}, c}",
-                                    "a::{b, c}"
-                                );
-                            }
-                            // UseTree::Group(_) => panic!(),
-                            UseTree::Rename(_) => {
-                                flatten_use_tree_rename_abort_warning(use_tree);
-                                return vec![];
-                            }
-                        }
-
-                        first = false;
-                    }
-                }
-                UseTree::Rename(_) => {
-                    flatten_use_tree_rename_abort_warning(use_tree);
-                    return vec![];
-                }
-            }
-        }
-
-        for item in items_to_push {
-            result.push(item);
+    match use_tree {
+        UseTree::Path(use_path) => flatten_use_tree(&use_path.tree)
+            .into_iter()
+            .map(|rest| [vec![use_path.ident.to_string()], rest].concat())
+            .collect(),
+        UseTree::Name(use_name) => vec![vec![use_name.ident.to_string()]],
+        UseTree::Glob(_) => vec![vec!["*".to_string()]],
+        UseTree::Group(use_group) => use_group.items.iter().flat_map(flatten_use_tree).collect(),
+        UseTree::Rename(_) => {
+            flatten_use_tree_rename_warning(use_tree);
+            vec![]
         }
     }
-
-    result.into_iter().map(|val| val.0).collect()
 }
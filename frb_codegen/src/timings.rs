@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use log::info;
+use serde::Serialize;
+
+/// Times the phases of the code generation, each one being logged once done along with what it
+/// went through.
+pub struct Timings {
+    start: Instant,
+    phase_start: Instant,
+    phases: Vec<PhaseTiming>,
+}
+
+#[derive(Serialize)]
+struct PhaseTiming {
+    name: &'static str,
+    millis: u128,
+    /// The numbers of items, such as functions or lines, by what they count
+    counts: BTreeMap<&'static str, usize>,
+}
+
+#[derive(Serialize)]
+struct TimingsReport<'a> {
+    total_millis: u128,
+    phases: &'a [PhaseTiming],
+}
+
+impl Timings {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Timings {
+            start: now,
+            phase_start: now,
+            phases: Vec::new(),
+        }
+    }
+
+    /// Ends the phase called `name`, which started when the previous one ended
+    pub fn phase_done(&mut self, name: &'static str, counts: &[(&'static str, usize)]) {
+        let millis = self.phase_start.elapsed().as_millis();
+        info!(
+            "Phase: {} ({} ms{})",
+            name,
+            millis,
+            counts
+                .iter()
+                .map(|(item, count)| format!(", {} {}", count, item))
+                .collect::<String>()
+        );
+        self.phases.push(PhaseTiming {
+            name,
+            millis,
+            counts: counts.iter().copied().collect(),
+        });
+        self.phase_start = Instant::now();
+    }
+
+    /// The time taken by each phase as JSON, for tools to compare runs
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&TimingsReport {
+            total_millis: self.start.elapsed().as_millis(),
+            phases: &self.phases,
+        })
+        .unwrap()
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use log::debug;
 
+use crate::config::DartNamesStyle;
 use crate::ir::IrType::*;
 use crate::ir::*;
 
-pub fn transform(src: IrFile) -> IrFile {
+pub fn transform(src: IrFile, dart_names_style: DartNamesStyle) -> IrFile {
     let dst_funcs = src
         .funcs
         .into_iter()
@@ -17,9 +20,70 @@ pub fn transform(src: IrFile) -> IrFile {
         })
         .collect();
 
-    IrFile {
+    let mut dst = IrFile {
         funcs: dst_funcs,
         ..src
+    };
+    add_tuple_structs(&mut dst);
+    apply_dart_names_style(&mut dst, dart_names_style);
+    dst
+}
+
+/// Dart names the fields, the arguments and the methods in the style of the config, while Rust
+/// keeps its names
+fn apply_dart_names_style(file: &mut IrFile, style: DartNamesStyle) {
+    for func in &mut file.funcs {
+        func.dart_names_style = style;
+        let names = func
+            .inputs
+            .iter_mut()
+            .map(|input| &mut input.name)
+            .chain(
+                func.cancellation_token
+                    .iter_mut()
+                    .map(|token| &mut token.name),
+            )
+            .chain(func.callbacks.iter_mut().map(|callback| &mut callback.name));
+        for name in names {
+            name.dart_names_style = style;
+        }
+    }
+    let variant_structs = file.enum_pool.values_mut().flat_map(|enu| {
+        enu.variants_mut()
+            .iter_mut()
+            .filter_map(|variant| match &mut variant.kind {
+                IrVariantKind::Struct(st) => Some(st),
+                IrVariantKind::Value => None,
+            })
+    });
+    for st in file.struct_pool.values_mut().chain(variant_structs) {
+        for field in &mut st.fields {
+            field.name.dart_names_style = style;
+        }
+    }
+}
+
+/// Tuples are handled as structs, so each distinct tuple type gets a hidden struct in the pool
+fn add_tuple_structs(file: &mut IrFile) {
+    let mut seen_idents = HashSet::new();
+    let mut tuple_structs = Vec::new();
+    file.visit_types(
+        &mut |ty| {
+            if !seen_idents.insert(ty.safe_ident()) {
+                return true;
+            }
+            if let StructRef(struct_ref) = ty {
+                tuple_structs.extend(struct_ref.tuple_struct());
+            }
+            false
+        },
+        true,
+        true,
+    );
+    for tuple_struct in tuple_structs {
+        debug!("add_tuple_structs add struct={:?}", tuple_struct);
+        file.struct_pool
+            .insert(tuple_struct.name.clone(), tuple_struct);
     }
 }
 
@@ -36,6 +100,7 @@ fn transform_func_input_add_boxed(input: IrField) -> IrField {
             IrField {
                 ty: Boxed(IrTypeBoxed {
                     exist_in_real_api: false, // <--
+                    pointer: IrTypeBoxedPointer::Box,
                     inner: Box::new(input.ty.clone()),
                 }),
                 ..input
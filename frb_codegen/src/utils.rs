@@ -2,7 +2,14 @@ use std::fs;
 use std::path::Path;
 
 pub fn mod_from_rust_path(code_path: &str, crate_path: &str) -> String {
-    Path::new(code_path)
+    let code_path = Path::new(code_path);
+    // `foo/mod.rs` is the module `foo`
+    let code_path = if code_path.file_stem().is_some_and(|stem| stem == "mod") {
+        code_path.parent().unwrap()
+    } else {
+        code_path
+    };
+    code_path
         .strip_prefix(Path::new(crate_path).join("src"))
         .unwrap()
         .with_extension("")
@@ -13,10 +20,43 @@ pub fn mod_from_rust_path(code_path: &str, crate_path: &str) -> String {
 }
 
 pub fn with_changed_file<F: FnOnce()>(path: &str, append_content: &str, f: F) {
-    let content_original = fs::read_to_string(&path).unwrap();
-    fs::write(&path, content_original.clone() + append_content).unwrap();
+    let content_original = fs::read_to_string(path).unwrap();
+    fs::write(path, content_original.clone() + append_content).unwrap();
 
     f();
 
-    fs::write(&path, content_original).unwrap();
+    fs::write(path, content_original).unwrap();
+}
+
+/// Runs `f` with the `files` temporarily holding the given contents, then puts back those which
+/// existed and removes the others, along with the directories created for them, even if `f` panics
+pub fn with_temporary_files<F: FnOnce()>(files: &[(&str, &str)], f: F) {
+    struct Restore(Vec<(String, Option<Vec<u8>>)>, Vec<std::path::PathBuf>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            for (path, content_original) in &self.0 {
+                let _ = match content_original {
+                    Some(content_original) => fs::write(path, content_original),
+                    None => fs::remove_file(path),
+                };
+            }
+            for dir in self.1.iter().rev() {
+                let _ = fs::remove_dir(dir);
+            }
+        }
+    }
+
+    let mut restore = Restore(Vec::new(), Vec::new());
+    for (path, content) in files {
+        if let Some(dir) = Path::new(path).parent() {
+            if !dir.as_os_str().is_empty() && !dir.exists() {
+                fs::create_dir_all(dir).unwrap();
+                restore.1.push(dir.to_owned());
+            }
+        }
+        restore.0.push((path.to_string(), fs::read(path).ok()));
+        fs::write(path, content).unwrap();
+    }
+
+    f();
 }
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+
+use crate::config::Opts;
+use crate::source_graph::Crate;
+
+/// How often the watched files are checked
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long the files must stay untouched before regenerating, since editors often write a file
+/// in several steps and a `git checkout` touches many files
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The modification time of every watched file, `None` when it does not exist
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Runs the code generation whenever the input files, the modules they can refer to or the
+/// manifest change, and never returns. The files are polled rather than watched through the OS,
+/// which is plenty for the few files of a crate.
+pub fn watch(config: &Opts, run_first: bool, mut run: impl FnMut() -> Result<()>) -> Result<()> {
+    if run_first {
+        run_and_report(&mut run);
+    }
+    let mut snapshot = take_snapshot(config);
+    info!("Watching {} files for changes", snapshot.len());
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        if take_snapshot(config) == snapshot {
+            continue;
+        }
+        let mut changed = take_snapshot(config);
+        loop {
+            thread::sleep(DEBOUNCE);
+            let latest = take_snapshot(config);
+            if latest == changed {
+                break;
+            }
+            changed = latest;
+        }
+        run_and_report(&mut run);
+        // The modules may have changed too, and generating touches some files such as `lib.rs`
+        snapshot = take_snapshot(config);
+    }
+}
+
+fn run_and_report(run: &mut impl FnMut() -> Result<()>) {
+    let start = Instant::now();
+    // The parser reports unsupported code by panicking, which should not end the watch
+    match catch_silently(run).and_then(|result| result) {
+        Ok(()) => info!("Regenerated in {:.1?}", start.elapsed()),
+        Err(e) => error!("Failed to regenerate: {:#}", e),
+    }
+}
+
+fn take_snapshot(config: &Opts) -> Snapshot {
+    let mut paths = vec![PathBuf::from(&config.manifest_path)];
+    paths.extend(config.rust_input_paths.iter().map(PathBuf::from));
+    // A module with a syntax error is not followed, but the files already known still are
+    if let Ok(Ok(crate_map)) = catch_silently(|| Crate::new(&config.manifest_path)) {
+        paths.extend(crate_map.module_files());
+    }
+    paths
+        .into_iter()
+        .filter(|path| !config.is_rust_output(path))
+        .map(|path| {
+            let modified = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Turns a panic into an error, without the panic hook printing it
+fn catch_silently<T>(f: impl FnOnce() -> T) -> Result<T> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.map_err(|payload| {
+        anyhow!(payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "the code generator panicked".to_owned()))
+    })
+}
@@ -23,6 +23,11 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
   @protected
   final T inner;
 
+  bool _disposed = false;
+
+  /// The ports of the streams still open, which [dispose] closes
+  final _streamPorts = <ReceivePort>{};
+
   void _sanityCheckSingleton() {
     if (_instances.contains(runtimeType)) {
       throw Exception(
@@ -35,18 +40,40 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
     inner.store_dart_post_cobject(NativeApi.postCObject.cast());
   }
 
+  /// Tears down the bridge, such as between tests or before a hot restart: the streams still open
+  /// are closed, no function can be called anymore, and another instance can then be created.
+  /// The calls already made still complete. Calling it again does nothing.
+  @mustCallSuper
+  void dispose() {
+    if (_disposed) return;
+    _disposed = true;
+    for (final receivePort in _streamPorts) {
+      receivePort.close();
+    }
+    _streamPorts.clear();
+    _instances.remove(runtimeType);
+  }
+
+  void _checkNotDisposed(FlutterRustBridgeBaseTask task) {
+    if (_disposed) {
+      throw StateError('Cannot call ${task.debugName}, since $runtimeType was disposed');
+    }
+  }
+
   /// Execute a normal ffi call. Usually called by generated code instead of manually called.
   @protected
   Future<S> executeNormal<S>(FlutterRustBridgeTask<S> task) {
+    _checkNotDisposed(task);
     final completer = Completer<dynamic>();
     final sendPort = singleCompletePort(completer);
     task.callFfi(sendPort.nativePort);
-    return completer.future.then((dynamic raw) => _transformRust2DartMessage(raw, task.parseSuccessData));
+    return completer.future.then((dynamic raw) => _transformRust2DartMessage(raw, task));
   }
 
   /// Similar to [executeNormal], except that this will return synchronously
   @protected
   Uint8List executeSync(FlutterRustBridgeSyncTask task) {
+    _checkNotDisposed(task);
     final raw = task.callFfi();
 
     final bytes = Uint8List.fromList(raw.ptr.asTypedList(raw.len));
@@ -64,27 +91,42 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
   /// Similar to [executeNormal], except that this will return a [Stream] instead of a [Future].
   @protected
   Stream<S> executeStream<S>(FlutterRustBridgeTask<S> task) async* {
+    _checkNotDisposed(task);
     final receivePort = ReceivePort();
+    _streamPorts.add(receivePort);
     task.callFfi(receivePort.sendPort.nativePort);
 
-    await for (final raw in receivePort) {
-      try {
-        yield _transformRust2DartMessage(raw, task.parseSuccessData);
-      } on _CloseStreamException {
-        receivePort.close();
+    try {
+      await for (final raw in receivePort) {
+        try {
+          yield _transformRust2DartMessage(raw, task);
+        } on _CloseStreamException {
+          receivePort.close();
+        } on FfiException catch (e, s) {
+          // Errors do not end the stream, Rust closes it after a failure of the function itself
+          yield* Stream<S>.error(e, s);
+        }
       }
+    } finally {
+      // Also when the listener cancels its subscription
+      _streamPorts.remove(receivePort);
+      receivePort.close();
     }
   }
 
-  S _transformRust2DartMessage<S>(dynamic raw, S Function(dynamic) parseSuccessData) {
+  S _transformRust2DartMessage<S>(dynamic raw, FlutterRustBridgeTask<S> task) {
     final action = raw[0];
     switch (action) {
       case _RUST2DART_ACTION_SUCCESS:
         assert(raw.length == 2);
-        return parseSuccessData(raw[1]);
+        return task.parseSuccessData(raw[1]);
       case _RUST2DART_ACTION_ERROR:
         assert(raw.length == 4);
-        throw FfiException(raw[1], raw[2], raw[3]);
+        final parseErrorData = task.parseErrorData;
+        if (raw[1] == _CUSTOM_ERROR_CODE && parseErrorData != null) {
+          throw parseErrorData(raw[3]);
+        }
+        throw transformFfiException(FfiException(raw[1], raw[2], raw[3]));
       case _RUST2DART_ACTION_CLOSE_STREAM:
         assert(raw.length == 1);
         throw _CloseStreamException();
@@ -93,9 +135,15 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
     }
   }
 
+  /// Turns an error sent by Rust into the exception thrown, such as panics into a generated
+  /// `PanicException`. Returns it as is by default.
+  @protected
+  FfiException transformFfiException(FfiException exception) => exception;
+
   static const _RUST2DART_ACTION_SUCCESS = 0; // ignore: constant_identifier_names
   static const _RUST2DART_ACTION_ERROR = 1; // ignore: constant_identifier_names
   static const _RUST2DART_ACTION_CLOSE_STREAM = 2; // ignore: constant_identifier_names
+  static const _CUSTOM_ERROR_CODE = 'CUSTOM_ERROR'; // ignore: constant_identifier_names
 }
 
 /// A task to call FFI function.
@@ -104,9 +152,14 @@ class FlutterRustBridgeTask<S> extends FlutterRustBridgeBaseTask {
   final void Function(int port) callFfi;
   final S Function(dynamic) parseSuccessData;
 
+  /// Reconstructs the error of functions returning `Result<T, E>` with a custom `E`, which is
+  /// then thrown instead of a [FfiException].
+  final Object Function(dynamic)? parseErrorData;
+
   const FlutterRustBridgeTask({
     required this.callFfi,
     required this.parseSuccessData,
+    this.parseErrorData,
     required FlutterRustBridgeTaskConstMeta constMeta,
     required List<dynamic> argValues,
     required dynamic hint,
@@ -134,6 +187,39 @@ class FlutterRustBridgeSyncTask extends FlutterRustBridgeBaseTask {
         );
 }
 
+/// Receives the calls Rust makes to a Dart function given to a Rust function taking a
+/// `DartCallback`, until [close] is called once the task of the function ends.
+/// Note: Normally you do not manually create instances of this class, the generated code does.
+class RustCallback<R> {
+  final _receivePort = ReceivePort();
+
+  /// [call] calls the Dart function with the arguments Rust sends, then what it returns is given
+  /// to Rust with [reply]. If it throws, Rust is told with [fail], and the exception is rethrown
+  /// as an uncaught error.
+  RustCallback(
+    R Function(List<dynamic> args) call,
+    void Function(int callId, R value) reply,
+    void Function(int callId) fail,
+  ) {
+    _receivePort.listen((dynamic message) {
+      final callId = message[0] as int;
+      final R value;
+      try {
+        value = call((message as List<dynamic>).sublist(1));
+      } catch (_) {
+        fail(callId);
+        rethrow;
+      }
+      reply(callId, value);
+    });
+  }
+
+  /// The port Rust sends the calls to
+  int get port => _receivePort.sendPort.nativePort;
+
+  void close() => _receivePort.close();
+}
+
 /// This class, together with its subclasses, are only for internal usage.
 /// Usually it should not be used by normal users.
 abstract class FlutterRustBridgeWireBase {
@@ -68,3 +68,37 @@ class FlutterRustBridgeTimeoutException {
   String toString() =>
       'FlutterRustBridgeTimeoutException(debugName=$debugName,duration=$duration,stackTrace=$stackTrace)';
 }
+
+/// Cancels the tasks of the Rust functions it is given to, which take a `CancellationToken` in
+/// Rust. A cancelled task throws an [FfiException] with the code `CANCELLED_ERROR`, or ends its
+/// stream with it. Synchronous functions cannot be cancelled.
+class CancellationToken {
+  static var _lastId = 0;
+
+  /// Identifies the token in Rust
+  final int id = ++_lastId;
+
+  final _cancelTasks = <void Function(int)>{};
+  var _isCancelled = false;
+
+  bool get isCancelled => _isCancelled;
+
+  /// Cancels the tasks given the token, as well as those it is given afterwards
+  void cancel() {
+    if (_isCancelled) return;
+    _isCancelled = true;
+    for (final cancelTask in _cancelTasks) {
+      cancelTask(id);
+    }
+  }
+
+  /// Called by the generated code once it gave the token to a task, with the function of the
+  /// bridge which cancels the tasks of a token.
+  void attach(void Function(int id) cancelTask) {
+    if (_isCancelled) {
+      cancelTask(id);
+    } else {
+      _cancelTasks.add(cancelTask);
+    }
+  }
+}
@@ -1,11 +1,12 @@
 // AUTO GENERATED FILE, DO NOT EDIT.
 // Generated by `flutter_rust_bridge`.
 
-// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import
+// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import, deprecated_member_use_from_same_package
 
 import 'dart:convert';
 import 'dart:typed_data';
 import 'package:freezed_annotation/freezed_annotation.dart';
+import 'dart:ffi' as ffi;
 
 import 'dart:convert';
 import 'dart:typed_data';
@@ -18,23 +19,61 @@ abstract class FlutterRustBridgeExample {
   /// Documentation on a simple adder function.
   Future<int> simpleAdder({required int a, required int b, dynamic hint});
 
-  ///
   /// Multiline comments are fine,
   /// but they are not preferred in Rust nor in Dart.
   ///
   /// Newlines are preserved.
-  ///
   Future<int> primitiveTypes(
       {required int myI32, required int myI64, required double myF64, required bool myBool, dynamic hint});
 
   Future<int> primitiveU32({required int myU32, dynamic hint});
 
+  /// Values above 2^53 arrive exactly, since native Dart `int`s are 64-bit. A `u64` above 2^63 is
+  /// the negative `int` of the same bits.
+  Future<int> addToU64({required int value, required int delta, dynamic hint});
+
   Future<String> handleString({required String s, dynamic hint});
 
+  /// Characters cross the bridge as their code point, which Dart checks before sending
+  Future<int> nextChar({required int c, dynamic hint});
+
+  /// Durations cross the bridge as microseconds, which Dart checks to be positive before sending
+  Future<Duration> doubleDuration({required Duration duration, dynamic hint});
+
+  /// Awaits a slow computation on another thread, which wakes the function once done
+  Future<int> handleAsyncSum({required Int32List numbers, dynamic hint});
+
+  /// Calls the `async` fn above from another one
+  Future<double> handleAsyncAverage({required Int32List numbers, dynamic hint});
+
+  /// Called without a `Future`, while the arguments Rust cannot accept are still reported to Dart
+  int countChars({required String s, dynamic hint});
+
+  /// Paths cross the bridge as strings, to check that `PathBuf` and `&Path` are converted both ways
+  Future<String> joinPathParts({required List<String> parts, dynamic hint});
+
+  Future<String?> pathFileName({required String path, dynamic hint});
+
+  /// Zeros never reach Rust, Dart refusing them first
+  Future<int> nextNonZeroId({required int id, int? step, dynamic hint});
+
+  /// Nested JSON crosses the bridge as a string, decoded on both sides
+  Future<Object?> wrapJson({required Object? value, Object? tag, dynamic hint});
+
+  /// Generic functions are bridged once for each instantiation
+  Future<int> parseNumberI32({required String text, dynamic hint});
+
+  /// Generic functions are bridged once for each instantiation
+  Future<double> parseNumberF64({required String text, dynamic hint});
+
   Future<void> handleReturnUnit({dynamic hint});
 
   Future<Uint8List> handleVecU8({required Uint8List v, dynamic hint});
 
+  Future<Uint8List> handleBoxedSlice({required Uint8List bytes, dynamic hint});
+
+  Future<List<String>> handleBoxedStringSlice({required List<String> names, dynamic hint});
+
   Future<VecOfPrimitivePack> handleVecOfPrimitive({required int n, dynamic hint});
 
   Future<ZeroCopyVecOfPrimitivePack> handleZeroCopyVecOfPrimitive({required int n, dynamic hint});
@@ -47,18 +86,47 @@ abstract class FlutterRustBridgeExample {
 
   Future<List<String>> handleStringList({required List<String> names, dynamic hint});
 
+  Future<List<Float64List>> handleNestedList({required List<Float64List> matrix, dynamic hint});
+
+  Future<List<List<String>>> handleNestedStringList({required List<List<String>> lines, dynamic hint});
+
+  Future<Map<String, int>> handleHashMap({required Map<String, int> counts, dynamic hint});
+
+  Future<Map<String, int>> handleBtreeMap({required Map<int, String> names, dynamic hint});
+
+  Future<List<String>> handleIterator({required String text, dynamic hint});
+
+  Future<Int32List> handleFallibleIterator({required int n, dynamic hint});
+
+  /// Maps each item with a Dart function, failing if it throws.
+  Future<Int32List> handleCallback({required Int32List items, required int Function(int) transform, dynamic hint});
+
   Future<MyTreeNode> handleComplexStruct({required MyTreeNode s, dynamic hint});
 
+  /// A chain of `depth` nodes, each having the next one as its only child
+  Future<MyTreeNode> makeDeepTree({required int depth, dynamic hint});
+
+  /// The number of levels of the tree, counted level by level rather than by recursing
+  Future<int> treeDepth({required MyTreeNode tree, dynamic hint});
+
   Uint8List handleSyncReturn({required String mode, dynamic hint});
 
   Stream<String> handleStream({required String arg, dynamic hint});
 
   Future<int> returnErr({dynamic hint});
 
+  Future<void> returnCustomErr({required bool fail, dynamic hint});
+
+  /// Enum errors are thrown as the exception of their variant
+  Future<void> login({required String password, required int attempts, dynamic hint});
+
   Future<int> returnPanic({dynamic hint});
 
   Future<double?> handleOptionalReturn({required double left, required double right, dynamic hint});
 
+  Future<double?> handleOptionalPrimitives(
+      {required List<int?> values, int? offset, double? scale, bool? negate, dynamic hint});
+
   Future<Element?> handleOptionalStruct({String? document, dynamic hint});
 
   Future<ExoticOptionals?> handleOptionalIncrement({ExoticOptionals? opt, dynamic hint});
@@ -79,6 +147,10 @@ abstract class FlutterRustBridgeExample {
 
   Future<Weekdays> handleEnumParameter({required Weekdays weekday, dynamic hint});
 
+  Future<SparseLists> handleSparseLists({required SparseLists lists, dynamic hint});
+
+  Future<Priority> handleNonExhaustiveEnum({required Priority priority, dynamic hint});
+
   Future<void> handleCustomizedStruct({required Customized val, dynamic hint});
 
   Future<KitchenSink> handleEnumStruct({required KitchenSink val, dynamic hint});
@@ -86,6 +158,96 @@ abstract class FlutterRustBridgeExample {
   Future<bool> useImportedStruct({required MyStruct myStruct, dynamic hint});
 
   Future<bool> useImportedEnum({required MyEnum myEnum, dynamic hint});
+
+  Future<int> getInitCount({dynamic hint});
+
+  /// Writes back the count it read a bit earlier, which would lose the increments made in the
+  /// meantime if the calls did not take turns
+  Future<int> incrementLockedCount({dynamic hint});
+
+  Future<int> getLockedCount({dynamic hint});
+
+  /// Sleeps a bit between the checks of the token, for Dart to cancel the task in between
+  Future<int> countUntilCancelled({required int limit, CancellationToken? token, dynamic hint});
+
+  Future<Counter> newCounter({required int start, dynamic hint});
+
+  Future<int> counterValue({required Counter counter, dynamic hint});
+
+  Future<Counter?> newCounterIfPositive({required int start, dynamic hint});
+
+  Future<int> counterValueOrZero({Counter? counter, dynamic hint});
+
+  Future<Calculator> newCalculator({required int base, dynamic hint});
+
+  Future<Float64List> reverseFixedArray({required Float64List values, dynamic hint});
+
+  Future<List<MySize>> swapFixedSizes({required List<MySize> sizes, dynamic hint});
+
+  Future<TupleStringI32> swapTuple({required TupleI32String pair, dynamic hint});
+
+  Future<double> distance({required TupleF64F64 a, required TupleF64F64 b, dynamic hint});
+
+  Future<BigInt> incrementU128({required BigInt value, dynamic hint});
+
+  Future<BigInt> decrementI128({required BigInt value, dynamic hint});
+
+  Future<Set<String>> uniqueWords({required String text, dynamic hint});
+
+  Future<Set<int>> sortedEvens({required Set<int> values, dynamic hint});
+
+  Future<Uint8List> fillBuffer({required int len, required int value, dynamic hint});
+
+  Future<TupleInt32ListStringList> parseNumbers({required String input, dynamic hint});
+
+  Future<int> clampTo({required int value, required int max, dynamic hint});
+
+  Future<String> shout({required String text, required int times, dynamic hint});
+
+  Future<DateTime> addOneDay({required DateTime time, dynamic hint});
+
+  Future<DateTime> toLocalTime({required DateTime time, dynamic hint});
+
+  Future<DateTime> naiveWallClock({required DateTime time, dynamic hint});
+
+  Future<Uint8List> nilUuid({dynamic hint});
+
+  Future<int> uuidVersion({required Uint8List id, dynamic hint});
+
+  Future<int> counterIncrement({required Counter that, required int by, dynamic hint});
+
+  Future<int> calculatorAdd({required Calculator that, required int x, dynamic hint});
+
+  Future<int> calculatorAccumulate({required Calculator that, required int x, dynamic hint});
+
+  Future<int> calculatorIntoBase({required Calculator that, dynamic hint});
+
+  /// Releases the Rust object of a [Counter]. Prefer calling its `dispose` method instead.
+  void dropOpaqueCounter(Counter raw);
+
+  /// Initializes the Rust library, running the functions marked `#[frb(init)]` the first time
+  /// it is called. Later calls do nothing, so it is safe to call it from several places.
+  Future<void> init({dynamic hint});
+
+  /// Throws a [StateError] if the Rust library was generated from another version of the API
+  /// than this code, such as a stale build, instead of the functions failing in obscure ways.
+  /// Meant to be called once at startup.
+  void checkWireHash();
+
+  /// Tears down the bridge, such as between tests or before a hot restart: the streams still
+  /// open are closed, no function can be called anymore, and another instance of the API can
+  /// then be created. The calls already made still complete. Calling it again does nothing.
+  void dispose();
+}
+
+extension FlutterRustBridgeExampleStringExtension on String {
+  Future<String> shout(FlutterRustBridgeExample bridge, {required int times, dynamic hint}) =>
+      bridge.shout(text: this, times: times, hint: hint);
+}
+
+extension FlutterRustBridgeExampleIntExtension on int {
+  Future<int> clampTo(FlutterRustBridgeExample bridge, {required int max, dynamic hint}) =>
+      bridge.clampTo(value: this, max: max, hint: hint);
 }
 
 class Attribute {
@@ -98,9 +260,37 @@ class Attribute {
   });
 }
 
+class Calculator {
+  final FlutterRustBridgeExample bridge;
+  final int base;
+
+  Calculator({
+    required this.bridge,
+    required this.base,
+  });
+
+  Future<int> add({required int x, dynamic hint}) => bridge.calculatorAdd(that: this, x: x, hint: hint);
+
+  /// Rust receives a copy of this object, so changes made to it by the method are not visible here.
+  Future<int> accumulate({required int x, dynamic hint}) => bridge.calculatorAccumulate(that: this, x: x, hint: hint);
+
+  /// Rust takes ownership of a copy of this object, which stays usable afterwards.
+  Future<int> intoBase({dynamic hint}) => bridge.calculatorIntoBase(that: this, hint: hint);
+}
+
+class CustomError {
+  final String message;
+  final int code;
+
+  CustomError({
+    required this.message,
+    required this.code,
+  });
+}
+
 class Customized {
   final String finalField;
-  final String? nonFinalField;
+  String? nonFinalField;
 
   Customized({
     required this.finalField,
@@ -184,6 +374,13 @@ class KitchenSink with _$KitchenSink {
   ) = Enums;
 }
 
+enum LoginError {
+  WrongPassword,
+
+  /// Too many wrong passwords were given
+  Locked,
+}
+
 enum MyEnum {
   False,
   True,
@@ -224,8 +421,101 @@ class MyTreeNode {
 class NewTypeInt {
   final int field0;
 
-  NewTypeInt({
+  NewTypeInt(
+    this.field0,
+  );
+}
+
+/// Stays in Rust, Dart only holding a handle to it
+class Counter implements ffi.Finalizable {
+  final FlutterRustBridgeExample bridge;
+
+  /// The address of the Rust object, which stays alive until [dispose] is called.
+  final int address;
+
+  bool _isDisposed = false;
+
+  Counter({required this.bridge, required this.address});
+
+  /// Whether [dispose] was called, after which this object can no longer be passed to Rust.
+  bool get isDisposed => _isDisposed;
+
+  /// Releases the Rust object without waiting for this object to be garbage collected. Calls that are still running keep it alive until they finish.
+  void dispose() {
+    if (_isDisposed) return;
+    _isDisposed = true;
+    bridge.dropOpaqueCounter(this);
+  }
+
+  Future<int> increment({required int by, dynamic hint}) => bridge.counterIncrement(that: this, by: by, hint: hint);
+}
+
+/// Enums that may gain variants, which Dart then reads as `unknown`.
+enum Priority {
+  Low,
+  High,
+
+  /// A variant the `#[non_exhaustive]` Rust enum has gained since this code was generated.
+  /// It cannot be sent to Rust.
+  unknown,
+}
+
+/// Sparse data, where any item may be missing.
+class SparseLists {
+  final List<double?> values;
+  final List<String?> labels;
+  final List<Weekdays?> weekdays;
+  final List<Attribute?> attributes;
+
+  SparseLists({
+    required this.values,
+    required this.labels,
+    required this.weekdays,
+    required this.attributes,
+  });
+}
+
+/// The Rust tuple `(f64, f64)`.
+class TupleF64F64 {
+  final double field0;
+  final double field1;
+
+  TupleF64F64({
+    required this.field0,
+    required this.field1,
+  });
+}
+
+/// The Rust tuple `(i32, String)`.
+class TupleI32String {
+  final int field0;
+  final String field1;
+
+  TupleI32String({
+    required this.field0,
+    required this.field1,
+  });
+}
+
+/// The Rust tuple `(Vec<i32>, Vec<String>)`.
+class TupleInt32ListStringList {
+  final Int32List field0;
+  final List<String> field1;
+
+  TupleInt32ListStringList({
+    required this.field0,
+    required this.field1,
+  });
+}
+
+/// The Rust tuple `(String, i32)`.
+class TupleStringI32 {
+  final String field0;
+  final int field1;
+
+  TupleStringI32({
     required this.field0,
+    required this.field1,
   });
 }
 
@@ -294,6 +584,32 @@ class ZeroCopyVecOfPrimitivePack {
   });
 }
 
+/// Thrown when Rust returns a [LoginError] as an error, as the subclass of its variant.
+abstract class LoginErrorException implements Exception {
+  const LoginErrorException._();
+
+  /// The error returned by Rust
+  LoginError get error;
+
+  @override
+  String toString() => 'LoginErrorException($error)';
+}
+
+class LoginErrorWrongPasswordException extends LoginErrorException {
+  @override
+  final LoginError error;
+
+  const LoginErrorWrongPasswordException(this.error) : super._();
+}
+
+/// Too many wrong passwords were given
+class LoginErrorLockedException extends LoginErrorException {
+  @override
+  final LoginError error;
+
+  const LoginErrorLockedException(this.error) : super._();
+}
+
 class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBridgeExampleWire>
     implements FlutterRustBridgeExample {
   factory FlutterRustBridgeExampleImpl(ffi.DynamicLibrary dylib) =>
@@ -337,6 +653,17 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
+  Future<int> addToU64({required int value, required int delta, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_add_to_u64(port_, _api2wire_u64(value), _api2wire_i64(delta)),
+        parseSuccessData: _wire2api_u64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "add_to_u64",
+          argNames: ["value", "delta"],
+        ),
+        argValues: [value, delta],
+        hint: hint,
+      ));
+
   Future<String> handleString({required String s, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
         callFfi: (port_) => inner.wire_handle_string(port_, _api2wire_String(s)),
         parseSuccessData: _wire2api_String,
@@ -348,6 +675,127 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
+  Future<int> nextChar({required int c, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_next_char(port_, _api2wire_Char(c)),
+        parseSuccessData: _wire2api_Char,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "next_char",
+          argNames: ["c"],
+        ),
+        argValues: [c],
+        hint: hint,
+      ));
+
+  Future<Duration> doubleDuration({required Duration duration, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_double_duration(port_, _api2wire_Duration(duration)),
+        parseSuccessData: _wire2api_Duration,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "double_duration",
+          argNames: ["duration"],
+        ),
+        argValues: [duration],
+        hint: hint,
+      ));
+
+  Future<int> handleAsyncSum({required Int32List numbers, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_async_sum(port_, _api2wire_int_32_list(numbers)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_async_sum",
+          argNames: ["numbers"],
+        ),
+        argValues: [numbers],
+        hint: hint,
+      ));
+
+  Future<double> handleAsyncAverage({required Int32List numbers, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_async_average(port_, _api2wire_int_32_list(numbers)),
+        parseSuccessData: _wire2api_f64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_async_average",
+          argNames: ["numbers"],
+        ),
+        argValues: [numbers],
+        hint: hint,
+      ));
+
+  int countChars({required String s, dynamic hint}) => ByteData.sublistView(executeSync(FlutterRustBridgeSyncTask(
+        callFfi: () => inner.wire_count_chars(_api2wire_String(s)),
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "count_chars",
+          argNames: ["s"],
+        ),
+        argValues: [s],
+        hint: hint,
+      ))).getUint32(0, Endian.host);
+
+  Future<String> joinPathParts({required List<String> parts, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_join_path_parts(port_, _api2wire_list_PathBuf(parts)),
+        parseSuccessData: _wire2api_PathBuf,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "join_path_parts",
+          argNames: ["parts"],
+        ),
+        argValues: [parts],
+        hint: hint,
+      ));
+
+  Future<String?> pathFileName({required String path, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_path_file_name(port_, _api2wire_Path(path)),
+        parseSuccessData: _wire2api_opt_String,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "path_file_name",
+          argNames: ["path"],
+        ),
+        argValues: [path],
+        hint: hint,
+      ));
+
+  Future<int> nextNonZeroId({required int id, int? step, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) =>
+            inner.wire_next_non_zero_id(port_, _api2wire_NonZero_u32(id), _api2wire_opt_box_autoadd_NonZero_u8(step)),
+        parseSuccessData: _wire2api_NonZero_u32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "next_non_zero_id",
+          argNames: ["id", "step"],
+        ),
+        argValues: [id, step],
+        hint: hint,
+      ));
+
+  Future<Object?> wrapJson({required Object? value, Object? tag, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_wrap_json(port_, _api2wire_Json(value), _api2wire_opt_Json(tag)),
+        parseSuccessData: _wire2api_Json,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "wrap_json",
+          argNames: ["value", "tag"],
+        ),
+        argValues: [value, tag],
+        hint: hint,
+      ));
+
+  Future<int> parseNumberI32({required String text, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_parse_number_i32(port_, _api2wire_String(text)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "parse_number_i32",
+          argNames: ["text"],
+        ),
+        argValues: [text],
+        hint: hint,
+      ));
+
+  Future<double> parseNumberF64({required String text, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_parse_number_f64(port_, _api2wire_String(text)),
+        parseSuccessData: _wire2api_f64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "parse_number_f64",
+          argNames: ["text"],
+        ),
+        argValues: [text],
+        hint: hint,
+      ));
+
   Future<void> handleReturnUnit({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
         callFfi: (port_) => inner.wire_handle_return_unit(port_),
         parseSuccessData: _wire2api_unit,
@@ -370,6 +818,29 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
+  Future<Uint8List> handleBoxedSlice({required Uint8List bytes, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_boxed_slice(port_, _api2wire_BoxedSlice_uint_8_list(bytes)),
+        parseSuccessData: _wire2api_BoxedSlice_uint_8_list,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_boxed_slice",
+          argNames: ["bytes"],
+        ),
+        argValues: [bytes],
+        hint: hint,
+      ));
+
+  Future<List<String>> handleBoxedStringSlice({required List<String> names, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_boxed_string_slice(port_, _api2wire_BoxedSlice_StringList(names)),
+        parseSuccessData: _wire2api_BoxedSlice_StringList,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_boxed_string_slice",
+          argNames: ["names"],
+        ),
+        argValues: [names],
+        hint: hint,
+      ));
+
   Future<VecOfPrimitivePack> handleVecOfPrimitive({required int n, dynamic hint}) =>
       executeNormal(FlutterRustBridgeTask(
         callFfi: (port_) => inner.wire_handle_vec_of_primitive(port_, _api2wire_i32(n)),
@@ -442,77 +913,225 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
-  Future<MyTreeNode> handleComplexStruct({required MyTreeNode s, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_handle_complex_struct(port_, _api2wire_box_autoadd_my_tree_node(s)),
-        parseSuccessData: _wire2api_my_tree_node,
+  Future<List<Float64List>> handleNestedList({required List<Float64List> matrix, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_nested_list(port_, _api2wire_list_float_64_list(matrix)),
+        parseSuccessData: _wire2api_list_float_64_list,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "handle_complex_struct",
-          argNames: ["s"],
+          debugName: "handle_nested_list",
+          argNames: ["matrix"],
         ),
-        argValues: [s],
+        argValues: [matrix],
         hint: hint,
       ));
 
-  Uint8List handleSyncReturn({required String mode, dynamic hint}) => executeSync(FlutterRustBridgeSyncTask(
-        callFfi: () => inner.wire_handle_sync_return(_api2wire_String(mode)),
+  Future<List<List<String>>> handleNestedStringList({required List<List<String>> lines, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_nested_string_list(port_, _api2wire_list_StringList(lines)),
+        parseSuccessData: _wire2api_list_StringList,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "handle_sync_return",
-          argNames: ["mode"],
+          debugName: "handle_nested_string_list",
+          argNames: ["lines"],
         ),
-        argValues: [mode],
+        argValues: [lines],
         hint: hint,
       ));
 
-  Stream<String> handleStream({required String arg, dynamic hint}) => executeStream(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_handle_stream(port_, _api2wire_String(arg)),
-        parseSuccessData: _wire2api_String,
+  Future<Map<String, int>> handleHashMap({required Map<String, int> counts, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_hash_map(port_, _api2wire_hash_map_String_i32(counts)),
+        parseSuccessData: _wire2api_hash_map_String_i32,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "handle_stream",
-          argNames: ["arg"],
+          debugName: "handle_hash_map",
+          argNames: ["counts"],
         ),
-        argValues: [arg],
+        argValues: [counts],
         hint: hint,
       ));
 
-  Future<int> returnErr({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_return_err(port_),
-        parseSuccessData: _wire2api_i32,
+  Future<Map<String, int>> handleBtreeMap({required Map<int, String> names, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_btree_map(port_, _api2wire_btree_map_i32_String(names)),
+        parseSuccessData: _wire2api_btree_map_String_i32,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "return_err",
-          argNames: [],
+          debugName: "handle_btree_map",
+          argNames: ["names"],
         ),
-        argValues: [],
+        argValues: [names],
         hint: hint,
       ));
 
-  Future<int> returnPanic({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_return_panic(port_),
-        parseSuccessData: _wire2api_i32,
+  Future<List<String>> handleIterator({required String text, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_iterator(port_, _api2wire_String(text)),
+        parseSuccessData: _wire2api_StringList,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "return_panic",
-          argNames: [],
+          debugName: "handle_iterator",
+          argNames: ["text"],
         ),
-        argValues: [],
+        argValues: [text],
         hint: hint,
       ));
 
-  Future<double?> handleOptionalReturn({required double left, required double right, dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_handle_optional_return(port_, _api2wire_f64(left), _api2wire_f64(right)),
-        parseSuccessData: _wire2api_opt_box_autoadd_f64,
+  Future<Int32List> handleFallibleIterator({required int n, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_fallible_iterator(port_, _api2wire_i32(n)),
+        parseSuccessData: _wire2api_int_32_list,
         constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "handle_optional_return",
-          argNames: ["left", "right"],
+          debugName: "handle_fallible_iterator",
+          argNames: ["n"],
         ),
-        argValues: [left, right],
+        argValues: [n],
         hint: hint,
       ));
 
-  Future<Element?> handleOptionalStruct({String? document, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
-        callFfi: (port_) => inner.wire_handle_optional_struct(port_, _api2wire_opt_String(document)),
-        parseSuccessData: _wire2api_opt_box_autoadd_element,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "handle_optional_struct",
+  Future<Int32List> handleCallback({required Int32List items, required int Function(int) transform, dynamic hint}) {
+    final transformCallback =
+        RustCallback<int>((args) => transform(args[0] as int), inner.reply_callback_i32, inner.fail_callback);
+    return executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => inner.wire_handle_callback(port_, _api2wire_int_32_list(items), transformCallback.port),
+      parseSuccessData: _wire2api_int_32_list,
+      constMeta: const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_callback",
+        argNames: ["items"],
+      ),
+      argValues: [items],
+      hint: hint,
+    )).whenComplete(() {
+      transformCallback.close();
+    });
+  }
+
+  Future<MyTreeNode> handleComplexStruct({required MyTreeNode s, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_complex_struct(port_, _api2wire_box_autoadd_my_tree_node(s)),
+        parseSuccessData: _wire2api_my_tree_node,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_complex_struct",
+          argNames: ["s"],
+        ),
+        argValues: [s],
+        hint: hint,
+      ));
+
+  Future<MyTreeNode> makeDeepTree({required int depth, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_make_deep_tree(port_, _api2wire_i32(depth)),
+        parseSuccessData: _wire2api_my_tree_node,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "make_deep_tree",
+          argNames: ["depth"],
+        ),
+        argValues: [depth],
+        hint: hint,
+      ));
+
+  Future<int> treeDepth({required MyTreeNode tree, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_tree_depth(port_, _api2wire_box_autoadd_my_tree_node(tree)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "tree_depth",
+          argNames: ["tree"],
+        ),
+        argValues: [tree],
+        hint: hint,
+      ));
+
+  Uint8List handleSyncReturn({required String mode, dynamic hint}) => executeSync(FlutterRustBridgeSyncTask(
+        callFfi: () => inner.wire_handle_sync_return(_api2wire_String(mode)),
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_sync_return",
+          argNames: ["mode"],
+        ),
+        argValues: [mode],
+        hint: hint,
+      ));
+
+  Stream<String> handleStream({required String arg, dynamic hint}) => executeStream(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_stream(port_, _api2wire_String(arg)),
+        parseSuccessData: _wire2api_String,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_stream",
+          argNames: ["arg"],
+        ),
+        argValues: [arg],
+        hint: hint,
+      ));
+
+  Future<int> returnErr({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_return_err(port_),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "return_err",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
+
+  Future<void> returnCustomErr({required bool fail, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_return_custom_err(port_, fail),
+        parseSuccessData: _wire2api_unit,
+        parseErrorData: _wire2api_custom_error,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "return_custom_err",
+          argNames: ["fail"],
+        ),
+        argValues: [fail],
+        hint: hint,
+      ));
+
+  Future<void> login({required String password, required int attempts, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_login(port_, _api2wire_String(password), _api2wire_u32(attempts)),
+        parseSuccessData: _wire2api_unit,
+        parseErrorData: _wire2api_login_error_exception,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "login",
+          argNames: ["password", "attempts"],
+        ),
+        argValues: [password, attempts],
+        hint: hint,
+      ));
+
+  Future<int> returnPanic({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_return_panic(port_),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "return_panic",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
+
+  Future<double?> handleOptionalReturn({required double left, required double right, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_optional_return(port_, _api2wire_f64(left), _api2wire_f64(right)),
+        parseSuccessData: _wire2api_opt_f64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_optional_return",
+          argNames: ["left", "right"],
+        ),
+        argValues: [left, right],
+        hint: hint,
+      ));
+
+  Future<double?> handleOptionalPrimitives(
+          {required List<int?> values, int? offset, double? scale, bool? negate, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_optional_primitives(port_, _api2wire_list_opt_i32(values),
+            _api2wire_opt_i64(offset), _api2wire_opt_f64(scale), _api2wire_opt_bool(negate)),
+        parseSuccessData: _wire2api_opt_f64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_optional_primitives",
+          argNames: ["values", "offset", "scale", "negate"],
+        ),
+        argValues: [values, offset, scale, negate],
+        hint: hint,
+      ));
+
+  Future<Element?> handleOptionalStruct({String? document, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_optional_struct(port_, _api2wire_opt_String(document)),
+        parseSuccessData: _wire2api_opt_box_autoadd_element,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_optional_struct",
           argNames: ["document"],
         ),
         argValues: [document],
@@ -573,7 +1192,7 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
 
   Future<Weekdays?> handleReturnEnum({required String input, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
         callFfi: (port_) => inner.wire_handle_return_enum(port_, _api2wire_String(input)),
-        parseSuccessData: _wire2api_opt_weekdays,
+        parseSuccessData: _wire2api_opt_box_autoadd_weekdays,
         constMeta: const FlutterRustBridgeTaskConstMeta(
           debugName: "handle_return_enum",
           argNames: ["input"],
@@ -594,6 +1213,30 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
+  Future<SparseLists> handleSparseLists({required SparseLists lists, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_sparse_lists(port_, _api2wire_box_autoadd_sparse_lists(lists)),
+        parseSuccessData: _wire2api_sparse_lists,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_sparse_lists",
+          argNames: ["lists"],
+        ),
+        argValues: [lists],
+        hint: hint,
+      ));
+
+  Future<Priority> handleNonExhaustiveEnum({required Priority priority, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_handle_non_exhaustive_enum(port_, _api2wire_priority(priority)),
+        parseSuccessData: _wire2api_priority,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "handle_non_exhaustive_enum",
+          argNames: ["priority"],
+        ),
+        argValues: [priority],
+        hint: hint,
+      ));
+
   Future<void> handleCustomizedStruct({required Customized val, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
         callFfi: (port_) => inner.wire_handle_customized_struct(port_, _api2wire_box_autoadd_customized(val)),
         parseSuccessData: _wire2api_unit,
@@ -638,90 +1281,530 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
-  // Section: api2wire
-  ffi.Pointer<wire_uint_8_list> _api2wire_String(String raw) {
-    return _api2wire_uint_8_list(utf8.encoder.convert(raw));
-  }
-
-  ffi.Pointer<wire_StringList> _api2wire_StringList(List<String> raw) {
-    final ans = inner.new_StringList(raw.length);
-    for (var i = 0; i < raw.length; i++) {
-      ans.ref.ptr[i] = _api2wire_String(raw[i]);
-    }
-    return ans;
-  }
+  Future<int> getInitCount({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_get_init_count(port_),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "get_init_count",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_uint_8_list> _api2wire_ZeroCopyBuffer_Uint8List(Uint8List raw) {
-    return _api2wire_uint_8_list(raw);
-  }
+  Future<int> incrementLockedCount({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_increment_locked_count(port_),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "increment_locked_count",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
 
-  int _api2wire_bool(bool raw) {
-    return raw ? 1 : 0;
-  }
+  Future<int> getLockedCount({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_get_locked_count(port_),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "get_locked_count",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_Attribute> _api2wire_box_autoadd_attribute(Attribute raw) {
-    final ptr = inner.new_box_autoadd_attribute();
-    _api_fill_to_wire_attribute(raw, ptr.ref);
-    return ptr;
-  }
+  Future<int> countUntilCancelled({required int limit, CancellationToken? token, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) {
+          inner.wire_count_until_cancelled(port_, _api2wire_u32(limit), token?.id ?? 0);
+          token?.attach(inner.cancel_task);
+        },
+        parseSuccessData: _wire2api_u32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "count_until_cancelled",
+          argNames: ["limit"],
+        ),
+        argValues: [limit],
+        hint: hint,
+      ));
 
-  ffi.Pointer<ffi.Uint8> _api2wire_box_autoadd_bool(bool raw) {
-    return inner.new_box_autoadd_bool(raw);
-  }
+  Future<Counter> newCounter({required int start, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_new_counter(port_, _api2wire_i32(start)),
+        parseSuccessData: _wire2api_opaque_counter,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "new_counter",
+          argNames: ["start"],
+        ),
+        argValues: [start],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_Customized> _api2wire_box_autoadd_customized(Customized raw) {
-    final ptr = inner.new_box_autoadd_customized();
-    _api_fill_to_wire_customized(raw, ptr.ref);
-    return ptr;
-  }
+  Future<int> counterValue({required Counter counter, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_counter_value(port_, _api2wire_opaque_counter(counter)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "counter_value",
+          argNames: ["counter"],
+        ),
+        argValues: [counter],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_ExoticOptionals> _api2wire_box_autoadd_exotic_optionals(ExoticOptionals raw) {
-    final ptr = inner.new_box_autoadd_exotic_optionals();
-    _api_fill_to_wire_exotic_optionals(raw, ptr.ref);
-    return ptr;
-  }
+  Future<Counter?> newCounterIfPositive({required int start, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_new_counter_if_positive(port_, _api2wire_i32(start)),
+        parseSuccessData: _wire2api_opt_opaque_counter,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "new_counter_if_positive",
+          argNames: ["start"],
+        ),
+        argValues: [start],
+        hint: hint,
+      ));
 
-  ffi.Pointer<ffi.Double> _api2wire_box_autoadd_f64(double raw) {
-    return inner.new_box_autoadd_f64(raw);
-  }
+  Future<int> counterValueOrZero({Counter? counter, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_counter_value_or_zero(port_, _api2wire_opt_opaque_counter(counter)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "counter_value_or_zero",
+          argNames: ["counter"],
+        ),
+        argValues: [counter],
+        hint: hint,
+      ));
 
-  ffi.Pointer<ffi.Int32> _api2wire_box_autoadd_i32(int raw) {
-    return inner.new_box_autoadd_i32(raw);
-  }
+  Future<Calculator> newCalculator({required int base, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_new_calculator(port_, _api2wire_i32(base)),
+        parseSuccessData: _wire2api_calculator,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "new_calculator",
+          argNames: ["base"],
+        ),
+        argValues: [base],
+        hint: hint,
+      ));
 
-  ffi.Pointer<ffi.Int64> _api2wire_box_autoadd_i64(int raw) {
-    return inner.new_box_autoadd_i64(raw);
-  }
+  Future<Float64List> reverseFixedArray({required Float64List values, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_reverse_fixed_array(port_, _api2wire_f64_array_4(values)),
+        parseSuccessData: _wire2api_f64_array_4,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "reverse_fixed_array",
+          argNames: ["values"],
+        ),
+        argValues: [values],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_KitchenSink> _api2wire_box_autoadd_kitchen_sink(KitchenSink raw) {
-    final ptr = inner.new_box_autoadd_kitchen_sink();
-    _api_fill_to_wire_kitchen_sink(raw, ptr.ref);
-    return ptr;
-  }
+  Future<List<MySize>> swapFixedSizes({required List<MySize> sizes, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_swap_fixed_sizes(port_, _api2wire_my_size_array_2(sizes)),
+        parseSuccessData: _wire2api_my_size_array_2,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "swap_fixed_sizes",
+          argNames: ["sizes"],
+        ),
+        argValues: [sizes],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_MySize> _api2wire_box_autoadd_my_size(MySize raw) {
-    final ptr = inner.new_box_autoadd_my_size();
-    _api_fill_to_wire_my_size(raw, ptr.ref);
-    return ptr;
-  }
+  Future<TupleStringI32> swapTuple({required TupleI32String pair, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_swap_tuple(port_, _api2wire_box_autoadd_tuple_i_32_string(pair)),
+        parseSuccessData: _wire2api_tuple_string_i_32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "swap_tuple",
+          argNames: ["pair"],
+        ),
+        argValues: [pair],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_MyStruct> _api2wire_box_autoadd_my_struct(MyStruct raw) {
-    final ptr = inner.new_box_autoadd_my_struct();
-    _api_fill_to_wire_my_struct(raw, ptr.ref);
-    return ptr;
-  }
+  Future<double> distance({required TupleF64F64 a, required TupleF64F64 b, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_distance(
+            port_, _api2wire_box_autoadd_tuple_f_64_f_64(a), _api2wire_box_autoadd_tuple_f_64_f_64(b)),
+        parseSuccessData: _wire2api_f64,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "distance",
+          argNames: ["a", "b"],
+        ),
+        argValues: [a, b],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_MyTreeNode> _api2wire_box_autoadd_my_tree_node(MyTreeNode raw) {
-    final ptr = inner.new_box_autoadd_my_tree_node();
-    _api_fill_to_wire_my_tree_node(raw, ptr.ref);
-    return ptr;
-  }
+  Future<BigInt> incrementU128({required BigInt value, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_increment_u128(port_, _api2wire_u128(value)),
+        parseSuccessData: _wire2api_u128,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "increment_u128",
+          argNames: ["value"],
+        ),
+        argValues: [value],
+        hint: hint,
+      ));
 
-  ffi.Pointer<wire_NewTypeInt> _api2wire_box_autoadd_new_type_int(NewTypeInt raw) {
-    final ptr = inner.new_box_autoadd_new_type_int();
-    _api_fill_to_wire_new_type_int(raw, ptr.ref);
-    return ptr;
-  }
+  Future<BigInt> decrementI128({required BigInt value, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_decrement_i128(port_, _api2wire_i128(value)),
+        parseSuccessData: _wire2api_i128,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "decrement_i128",
+          argNames: ["value"],
+        ),
+        argValues: [value],
+        hint: hint,
+      ));
+
+  Future<Set<String>> uniqueWords({required String text, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_unique_words(port_, _api2wire_String(text)),
+        parseSuccessData: _wire2api_hash_set_String,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "unique_words",
+          argNames: ["text"],
+        ),
+        argValues: [text],
+        hint: hint,
+      ));
+
+  Future<Set<int>> sortedEvens({required Set<int> values, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_sorted_evens(port_, _api2wire_hash_set_i32(values)),
+        parseSuccessData: _wire2api_btree_set_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "sorted_evens",
+          argNames: ["values"],
+        ),
+        argValues: [values],
+        hint: hint,
+      ));
+
+  Future<Uint8List> fillBuffer({required int len, required int value, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_fill_buffer(port_, _api2wire_u32(len), _api2wire_u8(value)),
+        parseSuccessData: _wire2api_uint_8_list,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "fill_buffer",
+          argNames: ["len", "value"],
+        ),
+        argValues: [len, value],
+        hint: hint,
+      ));
+
+  Future<TupleInt32ListStringList> parseNumbers({required String input, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_parse_numbers(port_, _api2wire_String(input)),
+        parseSuccessData: _wire2api_tuple_int_32_list_string_list,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "parse_numbers",
+          argNames: ["input"],
+        ),
+        argValues: [input],
+        hint: hint,
+      ));
+
+  Future<int> clampTo({required int value, required int max, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_clamp_to(port_, _api2wire_i32(value), _api2wire_i32(max)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "clamp_to",
+          argNames: ["value", "max"],
+        ),
+        argValues: [value, max],
+        hint: hint,
+      ));
+
+  Future<String> shout({required String text, required int times, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_shout(port_, _api2wire_String(text), _api2wire_u32(times)),
+        parseSuccessData: _wire2api_String,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "shout",
+          argNames: ["text", "times"],
+        ),
+        argValues: [text, times],
+        hint: hint,
+      ));
+
+  Future<DateTime> addOneDay({required DateTime time, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_add_one_day(port_, _api2wire_Chrono_Utc(time)),
+        parseSuccessData: _wire2api_Chrono_Utc,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "add_one_day",
+          argNames: ["time"],
+        ),
+        argValues: [time],
+        hint: hint,
+      ));
+
+  Future<DateTime> toLocalTime({required DateTime time, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_to_local_time(port_, _api2wire_Chrono_Utc(time)),
+        parseSuccessData: _wire2api_Chrono_Local,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "to_local_time",
+          argNames: ["time"],
+        ),
+        argValues: [time],
+        hint: hint,
+      ));
+
+  Future<DateTime> naiveWallClock({required DateTime time, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_naive_wall_clock(port_, _api2wire_Chrono_Utc(time)),
+        parseSuccessData: _wire2api_Chrono_Naive,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "naive_wall_clock",
+          argNames: ["time"],
+        ),
+        argValues: [time],
+        hint: hint,
+      ));
+
+  Future<Uint8List> nilUuid({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_nil_uuid(port_),
+        parseSuccessData: _wire2api_Uuid,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "nil_uuid",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
+
+  Future<int> uuidVersion({required Uint8List id, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_uuid_version(port_, _api2wire_Uuid(id)),
+        parseSuccessData: _wire2api_u32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "uuid_version",
+          argNames: ["id"],
+        ),
+        argValues: [id],
+        hint: hint,
+      ));
+
+  Future<int> counterIncrement({required Counter that, required int by, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_counter_increment(port_, _api2wire_opaque_counter(that), _api2wire_i32(by)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "counter_increment",
+          argNames: ["that", "by"],
+        ),
+        argValues: [that, by],
+        hint: hint,
+      ));
+
+  Future<int> calculatorAdd({required Calculator that, required int x, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_calculator_add(port_, _api2wire_box_autoadd_calculator(that), _api2wire_i32(x)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "calculator_add",
+          argNames: ["that", "x"],
+        ),
+        argValues: [that, x],
+        hint: hint,
+      ));
+
+  Future<int> calculatorAccumulate({required Calculator that, required int x, dynamic hint}) =>
+      executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) =>
+            inner.wire_calculator_accumulate(port_, _api2wire_box_autoadd_calculator(that), _api2wire_i32(x)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "calculator_accumulate",
+          argNames: ["that", "x"],
+        ),
+        argValues: [that, x],
+        hint: hint,
+      ));
+
+  Future<int> calculatorIntoBase({required Calculator that, dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_calculator_into_base(port_, _api2wire_box_autoadd_calculator(that)),
+        parseSuccessData: _wire2api_i32,
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "calculator_into_base",
+          argNames: ["that"],
+        ),
+        argValues: [that],
+        hint: hint,
+      ));
+
+  late final _opaqueCounterFinalizer =
+      ffi.NativeFinalizer(inner._lookup<ffi.NativeFinalizerFunction>('drop_opaque_Counter'));
+
+  void dropOpaqueCounter(Counter raw) {
+    _opaqueCounterFinalizer.detach(raw);
+    inner.drop_opaque_Counter(ffi.Pointer.fromAddress(raw.address));
+  }
+
+  Future<void> init({dynamic hint}) => executeNormal(FlutterRustBridgeTask(
+        callFfi: (port_) => inner.wire_init(port_),
+        parseSuccessData: (_) {},
+        constMeta: const FlutterRustBridgeTaskConstMeta(
+          debugName: "init",
+          argNames: [],
+        ),
+        argValues: [],
+        hint: hint,
+      ));
+
+  static const wireHash = 0xadf8cb7a;
+
+  void checkWireHash() {
+    final rustWireHash = inner.get_wire_hash();
+    if (rustWireHash != wireHash) {
+      throw StateError(
+          'The Rust library was generated from another version of the API than this Dart code: its wire hash is 0x${rustWireHash.toRadixString(16)}, while 0x${wireHash.toRadixString(16)} was expected. Generate the code again, then rebuild the Rust library.');
+    }
+  }
+
+  // Section: api2wire
+  ffi.Pointer<wire_StringList> _api2wire_BoxedSlice_StringList(List<String> raw) {
+    return _api2wire_StringList(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_BoxedSlice_uint_8_list(Uint8List raw) {
+    return _api2wire_uint_8_list(raw);
+  }
+
+  int _api2wire_Char(int raw) {
+    if (raw < 0 || raw > 0x10FFFF || (raw >= 0xD800 && raw <= 0xDFFF)) {
+      throw ArgumentError.value(raw, 'raw', 'Not a Unicode scalar value');
+    }
+    return raw;
+  }
+
+  int _api2wire_Chrono_Utc(DateTime raw) {
+    return raw.microsecondsSinceEpoch;
+  }
+
+  int _api2wire_Duration(Duration raw) {
+    if (raw.isNegative) throw ArgumentError.value(raw, 'raw', 'Rust durations cannot be negative');
+    return raw.inMicroseconds;
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_Json(Object? raw) {
+    return _api2wire_String(jsonEncode(raw));
+  }
+
+  int _api2wire_NonZero_u32(int raw) {
+    if (raw == 0) throw ArgumentError.value(raw, 'raw', 'Must not be zero');
+    return _api2wire_u32(raw);
+  }
+
+  int _api2wire_NonZero_u8(int raw) {
+    if (raw == 0) throw ArgumentError.value(raw, 'raw', 'Must not be zero');
+    return _api2wire_u8(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_Path(String raw) {
+    return _api2wire_String(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_PathBuf(String raw) {
+    return _api2wire_String(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_String(String raw) {
+    return _api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  ffi.Pointer<wire_StringList> _api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = _api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_Uuid(Uint8List raw) {
+    if (raw.length != 16) throw ArgumentError.value(raw, 'raw', 'A UUID has 16 bytes');
+    return _api2wire_uint_8_list(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_ZeroCopyBuffer_Uint8List(Uint8List raw) {
+    return _api2wire_uint_8_list(raw);
+  }
+
+  int _api2wire_bool(bool raw) {
+    return raw ? 1 : 0;
+  }
+
+  ffi.Pointer<ffi.Uint8> _api2wire_box_autoadd_NonZero_u8(int raw) {
+    return inner.new_box_autoadd_NonZero_u8(_api2wire_NonZero_u8(raw));
+  }
+
+  ffi.Pointer<wire_Attribute> _api2wire_box_autoadd_attribute(Attribute raw) {
+    final ptr = inner.new_box_autoadd_attribute();
+    _api_fill_to_wire_attribute(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_Calculator> _api2wire_box_autoadd_calculator(Calculator raw) {
+    final ptr = inner.new_box_autoadd_calculator();
+    _api_fill_to_wire_calculator(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_Customized> _api2wire_box_autoadd_customized(Customized raw) {
+    final ptr = inner.new_box_autoadd_customized();
+    _api_fill_to_wire_customized(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_ExoticOptionals> _api2wire_box_autoadd_exotic_optionals(ExoticOptionals raw) {
+    final ptr = inner.new_box_autoadd_exotic_optionals();
+    _api_fill_to_wire_exotic_optionals(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_KitchenSink> _api2wire_box_autoadd_kitchen_sink(KitchenSink raw) {
+    final ptr = inner.new_box_autoadd_kitchen_sink();
+    _api_fill_to_wire_kitchen_sink(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_MySize> _api2wire_box_autoadd_my_size(MySize raw) {
+    final ptr = inner.new_box_autoadd_my_size();
+    _api_fill_to_wire_my_size(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_MyStruct> _api2wire_box_autoadd_my_struct(MyStruct raw) {
+    final ptr = inner.new_box_autoadd_my_struct();
+    _api_fill_to_wire_my_struct(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_MyTreeNode> _api2wire_box_autoadd_my_tree_node(MyTreeNode raw) {
+    final ptr = inner.new_box_autoadd_my_tree_node();
+    _api_fill_to_wire_my_tree_node(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_NewTypeInt> _api2wire_box_autoadd_new_type_int(NewTypeInt raw) {
+    final ptr = inner.new_box_autoadd_new_type_int();
+    _api_fill_to_wire_new_type_int(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_SparseLists> _api2wire_box_autoadd_sparse_lists(SparseLists raw) {
+    final ptr = inner.new_box_autoadd_sparse_lists();
+    _api_fill_to_wire_sparse_lists(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_TupleF64F64> _api2wire_box_autoadd_tuple_f_64_f_64(TupleF64F64 raw) {
+    final ptr = inner.new_box_autoadd_tuple_f_64_f_64();
+    _api_fill_to_wire_tuple_f_64_f_64(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<wire_TupleI32String> _api2wire_box_autoadd_tuple_i_32_string(TupleI32String raw) {
+    final ptr = inner.new_box_autoadd_tuple_i_32_string();
+    _api_fill_to_wire_tuple_i_32_string(raw, ptr.ref);
+    return ptr;
+  }
+
+  ffi.Pointer<ffi.Int32> _api2wire_box_autoadd_weekdays(Weekdays raw) {
+    return inner.new_box_autoadd_weekdays(_api2wire_weekdays(raw));
+  }
 
   ffi.Pointer<ffi.Uint8> _api2wire_box_bool(bool raw) {
     return inner.new_box_bool(raw);
@@ -765,6 +1848,13 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return inner.new_box_u8(raw);
   }
 
+  ffi.Pointer<wire_btree_map_i32_String> _api2wire_btree_map_i32_String(Map<int, String> raw) {
+    final ans = inner.new_btree_map_i32_String();
+    ans.ref.keys = _api2wire_int_32_list(Int32List.fromList(raw.keys.toList()));
+    ans.ref.values = _api2wire_StringList(raw.values.toList());
+    return ans;
+  }
+
   double _api2wire_f32(double raw) {
     return raw;
   }
@@ -773,6 +1863,11 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return raw;
   }
 
+  ffi.Pointer<wire_float_64_list> _api2wire_f64_array_4(Float64List raw) {
+    if (raw.length != 4) throw ArgumentError('Expected a list of 4 items, but got ${raw.length}');
+    return _api2wire_float_64_list(raw);
+  }
+
   ffi.Pointer<wire_float_32_list> _api2wire_float_32_list(Float32List raw) {
     final ans = inner.new_float_32_list(raw.length);
     ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
@@ -785,6 +1880,25 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return ans;
   }
 
+  ffi.Pointer<wire_hash_map_String_i32> _api2wire_hash_map_String_i32(Map<String, int> raw) {
+    final ans = inner.new_hash_map_String_i32();
+    ans.ref.keys = _api2wire_StringList(raw.keys.toList());
+    ans.ref.values = _api2wire_int_32_list(Int32List.fromList(raw.values.toList()));
+    return ans;
+  }
+
+  ffi.Pointer<wire_int_32_list> _api2wire_hash_set_i32(Set<int> raw) {
+    return _api2wire_int_32_list(Int32List.fromList(raw.toList()));
+  }
+
+  ffi.Pointer<wire_int_128> _api2wire_i128(BigInt raw) {
+    if (!(raw.bitLength <= 127)) throw ArgumentError.value(raw, 'raw', 'Out of range for i128');
+    final ans = inner.new_i128();
+    ans.ref.hi = (raw >> 64).toSigned(64).toInt();
+    ans.ref.lo = raw.toSigned(64).toInt();
+    return ans;
+  }
+
   int _api2wire_i32(int raw) {
     return raw;
   }
@@ -815,6 +1929,22 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return ans;
   }
 
+  ffi.Pointer<wire_list_PathBuf> _api2wire_list_PathBuf(List<String> raw) {
+    final ans = inner.new_list_PathBuf(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = _api2wire_PathBuf(raw[i]);
+    }
+    return ans;
+  }
+
+  ffi.Pointer<wire_list_StringList> _api2wire_list_StringList(List<List<String>> raw) {
+    final ans = inner.new_list_StringList(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = _api2wire_StringList(raw[i]);
+    }
+    return ans;
+  }
+
   ffi.Pointer<wire_list_attribute> _api2wire_list_attribute(List<Attribute> raw) {
     final ans = inner.new_list_attribute(raw.length);
     for (var i = 0; i < raw.length; ++i) {
@@ -823,6 +1953,14 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return ans;
   }
 
+  ffi.Pointer<wire_list_float_64_list> _api2wire_list_float_64_list(List<Float64List> raw) {
+    final ans = inner.new_list_float_64_list(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = _api2wire_float_64_list(raw[i]);
+    }
+    return ans;
+  }
+
   ffi.Pointer<wire_list_my_size> _api2wire_list_my_size(List<MySize> raw) {
     final ans = inner.new_list_my_size(raw.length);
     for (var i = 0; i < raw.length; ++i) {
@@ -839,54 +1977,98 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return ans;
   }
 
+  ffi.Pointer<wire_list_opt_String> _api2wire_list_opt_String(List<String?> raw) {
+    final ans = inner.new_list_opt_String(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = _api2wire_opt_String(raw[i]);
+    }
+    return ans;
+  }
+
   ffi.Pointer<wire_list_opt_box_autoadd_attribute> _api2wire_list_opt_box_autoadd_attribute(List<Attribute?> raw) {
     final ans = inner.new_list_opt_box_autoadd_attribute(raw.length);
     for (var i = 0; i < raw.length; ++i) {
-      _api_fill_to_wire_opt_box_autoadd_attribute(raw[i], ans.ref.ptr[i]);
+      ans.ref.ptr[i] = _api2wire_opt_box_autoadd_attribute(raw[i]);
     }
     return ans;
   }
 
-  int _api2wire_my_enum(MyEnum raw) {
-    return raw.index;
+  ffi.Pointer<wire_list_opt_box_autoadd_weekdays> _api2wire_list_opt_box_autoadd_weekdays(List<Weekdays?> raw) {
+    final ans = inner.new_list_opt_box_autoadd_weekdays(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = _api2wire_opt_box_autoadd_weekdays(raw[i]);
+    }
+    return ans;
   }
 
-  ffi.Pointer<wire_uint_8_list> _api2wire_opt_String(String? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_String(raw);
+  ffi.Pointer<wire_list_opt_f64> _api2wire_list_opt_f64(List<double?> raw) {
+    final ans = inner.new_list_opt_f64(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_opt_f64(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  ffi.Pointer<wire_uint_8_list> _api2wire_opt_ZeroCopyBuffer_Uint8List(Uint8List? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_ZeroCopyBuffer_Uint8List(raw);
+  ffi.Pointer<wire_list_opt_i32> _api2wire_list_opt_i32(List<int?> raw) {
+    final ans = inner.new_list_opt_i32(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_opt_i32(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  ffi.Pointer<wire_Attribute> _api2wire_opt_box_autoadd_attribute(Attribute? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_attribute(raw);
+  int _api2wire_my_enum(MyEnum raw) {
+    return raw.index;
   }
 
-  ffi.Pointer<ffi.Uint8> _api2wire_opt_box_autoadd_bool(bool? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_bool(raw);
+  ffi.Pointer<wire_list_my_size> _api2wire_my_size_array_2(List<MySize> raw) {
+    if (raw.length != 2) throw ArgumentError('Expected a list of 2 items, but got ${raw.length}');
+    return _api2wire_list_my_size(raw);
   }
 
-  ffi.Pointer<wire_ExoticOptionals> _api2wire_opt_box_autoadd_exotic_optionals(ExoticOptionals? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_exotic_optionals(raw);
+  ffi.Pointer<wire_Counter> _api2wire_opaque_counter(Counter raw) {
+    if (raw.isDisposed) throw StateError('Counter was used after being disposed');
+    final ans = inner.new_opaque_counter();
+    ans.ref.ptr = ffi.Pointer.fromAddress(raw.address);
+    return ans;
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_opt_Json(Object? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_Json(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_opt_String(String? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_String(raw);
+  }
+
+  ffi.Pointer<wire_uint_8_list> _api2wire_opt_ZeroCopyBuffer_Uint8List(Uint8List? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_ZeroCopyBuffer_Uint8List(raw);
+  }
+
+  wire_opt_bool _api2wire_opt_bool(bool? raw) {
+    return inner.new_opt_bool(raw != null, raw ?? false);
   }
 
-  ffi.Pointer<ffi.Double> _api2wire_opt_box_autoadd_f64(double? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_f64(raw);
+  ffi.Pointer<ffi.Uint8> _api2wire_opt_box_autoadd_NonZero_u8(int? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_NonZero_u8(raw);
   }
 
-  ffi.Pointer<ffi.Int32> _api2wire_opt_box_autoadd_i32(int? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_i32(raw);
+  ffi.Pointer<wire_Attribute> _api2wire_opt_box_autoadd_attribute(Attribute? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_attribute(raw);
   }
 
-  ffi.Pointer<ffi.Int64> _api2wire_opt_box_autoadd_i64(int? raw) {
-    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_i64(raw);
+  ffi.Pointer<wire_ExoticOptionals> _api2wire_opt_box_autoadd_exotic_optionals(ExoticOptionals? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_exotic_optionals(raw);
   }
 
   ffi.Pointer<wire_NewTypeInt> _api2wire_opt_box_autoadd_new_type_int(NewTypeInt? raw) {
     return raw == null ? ffi.nullptr : _api2wire_box_autoadd_new_type_int(raw);
   }
 
+  ffi.Pointer<ffi.Int32> _api2wire_opt_box_autoadd_weekdays(Weekdays? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_box_autoadd_weekdays(raw);
+  }
+
   ffi.Pointer<ffi.Uint8> _api2wire_opt_box_bool(bool? raw) {
     return raw == null ? ffi.nullptr : _api2wire_box_bool(raw);
   }
@@ -915,6 +2097,10 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return raw == null ? ffi.nullptr : _api2wire_box_u8(raw);
   }
 
+  wire_opt_f64 _api2wire_opt_f64(double? raw) {
+    return inner.new_opt_f64(raw != null, raw ?? 0.0);
+  }
+
   ffi.Pointer<wire_float_32_list> _api2wire_opt_float_32_list(Float32List? raw) {
     return raw == null ? ffi.nullptr : _api2wire_float_32_list(raw);
   }
@@ -923,6 +2109,14 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return raw == null ? ffi.nullptr : _api2wire_float_64_list(raw);
   }
 
+  wire_opt_i32 _api2wire_opt_i32(int? raw) {
+    return inner.new_opt_i32(raw != null, raw ?? 0);
+  }
+
+  wire_opt_i64 _api2wire_opt_i64(int? raw) {
+    return inner.new_opt_i64(raw != null, raw ?? 0);
+  }
+
   ffi.Pointer<wire_int_32_list> _api2wire_opt_int_32_list(Int32List? raw) {
     return raw == null ? ffi.nullptr : _api2wire_int_32_list(raw);
   }
@@ -943,14 +2137,35 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     return raw == null ? ffi.nullptr : _api2wire_list_opt_box_autoadd_attribute(raw);
   }
 
+  ffi.Pointer<wire_Counter> _api2wire_opt_opaque_counter(Counter? raw) {
+    return raw == null ? ffi.nullptr : _api2wire_opaque_counter(raw);
+  }
+
   ffi.Pointer<wire_uint_8_list> _api2wire_opt_uint_8_list(Uint8List? raw) {
     return raw == null ? ffi.nullptr : _api2wire_uint_8_list(raw);
   }
 
+  int _api2wire_priority(Priority raw) {
+    if (raw == Priority.unknown) throw ArgumentError('The unknown variant of Priority cannot be sent to Rust');
+    return raw.index;
+  }
+
+  ffi.Pointer<wire_uint_128> _api2wire_u128(BigInt raw) {
+    if (!(!raw.isNegative && raw.bitLength <= 128)) throw ArgumentError.value(raw, 'raw', 'Out of range for u128');
+    final ans = inner.new_u128();
+    ans.ref.hi = (raw >> 64).toSigned(64).toInt();
+    ans.ref.lo = raw.toSigned(64).toInt();
+    return ans;
+  }
+
   int _api2wire_u32(int raw) {
     return raw;
   }
 
+  int _api2wire_u64(int raw) {
+    return raw;
+  }
+
   int _api2wire_u8(int raw) {
     return raw;
   }
@@ -976,6 +2191,10 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     _api_fill_to_wire_attribute(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_calculator(Calculator apiObj, ffi.Pointer<wire_Calculator> wireObj) {
+    _api_fill_to_wire_calculator(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_customized(Customized apiObj, ffi.Pointer<wire_Customized> wireObj) {
     _api_fill_to_wire_customized(apiObj, wireObj.ref);
   }
@@ -1005,6 +2224,19 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     _api_fill_to_wire_new_type_int(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_sparse_lists(SparseLists apiObj, ffi.Pointer<wire_SparseLists> wireObj) {
+    _api_fill_to_wire_sparse_lists(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_tuple_f_64_f_64(TupleF64F64 apiObj, ffi.Pointer<wire_TupleF64F64> wireObj) {
+    _api_fill_to_wire_tuple_f_64_f_64(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_tuple_i_32_string(
+      TupleI32String apiObj, ffi.Pointer<wire_TupleI32String> wireObj) {
+    _api_fill_to_wire_tuple_i_32_string(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_exotic_optionals(ExoticOptionals apiObj, ffi.Pointer<wire_ExoticOptionals> wireObj) {
     _api_fill_to_wire_exotic_optionals(apiObj, wireObj.ref);
   }
@@ -1017,16 +2249,20 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     _api_fill_to_wire_my_size(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_calculator(Calculator apiObj, wire_Calculator wireObj) {
+    wireObj.base = _api2wire_i32(apiObj.base);
+  }
+
   void _api_fill_to_wire_customized(Customized apiObj, wire_Customized wireObj) {
     wireObj.final_field = _api2wire_String(apiObj.finalField);
     wireObj.non_final_field = _api2wire_opt_String(apiObj.nonFinalField);
   }
 
   void _api_fill_to_wire_exotic_optionals(ExoticOptionals apiObj, wire_ExoticOptionals wireObj) {
-    wireObj.int32 = _api2wire_opt_box_autoadd_i32(apiObj.int32);
-    wireObj.int64 = _api2wire_opt_box_autoadd_i64(apiObj.int64);
-    wireObj.float64 = _api2wire_opt_box_autoadd_f64(apiObj.float64);
-    wireObj.boolean = _api2wire_opt_box_autoadd_bool(apiObj.boolean);
+    _api_fill_to_wire_opt_i32(apiObj.int32, wireObj.int32);
+    _api_fill_to_wire_opt_i64(apiObj.int64, wireObj.int64);
+    _api_fill_to_wire_opt_f64(apiObj.float64, wireObj.float64);
+    _api_fill_to_wire_opt_bool(apiObj.boolean, wireObj.boolean);
     wireObj.zerocopy = _api2wire_opt_ZeroCopyBuffer_Uint8List(apiObj.zerocopy);
     wireObj.int8list = _api2wire_opt_int_8_list(apiObj.int8List);
     wireObj.uint8list = _api2wire_opt_uint_8_list(apiObj.uint8List);
@@ -1060,8 +2296,8 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     if (apiObj is Optional) {
       wireObj.tag = 3;
       wireObj.kind = inner.inflate_KitchenSink_Optional();
-      wireObj.kind.ref.Optional.ref.field0 = _api2wire_opt_box_autoadd_i32(apiObj.field0);
-      wireObj.kind.ref.Optional.ref.field1 = _api2wire_opt_box_autoadd_i32(apiObj.field1);
+      _api_fill_to_wire_opt_i32(apiObj.field0, wireObj.kind.ref.Optional.ref.field0);
+      _api_fill_to_wire_opt_i32(apiObj.field1, wireObj.kind.ref.Optional.ref.field1);
     }
     if (apiObj is Buffer) {
       wireObj.tag = 4;
@@ -1095,461 +2331,648 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     wireObj.field0 = _api2wire_i64(apiObj.field0);
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_attribute(Attribute? apiObj, ffi.Pointer<wire_Attribute> wireObj) {
-    if (apiObj != null) _api_fill_to_wire_box_autoadd_attribute(apiObj, wireObj);
+  void _api_fill_to_wire_opt_bool(bool? apiObj, wire_opt_bool wireObj) {
+    wireObj.is_some = apiObj != null ? 1 : 0;
+    wireObj.value = _api2wire_bool(apiObj ?? false);
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_exotic_optionals(
-      ExoticOptionals? apiObj, ffi.Pointer<wire_ExoticOptionals> wireObj) {
-    if (apiObj != null) _api_fill_to_wire_box_autoadd_exotic_optionals(apiObj, wireObj);
+  void _api_fill_to_wire_opt_f64(double? apiObj, wire_opt_f64 wireObj) {
+    wireObj.is_some = apiObj != null ? 1 : 0;
+    wireObj.value = _api2wire_f64(apiObj ?? 0.0);
   }
 
-  void _api_fill_to_wire_opt_box_autoadd_new_type_int(NewTypeInt? apiObj, ffi.Pointer<wire_NewTypeInt> wireObj) {
-    if (apiObj != null) _api_fill_to_wire_box_autoadd_new_type_int(apiObj, wireObj);
+  void _api_fill_to_wire_opt_i32(int? apiObj, wire_opt_i32 wireObj) {
+    wireObj.is_some = apiObj != null ? 1 : 0;
+    wireObj.value = _api2wire_i32(apiObj ?? 0);
   }
 
-  void _api_fill_to_wire_opt_box_exotic_optionals(ExoticOptionals? apiObj, ffi.Pointer<wire_ExoticOptionals> wireObj) {
-    if (apiObj != null) _api_fill_to_wire_box_exotic_optionals(apiObj, wireObj);
+  void _api_fill_to_wire_opt_i64(int? apiObj, wire_opt_i64 wireObj) {
+    wireObj.is_some = apiObj != null ? 1 : 0;
+    wireObj.value = _api2wire_i64(apiObj ?? 0);
   }
-}
 
-// Section: wire2api
-String _wire2api_String(dynamic raw) {
-  return raw as String;
-}
+  void _api_fill_to_wire_sparse_lists(SparseLists apiObj, wire_SparseLists wireObj) {
+    wireObj.values = _api2wire_list_opt_f64(apiObj.values);
+    wireObj.labels = _api2wire_list_opt_String(apiObj.labels);
+    wireObj.weekdays = _api2wire_list_opt_box_autoadd_weekdays(apiObj.weekdays);
+    wireObj.attributes = _api2wire_list_opt_box_autoadd_attribute(apiObj.attributes);
+  }
 
-List<String> _wire2api_StringList(dynamic raw) {
-  return (raw as List<dynamic>).cast<String>();
-}
+  void _api_fill_to_wire_tuple_f_64_f_64(TupleF64F64 apiObj, wire_TupleF64F64 wireObj) {
+    wireObj.field0 = _api2wire_f64(apiObj.field0);
+    wireObj.field1 = _api2wire_f64(apiObj.field1);
+  }
 
-Uint8List _wire2api_SyncReturnVecU8(dynamic raw) {
-  return raw as Uint8List;
-}
+  void _api_fill_to_wire_tuple_i_32_string(TupleI32String apiObj, wire_TupleI32String wireObj) {
+    wireObj.field0 = _api2wire_i32(apiObj.field0);
+    wireObj.field1 = _api2wire_String(apiObj.field1);
+  }
 
-Float32List _wire2api_ZeroCopyBuffer_Float32List(dynamic raw) {
-  return raw as Float32List;
-}
+  // Section: wire2api
+  List<String> _wire2api_BoxedSlice_StringList(dynamic raw) {
+    return _wire2api_StringList(raw);
+  }
 
-Float64List _wire2api_ZeroCopyBuffer_Float64List(dynamic raw) {
-  return raw as Float64List;
-}
+  Uint8List _wire2api_BoxedSlice_uint_8_list(dynamic raw) {
+    return _wire2api_uint_8_list(raw);
+  }
 
-Int16List _wire2api_ZeroCopyBuffer_Int16List(dynamic raw) {
-  return raw as Int16List;
-}
+  int _wire2api_Char(dynamic raw) {
+    return raw as int;
+  }
 
-Int32List _wire2api_ZeroCopyBuffer_Int32List(dynamic raw) {
-  return raw as Int32List;
-}
+  DateTime _wire2api_Chrono_Local(dynamic raw) {
+    return DateTime.fromMicrosecondsSinceEpoch(raw as int, isUtc: false);
+  }
 
-Int64List _wire2api_ZeroCopyBuffer_Int64List(dynamic raw) {
-  return raw as Int64List;
-}
+  DateTime _wire2api_Chrono_Naive(dynamic raw) {
+    return DateTime.fromMicrosecondsSinceEpoch(raw as int, isUtc: true);
+  }
 
-Int8List _wire2api_ZeroCopyBuffer_Int8List(dynamic raw) {
-  return raw as Int8List;
-}
+  DateTime _wire2api_Chrono_Utc(dynamic raw) {
+    return DateTime.fromMicrosecondsSinceEpoch(raw as int, isUtc: true);
+  }
 
-Uint16List _wire2api_ZeroCopyBuffer_Uint16List(dynamic raw) {
-  return raw as Uint16List;
-}
+  Duration _wire2api_Duration(dynamic raw) {
+    return Duration(microseconds: raw as int);
+  }
 
-Uint32List _wire2api_ZeroCopyBuffer_Uint32List(dynamic raw) {
-  return raw as Uint32List;
-}
+  Object? _wire2api_Json(dynamic raw) {
+    return jsonDecode(_wire2api_String(raw));
+  }
 
-Uint64List _wire2api_ZeroCopyBuffer_Uint64List(dynamic raw) {
-  return raw as Uint64List;
-}
+  int _wire2api_NonZero_u32(dynamic raw) {
+    return _wire2api_u32(raw);
+  }
 
-Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
-  return raw as Uint8List;
-}
+  String _wire2api_PathBuf(dynamic raw) {
+    return _wire2api_String(raw);
+  }
 
-Attribute _wire2api_attribute(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-  return Attribute(
-    key: _wire2api_String(arr[0]),
-    value: _wire2api_String(arr[1]),
-  );
-}
+  String _wire2api_String(dynamic raw) {
+    if (raw is String) return raw;
+    return utf8.decode(raw as Uint8List);
+  }
 
-bool _wire2api_bool(dynamic raw) {
-  return raw as bool;
-}
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
 
-Attribute _wire2api_box_autoadd_attribute(dynamic raw) {
-  return _wire2api_attribute(raw);
-}
+  Uint8List _wire2api_SyncReturnVecU8(dynamic raw) {
+    return raw as Uint8List;
+  }
 
-bool _wire2api_box_autoadd_bool(dynamic raw) {
-  return raw as bool;
-}
+  Uint8List _wire2api_Uuid(dynamic raw) {
+    return raw as Uint8List;
+  }
 
-Element _wire2api_box_autoadd_element(dynamic raw) {
-  return _wire2api_element(raw);
-}
+  Float32List _wire2api_ZeroCopyBuffer_Float32List(dynamic raw) {
+    return raw as Float32List;
+  }
 
-ExoticOptionals _wire2api_box_autoadd_exotic_optionals(dynamic raw) {
-  return _wire2api_exotic_optionals(raw);
-}
+  Float64List _wire2api_ZeroCopyBuffer_Float64List(dynamic raw) {
+    return raw as Float64List;
+  }
 
-double _wire2api_box_autoadd_f64(dynamic raw) {
-  return raw as double;
-}
+  Int16List _wire2api_ZeroCopyBuffer_Int16List(dynamic raw) {
+    return raw as Int16List;
+  }
 
-int _wire2api_box_autoadd_i32(dynamic raw) {
-  return raw as int;
-}
+  Int32List _wire2api_ZeroCopyBuffer_Int32List(dynamic raw) {
+    return raw as Int32List;
+  }
 
-int _wire2api_box_autoadd_i64(dynamic raw) {
-  return raw as int;
-}
+  Int64List _wire2api_ZeroCopyBuffer_Int64List(dynamic raw) {
+    return raw as Int64List;
+  }
 
-NewTypeInt _wire2api_box_autoadd_new_type_int(dynamic raw) {
-  return _wire2api_new_type_int(raw);
-}
+  Int8List _wire2api_ZeroCopyBuffer_Int8List(dynamic raw) {
+    return raw as Int8List;
+  }
 
-KitchenSink _wire2api_box_kitchen_sink(dynamic raw) {
-  return raw as KitchenSink;
-}
+  Uint16List _wire2api_ZeroCopyBuffer_Uint16List(dynamic raw) {
+    return raw as Uint16List;
+  }
 
-Element _wire2api_element(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return Element(
-    tag: _wire2api_opt_String(arr[0]),
-    text: _wire2api_opt_String(arr[1]),
-    attributes: _wire2api_opt_list_attribute(arr[2]),
-    children: _wire2api_opt_list_element(arr[3]),
-  );
-}
+  Uint32List _wire2api_ZeroCopyBuffer_Uint32List(dynamic raw) {
+    return raw as Uint32List;
+  }
 
-ExoticOptionals _wire2api_exotic_optionals(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
-  return ExoticOptionals(
-    int32: _wire2api_opt_box_autoadd_i32(arr[0]),
-    int64: _wire2api_opt_box_autoadd_i64(arr[1]),
-    float64: _wire2api_opt_box_autoadd_f64(arr[2]),
-    boolean: _wire2api_opt_box_autoadd_bool(arr[3]),
-    zerocopy: _wire2api_opt_ZeroCopyBuffer_Uint8List(arr[4]),
-    int8List: _wire2api_opt_int_8_list(arr[5]),
-    uint8List: _wire2api_opt_uint_8_list(arr[6]),
-    int32List: _wire2api_opt_int_32_list(arr[7]),
-    int64List: _wire2api_opt_int_64_list(arr[8]),
-    float32List: _wire2api_opt_float_32_list(arr[9]),
-    float64List: _wire2api_opt_float_64_list(arr[10]),
-    attributes: _wire2api_opt_list_attribute(arr[11]),
-    attributesNullable: _wire2api_list_opt_box_autoadd_attribute(arr[12]),
-    nullableAttributes: _wire2api_opt_list_opt_box_autoadd_attribute(arr[13]),
-    newtypeint: _wire2api_opt_box_autoadd_new_type_int(arr[14]),
-  );
-}
+  Uint64List _wire2api_ZeroCopyBuffer_Uint64List(dynamic raw) {
+    return raw as Uint64List;
+  }
 
-double _wire2api_f32(dynamic raw) {
-  return raw as double;
-}
+  Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
+    return raw as Uint8List;
+  }
 
-double _wire2api_f64(dynamic raw) {
-  return raw as double;
-}
+  Attribute _wire2api_attribute(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Attribute(
+      key: _wire2api_String(arr[0]),
+      value: _wire2api_String(arr[1]),
+    );
+  }
 
-Float32List _wire2api_float_32_list(dynamic raw) {
-  return raw as Float32List;
-}
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
 
-Float64List _wire2api_float_64_list(dynamic raw) {
-  return raw as Float64List;
-}
+  Attribute _wire2api_box_autoadd_attribute(dynamic raw) {
+    return _wire2api_attribute(raw);
+  }
 
-int _wire2api_i16(dynamic raw) {
-  return raw as int;
-}
+  Element _wire2api_box_autoadd_element(dynamic raw) {
+    return _wire2api_element(raw);
+  }
 
-int _wire2api_i32(dynamic raw) {
-  return raw as int;
-}
+  ExoticOptionals _wire2api_box_autoadd_exotic_optionals(dynamic raw) {
+    return _wire2api_exotic_optionals(raw);
+  }
 
-int _wire2api_i64(dynamic raw) {
-  return raw as int;
-}
+  NewTypeInt _wire2api_box_autoadd_new_type_int(dynamic raw) {
+    return _wire2api_new_type_int(raw);
+  }
 
-int _wire2api_i8(dynamic raw) {
-  return raw as int;
-}
+  Weekdays _wire2api_box_autoadd_weekdays(dynamic raw) {
+    return _wire2api_weekdays(raw);
+  }
 
-Int16List _wire2api_int_16_list(dynamic raw) {
-  return raw as Int16List;
-}
+  KitchenSink _wire2api_box_kitchen_sink(dynamic raw) {
+    return _wire2api_kitchen_sink(raw);
+  }
 
-Int32List _wire2api_int_32_list(dynamic raw) {
-  return raw as Int32List;
-}
+  Map<String, int> _wire2api_btree_map_String_i32(dynamic raw) {
+    return Map.fromEntries(
+        (raw as List<dynamic>).map((entry) => MapEntry(_wire2api_String(entry[0]), _wire2api_i32(entry[1]))));
+  }
 
-Int64List _wire2api_int_64_list(dynamic raw) {
-  return raw as Int64List;
-}
+  Set<int> _wire2api_btree_set_i32(dynamic raw) {
+    return Set.of(_wire2api_int_32_list(raw));
+  }
 
-Int8List _wire2api_int_8_list(dynamic raw) {
-  return raw as Int8List;
-}
+  Calculator _wire2api_calculator(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Calculator(
+      bridge: this,
+      base: _wire2api_i32(arr[0]),
+    );
+  }
 
-KitchenSink _wire2api_kitchen_sink(dynamic raw) {
-  switch (raw[0]) {
-    case 0:
-      return Empty();
-    case 1:
-      return Primitives(
-        int32: _wire2api_i32(raw[1]),
-        float64: _wire2api_f64(raw[2]),
-        boolean: _wire2api_bool(raw[3]),
-      );
-    case 2:
-      return Nested(
-        _wire2api_box_kitchen_sink(raw[1]),
-      );
-    case 3:
-      return Optional(
-        _wire2api_opt_box_autoadd_i32(raw[1]),
-        _wire2api_opt_box_autoadd_i32(raw[2]),
-      );
-    case 4:
-      return Buffer(
-        _wire2api_ZeroCopyBuffer_Uint8List(raw[1]),
-      );
-    case 5:
-      return Enums(
-        _wire2api_weekdays(raw[1]),
-      );
-    default:
-      throw Exception("unreachable");
+  CustomError _wire2api_custom_error(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CustomError(
+      message: _wire2api_String(arr[0]),
+      code: _wire2api_i32(arr[1]),
+    );
   }
-}
 
-List<Attribute> _wire2api_list_attribute(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_attribute).toList();
-}
+  Element _wire2api_element(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Element(
+      tag: _wire2api_opt_String(arr[0]),
+      text: _wire2api_opt_String(arr[1]),
+      attributes: _wire2api_opt_list_attribute(arr[2]),
+      children: _wire2api_opt_list_element(arr[3]),
+    );
+  }
 
-List<Element> _wire2api_list_element(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_element).toList();
-}
+  ExoticOptionals _wire2api_exotic_optionals(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return ExoticOptionals(
+      int32: _wire2api_opt_i32(arr[0]),
+      int64: _wire2api_opt_i64(arr[1]),
+      float64: _wire2api_opt_f64(arr[2]),
+      boolean: _wire2api_opt_bool(arr[3]),
+      zerocopy: _wire2api_opt_ZeroCopyBuffer_Uint8List(arr[4]),
+      int8List: _wire2api_opt_int_8_list(arr[5]),
+      uint8List: _wire2api_opt_uint_8_list(arr[6]),
+      int32List: _wire2api_opt_int_32_list(arr[7]),
+      int64List: _wire2api_opt_int_64_list(arr[8]),
+      float32List: _wire2api_opt_float_32_list(arr[9]),
+      float64List: _wire2api_opt_float_64_list(arr[10]),
+      attributes: _wire2api_opt_list_attribute(arr[11]),
+      attributesNullable: _wire2api_list_opt_box_autoadd_attribute(arr[12]),
+      nullableAttributes: _wire2api_opt_list_opt_box_autoadd_attribute(arr[13]),
+      newtypeint: _wire2api_opt_box_autoadd_new_type_int(arr[14]),
+    );
+  }
 
-List<MySize> _wire2api_list_my_size(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_my_size).toList();
-}
+  double _wire2api_f32(dynamic raw) {
+    return raw as double;
+  }
 
-List<MyTreeNode> _wire2api_list_my_tree_node(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_my_tree_node).toList();
-}
+  double _wire2api_f64(dynamic raw) {
+    return raw as double;
+  }
 
-List<Attribute?> _wire2api_list_opt_box_autoadd_attribute(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_opt_box_autoadd_attribute).toList();
-}
+  Float64List _wire2api_f64_array_4(dynamic raw) {
+    return _wire2api_float_64_list(raw);
+  }
 
-MySize _wire2api_my_size(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-  return MySize(
-    width: _wire2api_i32(arr[0]),
-    height: _wire2api_i32(arr[1]),
-  );
-}
+  Float32List _wire2api_float_32_list(dynamic raw) {
+    return raw as Float32List;
+  }
 
-MyTreeNode _wire2api_my_tree_node(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return MyTreeNode(
-    valueI32: _wire2api_i32(arr[0]),
-    valueVecU8: _wire2api_uint_8_list(arr[1]),
-    valueBoolean: _wire2api_bool(arr[2]),
-    children: _wire2api_list_my_tree_node(arr[3]),
-  );
-}
+  Float64List _wire2api_float_64_list(dynamic raw) {
+    return raw as Float64List;
+  }
 
-NewTypeInt _wire2api_new_type_int(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-  return NewTypeInt(
-    field0: _wire2api_i64(arr[0]),
-  );
-}
+  Map<String, int> _wire2api_hash_map_String_i32(dynamic raw) {
+    return Map.fromEntries(
+        (raw as List<dynamic>).map((entry) => MapEntry(_wire2api_String(entry[0]), _wire2api_i32(entry[1]))));
+  }
 
-String? _wire2api_opt_String(dynamic raw) {
-  return raw == null ? null : _wire2api_String(raw);
-}
+  Set<String> _wire2api_hash_set_String(dynamic raw) {
+    return Set.of(_wire2api_StringList(raw));
+  }
 
-Uint8List? _wire2api_opt_ZeroCopyBuffer_Uint8List(dynamic raw) {
-  return raw == null ? null : _wire2api_ZeroCopyBuffer_Uint8List(raw);
-}
+  BigInt _wire2api_i128(dynamic raw) {
+    final arr = raw as List<int>;
+    return (BigInt.from(arr[0]) << 64) | BigInt.from(arr[1]).toUnsigned(64);
+  }
 
-Attribute? _wire2api_opt_box_autoadd_attribute(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_attribute(raw);
-}
+  int _wire2api_i16(dynamic raw) {
+    return raw as int;
+  }
 
-bool? _wire2api_opt_box_autoadd_bool(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_bool(raw);
-}
+  int _wire2api_i32(dynamic raw) {
+    return raw as int;
+  }
 
-Element? _wire2api_opt_box_autoadd_element(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_element(raw);
-}
+  int _wire2api_i64(dynamic raw) {
+    return raw as int;
+  }
 
-ExoticOptionals? _wire2api_opt_box_autoadd_exotic_optionals(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_exotic_optionals(raw);
-}
+  int _wire2api_i8(dynamic raw) {
+    return raw as int;
+  }
 
-double? _wire2api_opt_box_autoadd_f64(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_f64(raw);
-}
+  Int16List _wire2api_int_16_list(dynamic raw) {
+    return raw as Int16List;
+  }
 
-int? _wire2api_opt_box_autoadd_i32(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_i32(raw);
-}
+  Int32List _wire2api_int_32_list(dynamic raw) {
+    return raw as Int32List;
+  }
 
-int? _wire2api_opt_box_autoadd_i64(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_i64(raw);
-}
+  Int64List _wire2api_int_64_list(dynamic raw) {
+    return raw as Int64List;
+  }
 
-NewTypeInt? _wire2api_opt_box_autoadd_new_type_int(dynamic raw) {
-  return raw == null ? null : _wire2api_box_autoadd_new_type_int(raw);
-}
+  Int8List _wire2api_int_8_list(dynamic raw) {
+    return raw as Int8List;
+  }
 
-Float32List? _wire2api_opt_float_32_list(dynamic raw) {
-  return raw == null ? null : _wire2api_float_32_list(raw);
-}
+  KitchenSink _wire2api_kitchen_sink(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return Empty();
+      case 1:
+        return Primitives(
+          int32: _wire2api_i32(raw[1]),
+          float64: _wire2api_f64(raw[2]),
+          boolean: _wire2api_bool(raw[3]),
+        );
+      case 2:
+        return Nested(
+          _wire2api_box_kitchen_sink(raw[1]),
+        );
+      case 3:
+        return Optional(
+          _wire2api_opt_i32(raw[1]),
+          _wire2api_opt_i32(raw[2]),
+        );
+      case 4:
+        return Buffer(
+          _wire2api_ZeroCopyBuffer_Uint8List(raw[1]),
+        );
+      case 5:
+        return Enums(
+          _wire2api_weekdays(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
 
-Float64List? _wire2api_opt_float_64_list(dynamic raw) {
-  return raw == null ? null : _wire2api_float_64_list(raw);
-}
+  List<List<String>> _wire2api_list_StringList(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_StringList).toList();
+  }
 
-Int32List? _wire2api_opt_int_32_list(dynamic raw) {
-  return raw == null ? null : _wire2api_int_32_list(raw);
-}
+  List<Attribute> _wire2api_list_attribute(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_attribute).toList();
+  }
 
-Int64List? _wire2api_opt_int_64_list(dynamic raw) {
-  return raw == null ? null : _wire2api_int_64_list(raw);
-}
+  List<Element> _wire2api_list_element(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_element).toList();
+  }
 
-Int8List? _wire2api_opt_int_8_list(dynamic raw) {
-  return raw == null ? null : _wire2api_int_8_list(raw);
-}
+  List<Float64List> _wire2api_list_float_64_list(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_float_64_list).toList();
+  }
 
-List<Attribute>? _wire2api_opt_list_attribute(dynamic raw) {
-  return raw == null ? null : _wire2api_list_attribute(raw);
-}
+  List<MySize> _wire2api_list_my_size(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_my_size).toList();
+  }
 
-List<Element>? _wire2api_opt_list_element(dynamic raw) {
-  return raw == null ? null : _wire2api_list_element(raw);
-}
+  List<MyTreeNode> _wire2api_list_my_tree_node(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_my_tree_node).toList();
+  }
 
-List<Attribute?>? _wire2api_opt_list_opt_box_autoadd_attribute(dynamic raw) {
-  return raw == null ? null : _wire2api_list_opt_box_autoadd_attribute(raw);
-}
+  List<String?> _wire2api_list_opt_String(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_opt_String).toList();
+  }
 
-Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
-  return raw == null ? null : _wire2api_uint_8_list(raw);
-}
+  List<Attribute?> _wire2api_list_opt_box_autoadd_attribute(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_opt_box_autoadd_attribute).toList();
+  }
 
-Weekdays? _wire2api_opt_weekdays(dynamic raw) {
-  return raw == null ? null : _wire2api_weekdays(raw);
-}
+  List<Weekdays?> _wire2api_list_opt_box_autoadd_weekdays(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_opt_box_autoadd_weekdays).toList();
+  }
 
-int _wire2api_u16(dynamic raw) {
-  return raw as int;
-}
+  List<double?> _wire2api_list_opt_f64(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_opt_f64).toList();
+  }
 
-int _wire2api_u32(dynamic raw) {
-  return raw as int;
-}
+  LoginError _wire2api_login_error(dynamic raw) {
+    return LoginError.values[raw];
+  }
 
-int _wire2api_u64(dynamic raw) {
-  return raw as int;
-}
+  MySize _wire2api_my_size(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MySize(
+      width: _wire2api_i32(arr[0]),
+      height: _wire2api_i32(arr[1]),
+    );
+  }
 
-int _wire2api_u8(dynamic raw) {
-  return raw as int;
-}
+  List<MySize> _wire2api_my_size_array_2(dynamic raw) {
+    return _wire2api_list_my_size(raw);
+  }
 
-Uint16List _wire2api_uint_16_list(dynamic raw) {
-  return raw as Uint16List;
-}
+  MyTreeNode _wire2api_my_tree_node(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MyTreeNode(
+      valueI32: _wire2api_i32(arr[0]),
+      valueVecU8: _wire2api_uint_8_list(arr[1]),
+      valueBoolean: _wire2api_bool(arr[2]),
+      children: _wire2api_list_my_tree_node(arr[3]),
+    );
+  }
 
-Uint32List _wire2api_uint_32_list(dynamic raw) {
-  return raw as Uint32List;
-}
+  NewTypeInt _wire2api_new_type_int(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return NewTypeInt(
+      _wire2api_i64(arr[0]),
+    );
+  }
 
-Uint64List _wire2api_uint_64_list(dynamic raw) {
-  return raw as Uint64List;
-}
+  Counter _wire2api_opaque_counter(dynamic raw) {
+    final ans = Counter(bridge: this, address: raw as int);
+    _opaqueCounterFinalizer.attach(ans, ffi.Pointer.fromAddress(ans.address), detach: ans);
+    return ans;
+  }
 
-Uint8List _wire2api_uint_8_list(dynamic raw) {
-  return raw as Uint8List;
-}
+  String? _wire2api_opt_String(dynamic raw) {
+    return raw == null ? null : _wire2api_String(raw);
+  }
 
-void _wire2api_unit(dynamic raw) {
-  return;
-}
+  Uint8List? _wire2api_opt_ZeroCopyBuffer_Uint8List(dynamic raw) {
+    return raw == null ? null : _wire2api_ZeroCopyBuffer_Uint8List(raw);
+  }
 
-VecOfPrimitivePack _wire2api_vec_of_primitive_pack(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-  return VecOfPrimitivePack(
-    int8List: _wire2api_int_8_list(arr[0]),
-    uint8List: _wire2api_uint_8_list(arr[1]),
-    int16List: _wire2api_int_16_list(arr[2]),
-    uint16List: _wire2api_uint_16_list(arr[3]),
-    uint32List: _wire2api_uint_32_list(arr[4]),
-    int32List: _wire2api_int_32_list(arr[5]),
-    uint64List: _wire2api_uint_64_list(arr[6]),
-    int64List: _wire2api_int_64_list(arr[7]),
-    float32List: _wire2api_float_32_list(arr[8]),
-    float64List: _wire2api_float_64_list(arr[9]),
-  );
-}
+  bool? _wire2api_opt_bool(dynamic raw) {
+    return raw == null ? null : _wire2api_bool(raw);
+  }
 
-Weekdays _wire2api_weekdays(dynamic raw) {
-  return Weekdays.values[raw];
-}
-
-ZeroCopyVecOfPrimitivePack _wire2api_zero_copy_vec_of_primitive_pack(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-  return ZeroCopyVecOfPrimitivePack(
-    int8List: _wire2api_ZeroCopyBuffer_Int8List(arr[0]),
-    uint8List: _wire2api_ZeroCopyBuffer_Uint8List(arr[1]),
-    int16List: _wire2api_ZeroCopyBuffer_Int16List(arr[2]),
-    uint16List: _wire2api_ZeroCopyBuffer_Uint16List(arr[3]),
-    uint32List: _wire2api_ZeroCopyBuffer_Uint32List(arr[4]),
-    int32List: _wire2api_ZeroCopyBuffer_Int32List(arr[5]),
-    uint64List: _wire2api_ZeroCopyBuffer_Uint64List(arr[6]),
-    int64List: _wire2api_ZeroCopyBuffer_Int64List(arr[7]),
-    float32List: _wire2api_ZeroCopyBuffer_Float32List(arr[8]),
-    float64List: _wire2api_ZeroCopyBuffer_Float64List(arr[9]),
-  );
-}
+  Attribute? _wire2api_opt_box_autoadd_attribute(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_attribute(raw);
+  }
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+  Element? _wire2api_opt_box_autoadd_element(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_element(raw);
+  }
 
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
+  ExoticOptionals? _wire2api_opt_box_autoadd_exotic_optionals(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_exotic_optionals(raw);
+  }
 
-/// generated by flutter_rust_bridge
-class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) _lookup;
+  NewTypeInt? _wire2api_opt_box_autoadd_new_type_int(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_new_type_int(raw);
+  }
 
-  /// The symbols are looked up in [dynamicLibrary].
-  FlutterRustBridgeExampleWire(ffi.DynamicLibrary dynamicLibrary) : _lookup = dynamicLibrary.lookup;
+  Weekdays? _wire2api_opt_box_autoadd_weekdays(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_weekdays(raw);
+  }
 
-  /// The symbols are looked up with [lookup].
-  FlutterRustBridgeExampleWire.fromLookup(ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup)
-      : _lookup = lookup;
+  double? _wire2api_opt_f64(dynamic raw) {
+    return raw == null ? null : _wire2api_f64(raw);
+  }
 
-  void wire_simple_adder(
-    int port_,
+  Float32List? _wire2api_opt_float_32_list(dynamic raw) {
+    return raw == null ? null : _wire2api_float_32_list(raw);
+  }
+
+  Float64List? _wire2api_opt_float_64_list(dynamic raw) {
+    return raw == null ? null : _wire2api_float_64_list(raw);
+  }
+
+  int? _wire2api_opt_i32(dynamic raw) {
+    return raw == null ? null : _wire2api_i32(raw);
+  }
+
+  int? _wire2api_opt_i64(dynamic raw) {
+    return raw == null ? null : _wire2api_i64(raw);
+  }
+
+  Int32List? _wire2api_opt_int_32_list(dynamic raw) {
+    return raw == null ? null : _wire2api_int_32_list(raw);
+  }
+
+  Int64List? _wire2api_opt_int_64_list(dynamic raw) {
+    return raw == null ? null : _wire2api_int_64_list(raw);
+  }
+
+  Int8List? _wire2api_opt_int_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_int_8_list(raw);
+  }
+
+  List<Attribute>? _wire2api_opt_list_attribute(dynamic raw) {
+    return raw == null ? null : _wire2api_list_attribute(raw);
+  }
+
+  List<Element>? _wire2api_opt_list_element(dynamic raw) {
+    return raw == null ? null : _wire2api_list_element(raw);
+  }
+
+  List<Attribute?>? _wire2api_opt_list_opt_box_autoadd_attribute(dynamic raw) {
+    return raw == null ? null : _wire2api_list_opt_box_autoadd_attribute(raw);
+  }
+
+  Counter? _wire2api_opt_opaque_counter(dynamic raw) {
+    return raw == null ? null : _wire2api_opaque_counter(raw);
+  }
+
+  Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_uint_8_list(raw);
+  }
+
+  Priority _wire2api_priority(dynamic raw) {
+    final index = raw as int;
+    return index < 2 ? Priority.values[index] : Priority.unknown;
+  }
+
+  SparseLists _wire2api_sparse_lists(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SparseLists(
+      values: _wire2api_list_opt_f64(arr[0]),
+      labels: _wire2api_list_opt_String(arr[1]),
+      weekdays: _wire2api_list_opt_box_autoadd_weekdays(arr[2]),
+      attributes: _wire2api_list_opt_box_autoadd_attribute(arr[3]),
+    );
+  }
+
+  TupleInt32ListStringList _wire2api_tuple_int_32_list_string_list(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TupleInt32ListStringList(
+      field0: _wire2api_int_32_list(arr[0]),
+      field1: _wire2api_StringList(arr[1]),
+    );
+  }
+
+  TupleStringI32 _wire2api_tuple_string_i_32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TupleStringI32(
+      field0: _wire2api_String(arr[0]),
+      field1: _wire2api_i32(arr[1]),
+    );
+  }
+
+  BigInt _wire2api_u128(dynamic raw) {
+    final arr = raw as List<int>;
+    return (BigInt.from(arr[0]).toUnsigned(64) << 64) | BigInt.from(arr[1]).toUnsigned(64);
+  }
+
+  int _wire2api_u16(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u64(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
+
+  Uint16List _wire2api_uint_16_list(dynamic raw) {
+    return raw as Uint16List;
+  }
+
+  Uint32List _wire2api_uint_32_list(dynamic raw) {
+    return raw as Uint32List;
+  }
+
+  Uint64List _wire2api_uint_64_list(dynamic raw) {
+    return raw as Uint64List;
+  }
+
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
+
+  VecOfPrimitivePack _wire2api_vec_of_primitive_pack(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return VecOfPrimitivePack(
+      int8List: _wire2api_int_8_list(arr[0]),
+      uint8List: _wire2api_uint_8_list(arr[1]),
+      int16List: _wire2api_int_16_list(arr[2]),
+      uint16List: _wire2api_uint_16_list(arr[3]),
+      uint32List: _wire2api_uint_32_list(arr[4]),
+      int32List: _wire2api_int_32_list(arr[5]),
+      uint64List: _wire2api_uint_64_list(arr[6]),
+      int64List: _wire2api_int_64_list(arr[7]),
+      float32List: _wire2api_float_32_list(arr[8]),
+      float64List: _wire2api_float_64_list(arr[9]),
+    );
+  }
+
+  Weekdays _wire2api_weekdays(dynamic raw) {
+    return Weekdays.values[raw];
+  }
+
+  ZeroCopyVecOfPrimitivePack _wire2api_zero_copy_vec_of_primitive_pack(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ZeroCopyVecOfPrimitivePack(
+      int8List: _wire2api_ZeroCopyBuffer_Int8List(arr[0]),
+      uint8List: _wire2api_ZeroCopyBuffer_Uint8List(arr[1]),
+      int16List: _wire2api_ZeroCopyBuffer_Int16List(arr[2]),
+      uint16List: _wire2api_ZeroCopyBuffer_Uint16List(arr[3]),
+      uint32List: _wire2api_ZeroCopyBuffer_Uint32List(arr[4]),
+      int32List: _wire2api_ZeroCopyBuffer_Int32List(arr[5]),
+      uint64List: _wire2api_ZeroCopyBuffer_Uint64List(arr[6]),
+      int64List: _wire2api_ZeroCopyBuffer_Int64List(arr[7]),
+      float32List: _wire2api_ZeroCopyBuffer_Float32List(arr[8]),
+      float64List: _wire2api_ZeroCopyBuffer_Float64List(arr[9]),
+    );
+  }
+
+  LoginErrorException _wire2api_login_error_exception(dynamic raw) {
+    final error = _wire2api_login_error(raw);
+    switch (error) {
+      case LoginError.WrongPassword:
+        return LoginErrorWrongPasswordException(error);
+      case LoginError.Locked:
+        return LoginErrorLockedException(error);
+      default:
+        throw Exception("unreachable");
+    }
+  }
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+
+/// generated by flutter_rust_bridge
+class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  FlutterRustBridgeExampleWire(ffi.DynamicLibrary dynamicLibrary) : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  FlutterRustBridgeExampleWire.fromLookup(ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup)
+      : _lookup = lookup;
+
+  void wire_simple_adder(
+    int port_,
     int a,
     int b,
   ) {
@@ -1599,6 +3022,22 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_primitive_u32');
   late final _wire_primitive_u32 = _wire_primitive_u32Ptr.asFunction<void Function(int, int)>();
 
+  void wire_add_to_u64(
+    int port_,
+    int value,
+    int delta,
+  ) {
+    return _wire_add_to_u64(
+      port_,
+      value,
+      delta,
+    );
+  }
+
+  late final _wire_add_to_u64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Int64)>>('wire_add_to_u64');
+  late final _wire_add_to_u64 = _wire_add_to_u64Ptr.asFunction<void Function(int, int, int)>();
+
   void wire_handle_string(
     int port_,
     ffi.Pointer<wire_uint_8_list> s,
@@ -1614,6 +3053,175 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _wire_handle_string =
       _wire_handle_stringPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_next_char(
+    int port_,
+    int c,
+  ) {
+    return _wire_next_char(
+      port_,
+      c,
+    );
+  }
+
+  late final _wire_next_charPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_next_char');
+  late final _wire_next_char = _wire_next_charPtr.asFunction<void Function(int, int)>();
+
+  void wire_double_duration(
+    int port_,
+    int duration,
+  ) {
+    return _wire_double_duration(
+      port_,
+      duration,
+    );
+  }
+
+  late final _wire_double_durationPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_double_duration');
+  late final _wire_double_duration = _wire_double_durationPtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_async_sum(
+    int port_,
+    ffi.Pointer<wire_int_32_list> numbers,
+  ) {
+    return _wire_handle_async_sum(
+      port_,
+      numbers,
+    );
+  }
+
+  late final _wire_handle_async_sumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_int_32_list>)>>('wire_handle_async_sum');
+  late final _wire_handle_async_sum =
+      _wire_handle_async_sumPtr.asFunction<void Function(int, ffi.Pointer<wire_int_32_list>)>();
+
+  void wire_handle_async_average(
+    int port_,
+    ffi.Pointer<wire_int_32_list> numbers,
+  ) {
+    return _wire_handle_async_average(
+      port_,
+      numbers,
+    );
+  }
+
+  late final _wire_handle_async_averagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_int_32_list>)>>(
+          'wire_handle_async_average');
+  late final _wire_handle_async_average =
+      _wire_handle_async_averagePtr.asFunction<void Function(int, ffi.Pointer<wire_int_32_list>)>();
+
+  WireSyncReturnStruct wire_count_chars(
+    ffi.Pointer<wire_uint_8_list> s,
+  ) {
+    return _wire_count_chars(
+      s,
+    );
+  }
+
+  late final _wire_count_charsPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>>('wire_count_chars');
+  late final _wire_count_chars =
+      _wire_count_charsPtr.asFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_join_path_parts(
+    int port_,
+    ffi.Pointer<wire_list_PathBuf> parts,
+  ) {
+    return _wire_join_path_parts(
+      port_,
+      parts,
+    );
+  }
+
+  late final _wire_join_path_partsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_PathBuf>)>>('wire_join_path_parts');
+  late final _wire_join_path_parts =
+      _wire_join_path_partsPtr.asFunction<void Function(int, ffi.Pointer<wire_list_PathBuf>)>();
+
+  void wire_path_file_name(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> path,
+  ) {
+    return _wire_path_file_name(
+      port_,
+      path,
+    );
+  }
+
+  late final _wire_path_file_namePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_path_file_name');
+  late final _wire_path_file_name =
+      _wire_path_file_namePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_next_non_zero_id(
+    int port_,
+    int id,
+    ffi.Pointer<ffi.Uint8> step,
+  ) {
+    return _wire_next_non_zero_id(
+      port_,
+      id,
+      step,
+    );
+  }
+
+  late final _wire_next_non_zero_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<ffi.Uint8>)>>(
+          'wire_next_non_zero_id');
+  late final _wire_next_non_zero_id =
+      _wire_next_non_zero_idPtr.asFunction<void Function(int, int, ffi.Pointer<ffi.Uint8>)>();
+
+  void wire_wrap_json(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> value,
+    ffi.Pointer<wire_uint_8_list> tag,
+  ) {
+    return _wire_wrap_json(
+      port_,
+      value,
+      tag,
+    );
+  }
+
+  late final _wire_wrap_jsonPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_wrap_json');
+  late final _wire_wrap_json =
+      _wire_wrap_jsonPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_parse_number_i32(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> text,
+  ) {
+    return _wire_parse_number_i32(
+      port_,
+      text,
+    );
+  }
+
+  late final _wire_parse_number_i32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_parse_number_i32');
+  late final _wire_parse_number_i32 =
+      _wire_parse_number_i32Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_parse_number_f64(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> text,
+  ) {
+    return _wire_parse_number_f64(
+      port_,
+      text,
+    );
+  }
+
+  late final _wire_parse_number_f64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_parse_number_f64');
+  late final _wire_parse_number_f64 =
+      _wire_parse_number_f64Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_handle_return_unit(
     int port_,
   ) {
@@ -1641,6 +3249,38 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _wire_handle_vec_u8 =
       _wire_handle_vec_u8Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_handle_boxed_slice(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> bytes,
+  ) {
+    return _wire_handle_boxed_slice(
+      port_,
+      bytes,
+    );
+  }
+
+  late final _wire_handle_boxed_slicePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_boxed_slice');
+  late final _wire_handle_boxed_slice =
+      _wire_handle_boxed_slicePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_boxed_string_slice(
+    int port_,
+    ffi.Pointer<wire_StringList> names,
+  ) {
+    return _wire_handle_boxed_string_slice(
+      port_,
+      names,
+    );
+  }
+
+  late final _wire_handle_boxed_string_slicePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
+          'wire_handle_boxed_string_slice');
+  late final _wire_handle_boxed_string_slice =
+      _wire_handle_boxed_string_slicePtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+
   void wire_handle_vec_of_primitive(
     int port_,
     int n,
@@ -1651,349 +3291,1030 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
     );
   }
 
-  late final _wire_handle_vec_of_primitivePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_vec_of_primitive');
-  late final _wire_handle_vec_of_primitive = _wire_handle_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_vec_of_primitivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_vec_of_primitive');
+  late final _wire_handle_vec_of_primitive = _wire_handle_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_zero_copy_vec_of_primitive(
+    int port_,
+    int n,
+  ) {
+    return _wire_handle_zero_copy_vec_of_primitive(
+      port_,
+      n,
+    );
+  }
+
+  late final _wire_handle_zero_copy_vec_of_primitivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive');
+  late final _wire_handle_zero_copy_vec_of_primitive =
+      _wire_handle_zero_copy_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_struct(
+    int port_,
+    ffi.Pointer<wire_MySize> arg,
+    ffi.Pointer<wire_MySize> boxed,
+  ) {
+    return _wire_handle_struct(
+      port_,
+      arg,
+      boxed,
+    );
+  }
+
+  late final _wire_handle_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
+          'wire_handle_struct');
+  late final _wire_handle_struct =
+      _wire_handle_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
+
+  void wire_handle_newtype(
+    int port_,
+    ffi.Pointer<wire_NewTypeInt> arg,
+  ) {
+    return _wire_handle_newtype(
+      port_,
+      arg,
+    );
+  }
+
+  late final _wire_handle_newtypePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype');
+  late final _wire_handle_newtype =
+      _wire_handle_newtypePtr.asFunction<void Function(int, ffi.Pointer<wire_NewTypeInt>)>();
+
+  void wire_handle_list_of_struct(
+    int port_,
+    ffi.Pointer<wire_list_my_size> l,
+  ) {
+    return _wire_handle_list_of_struct(
+      port_,
+      l,
+    );
+  }
+
+  late final _wire_handle_list_of_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_my_size>)>>(
+          'wire_handle_list_of_struct');
+  late final _wire_handle_list_of_struct =
+      _wire_handle_list_of_structPtr.asFunction<void Function(int, ffi.Pointer<wire_list_my_size>)>();
+
+  void wire_handle_string_list(
+    int port_,
+    ffi.Pointer<wire_StringList> names,
+  ) {
+    return _wire_handle_string_list(
+      port_,
+      names,
+    );
+  }
+
+  late final _wire_handle_string_listPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
+          'wire_handle_string_list');
+  late final _wire_handle_string_list =
+      _wire_handle_string_listPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+
+  void wire_handle_nested_list(
+    int port_,
+    ffi.Pointer<wire_list_float_64_list> matrix,
+  ) {
+    return _wire_handle_nested_list(
+      port_,
+      matrix,
+    );
+  }
+
+  late final _wire_handle_nested_listPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_float_64_list>)>>(
+          'wire_handle_nested_list');
+  late final _wire_handle_nested_list =
+      _wire_handle_nested_listPtr.asFunction<void Function(int, ffi.Pointer<wire_list_float_64_list>)>();
+
+  void wire_handle_nested_string_list(
+    int port_,
+    ffi.Pointer<wire_list_StringList> lines,
+  ) {
+    return _wire_handle_nested_string_list(
+      port_,
+      lines,
+    );
+  }
+
+  late final _wire_handle_nested_string_listPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_StringList>)>>(
+          'wire_handle_nested_string_list');
+  late final _wire_handle_nested_string_list =
+      _wire_handle_nested_string_listPtr.asFunction<void Function(int, ffi.Pointer<wire_list_StringList>)>();
+
+  void wire_handle_hash_map(
+    int port_,
+    ffi.Pointer<wire_hash_map_String_i32> counts,
+  ) {
+    return _wire_handle_hash_map(
+      port_,
+      counts,
+    );
+  }
+
+  late final _wire_handle_hash_mapPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_hash_map_String_i32>)>>(
+          'wire_handle_hash_map');
+  late final _wire_handle_hash_map =
+      _wire_handle_hash_mapPtr.asFunction<void Function(int, ffi.Pointer<wire_hash_map_String_i32>)>();
+
+  void wire_handle_btree_map(
+    int port_,
+    ffi.Pointer<wire_btree_map_i32_String> names,
+  ) {
+    return _wire_handle_btree_map(
+      port_,
+      names,
+    );
+  }
+
+  late final _wire_handle_btree_mapPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_btree_map_i32_String>)>>(
+          'wire_handle_btree_map');
+  late final _wire_handle_btree_map =
+      _wire_handle_btree_mapPtr.asFunction<void Function(int, ffi.Pointer<wire_btree_map_i32_String>)>();
+
+  void wire_handle_iterator(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> text,
+  ) {
+    return _wire_handle_iterator(
+      port_,
+      text,
+    );
+  }
+
+  late final _wire_handle_iteratorPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_iterator');
+  late final _wire_handle_iterator =
+      _wire_handle_iteratorPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_fallible_iterator(
+    int port_,
+    int n,
+  ) {
+    return _wire_handle_fallible_iterator(
+      port_,
+      n,
+    );
+  }
+
+  late final _wire_handle_fallible_iteratorPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_fallible_iterator');
+  late final _wire_handle_fallible_iterator = _wire_handle_fallible_iteratorPtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_callback(
+    int port_,
+    ffi.Pointer<wire_int_32_list> items,
+    int transform,
+  ) {
+    return _wire_handle_callback(
+      port_,
+      items,
+      transform,
+    );
+  }
+
+  late final _wire_handle_callbackPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_int_32_list>, ffi.Int64)>>(
+          'wire_handle_callback');
+  late final _wire_handle_callback =
+      _wire_handle_callbackPtr.asFunction<void Function(int, ffi.Pointer<wire_int_32_list>, int)>();
+
+  void wire_handle_complex_struct(
+    int port_,
+    ffi.Pointer<wire_MyTreeNode> s,
+  ) {
+    return _wire_handle_complex_struct(
+      port_,
+      s,
+    );
+  }
+
+  late final _wire_handle_complex_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyTreeNode>)>>(
+          'wire_handle_complex_struct');
+  late final _wire_handle_complex_struct =
+      _wire_handle_complex_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyTreeNode>)>();
+
+  void wire_make_deep_tree(
+    int port_,
+    int depth,
+  ) {
+    return _wire_make_deep_tree(
+      port_,
+      depth,
+    );
+  }
+
+  late final _wire_make_deep_treePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_make_deep_tree');
+  late final _wire_make_deep_tree = _wire_make_deep_treePtr.asFunction<void Function(int, int)>();
+
+  void wire_tree_depth(
+    int port_,
+    ffi.Pointer<wire_MyTreeNode> tree,
+  ) {
+    return _wire_tree_depth(
+      port_,
+      tree,
+    );
+  }
+
+  late final _wire_tree_depthPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyTreeNode>)>>('wire_tree_depth');
+  late final _wire_tree_depth = _wire_tree_depthPtr.asFunction<void Function(int, ffi.Pointer<wire_MyTreeNode>)>();
+
+  WireSyncReturnStruct wire_handle_sync_return(
+    ffi.Pointer<wire_uint_8_list> mode,
+  ) {
+    return _wire_handle_sync_return(
+      mode,
+    );
+  }
+
+  late final _wire_handle_sync_returnPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_sync_return');
+  late final _wire_handle_sync_return =
+      _wire_handle_sync_returnPtr.asFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_stream(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> arg,
+  ) {
+    return _wire_handle_stream(
+      port_,
+      arg,
+    );
+  }
+
+  late final _wire_handle_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_stream');
+  late final _wire_handle_stream =
+      _wire_handle_streamPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_return_err(
+    int port_,
+  ) {
+    return _wire_return_err(
+      port_,
+    );
+  }
+
+  late final _wire_return_errPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_err');
+  late final _wire_return_err = _wire_return_errPtr.asFunction<void Function(int)>();
+
+  void wire_return_custom_err(
+    int port_,
+    bool fail,
+  ) {
+    return _wire_return_custom_err(
+      port_,
+      fail ? 1 : 0,
+    );
+  }
+
+  late final _wire_return_custom_errPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint8)>>('wire_return_custom_err');
+  late final _wire_return_custom_err = _wire_return_custom_errPtr.asFunction<void Function(int, int)>();
+
+  void wire_login(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> password,
+    int attempts,
+  ) {
+    return _wire_login(
+      port_,
+      password,
+      attempts,
+    );
+  }
+
+  late final _wire_loginPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32)>>(
+          'wire_login');
+  late final _wire_login = _wire_loginPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_return_panic(
+    int port_,
+  ) {
+    return _wire_return_panic(
+      port_,
+    );
+  }
+
+  late final _wire_return_panicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_panic');
+  late final _wire_return_panic = _wire_return_panicPtr.asFunction<void Function(int)>();
+
+  void wire_handle_optional_return(
+    int port_,
+    double left,
+    double right,
+  ) {
+    return _wire_handle_optional_return(
+      port_,
+      left,
+      right,
+    );
+  }
+
+  late final _wire_handle_optional_returnPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Double, ffi.Double)>>('wire_handle_optional_return');
+  late final _wire_handle_optional_return =
+      _wire_handle_optional_returnPtr.asFunction<void Function(int, double, double)>();
+
+  void wire_handle_optional_primitives(
+    int port_,
+    ffi.Pointer<wire_list_opt_i32> values,
+    wire_opt_i64 offset,
+    wire_opt_f64 scale,
+    wire_opt_bool negate,
+  ) {
+    return _wire_handle_optional_primitives(
+      port_,
+      values,
+      offset,
+      scale,
+      negate,
+    );
+  }
+
+  late final _wire_handle_optional_primitivesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_opt_i32>, wire_opt_i64, wire_opt_f64,
+              wire_opt_bool)>>('wire_handle_optional_primitives');
+  late final _wire_handle_optional_primitives = _wire_handle_optional_primitivesPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_list_opt_i32>, wire_opt_i64, wire_opt_f64, wire_opt_bool)>();
+
+  void wire_handle_optional_struct(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> document,
+  ) {
+    return _wire_handle_optional_struct(
+      port_,
+      document,
+    );
+  }
+
+  late final _wire_handle_optional_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_optional_struct');
+  late final _wire_handle_optional_struct =
+      _wire_handle_optional_structPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_optional_increment(
+    int port_,
+    ffi.Pointer<wire_ExoticOptionals> opt,
+  ) {
+    return _wire_handle_optional_increment(
+      port_,
+      opt,
+    );
+  }
+
+  late final _wire_handle_optional_incrementPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExoticOptionals>)>>(
+          'wire_handle_optional_increment');
+  late final _wire_handle_optional_increment =
+      _wire_handle_optional_incrementPtr.asFunction<void Function(int, ffi.Pointer<wire_ExoticOptionals>)>();
+
+  void wire_handle_increment_boxed_optional(
+    int port_,
+    ffi.Pointer<ffi.Double> opt,
+  ) {
+    return _wire_handle_increment_boxed_optional(
+      port_,
+      opt,
+    );
+  }
+
+  late final _wire_handle_increment_boxed_optionalPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Double>)>>(
+          'wire_handle_increment_boxed_optional');
+  late final _wire_handle_increment_boxed_optional =
+      _wire_handle_increment_boxed_optionalPtr.asFunction<void Function(int, ffi.Pointer<ffi.Double>)>();
+
+  void wire_handle_option_box_arguments(
+    int port_,
+    ffi.Pointer<ffi.Int8> i8box,
+    ffi.Pointer<ffi.Uint8> u8box,
+    ffi.Pointer<ffi.Int32> i32box,
+    ffi.Pointer<ffi.Int64> i64box,
+    ffi.Pointer<ffi.Double> f64box,
+    ffi.Pointer<ffi.Uint8> boolbox,
+    ffi.Pointer<wire_ExoticOptionals> structbox,
+  ) {
+    return _wire_handle_option_box_arguments(
+      port_,
+      i8box,
+      u8box,
+      i32box,
+      i64box,
+      f64box,
+      boolbox,
+      structbox,
+    );
+  }
+
+  late final _wire_handle_option_box_argumentsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<ffi.Int8>,
+              ffi.Pointer<ffi.Uint8>,
+              ffi.Pointer<ffi.Int32>,
+              ffi.Pointer<ffi.Int64>,
+              ffi.Pointer<ffi.Double>,
+              ffi.Pointer<ffi.Uint8>,
+              ffi.Pointer<wire_ExoticOptionals>)>>('wire_handle_option_box_arguments');
+  late final _wire_handle_option_box_arguments = _wire_handle_option_box_argumentsPtr.asFunction<
+      void Function(int, ffi.Pointer<ffi.Int8>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Int32>, ffi.Pointer<ffi.Int64>,
+          ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<wire_ExoticOptionals>)>();
+
+  void wire_handle_return_enum(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> input,
+  ) {
+    return _wire_handle_return_enum(
+      port_,
+      input,
+    );
+  }
+
+  late final _wire_handle_return_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_return_enum');
+  late final _wire_handle_return_enum =
+      _wire_handle_return_enumPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_enum_parameter(
+    int port_,
+    int weekday,
+  ) {
+    return _wire_handle_enum_parameter(
+      port_,
+      weekday,
+    );
+  }
+
+  late final _wire_handle_enum_parameterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_enum_parameter');
+  late final _wire_handle_enum_parameter = _wire_handle_enum_parameterPtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_sparse_lists(
+    int port_,
+    ffi.Pointer<wire_SparseLists> lists,
+  ) {
+    return _wire_handle_sparse_lists(
+      port_,
+      lists,
+    );
+  }
+
+  late final _wire_handle_sparse_listsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_SparseLists>)>>(
+          'wire_handle_sparse_lists');
+  late final _wire_handle_sparse_lists =
+      _wire_handle_sparse_listsPtr.asFunction<void Function(int, ffi.Pointer<wire_SparseLists>)>();
+
+  void wire_handle_non_exhaustive_enum(
+    int port_,
+    int priority,
+  ) {
+    return _wire_handle_non_exhaustive_enum(
+      port_,
+      priority,
+    );
+  }
+
+  late final _wire_handle_non_exhaustive_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_non_exhaustive_enum');
+  late final _wire_handle_non_exhaustive_enum =
+      _wire_handle_non_exhaustive_enumPtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_customized_struct(
+    int port_,
+    ffi.Pointer<wire_Customized> val,
+  ) {
+    return _wire_handle_customized_struct(
+      port_,
+      val,
+    );
+  }
+
+  late final _wire_handle_customized_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Customized>)>>(
+          'wire_handle_customized_struct');
+  late final _wire_handle_customized_struct =
+      _wire_handle_customized_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Customized>)>();
+
+  void wire_handle_enum_struct(
+    int port_,
+    ffi.Pointer<wire_KitchenSink> val,
+  ) {
+    return _wire_handle_enum_struct(
+      port_,
+      val,
+    );
+  }
+
+  late final _wire_handle_enum_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_KitchenSink>)>>(
+          'wire_handle_enum_struct');
+  late final _wire_handle_enum_struct =
+      _wire_handle_enum_structPtr.asFunction<void Function(int, ffi.Pointer<wire_KitchenSink>)>();
+
+  void wire_use_imported_struct(
+    int port_,
+    ffi.Pointer<wire_MyStruct> my_struct,
+  ) {
+    return _wire_use_imported_struct(
+      port_,
+      my_struct,
+    );
+  }
+
+  late final _wire_use_imported_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyStruct>)>>('wire_use_imported_struct');
+  late final _wire_use_imported_struct =
+      _wire_use_imported_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyStruct>)>();
+
+  void wire_use_imported_enum(
+    int port_,
+    int my_enum,
+  ) {
+    return _wire_use_imported_enum(
+      port_,
+      my_enum,
+    );
+  }
+
+  late final _wire_use_imported_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_use_imported_enum');
+  late final _wire_use_imported_enum = _wire_use_imported_enumPtr.asFunction<void Function(int, int)>();
+
+  void wire_get_init_count(
+    int port_,
+  ) {
+    return _wire_get_init_count(
+      port_,
+    );
+  }
+
+  late final _wire_get_init_countPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_init_count');
+  late final _wire_get_init_count = _wire_get_init_countPtr.asFunction<void Function(int)>();
+
+  void wire_increment_locked_count(
+    int port_,
+  ) {
+    return _wire_increment_locked_count(
+      port_,
+    );
+  }
+
+  late final _wire_increment_locked_countPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_increment_locked_count');
+  late final _wire_increment_locked_count = _wire_increment_locked_countPtr.asFunction<void Function(int)>();
+
+  void wire_get_locked_count(
+    int port_,
+  ) {
+    return _wire_get_locked_count(
+      port_,
+    );
+  }
+
+  late final _wire_get_locked_countPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_locked_count');
+  late final _wire_get_locked_count = _wire_get_locked_countPtr.asFunction<void Function(int)>();
+
+  void wire_count_until_cancelled(
+    int port_,
+    int limit,
+    int token,
+  ) {
+    return _wire_count_until_cancelled(
+      port_,
+      limit,
+      token,
+    );
+  }
+
+  late final _wire_count_until_cancelledPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Int64)>>('wire_count_until_cancelled');
+  late final _wire_count_until_cancelled = _wire_count_until_cancelledPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_new_counter(
+    int port_,
+    int start,
+  ) {
+    return _wire_new_counter(
+      port_,
+      start,
+    );
+  }
+
+  late final _wire_new_counterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_new_counter');
+  late final _wire_new_counter = _wire_new_counterPtr.asFunction<void Function(int, int)>();
+
+  void wire_counter_value(
+    int port_,
+    ffi.Pointer<wire_Counter> counter,
+  ) {
+    return _wire_counter_value(
+      port_,
+      counter,
+    );
+  }
+
+  late final _wire_counter_valuePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Counter>)>>('wire_counter_value');
+  late final _wire_counter_value = _wire_counter_valuePtr.asFunction<void Function(int, ffi.Pointer<wire_Counter>)>();
+
+  void wire_new_counter_if_positive(
+    int port_,
+    int start,
+  ) {
+    return _wire_new_counter_if_positive(
+      port_,
+      start,
+    );
+  }
+
+  late final _wire_new_counter_if_positivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_new_counter_if_positive');
+  late final _wire_new_counter_if_positive = _wire_new_counter_if_positivePtr.asFunction<void Function(int, int)>();
+
+  void wire_counter_value_or_zero(
+    int port_,
+    ffi.Pointer<wire_Counter> counter,
+  ) {
+    return _wire_counter_value_or_zero(
+      port_,
+      counter,
+    );
+  }
+
+  late final _wire_counter_value_or_zeroPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Counter>)>>(
+          'wire_counter_value_or_zero');
+  late final _wire_counter_value_or_zero =
+      _wire_counter_value_or_zeroPtr.asFunction<void Function(int, ffi.Pointer<wire_Counter>)>();
+
+  void wire_new_calculator(
+    int port_,
+    int base,
+  ) {
+    return _wire_new_calculator(
+      port_,
+      base,
+    );
+  }
+
+  late final _wire_new_calculatorPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_new_calculator');
+  late final _wire_new_calculator = _wire_new_calculatorPtr.asFunction<void Function(int, int)>();
+
+  void wire_reverse_fixed_array(
+    int port_,
+    ffi.Pointer<wire_float_64_list> values,
+  ) {
+    return _wire_reverse_fixed_array(
+      port_,
+      values,
+    );
+  }
+
+  late final _wire_reverse_fixed_arrayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_float_64_list>)>>(
+          'wire_reverse_fixed_array');
+  late final _wire_reverse_fixed_array =
+      _wire_reverse_fixed_arrayPtr.asFunction<void Function(int, ffi.Pointer<wire_float_64_list>)>();
 
-  void wire_handle_zero_copy_vec_of_primitive(
+  void wire_swap_fixed_sizes(
     int port_,
-    int n,
+    ffi.Pointer<wire_list_my_size> sizes,
   ) {
-    return _wire_handle_zero_copy_vec_of_primitive(
+    return _wire_swap_fixed_sizes(
       port_,
-      n,
+      sizes,
     );
   }
 
-  late final _wire_handle_zero_copy_vec_of_primitivePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive');
-  late final _wire_handle_zero_copy_vec_of_primitive =
-      _wire_handle_zero_copy_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+  late final _wire_swap_fixed_sizesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_my_size>)>>(
+          'wire_swap_fixed_sizes');
+  late final _wire_swap_fixed_sizes =
+      _wire_swap_fixed_sizesPtr.asFunction<void Function(int, ffi.Pointer<wire_list_my_size>)>();
 
-  void wire_handle_struct(
+  void wire_swap_tuple(
     int port_,
-    ffi.Pointer<wire_MySize> arg,
-    ffi.Pointer<wire_MySize> boxed,
+    ffi.Pointer<wire_TupleI32String> pair,
   ) {
-    return _wire_handle_struct(
+    return _wire_swap_tuple(
       port_,
-      arg,
-      boxed,
+      pair,
     );
   }
 
-  late final _wire_handle_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
-          'wire_handle_struct');
-  late final _wire_handle_struct =
-      _wire_handle_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
+  late final _wire_swap_tuplePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_TupleI32String>)>>('wire_swap_tuple');
+  late final _wire_swap_tuple = _wire_swap_tuplePtr.asFunction<void Function(int, ffi.Pointer<wire_TupleI32String>)>();
 
-  void wire_handle_newtype(
+  void wire_distance(
     int port_,
-    ffi.Pointer<wire_NewTypeInt> arg,
+    ffi.Pointer<wire_TupleF64F64> a,
+    ffi.Pointer<wire_TupleF64F64> b,
   ) {
-    return _wire_handle_newtype(
+    return _wire_distance(
       port_,
-      arg,
+      a,
+      b,
     );
   }
 
-  late final _wire_handle_newtypePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype');
-  late final _wire_handle_newtype =
-      _wire_handle_newtypePtr.asFunction<void Function(int, ffi.Pointer<wire_NewTypeInt>)>();
+  late final _wire_distancePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_TupleF64F64>, ffi.Pointer<wire_TupleF64F64>)>>(
+      'wire_distance');
+  late final _wire_distance =
+      _wire_distancePtr.asFunction<void Function(int, ffi.Pointer<wire_TupleF64F64>, ffi.Pointer<wire_TupleF64F64>)>();
 
-  void wire_handle_list_of_struct(
+  void wire_increment_u128(
     int port_,
-    ffi.Pointer<wire_list_my_size> l,
+    ffi.Pointer<wire_uint_128> value,
   ) {
-    return _wire_handle_list_of_struct(
+    return _wire_increment_u128(
       port_,
-      l,
+      value,
     );
   }
 
-  late final _wire_handle_list_of_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_my_size>)>>(
-          'wire_handle_list_of_struct');
-  late final _wire_handle_list_of_struct =
-      _wire_handle_list_of_structPtr.asFunction<void Function(int, ffi.Pointer<wire_list_my_size>)>();
+  late final _wire_increment_u128Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_128>)>>('wire_increment_u128');
+  late final _wire_increment_u128 =
+      _wire_increment_u128Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_128>)>();
 
-  void wire_handle_string_list(
+  void wire_decrement_i128(
     int port_,
-    ffi.Pointer<wire_StringList> names,
+    ffi.Pointer<wire_int_128> value,
   ) {
-    return _wire_handle_string_list(
+    return _wire_decrement_i128(
       port_,
-      names,
+      value,
     );
   }
 
-  late final _wire_handle_string_listPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
-          'wire_handle_string_list');
-  late final _wire_handle_string_list =
-      _wire_handle_string_listPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+  late final _wire_decrement_i128Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_int_128>)>>('wire_decrement_i128');
+  late final _wire_decrement_i128 = _wire_decrement_i128Ptr.asFunction<void Function(int, ffi.Pointer<wire_int_128>)>();
 
-  void wire_handle_complex_struct(
+  void wire_unique_words(
     int port_,
-    ffi.Pointer<wire_MyTreeNode> s,
+    ffi.Pointer<wire_uint_8_list> text,
   ) {
-    return _wire_handle_complex_struct(
+    return _wire_unique_words(
       port_,
-      s,
+      text,
     );
   }
 
-  late final _wire_handle_complex_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyTreeNode>)>>(
-          'wire_handle_complex_struct');
-  late final _wire_handle_complex_struct =
-      _wire_handle_complex_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyTreeNode>)>();
+  late final _wire_unique_wordsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_unique_words');
+  late final _wire_unique_words = _wire_unique_wordsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  WireSyncReturnStruct wire_handle_sync_return(
-    ffi.Pointer<wire_uint_8_list> mode,
+  void wire_sorted_evens(
+    int port_,
+    ffi.Pointer<wire_int_32_list> values,
   ) {
-    return _wire_handle_sync_return(
-      mode,
+    return _wire_sorted_evens(
+      port_,
+      values,
     );
   }
 
-  late final _wire_handle_sync_returnPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_handle_sync_return');
-  late final _wire_handle_sync_return =
-      _wire_handle_sync_returnPtr.asFunction<WireSyncReturnStruct Function(ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_sorted_evensPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_int_32_list>)>>('wire_sorted_evens');
+  late final _wire_sorted_evens = _wire_sorted_evensPtr.asFunction<void Function(int, ffi.Pointer<wire_int_32_list>)>();
 
-  void wire_handle_stream(
+  void wire_fill_buffer(
     int port_,
-    ffi.Pointer<wire_uint_8_list> arg,
+    int len,
+    int value,
   ) {
-    return _wire_handle_stream(
+    return _wire_fill_buffer(
       port_,
-      arg,
+      len,
+      value,
     );
   }
 
-  late final _wire_handle_streamPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_stream');
-  late final _wire_handle_stream =
-      _wire_handle_streamPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_fill_bufferPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint8)>>('wire_fill_buffer');
+  late final _wire_fill_buffer = _wire_fill_bufferPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_return_err(
+  void wire_parse_numbers(
     int port_,
+    ffi.Pointer<wire_uint_8_list> input,
   ) {
-    return _wire_return_err(
+    return _wire_parse_numbers(
       port_,
+      input,
     );
   }
 
-  late final _wire_return_errPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_err');
-  late final _wire_return_err = _wire_return_errPtr.asFunction<void Function(int)>();
+  late final _wire_parse_numbersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_parse_numbers');
+  late final _wire_parse_numbers =
+      _wire_parse_numbersPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_return_panic(
+  void wire_clamp_to(
     int port_,
+    int value,
+    int max,
   ) {
-    return _wire_return_panic(
+    return _wire_clamp_to(
       port_,
+      value,
+      max,
     );
   }
 
-  late final _wire_return_panicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_panic');
-  late final _wire_return_panic = _wire_return_panicPtr.asFunction<void Function(int)>();
+  late final _wire_clamp_toPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_clamp_to');
+  late final _wire_clamp_to = _wire_clamp_toPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_handle_optional_return(
+  void wire_shout(
     int port_,
-    double left,
-    double right,
+    ffi.Pointer<wire_uint_8_list> text,
+    int times,
   ) {
-    return _wire_handle_optional_return(
+    return _wire_shout(
       port_,
-      left,
-      right,
+      text,
+      times,
     );
   }
 
-  late final _wire_handle_optional_returnPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Double, ffi.Double)>>('wire_handle_optional_return');
-  late final _wire_handle_optional_return =
-      _wire_handle_optional_returnPtr.asFunction<void Function(int, double, double)>();
+  late final _wire_shoutPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32)>>(
+          'wire_shout');
+  late final _wire_shout = _wire_shoutPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_handle_optional_struct(
+  void wire_add_one_day(
     int port_,
-    ffi.Pointer<wire_uint_8_list> document,
+    int time,
   ) {
-    return _wire_handle_optional_struct(
+    return _wire_add_one_day(
       port_,
-      document,
+      time,
     );
   }
 
-  late final _wire_handle_optional_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_handle_optional_struct');
-  late final _wire_handle_optional_struct =
-      _wire_handle_optional_structPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_add_one_dayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_add_one_day');
+  late final _wire_add_one_day = _wire_add_one_dayPtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_optional_increment(
+  void wire_to_local_time(
     int port_,
-    ffi.Pointer<wire_ExoticOptionals> opt,
+    int time,
   ) {
-    return _wire_handle_optional_increment(
+    return _wire_to_local_time(
       port_,
-      opt,
+      time,
     );
   }
 
-  late final _wire_handle_optional_incrementPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExoticOptionals>)>>(
-          'wire_handle_optional_increment');
-  late final _wire_handle_optional_increment =
-      _wire_handle_optional_incrementPtr.asFunction<void Function(int, ffi.Pointer<wire_ExoticOptionals>)>();
+  late final _wire_to_local_timePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_to_local_time');
+  late final _wire_to_local_time = _wire_to_local_timePtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_increment_boxed_optional(
+  void wire_naive_wall_clock(
     int port_,
-    ffi.Pointer<ffi.Double> opt,
+    int time,
   ) {
-    return _wire_handle_increment_boxed_optional(
+    return _wire_naive_wall_clock(
       port_,
-      opt,
+      time,
     );
   }
 
-  late final _wire_handle_increment_boxed_optionalPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Double>)>>(
-          'wire_handle_increment_boxed_optional');
-  late final _wire_handle_increment_boxed_optional =
-      _wire_handle_increment_boxed_optionalPtr.asFunction<void Function(int, ffi.Pointer<ffi.Double>)>();
+  late final _wire_naive_wall_clockPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_naive_wall_clock');
+  late final _wire_naive_wall_clock = _wire_naive_wall_clockPtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_option_box_arguments(
+  void wire_nil_uuid(
     int port_,
-    ffi.Pointer<ffi.Int8> i8box,
-    ffi.Pointer<ffi.Uint8> u8box,
-    ffi.Pointer<ffi.Int32> i32box,
-    ffi.Pointer<ffi.Int64> i64box,
-    ffi.Pointer<ffi.Double> f64box,
-    ffi.Pointer<ffi.Uint8> boolbox,
-    ffi.Pointer<wire_ExoticOptionals> structbox,
   ) {
-    return _wire_handle_option_box_arguments(
+    return _wire_nil_uuid(
       port_,
-      i8box,
-      u8box,
-      i32box,
-      i64box,
-      f64box,
-      boolbox,
-      structbox,
     );
   }
 
-  late final _wire_handle_option_box_argumentsPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Pointer<ffi.Int8>,
-              ffi.Pointer<ffi.Uint8>,
-              ffi.Pointer<ffi.Int32>,
-              ffi.Pointer<ffi.Int64>,
-              ffi.Pointer<ffi.Double>,
-              ffi.Pointer<ffi.Uint8>,
-              ffi.Pointer<wire_ExoticOptionals>)>>('wire_handle_option_box_arguments');
-  late final _wire_handle_option_box_arguments = _wire_handle_option_box_argumentsPtr.asFunction<
-      void Function(int, ffi.Pointer<ffi.Int8>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Int32>, ffi.Pointer<ffi.Int64>,
-          ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<wire_ExoticOptionals>)>();
+  late final _wire_nil_uuidPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_nil_uuid');
+  late final _wire_nil_uuid = _wire_nil_uuidPtr.asFunction<void Function(int)>();
 
-  void wire_handle_return_enum(
+  void wire_uuid_version(
     int port_,
-    ffi.Pointer<wire_uint_8_list> input,
+    ffi.Pointer<wire_uint_8_list> id,
   ) {
-    return _wire_handle_return_enum(
+    return _wire_uuid_version(
       port_,
-      input,
+      id,
     );
   }
 
-  late final _wire_handle_return_enumPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_handle_return_enum');
-  late final _wire_handle_return_enum =
-      _wire_handle_return_enumPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_uuid_versionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_uuid_version');
+  late final _wire_uuid_version = _wire_uuid_versionPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_enum_parameter(
+  void wire_counter_increment(
     int port_,
-    int weekday,
+    ffi.Pointer<wire_Counter> that,
+    int by,
   ) {
-    return _wire_handle_enum_parameter(
+    return _wire_counter_increment(
       port_,
-      weekday,
+      that,
+      by,
     );
   }
 
-  late final _wire_handle_enum_parameterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_enum_parameter');
-  late final _wire_handle_enum_parameter = _wire_handle_enum_parameterPtr.asFunction<void Function(int, int)>();
+  late final _wire_counter_incrementPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Counter>, ffi.Int32)>>(
+          'wire_counter_increment');
+  late final _wire_counter_increment =
+      _wire_counter_incrementPtr.asFunction<void Function(int, ffi.Pointer<wire_Counter>, int)>();
 
-  void wire_handle_customized_struct(
+  void wire_calculator_add(
     int port_,
-    ffi.Pointer<wire_Customized> val,
+    ffi.Pointer<wire_Calculator> that,
+    int x,
   ) {
-    return _wire_handle_customized_struct(
+    return _wire_calculator_add(
       port_,
-      val,
+      that,
+      x,
     );
   }
 
-  late final _wire_handle_customized_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Customized>)>>(
-          'wire_handle_customized_struct');
-  late final _wire_handle_customized_struct =
-      _wire_handle_customized_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Customized>)>();
+  late final _wire_calculator_addPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Calculator>, ffi.Int32)>>(
+          'wire_calculator_add');
+  late final _wire_calculator_add =
+      _wire_calculator_addPtr.asFunction<void Function(int, ffi.Pointer<wire_Calculator>, int)>();
 
-  void wire_handle_enum_struct(
+  void wire_calculator_accumulate(
     int port_,
-    ffi.Pointer<wire_KitchenSink> val,
+    ffi.Pointer<wire_Calculator> that,
+    int x,
   ) {
-    return _wire_handle_enum_struct(
+    return _wire_calculator_accumulate(
       port_,
-      val,
+      that,
+      x,
     );
   }
 
-  late final _wire_handle_enum_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_KitchenSink>)>>(
-          'wire_handle_enum_struct');
-  late final _wire_handle_enum_struct =
-      _wire_handle_enum_structPtr.asFunction<void Function(int, ffi.Pointer<wire_KitchenSink>)>();
+  late final _wire_calculator_accumulatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Calculator>, ffi.Int32)>>(
+          'wire_calculator_accumulate');
+  late final _wire_calculator_accumulate =
+      _wire_calculator_accumulatePtr.asFunction<void Function(int, ffi.Pointer<wire_Calculator>, int)>();
 
-  void wire_use_imported_struct(
+  void wire_calculator_into_base(
     int port_,
-    ffi.Pointer<wire_MyStruct> my_struct,
+    ffi.Pointer<wire_Calculator> that,
   ) {
-    return _wire_use_imported_struct(
+    return _wire_calculator_into_base(
       port_,
-      my_struct,
+      that,
     );
   }
 
-  late final _wire_use_imported_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyStruct>)>>('wire_use_imported_struct');
-  late final _wire_use_imported_struct =
-      _wire_use_imported_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyStruct>)>();
+  late final _wire_calculator_into_basePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Calculator>)>>(
+          'wire_calculator_into_base');
+  late final _wire_calculator_into_base =
+      _wire_calculator_into_basePtr.asFunction<void Function(int, ffi.Pointer<wire_Calculator>)>();
 
-  void wire_use_imported_enum(
+  void wire_init(
     int port_,
-    int my_enum,
   ) {
-    return _wire_use_imported_enum(
+    return _wire_init(
       port_,
-      my_enum,
     );
   }
 
-  late final _wire_use_imported_enumPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_use_imported_enum');
-  late final _wire_use_imported_enum = _wire_use_imported_enumPtr.asFunction<void Function(int, int)>();
+  late final _wire_initPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_init');
+  late final _wire_init = _wire_initPtr.asFunction<void Function(int)>();
 
   ffi.Pointer<wire_StringList> new_StringList(
     int len,
@@ -2007,6 +4328,19 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>('new_StringList');
   late final _new_StringList = _new_StringListPtr.asFunction<ffi.Pointer<wire_StringList> Function(int)>();
 
+  ffi.Pointer<ffi.Uint8> new_box_autoadd_NonZero_u8(
+    int value,
+  ) {
+    return _new_box_autoadd_NonZero_u8(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_NonZero_u8Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>('new_box_autoadd_NonZero_u8');
+  late final _new_box_autoadd_NonZero_u8 =
+      _new_box_autoadd_NonZero_u8Ptr.asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
+
   ffi.Pointer<wire_Attribute> new_box_autoadd_attribute() {
     return _new_box_autoadd_attribute();
   }
@@ -2016,71 +4350,32 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_attribute =
       _new_box_autoadd_attributePtr.asFunction<ffi.Pointer<wire_Attribute> Function()>();
 
-  ffi.Pointer<ffi.Uint8> new_box_autoadd_bool(
-    bool value,
-  ) {
-    return _new_box_autoadd_bool(
-      value ? 1 : 0,
-    );
-  }
-
-  late final _new_box_autoadd_boolPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>('new_box_autoadd_bool');
-  late final _new_box_autoadd_bool = _new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
-
-  ffi.Pointer<wire_Customized> new_box_autoadd_customized() {
-    return _new_box_autoadd_customized();
-  }
-
-  late final _new_box_autoadd_customizedPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Customized> Function()>>('new_box_autoadd_customized');
-  late final _new_box_autoadd_customized =
-      _new_box_autoadd_customizedPtr.asFunction<ffi.Pointer<wire_Customized> Function()>();
-
-  ffi.Pointer<wire_ExoticOptionals> new_box_autoadd_exotic_optionals() {
-    return _new_box_autoadd_exotic_optionals();
-  }
-
-  late final _new_box_autoadd_exotic_optionalsPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExoticOptionals> Function()>>('new_box_autoadd_exotic_optionals');
-  late final _new_box_autoadd_exotic_optionals =
-      _new_box_autoadd_exotic_optionalsPtr.asFunction<ffi.Pointer<wire_ExoticOptionals> Function()>();
-
-  ffi.Pointer<ffi.Double> new_box_autoadd_f64(
-    double value,
-  ) {
-    return _new_box_autoadd_f64(
-      value,
-    );
-  }
-
-  late final _new_box_autoadd_f64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>('new_box_autoadd_f64');
-  late final _new_box_autoadd_f64 = _new_box_autoadd_f64Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
-
-  ffi.Pointer<ffi.Int32> new_box_autoadd_i32(
-    int value,
-  ) {
-    return _new_box_autoadd_i32(
-      value,
-    );
+  ffi.Pointer<wire_Calculator> new_box_autoadd_calculator() {
+    return _new_box_autoadd_calculator();
   }
 
-  late final _new_box_autoadd_i32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_i32');
-  late final _new_box_autoadd_i32 = _new_box_autoadd_i32Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+  late final _new_box_autoadd_calculatorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Calculator> Function()>>('new_box_autoadd_calculator');
+  late final _new_box_autoadd_calculator =
+      _new_box_autoadd_calculatorPtr.asFunction<ffi.Pointer<wire_Calculator> Function()>();
 
-  ffi.Pointer<ffi.Int64> new_box_autoadd_i64(
-    int value,
-  ) {
-    return _new_box_autoadd_i64(
-      value,
-    );
+  ffi.Pointer<wire_Customized> new_box_autoadd_customized() {
+    return _new_box_autoadd_customized();
+  }
+
+  late final _new_box_autoadd_customizedPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Customized> Function()>>('new_box_autoadd_customized');
+  late final _new_box_autoadd_customized =
+      _new_box_autoadd_customizedPtr.asFunction<ffi.Pointer<wire_Customized> Function()>();
+
+  ffi.Pointer<wire_ExoticOptionals> new_box_autoadd_exotic_optionals() {
+    return _new_box_autoadd_exotic_optionals();
   }
 
-  late final _new_box_autoadd_i64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int64> Function(ffi.Int64)>>('new_box_autoadd_i64');
-  late final _new_box_autoadd_i64 = _new_box_autoadd_i64Ptr.asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
+  late final _new_box_autoadd_exotic_optionalsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExoticOptionals> Function()>>('new_box_autoadd_exotic_optionals');
+  late final _new_box_autoadd_exotic_optionals =
+      _new_box_autoadd_exotic_optionalsPtr.asFunction<ffi.Pointer<wire_ExoticOptionals> Function()>();
 
   ffi.Pointer<wire_KitchenSink> new_box_autoadd_kitchen_sink() {
     return _new_box_autoadd_kitchen_sink();
@@ -2126,6 +4421,46 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_new_type_int =
       _new_box_autoadd_new_type_intPtr.asFunction<ffi.Pointer<wire_NewTypeInt> Function()>();
 
+  ffi.Pointer<wire_SparseLists> new_box_autoadd_sparse_lists() {
+    return _new_box_autoadd_sparse_lists();
+  }
+
+  late final _new_box_autoadd_sparse_listsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SparseLists> Function()>>('new_box_autoadd_sparse_lists');
+  late final _new_box_autoadd_sparse_lists =
+      _new_box_autoadd_sparse_listsPtr.asFunction<ffi.Pointer<wire_SparseLists> Function()>();
+
+  ffi.Pointer<wire_TupleF64F64> new_box_autoadd_tuple_f_64_f_64() {
+    return _new_box_autoadd_tuple_f_64_f_64();
+  }
+
+  late final _new_box_autoadd_tuple_f_64_f_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_TupleF64F64> Function()>>('new_box_autoadd_tuple_f_64_f_64');
+  late final _new_box_autoadd_tuple_f_64_f_64 =
+      _new_box_autoadd_tuple_f_64_f_64Ptr.asFunction<ffi.Pointer<wire_TupleF64F64> Function()>();
+
+  ffi.Pointer<wire_TupleI32String> new_box_autoadd_tuple_i_32_string() {
+    return _new_box_autoadd_tuple_i_32_string();
+  }
+
+  late final _new_box_autoadd_tuple_i_32_stringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_TupleI32String> Function()>>('new_box_autoadd_tuple_i_32_string');
+  late final _new_box_autoadd_tuple_i_32_string =
+      _new_box_autoadd_tuple_i_32_stringPtr.asFunction<ffi.Pointer<wire_TupleI32String> Function()>();
+
+  ffi.Pointer<ffi.Int32> new_box_autoadd_weekdays(
+    int value,
+  ) {
+    return _new_box_autoadd_weekdays(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_weekdaysPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_weekdays');
+  late final _new_box_autoadd_weekdays =
+      _new_box_autoadd_weekdaysPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<ffi.Uint8> new_box_bool(
     bool value,
   ) {
@@ -2216,6 +4551,15 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _new_box_u8Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>('new_box_u8');
   late final _new_box_u8 = _new_box_u8Ptr.asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
 
+  ffi.Pointer<wire_btree_map_i32_String> new_btree_map_i32_String() {
+    return _new_btree_map_i32_String();
+  }
+
+  late final _new_btree_map_i32_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_btree_map_i32_String> Function()>>('new_btree_map_i32_String');
+  late final _new_btree_map_i32_String =
+      _new_btree_map_i32_StringPtr.asFunction<ffi.Pointer<wire_btree_map_i32_String> Function()>();
+
   ffi.Pointer<wire_float_32_list> new_float_32_list(
     int len,
   ) {
@@ -2240,6 +4584,22 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_float_64_list> Function(ffi.Int32)>>('new_float_64_list');
   late final _new_float_64_list = _new_float_64_listPtr.asFunction<ffi.Pointer<wire_float_64_list> Function(int)>();
 
+  ffi.Pointer<wire_hash_map_String_i32> new_hash_map_String_i32() {
+    return _new_hash_map_String_i32();
+  }
+
+  late final _new_hash_map_String_i32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_hash_map_String_i32> Function()>>('new_hash_map_String_i32');
+  late final _new_hash_map_String_i32 =
+      _new_hash_map_String_i32Ptr.asFunction<ffi.Pointer<wire_hash_map_String_i32> Function()>();
+
+  ffi.Pointer<wire_int_128> new_i128() {
+    return _new_i128();
+  }
+
+  late final _new_i128Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_int_128> Function()>>('new_i128');
+  late final _new_i128 = _new_i128Ptr.asFunction<ffi.Pointer<wire_int_128> Function()>();
+
   ffi.Pointer<wire_int_32_list> new_int_32_list(
     int len,
   ) {
@@ -2276,6 +4636,31 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_int_8_list> Function(ffi.Int32)>>('new_int_8_list');
   late final _new_int_8_list = _new_int_8_listPtr.asFunction<ffi.Pointer<wire_int_8_list> Function(int)>();
 
+  ffi.Pointer<wire_list_PathBuf> new_list_PathBuf(
+    int len,
+  ) {
+    return _new_list_PathBuf(
+      len,
+    );
+  }
+
+  late final _new_list_PathBufPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_PathBuf> Function(ffi.Int32)>>('new_list_PathBuf');
+  late final _new_list_PathBuf = _new_list_PathBufPtr.asFunction<ffi.Pointer<wire_list_PathBuf> Function(int)>();
+
+  ffi.Pointer<wire_list_StringList> new_list_StringList(
+    int len,
+  ) {
+    return _new_list_StringList(
+      len,
+    );
+  }
+
+  late final _new_list_StringListPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_StringList> Function(ffi.Int32)>>('new_list_StringList');
+  late final _new_list_StringList =
+      _new_list_StringListPtr.asFunction<ffi.Pointer<wire_list_StringList> Function(int)>();
+
   ffi.Pointer<wire_list_attribute> new_list_attribute(
     int len,
   ) {
@@ -2288,6 +4673,19 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_attribute> Function(ffi.Int32)>>('new_list_attribute');
   late final _new_list_attribute = _new_list_attributePtr.asFunction<ffi.Pointer<wire_list_attribute> Function(int)>();
 
+  ffi.Pointer<wire_list_float_64_list> new_list_float_64_list(
+    int len,
+  ) {
+    return _new_list_float_64_list(
+      len,
+    );
+  }
+
+  late final _new_list_float_64_listPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_float_64_list> Function(ffi.Int32)>>('new_list_float_64_list');
+  late final _new_list_float_64_list =
+      _new_list_float_64_listPtr.asFunction<ffi.Pointer<wire_list_float_64_list> Function(int)>();
+
   ffi.Pointer<wire_list_my_size> new_list_my_size(
     int len,
   ) {
@@ -2313,6 +4711,19 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _new_list_my_tree_node =
       _new_list_my_tree_nodePtr.asFunction<ffi.Pointer<wire_list_my_tree_node> Function(int)>();
 
+  ffi.Pointer<wire_list_opt_String> new_list_opt_String(
+    int len,
+  ) {
+    return _new_list_opt_String(
+      len,
+    );
+  }
+
+  late final _new_list_opt_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_opt_String> Function(ffi.Int32)>>('new_list_opt_String');
+  late final _new_list_opt_String =
+      _new_list_opt_StringPtr.asFunction<ffi.Pointer<wire_list_opt_String> Function(int)>();
+
   ffi.Pointer<wire_list_opt_box_autoadd_attribute> new_list_opt_box_autoadd_attribute(
     int len,
   ) {
@@ -2327,6 +4738,112 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _new_list_opt_box_autoadd_attribute = _new_list_opt_box_autoadd_attributePtr
       .asFunction<ffi.Pointer<wire_list_opt_box_autoadd_attribute> Function(int)>();
 
+  ffi.Pointer<wire_list_opt_box_autoadd_weekdays> new_list_opt_box_autoadd_weekdays(
+    int len,
+  ) {
+    return _new_list_opt_box_autoadd_weekdays(
+      len,
+    );
+  }
+
+  late final _new_list_opt_box_autoadd_weekdaysPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_opt_box_autoadd_weekdays> Function(ffi.Int32)>>(
+          'new_list_opt_box_autoadd_weekdays');
+  late final _new_list_opt_box_autoadd_weekdays =
+      _new_list_opt_box_autoadd_weekdaysPtr.asFunction<ffi.Pointer<wire_list_opt_box_autoadd_weekdays> Function(int)>();
+
+  ffi.Pointer<wire_list_opt_f64> new_list_opt_f64(
+    int len,
+  ) {
+    return _new_list_opt_f64(
+      len,
+    );
+  }
+
+  late final _new_list_opt_f64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_opt_f64> Function(ffi.Int32)>>('new_list_opt_f64');
+  late final _new_list_opt_f64 = _new_list_opt_f64Ptr.asFunction<ffi.Pointer<wire_list_opt_f64> Function(int)>();
+
+  ffi.Pointer<wire_list_opt_i32> new_list_opt_i32(
+    int len,
+  ) {
+    return _new_list_opt_i32(
+      len,
+    );
+  }
+
+  late final _new_list_opt_i32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_opt_i32> Function(ffi.Int32)>>('new_list_opt_i32');
+  late final _new_list_opt_i32 = _new_list_opt_i32Ptr.asFunction<ffi.Pointer<wire_list_opt_i32> Function(int)>();
+
+  ffi.Pointer<wire_Counter> new_opaque_counter() {
+    return _new_opaque_counter();
+  }
+
+  late final _new_opaque_counterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Counter> Function()>>('new_opaque_counter');
+  late final _new_opaque_counter = _new_opaque_counterPtr.asFunction<ffi.Pointer<wire_Counter> Function()>();
+
+  wire_opt_bool new_opt_bool(
+    bool is_some,
+    bool value,
+  ) {
+    return _new_opt_bool(
+      is_some ? 1 : 0,
+      value ? 1 : 0,
+    );
+  }
+
+  late final _new_opt_boolPtr =
+      _lookup<ffi.NativeFunction<wire_opt_bool Function(ffi.Uint8, ffi.Uint8)>>('new_opt_bool');
+  late final _new_opt_bool = _new_opt_boolPtr.asFunction<wire_opt_bool Function(int, int)>();
+
+  wire_opt_f64 new_opt_f64(
+    bool is_some,
+    double value,
+  ) {
+    return _new_opt_f64(
+      is_some ? 1 : 0,
+      value,
+    );
+  }
+
+  late final _new_opt_f64Ptr = _lookup<ffi.NativeFunction<wire_opt_f64 Function(ffi.Uint8, ffi.Double)>>('new_opt_f64');
+  late final _new_opt_f64 = _new_opt_f64Ptr.asFunction<wire_opt_f64 Function(int, double)>();
+
+  wire_opt_i32 new_opt_i32(
+    bool is_some,
+    int value,
+  ) {
+    return _new_opt_i32(
+      is_some ? 1 : 0,
+      value,
+    );
+  }
+
+  late final _new_opt_i32Ptr = _lookup<ffi.NativeFunction<wire_opt_i32 Function(ffi.Uint8, ffi.Int32)>>('new_opt_i32');
+  late final _new_opt_i32 = _new_opt_i32Ptr.asFunction<wire_opt_i32 Function(int, int)>();
+
+  wire_opt_i64 new_opt_i64(
+    bool is_some,
+    int value,
+  ) {
+    return _new_opt_i64(
+      is_some ? 1 : 0,
+      value,
+    );
+  }
+
+  late final _new_opt_i64Ptr = _lookup<ffi.NativeFunction<wire_opt_i64 Function(ffi.Uint8, ffi.Int64)>>('new_opt_i64');
+  late final _new_opt_i64 = _new_opt_i64Ptr.asFunction<wire_opt_i64 Function(int, int)>();
+
+  ffi.Pointer<wire_uint_128> new_u128() {
+    return _new_u128();
+  }
+
+  late final _new_u128Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_128> Function()>>('new_u128');
+  late final _new_u128 = _new_u128Ptr.asFunction<ffi.Pointer<wire_uint_128> Function()>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list(
     int len,
   ) {
@@ -2339,6 +4856,18 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_8_list> Function(ffi.Int32)>>('new_uint_8_list');
   late final _new_uint_8_list = _new_uint_8_listPtr.asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  void drop_opaque_Counter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_Counter(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_Counter');
+  late final _drop_opaque_Counter = _drop_opaque_CounterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
   ffi.Pointer<KitchenSinkKind> inflate_KitchenSink_Primitives() {
     return _inflate_KitchenSink_Primitives();
   }
@@ -2384,6 +4913,49 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _inflate_KitchenSink_Enums =
       _inflate_KitchenSink_EnumsPtr.asFunction<ffi.Pointer<KitchenSinkKind> Function()>();
 
+  void cancel_task(
+    int id,
+  ) {
+    return _cancel_task(
+      id,
+    );
+  }
+
+  late final _cancel_taskPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('cancel_task');
+  late final _cancel_task = _cancel_taskPtr.asFunction<void Function(int)>();
+
+  void reply_callback_i32(
+    int call_id,
+    int value,
+  ) {
+    return _reply_callback_i32(
+      call_id,
+      value,
+    );
+  }
+
+  late final _reply_callback_i32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('reply_callback_i32');
+  late final _reply_callback_i32 = _reply_callback_i32Ptr.asFunction<void Function(int, int)>();
+
+  void fail_callback(
+    int call_id,
+  ) {
+    return _fail_callback(
+      call_id,
+    );
+  }
+
+  late final _fail_callbackPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('fail_callback');
+  late final _fail_callback = _fail_callbackPtr.asFunction<void Function(int)>();
+
+  int get_wire_hash() {
+    return _get_wire_hash();
+  }
+
+  late final _get_wire_hashPtr = _lookup<ffi.NativeFunction<ffi.Uint32 Function()>>('get_wire_hash');
+  late final _get_wire_hash = _get_wire_hashPtr.asFunction<int Function()>();
+
   void free_WireSyncReturnStruct(
     WireSyncReturnStruct val,
   ) {
@@ -2417,6 +4989,13 @@ class wire_uint_8_list extends ffi.Struct {
   external int len;
 }
 
+class wire_list_PathBuf extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_MySize extends ffi.Struct {
   @ffi.Int32()
   external int width;
@@ -2444,6 +5023,39 @@ class wire_StringList extends ffi.Struct {
   external int len;
 }
 
+class wire_list_float_64_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_float_64_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_list_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_StringList>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_int_32_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_hash_map_String_i32 extends ffi.Struct {
+  external ffi.Pointer<wire_StringList> keys;
+
+  external ffi.Pointer<wire_int_32_list> values;
+}
+
+class wire_btree_map_i32_String extends ffi.Struct {
+  external ffi.Pointer<wire_int_32_list> keys;
+
+  external ffi.Pointer<wire_StringList> values;
+}
+
 class wire_list_my_tree_node extends ffi.Struct {
   external ffi.Pointer<wire_MyTreeNode> ptr;
 
@@ -2470,13 +5082,6 @@ class wire_int_8_list extends ffi.Struct {
   external int len;
 }
 
-class wire_int_32_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Int32> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
 class wire_int_64_list extends ffi.Struct {
   external ffi.Pointer<ffi.Int64> ptr;
 
@@ -2498,6 +5103,45 @@ class wire_float_64_list extends ffi.Struct {
   external int len;
 }
 
+class wire_opt_i32 extends ffi.Struct {
+  @ffi.Uint8()
+  external int is_some;
+
+  @ffi.Int32()
+  external int value;
+}
+
+class wire_list_opt_i32 extends ffi.Struct {
+  external ffi.Pointer<wire_opt_i32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_opt_i64 extends ffi.Struct {
+  @ffi.Uint8()
+  external int is_some;
+
+  @ffi.Int64()
+  external int value;
+}
+
+class wire_opt_f64 extends ffi.Struct {
+  @ffi.Uint8()
+  external int is_some;
+
+  @ffi.Double()
+  external double value;
+}
+
+class wire_opt_bool extends ffi.Struct {
+  @ffi.Uint8()
+  external int is_some;
+
+  @ffi.Uint8()
+  external int value;
+}
+
 class wire_Attribute extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> key;
 
@@ -2519,13 +5163,13 @@ class wire_list_opt_box_autoadd_attribute extends ffi.Struct {
 }
 
 class wire_ExoticOptionals extends ffi.Struct {
-  external ffi.Pointer<ffi.Int32> int32;
+  external wire_opt_i32 int32;
 
-  external ffi.Pointer<ffi.Int64> int64;
+  external wire_opt_i64 int64;
 
-  external ffi.Pointer<ffi.Double> float64;
+  external wire_opt_f64 float64;
 
-  external ffi.Pointer<ffi.Uint8> boolean;
+  external wire_opt_bool boolean;
 
   external ffi.Pointer<wire_uint_8_list> zerocopy;
 
@@ -2550,6 +5194,37 @@ class wire_ExoticOptionals extends ffi.Struct {
   external ffi.Pointer<wire_NewTypeInt> newtypeint;
 }
 
+class wire_list_opt_f64 extends ffi.Struct {
+  external ffi.Pointer<wire_opt_f64> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_list_opt_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_list_opt_box_autoadd_weekdays extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<ffi.Int32>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_SparseLists extends ffi.Struct {
+  external ffi.Pointer<wire_list_opt_f64> values;
+
+  external ffi.Pointer<wire_list_opt_String> labels;
+
+  external ffi.Pointer<wire_list_opt_box_autoadd_weekdays> weekdays;
+
+  external ffi.Pointer<wire_list_opt_box_autoadd_attribute> attributes;
+}
+
 class wire_Customized extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> final_field;
 
@@ -2595,9 +5270,9 @@ class KitchenSinkKind extends ffi.Union {
 }
 
 class KitchenSink_Optional extends ffi.Struct {
-  external ffi.Pointer<ffi.Int32> field0;
+  external wire_opt_i32 field0;
 
-  external ffi.Pointer<ffi.Int32> field1;
+  external wire_opt_i32 field1;
 }
 
 class KitchenSink_Buffer extends ffi.Struct {
@@ -2614,5 +5289,45 @@ class wire_MyStruct extends ffi.Struct {
   external int content;
 }
 
+class wire_Counter extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_Calculator extends ffi.Struct {
+  @ffi.Int32()
+  external int base;
+}
+
+class wire_TupleI32String extends ffi.Struct {
+  @ffi.Int32()
+  external int field0;
+
+  external ffi.Pointer<wire_uint_8_list> field1;
+}
+
+class wire_TupleF64F64 extends ffi.Struct {
+  @ffi.Double()
+  external double field0;
+
+  @ffi.Double()
+  external double field1;
+}
+
+class wire_uint_128 extends ffi.Struct {
+  @ffi.Uint64()
+  external int hi;
+
+  @ffi.Uint64()
+  external int lo;
+}
+
+class wire_int_128 extends ffi.Struct {
+  @ffi.Uint64()
+  external int hi;
+
+  @ffi.Uint64()
+  external int lo;
+}
+
 typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<ffi.Uint8 Function(DartPort, ffi.Pointer<ffi.Void>)>>;
 typedef DartPort = ffi.Int64;
@@ -1,4 +1,7 @@
+import 'dart:async';
+import 'dart:convert';
 import 'dart:ffi';
+import 'dart:isolate';
 import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
@@ -15,6 +18,16 @@ void main(List<String> args) async {
     final dylib = DynamicLibrary.open(dylibPath);
     final api = FlutterRustBridgeExampleImpl(dylib);
 
+    print('dart call checkWireHash');
+    api.checkWireHash();
+
+    print('dart call init');
+    {
+      await Future.wait([api.init(), api.init()]);
+      await api.init();
+      expect(await api.getInitCount(), 1);
+    }
+
     print('call functions');
 
     print('dart call simpleAdder');
@@ -33,6 +46,15 @@ void main(List<String> args) async {
       expect(await api.primitiveU32(myU32: 0xff112233), 0xfe112233);
     }
 
+    print('dart call addToU64');
+    {
+      // Above 2^53, where doubles, and the ints of Dart on the web, are no longer exact
+      expect(await api.addToU64(value: 9007199254740993, delta: 2), 9007199254740995);
+      final aboveI64Max = await api.addToU64(value: 1, delta: -9007199254740993);
+      expect(aboveI64Max, -9007199254740992);
+      expect(BigInt.from(aboveI64Max).toUnsigned(64), (BigInt.one << 64) - BigInt.from(9007199254740992));
+    }
+
     print('dart call handleReturnUnit');
     {
       await api.handleReturnUnit();
@@ -41,6 +63,152 @@ void main(List<String> args) async {
     print('dart call handleString');
     {
       expect(await api.handleString(s: "Hello, world!"), "Hello, world!Hello, world!");
+      // Sent as bytes both ways, so neither the NUL nor the characters beyond ASCII are lost
+      final long = 'h\u0000éllo, 世界 🌍 ' * 1000;
+      expect(await api.handleString(s: long), long + long);
+    }
+
+    print('dart call wire_handle_string with invalid UTF-8');
+    {
+      // Only bytes sent without `utf8.encode` can be invalid, which Rust reports as an error
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final bytes = [0x68, 0xff, 0x69];
+      final s = wire.new_uint_8_list(bytes.length);
+      s.ref.ptr.asTypedList(bytes.length).setAll(0, bytes);
+      final receivePort = ReceivePort();
+      wire.wire_handle_string(receivePort.sendPort.nativePort, s);
+      final raw = await receivePort.first as List<dynamic>;
+      receivePort.close();
+      print('dart receive raw: $raw');
+      expect(raw[1], 'RESULT_ERROR');
+      expect(raw[2], contains('invalid utf-8'));
+    }
+
+    print('dart call nextChar');
+    {
+      expect(await api.nextChar(c: 'a'.runes.single), 'b'.runes.single);
+      expect(await api.nextChar(c: 0x1F30D), 0x1F30E);
+      expect(() => api.nextChar(c: 0xD800), throwsArgumentError);
+      // A surrogate sent without the check of Dart is reported as an error by Rust
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final receivePort = ReceivePort();
+      wire.wire_next_char(receivePort.sendPort.nativePort, 0xD800);
+      final raw = await receivePort.first as List<dynamic>;
+      receivePort.close();
+      print('dart receive raw: $raw');
+      expect(raw[1], 'RESULT_ERROR');
+      expect(raw[2], contains('0xd800 is not a Unicode scalar value'));
+    }
+
+    print('dart call doubleDuration');
+    {
+      expect(await api.doubleDuration(duration: const Duration(seconds: 3)), const Duration(seconds: 6));
+      expect(() => api.doubleDuration(duration: const Duration(seconds: -1)), throwsArgumentError);
+      // A negative duration sent without the check of Dart is reported as an error by Rust
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final receivePort = ReceivePort();
+      wire.wire_double_duration(receivePort.sendPort.nativePort, -1000000);
+      final raw = await receivePort.first as List<dynamic>;
+      receivePort.close();
+      print('dart receive raw: $raw');
+      expect(raw[1], 'RESULT_ERROR');
+      expect(raw[2], contains('-1000000 microseconds is not a valid Duration'));
+    }
+
+    print('dart call handleAsyncSum');
+    {
+      expect(await api.handleAsyncSum(numbers: Int32List.fromList([1, 2, 3])), 6);
+      expect(() => api.handleAsyncSum(numbers: Int32List.fromList([0x7fffffff, 1])),
+          throwsA(isA<FfiException>().having((e) => e.message, 'message', contains('overflows'))));
+    }
+
+    print('dart call handleAsyncAverage');
+    {
+      expect(await api.handleAsyncAverage(numbers: Int32List.fromList([1, 2, 3, 4])), 2.5);
+    }
+
+    print('dart call countChars');
+    {
+      expect(api.countChars(s: 'h\u0000éllo, 世界 🌍'), 12);
+      // Without the port, the invalid UTF-8 is reported in the bytes returned by the call
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final bytes = [0x68, 0xff, 0x69];
+      final s = wire.new_uint_8_list(bytes.length);
+      s.ref.ptr.asTypedList(bytes.length).setAll(0, bytes);
+      final raw = wire.wire_count_chars(s);
+      final message = utf8.decode(raw.ptr.asTypedList(raw.len));
+      final success = raw.success > 0;
+      wire.free_WireSyncReturnStruct(raw);
+      expect(success, false);
+      expect(message, contains('invalid utf-8'));
+    }
+
+    print('dart call joinPathParts, pathFileName');
+    {
+      final path = await api.joinPathParts(parts: ['data', 'images', 'cat.png']);
+      expect(path.split(RegExp(r'[/\\]')), ['data', 'images', 'cat.png']);
+      expect(await api.pathFileName(path: path), 'cat.png');
+      expect(await api.pathFileName(path: '/'), null);
+    }
+
+    print('dart call nextNonZeroId');
+    {
+      expect(await api.nextNonZeroId(id: 41), 42);
+      expect(await api.nextNonZeroId(id: 40, step: 2), 42);
+      try {
+        await api.nextNonZeroId(id: 1, step: 0);
+        fail("exception not thrown");
+      } on ArgumentError catch (e) {
+        print('dart catch e: $e');
+      }
+      // A zero sent without the check of Dart is reported as an error by Rust
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final receivePort = ReceivePort();
+      wire.wire_next_non_zero_id(receivePort.sendPort.nativePort, 0, nullptr);
+      final raw = await receivePort.first as List<dynamic>;
+      receivePort.close();
+      print('dart receive raw: $raw');
+      expect(raw[1], 'RESULT_ERROR');
+      expect(raw[2], contains('zero is not a valid std::num::NonZeroU32'));
+    }
+
+    print('dart call wrapJson');
+    {
+      final value = {
+        'name': 'frb',
+        'tags': ['a', 'b'],
+        'nested': {'count': 42, 'ratio': 0.5, 'ok': true, 'none': null},
+      };
+      expect(await api.wrapJson(value: value), {'wrapped': value, 'tag': null});
+      expect(await api.wrapJson(value: [1, 'two'], tag: 'list'), {
+        'wrapped': [1, 'two'],
+        'tag': 'list'
+      });
+      expect(await api.wrapJson(value: null), {'wrapped': null, 'tag': null});
+      // A string sent without `jsonEncode` that is not JSON is reported as an error by Rust
+      final wire = FlutterRustBridgeExampleWire(dylib);
+      final bytes = utf8.encode('{"name": ');
+      final json = wire.new_uint_8_list(bytes.length);
+      json.ref.ptr.asTypedList(bytes.length).setAll(0, bytes);
+      final receivePort = ReceivePort();
+      wire.wire_wrap_json(receivePort.sendPort.nativePort, json, nullptr);
+      final raw = await receivePort.first as List<dynamic>;
+      receivePort.close();
+      print('dart receive raw: $raw');
+      expect(raw[1], 'RESULT_ERROR');
+      expect(raw[2], contains('EOF while parsing'));
+    }
+
+    print('dart call parseNumberI32 and parseNumberF64');
+    {
+      expect(await api.parseNumberI32(text: '42'), 42);
+      expect(await api.parseNumberF64(text: '0.5'), 0.5);
+      try {
+        await api.parseNumberI32(text: '0.5');
+        fail("exception not thrown");
+      } on FfiException catch (e) {
+        print('dart catch e: $e');
+      }
     }
 
     print('dart call handleVecU8');
@@ -50,6 +218,12 @@ void main(List<String> args) async {
           Uint8List.fromList(List.filled(len * 2, 127)));
     }
 
+    print('dart call handleBoxedSlice');
+    {
+      expect(await api.handleBoxedSlice(bytes: Uint8List.fromList([1, 2, 3])), Uint8List.fromList([3, 2, 1]));
+      expect(await api.handleBoxedStringSlice(names: ['bob', 'alex']), ['alex', 'bob']);
+    }
+
     print('dart call handleVecOfPrimitive');
     {
       final n = 10000;
@@ -92,7 +266,7 @@ void main(List<String> args) async {
 
     print('dart call handleNewtype');
     {
-      final newtypeResp = await api.handleNewtype(arg: NewTypeInt(field0: 42));
+      final newtypeResp = await api.handleNewtype(arg: NewTypeInt(42));
       expect(newtypeResp.field0, 84);
     }
 
@@ -113,6 +287,79 @@ void main(List<String> args) async {
       expect(names, ['Steve', 'Bob', 'Alex']);
     }
 
+    print('dart call handleNestedList');
+    {
+      // Jagged, with an empty row
+      final matrix = await api.handleNestedList(matrix: [
+        Float64List.fromList([1, 2, 3]),
+        Float64List(0),
+        Float64List.fromList([4]),
+      ]);
+      expect(matrix, [
+        [2, 4, 6],
+        <double>[],
+        [8]
+      ]);
+      expect(await api.handleNestedList(matrix: []), isEmpty);
+    }
+
+    print('dart call handleNestedStringList');
+    {
+      final lines = [
+        ['Steve', 'Bob'],
+        <String>[],
+        ['Alex'],
+      ];
+      expect(await api.handleNestedStringList(lines: lines), lines);
+    }
+
+    print('dart call handleHashMap');
+    {
+      expect(await api.handleHashMap(counts: {'apple': 1, 'pear': 41}), {'apple': 2, 'pear': 42});
+      expect(await api.handleHashMap(counts: {}), isEmpty);
+    }
+
+    print('dart call handleBtreeMap');
+    {
+      expect(await api.handleBtreeMap(names: {2: 'Bob', 1: 'Steve'}), {'Bob': 2, 'Steve': 1});
+    }
+
+    print('dart call handleIterator');
+    {
+      expect(await api.handleIterator(text: 'steve bob  alex'), ['STEVE', 'BOB', 'ALEX']);
+      expect(await api.handleIterator(text: ''), isEmpty);
+    }
+
+    print('dart call handleFallibleIterator');
+    {
+      expect(await api.handleFallibleIterator(n: 4), [0, 1, 4, 9]);
+      try {
+        await api.handleFallibleIterator(n: -1);
+        fail("exception not thrown");
+      } catch (e) {
+        print('dart catch e: $e');
+        expect(e, isA<FfiException>());
+      }
+    }
+
+    print('dart call handleCallback');
+    {
+      expect(await api.handleCallback(items: Int32List.fromList([1, 2, 3]), transform: (item) => item * 10),
+          [10, 20, 30]);
+      // The exception thrown by the callback is reported as uncaught, which is expected here
+      final uncaught = [];
+      await runZonedGuarded(() async {
+        try {
+          await api.handleCallback(items: Int32List.fromList([1]), transform: (item) => throw Exception('no'));
+          fail("exception not thrown");
+        } catch (e) {
+          print('dart catch e: $e');
+          expect(e, isA<FfiException>());
+        }
+      }, (e, s) => uncaught.add(e));
+      expect(uncaught, hasLength(1));
+    }
+
     print('dart call handleComplexStruct');
     {
       final arrLen = 5;
@@ -124,6 +371,21 @@ void main(List<String> args) async {
       expect(complexStructResp.children[1].valueVecU8, List.filled(arrLen, 120));
     }
 
+    print('dart call makeDeepTree, treeDepth');
+    {
+      var node = await api.makeDeepTree(depth: 1000);
+      for (var value = 1; value < 1000; value++) {
+        expect(node.valueI32, value);
+        expect(node.children, hasLength(1));
+        node = node.children.single;
+      }
+      expect(node.valueI32, 1000);
+      expect(node.children, isEmpty);
+
+      expect(await api.treeDepth(tree: await api.makeDeepTree(depth: 1000)), 1000);
+      expect(await api.treeDepth(tree: _createMyTreeNode(arrLen: 5)), 3);
+    }
+
     print('dart call handle_sync_return');
     {
       expect(api.handleSyncReturn(mode: 'NORMAL'), List.filled(100, 42));
@@ -161,6 +423,36 @@ void main(List<String> args) async {
       }
     }
 
+    print('dart call returnCustomErr');
+    {
+      await api.returnCustomErr(fail: false);
+      try {
+        await api.returnCustomErr(fail: true);
+        fail("exception not thrown");
+      } on CustomError catch (e) {
+        expect(e.message, "deliberately failed");
+        expect(e.code, 42);
+      }
+    }
+
+    print('dart call login');
+    {
+      await api.login(password: 'hunter2', attempts: 0);
+      try {
+        await api.login(password: 'hunter3', attempts: 0);
+        fail("exception not thrown");
+      } on LoginErrorWrongPasswordException catch (e) {
+        expect(e.error, LoginError.WrongPassword);
+      }
+      try {
+        await api.login(password: 'hunter2', attempts: 3);
+        fail("exception not thrown");
+      } on LoginErrorException catch (e) {
+        expect(e, isA<LoginErrorLockedException>());
+        expect(e.error, LoginError.Locked);
+      }
+    }
+
     print('dart call returnPanic');
     {
       try {
@@ -178,6 +470,13 @@ void main(List<String> args) async {
       expect(await api.handleOptionalReturn(left: 2, right: 0), null);
     }
 
+    print('dart call handleOptionalPrimitives');
+    {
+      expect(await api.handleOptionalPrimitives(values: [1, null, 3], scale: 2.5, negate: true), -10);
+      expect(await api.handleOptionalPrimitives(values: [1], offset: -1, negate: false), 0);
+      expect(await api.handleOptionalPrimitives(values: [null, null], offset: 1), null);
+    }
+
     print('dart call handleOptionalStruct');
     {
       {
@@ -263,6 +562,39 @@ void main(List<String> args) async {
       expect(await api.handleEnumParameter(weekday: Weekdays.Saturday), Weekdays.Saturday);
     }
 
+    print('dart call handleSparseLists');
+    {
+      final lists = SparseLists(
+        values: [1.5, null, null, 2.5],
+        labels: [null, 'a', null],
+        weekdays: [Weekdays.Monday, null, Weekdays.Sunday],
+        attributes: [Attribute(key: 'k', value: 'v'), null],
+      );
+      final resp = await api.handleSparseLists(lists: lists);
+      expect(resp.values, [2.5, null, null, 1.5]);
+      expect(resp.labels, [null, 'a', null]);
+      expect(resp.weekdays, [Weekdays.Sunday, null, Weekdays.Monday]);
+      expect(resp.attributes.map((attribute) => attribute?.key), [null, 'k']);
+    }
+
+    print('dart call handleNonExhaustiveEnum');
+    {
+      expect(await api.handleNonExhaustiveEnum(priority: Priority.Low), Priority.High);
+      try {
+        await api.handleNonExhaustiveEnum(priority: Priority.unknown);
+        fail("exception not thrown");
+      } on ArgumentError catch (e) {
+        print('dart catch e: $e');
+      }
+    }
+
+    print('dart call handleCustomizedStruct');
+    {
+      final val = Customized(finalField: "final");
+      val.nonFinalField = "assigned";
+      await api.handleCustomizedStruct(val: val);
+    }
+
     print('dart call handleEnumStruct');
     {
       expect(await api.handleEnumStruct(val: Empty()), Empty());
@@ -284,6 +616,13 @@ void main(List<String> args) async {
         await api.handleEnumStruct(val: Enums(Weekdays.Monday)),
         Enums(Weekdays.Tuesday),
       );
+      expect(
+        await api.handleEnumStruct(
+          val: Nested(Nested(Nested(Primitives(int32: 0, float64: 1, boolean: false)))),
+        ),
+        Nested(Nested(Nested(Primitives(int32: 1, float64: 2, boolean: true)))),
+      );
+      expect(await api.handleEnumStruct(val: Nested(Empty())), Nested(Empty()));
     }
 
     print('dart call useImportedStruct()');
@@ -310,6 +649,176 @@ void main(List<String> args) async {
       );
     }
 
+    print('dart call incrementLockedCount, getLockedCount');
+    {
+      final counts = await Future.wait(List.generate(8, (_) => api.incrementLockedCount()));
+      expect(counts.toSet(), {1, 2, 3, 4, 5, 6, 7, 8});
+      expect(await api.getLockedCount(), 8);
+    }
+
+    print('dart call countUntilCancelled');
+    {
+      expect(await api.countUntilCancelled(limit: 3), 3);
+      final token = CancellationToken();
+      final future = api.countUntilCancelled(limit: 1000, token: token);
+      await Future.delayed(const Duration(milliseconds: 50));
+      token.cancel();
+      try {
+        await future;
+        fail("exception not thrown");
+      } on FfiException catch (e) {
+        print('dart catch e: $e');
+        expect(e.code, 'CANCELLED_ERROR');
+      }
+      // A token cancelled already cancels the tasks it is given afterwards
+      expect(() => api.countUntilCancelled(limit: 1000, token: token),
+          throwsA(isA<FfiException>().having((e) => e.code, 'code', 'CANCELLED_ERROR')));
+    }
+
+    print('dart call newCounter');
+    {
+      final counter = await api.newCounter(start: 1);
+      expect(await counter.increment(by: 2), 3);
+      expect(await api.counterValue(counter: counter), 3);
+      counter.dispose();
+      expect(counter.isDisposed, true);
+      expect(() => api.counterValue(counter: counter), throwsStateError);
+    }
+
+    print('dart call newCounterIfPositive');
+    {
+      expect(await api.newCounterIfPositive(start: 0), null);
+      final counter = await api.newCounterIfPositive(start: 5);
+      expect(counter, isNotNull);
+      expect(await api.counterValueOrZero(counter: counter), 5);
+      expect(await api.counterValueOrZero(), 0);
+      counter!.dispose();
+    }
+
+    print('dart call newCalculator');
+    {
+      final calculator = await api.newCalculator(base: 10);
+      expect(await calculator.add(x: 2), 12);
+      expect(await calculator.accumulate(x: 5), 15);
+      // Rust accumulated into its own copy
+      expect(calculator.base, 10);
+      expect(await calculator.intoBase(), 10);
+      expect(await calculator.add(x: 1), 11);
+    }
+
+    print('dart call reverseFixedArray');
+    {
+      expect(await api.reverseFixedArray(values: Float64List.fromList([1, 2, 3, 4])), [4, 3, 2, 1]);
+      expect(() => api.reverseFixedArray(values: Float64List.fromList([1, 2, 3])), throwsArgumentError);
+    }
+
+    print('dart call swapFixedSizes');
+    {
+      final sizes = await api.swapFixedSizes(sizes: [MySize(width: 1, height: 2), MySize(width: 3, height: 4)]);
+      expect(sizes.map((size) => size.width), [3, 1]);
+      expect(sizes.map((size) => size.height), [4, 2]);
+      expect(() => api.swapFixedSizes(sizes: []), throwsArgumentError);
+    }
+
+    print('dart call swapTuple');
+    {
+      final swapped = await api.swapTuple(pair: TupleI32String(field0: 42, field1: 'answer'));
+      expect(swapped.field0, 'answer');
+      expect(swapped.field1, 42);
+    }
+
+    print('dart call distance');
+    {
+      expect(await api.distance(a: TupleF64F64(field0: 1, field1: 1), b: TupleF64F64(field0: 4, field1: 5)), 5);
+    }
+
+    print('dart call incrementU128');
+    {
+      expect(await api.incrementU128(value: (BigInt.one << 64) - BigInt.one), BigInt.one << 64);
+      expect(await api.incrementU128(value: (BigInt.one << 128) - BigInt.one), BigInt.zero);
+      expect(() => api.incrementU128(value: -BigInt.one), throwsArgumentError);
+    }
+
+    print('dart call decrementI128');
+    {
+      expect(await api.decrementI128(value: BigInt.one << 64), (BigInt.one << 64) - BigInt.one);
+      expect(await api.decrementI128(value: BigInt.zero), -BigInt.one);
+      // Wraps around from i128::MIN to i128::MAX
+      expect(await api.decrementI128(value: -(BigInt.one << 127)), (BigInt.one << 127) - BigInt.one);
+      expect(() => api.decrementI128(value: BigInt.one << 127), throwsArgumentError);
+    }
+
+    print('dart call uniqueWords');
+    {
+      // The items of a HashSet are sorted before being sent
+      expect((await api.uniqueWords(text: 'to be or not to be')).toList(), ['be', 'not', 'or', 'to']);
+    }
+
+    print('dart call sortedEvens');
+    {
+      expect((await api.sortedEvens(values: {8, 3, 2, 6, 5})).toList(), [2, 6, 8]);
+    }
+
+    print('dart call fillBuffer');
+    {
+      expect(await api.fillBuffer(len: 3, value: 7), [7, 7, 7]);
+    }
+
+    print('dart call parseNumbers');
+    {
+      final parsed = await api.parseNumbers(input: '1 two 3 four');
+      expect(parsed.field0, [1, 3]);
+      expect(parsed.field1, ['two', 'four']);
+    }
+
+    print('dart call clampTo');
+    {
+      expect(await 42.clampTo(api, max: 10), 10);
+      expect(await api.clampTo(value: 3, max: 10), 3);
+    }
+
+    print('dart call shout');
+    {
+      expect(await 'hello'.shout(api, times: 2), 'HELLO!!');
+    }
+
+    print('dart call addOneDay');
+    {
+      final time = DateTime.utc(2022, 1, 31, 12, 30, 0, 0, 123);
+      final nextDay = await api.addOneDay(time: time);
+      expect(nextDay, DateTime.utc(2022, 2, 1, 12, 30, 0, 0, 123));
+      expect(nextDay.isUtc, true);
+      // Any time zone can be sent, as the instant is what crosses the bridge
+      expect(await api.addOneDay(time: time.toLocal()), nextDay);
+    }
+
+    print('dart call toLocalTime');
+    {
+      final time = DateTime.utc(2022, 6, 15, 8);
+      final local = await api.toLocalTime(time: time);
+      expect(local.isUtc, false);
+      expect(local.isAtSameMomentAs(time), true);
+    }
+
+    print('dart call naiveWallClock');
+    {
+      expect(await api.naiveWallClock(time: DateTime.utc(2022, 6, 15, 8, 45)), DateTime.utc(2022, 6, 15, 8, 45));
+    }
+
+    print('dart call nilUuid');
+    {
+      expect(await api.nilUuid(), Uint8List(16));
+    }
+
+    print('dart call uuidVersion');
+    {
+      // 67e55044-10b1-426f-9247-bb680e5fe0c8, of version 4
+      final id = Uint8List.fromList(
+          [0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8]);
+      expect(await api.uuidVersion(id: id), 4);
+      expect(() => api.uuidVersion(id: Uint8List(15)), throwsArgumentError);
+    }
+
     _createGarbage();
     await Future.delayed(Duration(seconds: 1));
     _createGarbage();
@@ -321,6 +830,23 @@ void main(List<String> args) async {
       obj = await api.handleComplexStruct(s: obj);
     }
 
+    print('dart call dispose');
+    {
+      final values = <String>[];
+      final streamDone = api.handleStream(arg: 'disposed').listen(values.add).asFuture<void>();
+      await Future.delayed(Duration(milliseconds: 120));
+      api.dispose();
+      api.dispose();
+      // The stream is closed, although Rust keeps sending for a while
+      await streamDone;
+      expect(values.length, lessThan(10));
+      expect(() => api.getInitCount(), throwsStateError);
+
+      final recreatedApi = FlutterRustBridgeExampleImpl(dylib);
+      expect(await recreatedApi.getInitCount(), 1);
+      recreatedApi.dispose();
+    }
+
     print('flutter_rust_bridge example program end');
   });
 }
@@ -1,11 +1,18 @@
 #![allow(unused_variables)]
 
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::future::{self, Future};
+use std::num::{NonZeroU32, NonZeroU8};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicI32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
 use std::thread;
 use std::time::Duration;
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use uuid::Uuid;
 
 use flutter_rust_bridge::*;
 
@@ -38,12 +45,103 @@ pub fn primitive_u32(my_u32: u32) -> u32 {
     ret
 }
 
+/// Values above 2^53 arrive exactly, since native Dart `int`s are 64-bit. A `u64` above 2^63 is
+/// the negative `int` of the same bits.
+pub fn add_to_u64(value: u64, delta: i64) -> u64 {
+    value.wrapping_add(delta as u64)
+}
+
 pub fn handle_string(s: String) -> String {
     println!("handle_string({})", &s);
     let s2 = s.clone();
     s + &s2
 }
 
+/// Characters cross the bridge as their code point, which Dart checks before sending
+pub fn next_char(c: char) -> char {
+    char::from_u32(c as u32 + 1).unwrap_or(c)
+}
+
+/// Durations cross the bridge as microseconds, which Dart checks to be positive before sending
+pub fn double_duration(duration: Duration) -> Duration {
+    duration * 2
+}
+
+/// Awaits a slow computation on another thread, which wakes the function once done
+pub async fn handle_async_sum(numbers: Vec<i32>) -> Result<i32> {
+    let sum = sum_on_thread(numbers).await;
+    sum.ok_or_else(|| anyhow!("the sum overflows"))
+}
+
+/// Calls the `async` fn above from another one
+pub async fn handle_async_average(numbers: Vec<i32>) -> Result<f64> {
+    let len = numbers.len();
+    let sum = handle_async_sum(numbers).await?;
+    Ok(sum as f64 / len as f64)
+}
+
+fn sum_on_thread(numbers: Vec<i32>) -> impl Future<Output = Option<i32>> {
+    let state = Arc::new(Mutex::new((None, None::<Waker>)));
+    let thread_state = state.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        let sum = numbers.iter().try_fold(0i32, |sum, &n| sum.checked_add(n));
+        let mut state = thread_state.lock().unwrap();
+        state.0 = Some(sum);
+        if let Some(waker) = state.1.take() {
+            waker.wake();
+        }
+    });
+    future::poll_fn(move |cx| {
+        let mut state = state.lock().unwrap();
+        match state.0.take() {
+            Some(sum) => Poll::Ready(sum),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
+
+/// Called without a `Future`, while the arguments Rust cannot accept are still reported to Dart
+#[frb(sync)]
+pub fn count_chars(s: String) -> u32 {
+    s.chars().count() as u32
+}
+
+/// Paths cross the bridge as strings, to check that `PathBuf` and `&Path` are converted both ways
+pub fn join_path_parts(parts: Vec<PathBuf>) -> PathBuf {
+    parts.iter().collect()
+}
+
+pub fn path_file_name(path: &Path) -> Option<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+/// Zeros never reach Rust, Dart refusing them first
+pub fn next_non_zero_id(id: NonZeroU32, step: Option<NonZeroU8>) -> NonZeroU32 {
+    let step = step.map_or(1, |step| u32::from(step.get()));
+    NonZeroU32::new(id.get() + step).unwrap()
+}
+
+/// Nested JSON crosses the bridge as a string, decoded on both sides
+pub fn wrap_json(value: serde_json::Value, tag: Option<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({ "wrapped": value, "tag": tag })
+}
+
+/// Generic functions are bridged once for each instantiation
+#[frb(instantiate = "i32")]
+#[frb(instantiate = "f64")]
+pub fn parse_number<T: std::str::FromStr>(text: String) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    text.parse()
+        .map_err(|err| anyhow!("cannot parse `{}`: {}", text, err))
+}
+
 #[allow(clippy::unused_unit)]
 pub fn handle_return_unit() -> () {
     println!("handle_return_unit()");
@@ -55,6 +153,19 @@ pub fn handle_vec_u8(v: Vec<u8>) -> Vec<u8> {
     v.repeat(2)
 }
 
+// `Box<[T]>` crosses the bridge like `Vec<T>`
+pub fn handle_boxed_slice(bytes: Box<[u8]>) -> Box<[u8]> {
+    let mut bytes = bytes;
+    bytes.reverse();
+    bytes
+}
+
+pub fn handle_boxed_string_slice(names: Box<[String]>) -> Box<[String]> {
+    let mut names = names;
+    names.sort();
+    names
+}
+
 pub struct VecOfPrimitivePack {
     pub int8list: Vec<i8>,
     pub uint8list: Vec<u8>,
@@ -147,12 +258,59 @@ pub fn handle_string_list(names: Vec<String>) -> Vec<String> {
     names
 }
 
+pub fn handle_nested_list(matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    matrix
+        .into_iter()
+        .map(|row| row.into_iter().map(|x| x * 2.0).collect())
+        .collect()
+}
+
+pub fn handle_nested_string_list(lines: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    lines
+}
+
+pub fn handle_hash_map(counts: HashMap<String, i32>) -> HashMap<String, i32> {
+    counts
+        .into_iter()
+        .map(|(word, count)| (word, count + 1))
+        .collect()
+}
+
+pub fn handle_btree_map(names: BTreeMap<i32, String>) -> BTreeMap<String, i32> {
+    names.into_iter().map(|(id, name)| (name, id)).collect()
+}
+
+pub fn handle_iterator(text: String) -> impl Iterator<Item = String> {
+    text.split_whitespace()
+        .map(str::to_uppercase)
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+pub fn handle_fallible_iterator(n: i32) -> Result<impl Iterator<Item = i32>> {
+    if n < 0 {
+        return Err(anyhow!("negative length {}", n));
+    }
+    Ok((0..n).map(|i| i * i))
+}
+
+/// Maps each item with a Dart function, failing if it throws.
+pub fn handle_callback(
+    items: Vec<i32>,
+    transform: DartCallback<fn(i32) -> i32>,
+) -> Result<Vec<i32>> {
+    items
+        .into_iter()
+        .map(|item| Ok(transform.call(item)?))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct MyTreeNode {
     pub value_i32: i32,
     pub value_vec_u8: Vec<u8>,
     pub value_boolean: bool,
-    pub children: Vec<MyTreeNode>,
+    pub children: Vec<Self>,
 }
 
 pub fn handle_complex_struct(s: MyTreeNode) -> MyTreeNode {
@@ -161,6 +319,31 @@ pub fn handle_complex_struct(s: MyTreeNode) -> MyTreeNode {
     s
 }
 
+/// A chain of `depth` nodes, each having the next one as its only child
+pub fn make_deep_tree(depth: i32) -> MyTreeNode {
+    let mut node = None;
+    for value in (1..=depth).rev() {
+        node = Some(MyTreeNode {
+            value_i32: value,
+            value_vec_u8: vec![],
+            value_boolean: true,
+            children: node.into_iter().collect(),
+        });
+    }
+    node.expect("a tree has at least one node")
+}
+
+/// The number of levels of the tree, counted level by level rather than by recursing
+pub fn tree_depth(tree: MyTreeNode) -> i32 {
+    let mut depth = 0;
+    let mut level = vec![tree];
+    while !level.is_empty() {
+        depth += 1;
+        level = level.into_iter().flat_map(|node| node.children).collect();
+    }
+    depth
+}
+
 pub fn handle_sync_return(mode: String) -> Result<SyncReturn<Vec<u8>>> {
     match &mode[..] {
         "NORMAL" => Ok(SyncReturn(vec![42u8; 100])),
@@ -206,6 +389,39 @@ pub fn return_err() -> Result<i32> {
     ))
 }
 
+#[derive(Debug, Clone)]
+pub struct CustomError {
+    pub message: String,
+    pub code: i32,
+}
+
+pub fn return_custom_err(fail: bool) -> Result<(), CustomError> {
+    if fail {
+        return Err(CustomError {
+            message: "deliberately failed".to_string(),
+            code: 42,
+        });
+    }
+    Ok(())
+}
+
+pub enum LoginError {
+    WrongPassword,
+    /// Too many wrong passwords were given
+    Locked,
+}
+
+/// Enum errors are thrown as the exception of their variant
+pub fn login(password: String, attempts: u32) -> Result<(), LoginError> {
+    if attempts >= 3 {
+        Err(LoginError::Locked)
+    } else if password != "hunter2" {
+        Err(LoginError::WrongPassword)
+    } else {
+        Ok(())
+    }
+}
+
 pub fn return_panic() -> i32 {
     panic!("return_panic() is called, thus deliberately panic")
 }
@@ -218,6 +434,25 @@ pub fn handle_optional_return(left: f64, right: f64) -> Option<f64> {
     }
 }
 
+pub fn handle_optional_primitives(
+    values: Vec<Option<i32>>,
+    offset: Option<i64>,
+    scale: Option<f64>,
+    negate: Option<bool>,
+) -> Option<f64> {
+    let present = values.into_iter().flatten().collect::<Vec<_>>();
+    if present.is_empty() {
+        return None;
+    }
+    let sum = present.into_iter().map(i64::from).sum::<i64>() + offset.unwrap_or(0);
+    let scaled = sum as f64 * scale.unwrap_or(1.);
+    Some(if negate == Some(true) {
+        -scaled
+    } else {
+        scaled
+    })
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct Element {
     pub tag: Option<String>,
@@ -373,6 +608,43 @@ pub fn handle_enum_parameter(weekday: Weekdays) -> Weekdays {
     weekday
 }
 
+/// Sparse data, where any item may be missing.
+pub struct SparseLists {
+    pub values: Vec<Option<f64>>,
+    pub labels: Vec<Option<String>>,
+    pub weekdays: Vec<Option<Weekdays>>,
+    pub attributes: Vec<Option<Attribute>>,
+}
+
+pub fn handle_sparse_lists(lists: SparseLists) -> SparseLists {
+    fn reversed<T>(mut list: Vec<Option<T>>) -> Vec<Option<T>> {
+        list.reverse();
+        list
+    }
+
+    SparseLists {
+        values: reversed(lists.values),
+        labels: reversed(lists.labels),
+        weekdays: reversed(lists.weekdays),
+        attributes: reversed(lists.attributes),
+    }
+}
+
+/// Enums that may gain variants, which Dart then reads as `unknown`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Priority {
+    Low,
+    High,
+}
+
+pub fn handle_non_exhaustive_enum(priority: Priority) -> Priority {
+    match priority {
+        Priority::Low => Priority::High,
+        Priority::High => Priority::Low,
+    }
+}
+
 #[frb]
 #[derive(Debug, Clone)]
 pub struct Customized {
@@ -424,7 +696,8 @@ pub fn handle_enum_struct(val: KitchenSink) -> KitchenSink {
             float64: float64 + 1.,
             boolean: !boolean,
         },
-        Nested(_) => Nested(Box::new(Empty)),
+        // Goes down to the innermost variant, each level being boxed on the wire
+        Nested(inner) => Nested(Box::new(handle_enum_struct(*inner))),
         Optional(a, b) => Optional(a.map(inc), b.map(inc)),
         Buffer(ZeroCopyBuffer(mut buf)) => {
             buf.push(1);
@@ -455,3 +728,183 @@ pub fn use_imported_enum(my_enum: MyEnum) -> bool {
         MyEnum::True => true,
     }
 }
+
+// Left out of the API, since the bridge is not built for the tests
+#[cfg(test)]
+pub fn only_for_tests() -> bool {
+    true
+}
+
+static INIT_COUNT: AtomicI32 = AtomicI32::new(0);
+
+#[frb(init)]
+pub fn count_init() {
+    INIT_COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+pub fn get_init_count() -> i32 {
+    INIT_COUNT.load(Ordering::SeqCst)
+}
+
+static LOCKED_COUNT: AtomicI32 = AtomicI32::new(0);
+
+/// Writes back the count it read a bit earlier, which would lose the increments made in the
+/// meantime if the calls did not take turns
+#[frb(lock = "locked_count")]
+pub fn increment_locked_count() -> i32 {
+    let count = LOCKED_COUNT.load(Ordering::SeqCst) + 1;
+    thread::sleep(Duration::from_millis(10));
+    LOCKED_COUNT.store(count, Ordering::SeqCst);
+    count
+}
+
+#[frb(read_lock = "locked_count")]
+pub fn get_locked_count() -> i32 {
+    LOCKED_COUNT.load(Ordering::SeqCst)
+}
+
+/// Sleeps a bit between the checks of the token, for Dart to cancel the task in between
+pub fn count_until_cancelled(limit: u32, token: CancellationToken) -> Result<u32> {
+    for _ in 0..limit {
+        token.check()?;
+        thread::sleep(Duration::from_millis(10));
+    }
+    Ok(limit)
+}
+
+/// Stays in Rust, Dart only holding a handle to it
+#[frb(opaque)]
+pub struct Counter {
+    count: Mutex<i32>,
+}
+
+impl Counter {
+    pub fn increment(&self, by: i32) -> i32 {
+        let mut count = self.count.lock().unwrap();
+        *count += by;
+        *count
+    }
+}
+
+pub fn new_counter(start: i32) -> Counter {
+    Counter {
+        count: Mutex::new(start),
+    }
+}
+
+pub fn counter_value(counter: &Counter) -> i32 {
+    *counter.count.lock().unwrap()
+}
+
+pub fn new_counter_if_positive(start: i32) -> Option<Counter> {
+    (start > 0).then(|| new_counter(start))
+}
+
+pub fn counter_value_or_zero(counter: Option<&Counter>) -> i32 {
+    counter.map_or(0, counter_value)
+}
+
+pub struct Calculator {
+    pub base: i32,
+}
+
+impl Calculator {
+    pub fn add(&self, x: i32) -> i32 {
+        self.base + x
+    }
+
+    pub fn accumulate(&mut self, x: i32) -> i32 {
+        self.base += x;
+        self.base
+    }
+
+    pub fn into_base(self) -> i32 {
+        self.base
+    }
+}
+
+pub fn new_calculator(base: i32) -> Calculator {
+    Calculator { base }
+}
+
+pub fn reverse_fixed_array(values: [f64; 4]) -> [f64; 4] {
+    let mut values = values;
+    values.reverse();
+    values
+}
+
+pub fn swap_fixed_sizes(sizes: [MySize; 2]) -> [MySize; 2] {
+    let [first, second] = sizes;
+    [second, first]
+}
+
+pub fn swap_tuple(pair: (i32, String)) -> (String, i32) {
+    (pair.1, pair.0)
+}
+
+pub fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+pub fn increment_u128(value: u128) -> u128 {
+    value.wrapping_add(1)
+}
+
+pub fn decrement_i128(value: i128) -> i128 {
+    value.wrapping_sub(1)
+}
+
+pub fn unique_words(text: String) -> HashSet<String> {
+    text.split_whitespace().map(str::to_owned).collect()
+}
+
+pub fn sorted_evens(values: HashSet<i32>) -> BTreeSet<i32> {
+    values.into_iter().filter(|value| value % 2 == 0).collect()
+}
+
+#[frb]
+pub fn fill_buffer(len: u32, value: u8, #[frb(out)] buf: &mut Vec<u8>) {
+    buf.resize(len as usize, value);
+}
+
+#[frb]
+pub fn parse_numbers(input: String, #[frb(out)] rejected: &mut Vec<String>) -> Vec<i32> {
+    let mut numbers = Vec::new();
+    for word in input.split_whitespace() {
+        match word.parse() {
+            Ok(number) => numbers.push(number),
+            Err(_) => rejected.push(word.to_owned()),
+        }
+    }
+    numbers
+}
+
+#[frb(extension)]
+pub fn clamp_to(value: i32, max: i32) -> i32 {
+    value.min(max)
+}
+
+#[frb(extension)]
+pub fn shout(text: String, times: u32) -> String {
+    format!("{}{}", text.to_uppercase(), "!".repeat(times as usize))
+}
+
+pub fn add_one_day(time: DateTime<Utc>) -> DateTime<Utc> {
+    time + chrono::Duration::days(1)
+}
+
+pub fn to_local_time(time: DateTime<Utc>) -> DateTime<Local> {
+    time.with_timezone(&Local)
+}
+
+pub fn naive_wall_clock(time: DateTime<Utc>) -> NaiveDateTime {
+    time.naive_utc()
+}
+
+pub fn nil_uuid() -> Uuid {
+    Uuid::nil()
+}
+
+pub fn uuid_version(id: Uuid) -> u32 {
+    id.get_version_num() as u32
+}
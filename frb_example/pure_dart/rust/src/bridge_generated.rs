@@ -4,7 +4,8 @@
     clippy::redundant_closure,
     clippy::useless_conversion,
     clippy::unit_arg,
-    non_snake_case
+    non_snake_case,
+    deprecated
 )]
 // AUTO GENERATED FILE, DO NOT EDIT.
 // Generated by `flutter_rust_bridge`.
@@ -28,9 +29,9 @@ pub extern "C" fn wire_simple_adder(port_: i64, a: i32, b: i32) {
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_a = a.wire2api();
-            let api_b = b.wire2api();
-            move |task_callback| Ok(simple_adder(api_a, api_b))
+            let api_a = a.wire2api()?;
+            let api_b = b.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(simple_adder(api_a, api_b)))
         },
     )
 }
@@ -50,428 +51,1641 @@ pub extern "C" fn wire_primitive_types(
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_my_i32 = my_i32.wire2api();
-            let api_my_i64 = my_i64.wire2api();
-            let api_my_f64 = my_f64.wire2api();
-            let api_my_bool = my_bool.wire2api();
-            move |task_callback| {
+            let api_my_i32 = my_i32.wire2api()?;
+            let api_my_i64 = my_i64.wire2api()?;
+            let api_my_f64 = my_f64.wire2api()?;
+            let api_my_bool = my_bool.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
                 Ok(primitive_types(
                     api_my_i32,
                     api_my_i64,
                     api_my_f64,
                     api_my_bool,
                 ))
-            }
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_primitive_u32(port_: i64, my_u32: u32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "primitive_u32",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_my_u32 = my_u32.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(primitive_u32(api_my_u32)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_add_to_u64(port_: i64, value: u64, delta: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "add_to_u64",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_value = value.wire2api()?;
+            let api_delta = delta.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(add_to_u64(api_value, api_delta)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_string(port_: i64, s: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_string",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_s = s.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(handle_string(api_s).into_bytes()).into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_char(port_: i64, c: u32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "next_char",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_c = c.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    (next_char(api_c) as u32).into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_double_duration(port_: i64, duration: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "double_duration",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_duration = duration.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let duration = double_duration(api_duration);
+                        <i64 as std::convert::TryFrom<_>>::try_from(duration.as_micros())
+                            .unwrap_or_else(|_| {
+                                panic!("{:?} is too long for a Dart Duration", duration)
+                            })
+                            .into_dart()
+                    }
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_async_sum(port_: i64, numbers: *mut wire_int_32_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_async_sum",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_numbers = numbers.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                crate::executor::block_on(handle_async_sum(api_numbers))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_async_average(port_: i64, numbers: *mut wire_int_32_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_async_average",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_numbers = numbers.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                crate::executor::block_on(handle_async_average(api_numbers))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_chars(s: *mut wire_uint_8_list) -> support::WireSyncReturnStruct {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "count_chars",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_s = s.wire2api()?;
+            Ok(SyncReturn(count_chars(api_s).to_ne_bytes().to_vec()))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_join_path_parts(port_: i64, parts: *mut wire_list_PathBuf) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "join_path_parts",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_parts = parts.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(
+                        std::path::Path::to_string_lossy(join_path_parts(api_parts).as_ref())
+                            .into_owned()
+                            .into_bytes(),
+                    )
+                    .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_path_file_name(port_: i64, path: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "path_file_name",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path: String = path.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    path_file_name(std::path::Path::new(&api_path))
+                        .map(|e| ZeroCopyBuffer(e.into_bytes()).into_dart())
+                        .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_non_zero_id(port_: i64, id: u32, step: *mut u8) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "next_non_zero_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.wire2api()?;
+            let api_step = step.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    next_non_zero_id(api_id, api_step).get().into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_wrap_json(
+    port_: i64,
+    value: *mut wire_uint_8_list,
+    tag: *mut wire_uint_8_list,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "wrap_json",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_value = value.wire2api()?;
+            let api_tag = tag.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(
+                        serde_json::to_string(&wrap_json(api_value, api_tag))
+                            .unwrap()
+                            .into_bytes(),
+                    )
+                    .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_number_i32(port_: i64, text: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "parse_number_i32",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| parse_number::<i32>(api_text))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_number_f64(port_: i64, text: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "parse_number_f64",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| parse_number::<f64>(api_text))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_return_unit(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_return_unit",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_return_unit())),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_vec_u8(port_: i64, v: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_vec_u8",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_v = v.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_vec_u8(api_v)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_boxed_slice(port_: i64, bytes: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_boxed_slice",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_bytes = bytes.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    Vec::from(handle_boxed_slice(api_bytes)).into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_boxed_string_slice(port_: i64, names: *mut wire_StringList) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_boxed_string_slice",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_names = names.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    Vec::from(handle_boxed_string_slice(api_names)).into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_vec_of_primitive(port_: i64, n: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_vec_of_primitive",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_n = n.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_vec_of_primitive(api_n)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_zero_copy_vec_of_primitive(port_: i64, n: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_zero_copy_vec_of_primitive",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_n = n.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_zero_copy_vec_of_primitive(api_n))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_struct(port_: i64, arg: *mut wire_MySize, boxed: *mut wire_MySize) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_arg = arg.wire2api()?;
+            let api_boxed = boxed.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_struct(api_arg, api_boxed)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_newtype(port_: i64, arg: *mut wire_NewTypeInt) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_newtype",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_arg = arg.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_newtype(api_arg)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_list_of_struct(port_: i64, l: *mut wire_list_my_size) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_list_of_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_l = l.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_list_of_struct(api_l)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_string_list(port_: i64, names: *mut wire_StringList) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_string_list",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_names = names.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_string_list(api_names)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_nested_list(port_: i64, matrix: *mut wire_list_float_64_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_nested_list",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_matrix = matrix.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    handle_nested_list(api_matrix)
+                        .into_iter()
+                        .map(|e| e.into_dart())
+                        .collect::<Vec<_>>()
+                        .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_nested_string_list(port_: i64, lines: *mut wire_list_StringList) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_nested_string_list",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_lines = lines.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    handle_nested_string_list(api_lines)
+                        .into_iter()
+                        .map(|e| e.into_dart())
+                        .collect::<Vec<_>>()
+                        .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_hash_map(port_: i64, counts: *mut wire_hash_map_String_i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_hash_map",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_counts = counts.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    handle_hash_map(api_counts)
+                        .into_iter()
+                        .map(|(k, v)| {
+                            vec![ZeroCopyBuffer(k.into_bytes()).into_dart(), v.into_dart()]
+                                .into_dart()
+                        })
+                        .collect::<Vec<_>>()
+                        .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_btree_map(port_: i64, names: *mut wire_btree_map_i32_String) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_btree_map",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_names = names.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    handle_btree_map(api_names)
+                        .into_iter()
+                        .map(|(k, v)| {
+                            vec![ZeroCopyBuffer(k.into_bytes()).into_dart(), v.into_dart()]
+                                .into_dart()
+                        })
+                        .collect::<Vec<_>>()
+                        .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_iterator(port_: i64, text: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_iterator",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    let ans = handle_iterator(api_text);
+                    ans.collect::<Vec<_>>()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_fallible_iterator(port_: i64, n: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_fallible_iterator",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_n = n.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                let ans = handle_fallible_iterator(api_n);
+                ans.map(|ans| ans.collect::<Vec<_>>())
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_callback(port_: i64, items: *mut wire_int_32_list, transform: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_callback",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_items = items.wire2api()?;
+            let api_transform = callback::DartCallback::new(transform);
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                let _api_transform_guard = api_transform.close_on_drop();
+                handle_callback(api_items, api_transform.clone())
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_complex_struct(port_: i64, s: *mut wire_MyTreeNode) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_complex_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_s = s.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_complex_struct(api_s)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_make_deep_tree(port_: i64, depth: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "make_deep_tree",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_depth = depth.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(make_deep_tree(api_depth)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_tree_depth(port_: i64, tree: *mut wire_MyTreeNode) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "tree_depth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_tree = tree.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(tree_depth(api_tree)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_sync_return(
+    mode: *mut wire_uint_8_list,
+) -> support::WireSyncReturnStruct {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "handle_sync_return",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_mode = mode.wire2api()?;
+            handle_sync_return(api_mode)
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream(port_: i64, arg: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_stream",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            let api_arg = arg.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                handle_stream(task_callback.stream_sink(), api_arg)
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_return_err(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "return_err",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || Ok(move |task_callback: rust2dart::TaskCallback| return_err()),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_return_custom_err(port_: i64, fail: bool) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "return_custom_err",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_fail = fail.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                return_custom_err(api_fail).map_err(handler::custom_error)
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_login(port_: i64, password: *mut wire_uint_8_list, attempts: u32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "login",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_password = password.wire2api()?;
+            let api_attempts = attempts.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                login(api_password, api_attempts).map_err(handler::custom_error)
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_return_panic(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "return_panic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || Ok(move |task_callback: rust2dart::TaskCallback| Ok(return_panic())),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_optional_return(port_: i64, left: f64, right: f64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_optional_return",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_left = left.wire2api()?;
+            let api_right = right.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_optional_return(api_left, api_right))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_optional_primitives(
+    port_: i64,
+    values: *mut wire_list_opt_i32,
+    offset: wire_opt_i64,
+    scale: wire_opt_f64,
+    negate: wire_opt_bool,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_optional_primitives",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_values = values.wire2api()?;
+            let api_offset = offset.wire2api()?;
+            let api_scale = scale.wire2api()?;
+            let api_negate = negate.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_optional_primitives(
+                    api_values, api_offset, api_scale, api_negate,
+                ))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_optional_struct(port_: i64, document: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_optional_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_document = document.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_optional_struct(api_document))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_optional_increment(port_: i64, opt: *mut wire_ExoticOptionals) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_optional_increment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_opt = opt.wire2api()?;
+            Ok(
+                move |task_callback: rust2dart::TaskCallback| {
+                    Ok(handle_optional_increment(api_opt))
+                },
+            )
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_increment_boxed_optional(port_: i64, opt: *mut f64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_increment_boxed_optional",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_opt = opt.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_increment_boxed_optional(api_opt))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_option_box_arguments(
+    port_: i64,
+    i8box: *mut i8,
+    u8box: *mut u8,
+    i32box: *mut i32,
+    i64box: *mut i64,
+    f64box: *mut f64,
+    boolbox: *mut bool,
+    structbox: *mut wire_ExoticOptionals,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_option_box_arguments",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_i8box = i8box.wire2api()?;
+            let api_u8box = u8box.wire2api()?;
+            let api_i32box = i32box.wire2api()?;
+            let api_i64box = i64box.wire2api()?;
+            let api_f64box = f64box.wire2api()?;
+            let api_boolbox = boolbox.wire2api()?;
+            let api_structbox = structbox.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(
+                        handle_option_box_arguments(
+                            api_i8box,
+                            api_u8box,
+                            api_i32box,
+                            api_i64box,
+                            api_f64box,
+                            api_boolbox,
+                            api_structbox,
+                        )
+                        .into_bytes(),
+                    )
+                    .into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_return_enum(port_: i64, input: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_return_enum",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_input = input.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_return_enum(api_input)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_enum_parameter(port_: i64, weekday: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_enum_parameter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_weekday = weekday.wire2api()?;
+            Ok(
+                move |task_callback: rust2dart::TaskCallback| {
+                    Ok(handle_enum_parameter(api_weekday))
+                },
+            )
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_sparse_lists(port_: i64, lists: *mut wire_SparseLists) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_sparse_lists",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_lists = lists.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_sparse_lists(api_lists)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_primitive_u32(port_: i64, my_u32: u32) {
+pub extern "C" fn wire_handle_non_exhaustive_enum(port_: i64, priority: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "primitive_u32",
+            debug_name: "handle_non_exhaustive_enum",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_my_u32 = my_u32.wire2api();
-            move |task_callback| Ok(primitive_u32(api_my_u32))
+            let api_priority = priority.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(handle_non_exhaustive_enum(api_priority))
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_string(port_: i64, s: *mut wire_uint_8_list) {
+pub extern "C" fn wire_handle_customized_struct(port_: i64, val: *mut wire_Customized) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_string",
+            debug_name: "handle_customized_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.wire2api();
-            move |task_callback| Ok(handle_string(api_s))
+            let api_val = val.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_customized_struct(api_val)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_return_unit(port_: i64) {
+pub extern "C" fn wire_handle_enum_struct(port_: i64, val: *mut wire_KitchenSink) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_return_unit",
+            debug_name: "handle_enum_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(handle_return_unit()),
+        move || {
+            let api_val = val.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(handle_enum_struct(api_val)))
+        },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_vec_u8(port_: i64, v: *mut wire_uint_8_list) {
+pub extern "C" fn wire_use_imported_struct(port_: i64, my_struct: *mut wire_MyStruct) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_u8",
+            debug_name: "use_imported_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_v = v.wire2api();
-            move |task_callback| Ok(handle_vec_u8(api_v))
+            let api_my_struct = my_struct.wire2api()?;
+            Ok(
+                move |task_callback: rust2dart::TaskCallback| {
+                    Ok(use_imported_struct(api_my_struct))
+                },
+            )
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_vec_of_primitive(port_: i64, n: i32) {
+pub extern "C" fn wire_use_imported_enum(port_: i64, my_enum: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_of_primitive",
+            debug_name: "use_imported_enum",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_n = n.wire2api();
-            move |task_callback| Ok(handle_vec_of_primitive(api_n))
+            let api_my_enum = my_enum.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(use_imported_enum(api_my_enum)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_zero_copy_vec_of_primitive(port_: i64, n: i32) {
+pub extern "C" fn wire_get_init_count(port_: i64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_zero_copy_vec_of_primitive",
+            debug_name: "get_init_count",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || Ok(move |task_callback: rust2dart::TaskCallback| Ok(get_init_count())),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_increment_locked_count(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "increment_locked_count",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_n = n.wire2api();
-            move |task_callback| Ok(handle_zero_copy_vec_of_primitive(api_n))
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    let _lock_guard = FLUTTER_RUST_BRIDGE_LOCK_LOCKED_COUNT
+                        .write()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner);
+                    increment_locked_count()
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_struct(port_: i64, arg: *mut wire_MySize, boxed: *mut wire_MySize) {
+pub extern "C" fn wire_get_locked_count(port_: i64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_struct",
+            debug_name: "get_locked_count",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            let api_boxed = boxed.wire2api();
-            move |task_callback| Ok(handle_struct(api_arg, api_boxed))
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    let _lock_guard = FLUTTER_RUST_BRIDGE_LOCK_LOCKED_COUNT
+                        .read()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner);
+                    get_locked_count()
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_newtype(port_: i64, arg: *mut wire_NewTypeInt) {
+pub extern "C" fn wire_count_until_cancelled(port_: i64, limit: u32, token: i64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_newtype",
+            debug_name: "count_until_cancelled",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            move |task_callback| Ok(handle_newtype(api_arg))
+            let api_limit = limit.wire2api()?;
+            let api_token = cancel::cancellation_token(token);
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                {
+                    let ans = count_until_cancelled(api_limit, api_token.clone());
+                    api_token.check().map(|()| ans)
+                }
+                .and_then(|ans| ans)
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_list_of_struct(port_: i64, l: *mut wire_list_my_size) {
+pub extern "C" fn wire_new_counter(port_: i64, start: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_list_of_struct",
+            debug_name: "new_counter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_l = l.wire2api();
-            move |task_callback| Ok(handle_list_of_struct(api_l))
+            let api_start = start.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(new_counter(api_start)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_string_list(port_: i64, names: *mut wire_StringList) {
+pub extern "C" fn wire_counter_value(port_: i64, counter: *mut wire_Counter) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_string_list",
+            debug_name: "counter_value",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_names = names.wire2api();
-            move |task_callback| Ok(handle_string_list(api_names))
+            let api_counter: std::sync::Arc<Counter> = counter.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(counter_value(&api_counter)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_complex_struct(port_: i64, s: *mut wire_MyTreeNode) {
+pub extern "C" fn wire_new_counter_if_positive(port_: i64, start: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_complex_struct",
+            debug_name: "new_counter_if_positive",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.wire2api();
-            move |task_callback| Ok(handle_complex_struct(api_s))
+            let api_start = start.wire2api()?;
+            Ok(
+                move |task_callback: rust2dart::TaskCallback| {
+                    Ok(new_counter_if_positive(api_start))
+                },
+            )
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_sync_return(
-    mode: *mut wire_uint_8_list,
-) -> support::WireSyncReturnStruct {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+pub extern "C" fn wire_counter_value_or_zero(port_: i64, counter: *mut wire_Counter) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_sync_return",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "counter_value_or_zero",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_mode = mode.wire2api();
-            handle_sync_return(api_mode)
+            let api_counter: Option<std::sync::Arc<Counter>> = counter.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(counter_value_or_zero(api_counter.as_deref()))
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_stream(port_: i64, arg: *mut wire_uint_8_list) {
+pub extern "C" fn wire_new_calculator(port_: i64, base: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_stream",
+            debug_name: "new_calculator",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            move |task_callback| handle_stream(task_callback.stream_sink(), api_arg)
+            let api_base = base.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(new_calculator(api_base)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_return_err(port_: i64) {
+pub extern "C" fn wire_reverse_fixed_array(port_: i64, values: *mut wire_float_64_list) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_err",
+            debug_name: "reverse_fixed_array",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| return_err(),
+        move || {
+            let api_values = values.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    Vec::from(reverse_fixed_array(api_values)).into_dart()
+                })
+            })
+        },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_return_panic(port_: i64) {
+pub extern "C" fn wire_swap_fixed_sizes(port_: i64, sizes: *mut wire_list_my_size) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_panic",
+            debug_name: "swap_fixed_sizes",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_sizes = sizes.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    Vec::from(swap_fixed_sizes(api_sizes)).into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_swap_tuple(port_: i64, pair: *mut wire_TupleI32String) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "swap_tuple",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(return_panic()),
+        move || {
+            let api_pair = pair.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let tuple = swap_tuple(api_pair);
+                        vec![
+                            ZeroCopyBuffer(tuple.0.into_bytes()).into_dart(),
+                            tuple.1.into_dart(),
+                        ]
+                        .into_dart()
+                    }
+                })
+            })
+        },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_optional_return(port_: i64, left: f64, right: f64) {
+pub extern "C" fn wire_distance(port_: i64, a: *mut wire_TupleF64F64, b: *mut wire_TupleF64F64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_optional_return",
+            debug_name: "distance",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_left = left.wire2api();
-            let api_right = right.wire2api();
-            move |task_callback| Ok(handle_optional_return(api_left, api_right))
+            let api_a = a.wire2api()?;
+            let api_b = b.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(distance(api_a, api_b)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_optional_struct(port_: i64, document: *mut wire_uint_8_list) {
+pub extern "C" fn wire_increment_u128(port_: i64, value: *mut wire_uint_128) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_optional_struct",
+            debug_name: "increment_u128",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_document = document.wire2api();
-            move |task_callback| Ok(handle_optional_struct(api_document))
+            let api_value = value.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let value = increment_u128(api_value);
+                        vec![(value >> 64) as i64, value as i64].into_dart()
+                    }
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_optional_increment(port_: i64, opt: *mut wire_ExoticOptionals) {
+pub extern "C" fn wire_decrement_i128(port_: i64, value: *mut wire_int_128) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_optional_increment",
+            debug_name: "decrement_i128",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opt = opt.wire2api();
-            move |task_callback| Ok(handle_optional_increment(api_opt))
+            let api_value = value.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let value = decrement_i128(api_value);
+                        vec![(value >> 64) as i64, value as i64].into_dart()
+                    }
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_increment_boxed_optional(port_: i64, opt: *mut f64) {
+pub extern "C" fn wire_unique_words(port_: i64, text: *mut wire_uint_8_list) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_increment_boxed_optional",
+            debug_name: "unique_words",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opt = opt.wire2api();
-            move |task_callback| Ok(handle_increment_boxed_optional(api_opt))
+            let api_text = text.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let mut items = unique_words(api_text).into_iter().collect::<Vec<_>>();
+                        items.sort();
+                        items
+                    }
+                    .into_dart()
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_option_box_arguments(
-    port_: i64,
-    i8box: *mut i8,
-    u8box: *mut u8,
-    i32box: *mut i32,
-    i64box: *mut i64,
-    f64box: *mut f64,
-    boolbox: *mut bool,
-    structbox: *mut wire_ExoticOptionals,
-) {
+pub extern "C" fn wire_sorted_evens(port_: i64, values: *mut wire_int_32_list) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_option_box_arguments",
+            debug_name: "sorted_evens",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_i8box = i8box.wire2api();
-            let api_u8box = u8box.wire2api();
-            let api_i32box = i32box.wire2api();
-            let api_i64box = i64box.wire2api();
-            let api_f64box = f64box.wire2api();
-            let api_boolbox = boolbox.wire2api();
-            let api_structbox = structbox.wire2api();
-            move |task_callback| {
-                Ok(handle_option_box_arguments(
-                    api_i8box,
-                    api_u8box,
-                    api_i32box,
-                    api_i64box,
-                    api_f64box,
-                    api_boolbox,
-                    api_structbox,
-                ))
-            }
+            let api_values = values.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    sorted_evens(api_values)
+                        .into_iter()
+                        .collect::<Vec<_>>()
+                        .into_dart()
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_return_enum(port_: i64, input: *mut wire_uint_8_list) {
+pub extern "C" fn wire_fill_buffer(port_: i64, len: u32, value: u8) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_return_enum",
+            debug_name: "fill_buffer",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(handle_return_enum(api_input))
+            let api_len = len.wire2api()?;
+            let api_value = value.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    let mut api_buf: Vec<u8> = Default::default();
+                    fill_buffer(api_len, api_value, &mut api_buf);
+                    api_buf
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_enum_parameter(port_: i64, weekday: i32) {
+pub extern "C" fn wire_parse_numbers(port_: i64, input: *mut wire_uint_8_list) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_enum_parameter",
+            debug_name: "parse_numbers",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_weekday = weekday.wire2api();
-            move |task_callback| Ok(handle_enum_parameter(api_weekday))
+            let api_input = input.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let tuple = {
+                            let mut api_rejected: Vec<String> = Default::default();
+                            let ans = parse_numbers(api_input, &mut api_rejected);
+                            (ans, api_rejected)
+                        };
+                        vec![tuple.0.into_dart(), tuple.1.into_dart()].into_dart()
+                    }
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_customized_struct(port_: i64, val: *mut wire_Customized) {
+pub extern "C" fn wire_clamp_to(port_: i64, value: i32, max: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_customized_struct",
+            debug_name: "clamp_to",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_val = val.wire2api();
-            move |task_callback| Ok(handle_customized_struct(api_val))
+            let api_value = value.wire2api()?;
+            let api_max = max.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(clamp_to(api_value, api_max)))
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_handle_enum_struct(port_: i64, val: *mut wire_KitchenSink) {
+pub extern "C" fn wire_shout(port_: i64, text: *mut wire_uint_8_list, times: u32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_enum_struct",
+            debug_name: "shout",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_val = val.wire2api();
-            move |task_callback| Ok(handle_enum_struct(api_val))
+            let api_text = text.wire2api()?;
+            let api_times = times.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(shout(api_text, api_times).into_bytes()).into_dart()
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_use_imported_struct(port_: i64, my_struct: *mut wire_MyStruct) {
+pub extern "C" fn wire_add_one_day(port_: i64, time: i64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "use_imported_struct",
+            debug_name: "add_one_day",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_my_struct = my_struct.wire2api();
-            move |task_callback| Ok(use_imported_struct(api_my_struct))
+            let api_time = time.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let time = add_one_day(api_time);
+                        (time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64)
+                            .into_dart()
+                    }
+                })
+            })
         },
     )
 }
 
 #[no_mangle]
-pub extern "C" fn wire_use_imported_enum(port_: i64, my_enum: i32) {
+pub extern "C" fn wire_to_local_time(port_: i64, time: i64) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "use_imported_enum",
+            debug_name: "to_local_time",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_time = time.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let time = to_local_time(api_time);
+                        (time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64)
+                            .into_dart()
+                    }
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_naive_wall_clock(port_: i64, time: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "naive_wall_clock",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_time = time.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    {
+                        let time = naive_wall_clock(api_time);
+                        (time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64)
+                            .into_dart()
+                    }
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_nil_uuid(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "nil_uuid",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    nil_uuid().as_bytes().to_vec().into_dart()
+                })
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_uuid_version(port_: i64, id: *mut wire_uint_8_list) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "uuid_version",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(uuid_version(api_id)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_counter_increment(port_: i64, that: *mut wire_Counter, by: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "counter_increment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that: std::sync::Arc<Counter> = that.wire2api()?;
+            let api_by = by.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(<Counter>::increment(&api_that, api_by))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_calculator_add(port_: i64, that: *mut wire_Calculator, x: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "calculator_add",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api()?;
+            let api_x = x.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(<Calculator>::add(&api_that, api_x))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_calculator_accumulate(port_: i64, that: *mut wire_Calculator, x: i32) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "calculator_accumulate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let mut api_that = that.wire2api()?;
+            let api_x = x.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(<Calculator>::accumulate(&mut api_that, api_x))
+            })
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_calculator_into_base(port_: i64, that: *mut wire_Calculator) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "calculator_into_base",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(<Calculator>::into_base(api_that)))
+        },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_init(port_: i64) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "init",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_my_enum = my_enum.wire2api();
-            move |task_callback| Ok(use_imported_enum(api_my_enum))
+            Ok(move |_: rust2dart::TaskCallback| {
+                static INIT: std::sync::Once = std::sync::Once::new();
+                INIT.call_once(|| {
+                    count_init();
+                });
+                Ok(())
+            })
         },
     )
 }
@@ -492,6 +1706,19 @@ pub struct wire_Attribute {
     value: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_btree_map_i32_String {
+    keys: *mut wire_int_32_list,
+    values: *mut wire_StringList,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Calculator {
+    base: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Customized {
@@ -502,10 +1729,10 @@ pub struct wire_Customized {
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ExoticOptionals {
-    int32: *mut i32,
-    int64: *mut i64,
-    float64: *mut f64,
-    boolean: *mut bool,
+    int32: wire_opt_i32,
+    int64: wire_opt_i64,
+    float64: wire_opt_f64,
+    boolean: wire_opt_bool,
     zerocopy: *mut wire_uint_8_list,
     int8list: *mut wire_int_8_list,
     uint8list: *mut wire_uint_8_list,
@@ -533,6 +1760,20 @@ pub struct wire_float_64_list {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_hash_map_String_i32 {
+    keys: *mut wire_StringList,
+    values: *mut wire_int_32_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_int_128 {
+    hi: u64,
+    lo: u64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_int_32_list {
@@ -556,29 +1797,78 @@ pub struct wire_int_8_list {
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_attribute {
-    ptr: *mut wire_Attribute,
+pub struct wire_list_PathBuf {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_StringList {
+    ptr: *mut *mut wire_StringList,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_attribute {
+    ptr: *mut wire_Attribute,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_float_64_list {
+    ptr: *mut *mut wire_float_64_list,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_my_size {
+    ptr: *mut wire_MySize,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_my_tree_node {
+    ptr: *mut wire_MyTreeNode,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_opt_String {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_opt_box_autoadd_attribute {
+    ptr: *mut *mut wire_Attribute,
     len: i32,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_my_size {
-    ptr: *mut wire_MySize,
+pub struct wire_list_opt_box_autoadd_weekdays {
+    ptr: *mut *mut i32,
     len: i32,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_my_tree_node {
-    ptr: *mut wire_MyTreeNode,
+pub struct wire_list_opt_f64 {
+    ptr: *mut wire_opt_f64,
     len: i32,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_opt_box_autoadd_attribute {
-    ptr: *mut *mut wire_Attribute,
+pub struct wire_list_opt_i32 {
+    ptr: *mut wire_opt_i32,
     len: i32,
 }
 
@@ -610,6 +1900,70 @@ pub struct wire_NewTypeInt {
     field0: i64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Counter {
+    ptr: *const core::ffi::c_void,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_opt_bool {
+    is_some: bool,
+    value: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_opt_f64 {
+    is_some: bool,
+    value: f64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_opt_i32 {
+    is_some: bool,
+    value: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_opt_i64 {
+    is_some: bool,
+    value: i64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SparseLists {
+    values: *mut wire_list_opt_f64,
+    labels: *mut wire_list_opt_String,
+    weekdays: *mut wire_list_opt_box_autoadd_weekdays,
+    attributes: *mut wire_list_opt_box_autoadd_attribute,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_TupleF64F64 {
+    field0: f64,
+    field1: f64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_TupleI32String {
+    field0: i32,
+    field1: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_uint_128 {
+    hi: u64,
+    lo: u64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -655,8 +2009,8 @@ pub struct KitchenSink_Nested {
 #[repr(C)]
 #[derive(Clone)]
 pub struct KitchenSink_Optional {
-    field0: *mut i32,
-    field1: *mut i32,
+    field0: wire_opt_i32,
+    field1: wire_opt_i32,
 }
 
 #[repr(C)]
@@ -682,14 +2036,19 @@ pub extern "C" fn new_StringList(len: i32) -> *mut wire_StringList {
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_NonZero_u8(value: u8) -> *mut u8 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_attribute() -> *mut wire_Attribute {
     support::new_leak_box_ptr(wire_Attribute::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_bool(value: bool) -> *mut bool {
-    support::new_leak_box_ptr(value)
+pub extern "C" fn new_box_autoadd_calculator() -> *mut wire_Calculator {
+    support::new_leak_box_ptr(wire_Calculator::new_with_null_ptr())
 }
 
 #[no_mangle]
@@ -702,21 +2061,6 @@ pub extern "C" fn new_box_autoadd_exotic_optionals() -> *mut wire_ExoticOptional
     support::new_leak_box_ptr(wire_ExoticOptionals::new_with_null_ptr())
 }
 
-#[no_mangle]
-pub extern "C" fn new_box_autoadd_f64(value: f64) -> *mut f64 {
-    support::new_leak_box_ptr(value)
-}
-
-#[no_mangle]
-pub extern "C" fn new_box_autoadd_i32(value: i32) -> *mut i32 {
-    support::new_leak_box_ptr(value)
-}
-
-#[no_mangle]
-pub extern "C" fn new_box_autoadd_i64(value: i64) -> *mut i64 {
-    support::new_leak_box_ptr(value)
-}
-
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_kitchen_sink() -> *mut wire_KitchenSink {
     support::new_leak_box_ptr(wire_KitchenSink::new_with_null_ptr())
@@ -742,6 +2086,26 @@ pub extern "C" fn new_box_autoadd_new_type_int() -> *mut wire_NewTypeInt {
     support::new_leak_box_ptr(wire_NewTypeInt::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sparse_lists() -> *mut wire_SparseLists {
+    support::new_leak_box_ptr(wire_SparseLists::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_tuple_f_64_f_64() -> *mut wire_TupleF64F64 {
+    support::new_leak_box_ptr(wire_TupleF64F64::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_tuple_i_32_string() -> *mut wire_TupleI32String {
+    support::new_leak_box_ptr(wire_TupleI32String::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_weekdays(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_bool(value: bool) -> *mut bool {
     support::new_leak_box_ptr(value)
@@ -787,6 +2151,11 @@ pub extern "C" fn new_box_u8(value: u8) -> *mut u8 {
     support::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn new_btree_map_i32_String() -> *mut wire_btree_map_i32_String {
+    support::new_leak_box_ptr(wire_btree_map_i32_String::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_float_32_list(len: i32) -> *mut wire_float_32_list {
     let ans = wire_float_32_list {
@@ -805,6 +2174,16 @@ pub extern "C" fn new_float_64_list(len: i32) -> *mut wire_float_64_list {
     support::new_leak_box_ptr(ans)
 }
 
+#[no_mangle]
+pub extern "C" fn new_hash_map_String_i32() -> *mut wire_hash_map_String_i32 {
+    support::new_leak_box_ptr(wire_hash_map_String_i32::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_i128() -> *mut wire_int_128 {
+    support::new_leak_box_ptr(wire_int_128 { hi: 0, lo: 0 })
+}
+
 #[no_mangle]
 pub extern "C" fn new_int_32_list(len: i32) -> *mut wire_int_32_list {
     let ans = wire_int_32_list {
@@ -832,6 +2211,24 @@ pub extern "C" fn new_int_8_list(len: i32) -> *mut wire_int_8_list {
     support::new_leak_box_ptr(ans)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_PathBuf(len: i32) -> *mut wire_list_PathBuf {
+    let wrap = wire_list_PathBuf {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_StringList(len: i32) -> *mut wire_list_StringList {
+    let wrap = wire_list_StringList {
+        ptr: support::new_leak_vec_ptr(<*mut wire_StringList>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_attribute(len: i32) -> *mut wire_list_attribute {
     let wrap = wire_list_attribute {
@@ -841,6 +2238,15 @@ pub extern "C" fn new_list_attribute(len: i32) -> *mut wire_list_attribute {
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_float_64_list(len: i32) -> *mut wire_list_float_64_list {
+    let wrap = wire_list_float_64_list {
+        ptr: support::new_leak_vec_ptr(<*mut wire_float_64_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_my_size(len: i32) -> *mut wire_list_my_size {
     let wrap = wire_list_my_size {
@@ -859,6 +2265,15 @@ pub extern "C" fn new_list_my_tree_node(len: i32) -> *mut wire_list_my_tree_node
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_opt_String(len: i32) -> *mut wire_list_opt_String {
+    let wrap = wire_list_opt_String {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_opt_box_autoadd_attribute(
     len: i32,
@@ -870,6 +2285,65 @@ pub extern "C" fn new_list_opt_box_autoadd_attribute(
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_opt_box_autoadd_weekdays(
+    len: i32,
+) -> *mut wire_list_opt_box_autoadd_weekdays {
+    let wrap = wire_list_opt_box_autoadd_weekdays {
+        ptr: support::new_leak_vec_ptr(<*mut i32>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_opt_f64(len: i32) -> *mut wire_list_opt_f64 {
+    let wrap = wire_list_opt_f64 {
+        ptr: support::new_leak_vec_ptr(<wire_opt_f64>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_opt_i32(len: i32) -> *mut wire_list_opt_i32 {
+    let wrap = wire_list_opt_i32 {
+        ptr: support::new_leak_vec_ptr(<wire_opt_i32>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_opaque_counter() -> *mut wire_Counter {
+    support::new_leak_box_ptr(wire_Counter::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_opt_bool(is_some: bool, value: bool) -> wire_opt_bool {
+    wire_opt_bool { is_some, value }
+}
+
+#[no_mangle]
+pub extern "C" fn new_opt_f64(is_some: bool, value: f64) -> wire_opt_f64 {
+    wire_opt_f64 { is_some, value }
+}
+
+#[no_mangle]
+pub extern "C" fn new_opt_i32(is_some: bool, value: i32) -> wire_opt_i32 {
+    wire_opt_i32 { is_some, value }
+}
+
+#[no_mangle]
+pub extern "C" fn new_opt_i64(is_some: bool, value: i64) -> wire_opt_i64 {
+    wire_opt_i64 { is_some, value }
+}
+
+#[no_mangle]
+pub extern "C" fn new_u128() -> *mut wire_uint_128 {
+    support::new_leak_box_ptr(wire_uint_128 { hi: 0, lo: 0 })
+}
+
 #[no_mangle]
 pub extern "C" fn new_uint_8_list(len: i32) -> *mut wire_uint_8_list {
     let ans = wire_uint_8_list {
@@ -879,34 +2353,112 @@ pub extern "C" fn new_uint_8_list(len: i32) -> *mut wire_uint_8_list {
     support::new_leak_box_ptr(ans)
 }
 
+#[no_mangle]
+pub extern "C" fn drop_opaque_Counter(ptr: *const core::ffi::c_void) {
+    unsafe { support::drop_opaque::<Counter>(ptr) }
+}
+
 // Section: impl Wire2Api
 
 pub trait Wire2Api<T> {
-    fn wire2api(self) -> T;
+    fn wire2api(self) -> support::anyhow::Result<T>;
 }
 
 impl<T, S> Wire2Api<Option<T>> for *mut S
 where
     *mut S: Wire2Api<T>,
 {
-    fn wire2api(self) -> Option<T> {
+    fn wire2api(self) -> support::anyhow::Result<Option<T>> {
         if self.is_null() {
-            None
+            Ok(None)
         } else {
-            Some(self.wire2api())
+            self.wire2api().map(Some)
         }
     }
 }
 
+impl Wire2Api<Box<[String]>> for *mut wire_StringList {
+    fn wire2api(self) -> support::anyhow::Result<Box<[String]>> {
+        let vec: Vec<String> = self.wire2api()?;
+        Ok(vec.into_boxed_slice())
+    }
+}
+
+impl Wire2Api<Box<[u8]>> for *mut wire_uint_8_list {
+    fn wire2api(self) -> support::anyhow::Result<Box<[u8]>> {
+        let vec: Vec<u8> = self.wire2api()?;
+        Ok(vec.into_boxed_slice())
+    }
+}
+
+impl Wire2Api<char> for u32 {
+    fn wire2api(self) -> support::anyhow::Result<char> {
+        char::from_u32(self)
+            .ok_or_else(|| support::anyhow::anyhow!("{:#x} is not a Unicode scalar value", self))
+    }
+}
+
+impl Wire2Api<chrono::DateTime<chrono::Utc>> for i64 {
+    fn wire2api(self) -> support::anyhow::Result<chrono::DateTime<chrono::Utc>> {
+        let (secs, nsecs) = (
+            self.div_euclid(1_000_000),
+            (self.rem_euclid(1_000_000) * 1_000) as u32,
+        );
+        chrono::TimeZone::timestamp_opt(&chrono::Utc, secs, nsecs)
+            .single()
+            .ok_or_else(|| support::anyhow::anyhow!("the timestamp {} is out of range", self))
+    }
+}
+
+impl Wire2Api<std::time::Duration> for i64 {
+    fn wire2api(self) -> support::anyhow::Result<std::time::Duration> {
+        let micros: u64 = std::convert::TryInto::try_into(self).map_err(|_| {
+            support::anyhow::anyhow!(
+                "{} microseconds is not a valid Duration, which cannot be negative",
+                self
+            )
+        })?;
+        Ok(std::time::Duration::from_micros(micros))
+    }
+}
+
+impl Wire2Api<serde_json::Value> for *mut wire_uint_8_list {
+    fn wire2api(self) -> support::anyhow::Result<serde_json::Value> {
+        let string: String = self.wire2api()?;
+        serde_json::from_str(&string).map_err(support::anyhow::Error::from)
+    }
+}
+
+impl Wire2Api<std::num::NonZeroU32> for u32 {
+    fn wire2api(self) -> support::anyhow::Result<std::num::NonZeroU32> {
+        std::num::NonZeroU32::new(self)
+            .ok_or_else(|| support::anyhow::anyhow!("zero is not a valid std::num::NonZeroU32"))
+    }
+}
+
+impl Wire2Api<std::num::NonZeroU8> for u8 {
+    fn wire2api(self) -> support::anyhow::Result<std::num::NonZeroU8> {
+        std::num::NonZeroU8::new(self)
+            .ok_or_else(|| support::anyhow::anyhow!("zero is not a valid std::num::NonZeroU8"))
+    }
+}
+
+impl Wire2Api<std::path::PathBuf> for *mut wire_uint_8_list {
+    fn wire2api(self) -> support::anyhow::Result<std::path::PathBuf> {
+        let string: String = self.wire2api()?;
+        Ok(std::path::PathBuf::from(string))
+    }
+}
+
 impl Wire2Api<String> for *mut wire_uint_8_list {
-    fn wire2api(self) -> String {
-        let vec: Vec<u8> = self.wire2api();
-        String::from_utf8_lossy(&vec).into_owned()
+    fn wire2api(self) -> support::anyhow::Result<String> {
+        let vec: Vec<u8> = self.wire2api()?;
+        Ok(String::from_utf8(vec)?)
     }
 }
 
 impl Wire2Api<Vec<String>> for *mut wire_StringList {
-    fn wire2api(self) -> Vec<String> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<String>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -915,310 +2467,421 @@ impl Wire2Api<Vec<String>> for *mut wire_StringList {
     }
 }
 
+impl Wire2Api<uuid::Uuid> for *mut wire_uint_8_list {
+    fn wire2api(self) -> support::anyhow::Result<uuid::Uuid> {
+        let bytes: Vec<u8> = self.wire2api()?;
+        let bytes: [u8; 16] =
+            std::convert::TryInto::try_into(bytes).map_err(|bytes: Vec<u8>| {
+                support::anyhow::anyhow!("a UUID has 16 bytes, not {}", bytes.len())
+            })?;
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+}
+
 impl Wire2Api<ZeroCopyBuffer<Vec<u8>>> for *mut wire_uint_8_list {
-    fn wire2api(self) -> ZeroCopyBuffer<Vec<u8>> {
-        ZeroCopyBuffer(self.wire2api())
+    fn wire2api(self) -> support::anyhow::Result<ZeroCopyBuffer<Vec<u8>>> {
+        self.wire2api().map(ZeroCopyBuffer)
     }
 }
 
 impl Wire2Api<Attribute> for wire_Attribute {
-    fn wire2api(self) -> Attribute {
-        Attribute {
-            key: self.key.wire2api(),
-            value: self.value.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<Attribute> {
+        Ok(Attribute {
+            key: self.key.wire2api()?,
+            value: self.value.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<bool> for bool {
-    fn wire2api(self) -> bool {
-        self
+    fn wire2api(self) -> support::anyhow::Result<bool> {
+        Ok(self)
+    }
+}
+
+impl Wire2Api<std::num::NonZeroU8> for *mut u8 {
+    fn wire2api(self) -> support::anyhow::Result<std::num::NonZeroU8> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<std::num::NonZeroU8>::wire2api(*wrap).map(Into::into)
     }
 }
 
 impl Wire2Api<Attribute> for *mut wire_Attribute {
-    fn wire2api(self) -> Attribute {
+    fn wire2api(self) -> support::anyhow::Result<Attribute> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<bool> for *mut bool {
-    fn wire2api(self) -> bool {
-        unsafe { *support::box_from_leak_ptr(self) }
+impl Wire2Api<Calculator> for *mut wire_Calculator {
+    fn wire2api(self) -> support::anyhow::Result<Calculator> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<Customized> for *mut wire_Customized {
-    fn wire2api(self) -> Customized {
+    fn wire2api(self) -> support::anyhow::Result<Customized> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<ExoticOptionals> for *mut wire_ExoticOptionals {
-    fn wire2api(self) -> ExoticOptionals {
+    fn wire2api(self) -> support::anyhow::Result<ExoticOptionals> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        (*wrap).wire2api().map(Into::into)
+    }
+}
+
+impl Wire2Api<KitchenSink> for *mut wire_KitchenSink {
+    fn wire2api(self) -> support::anyhow::Result<KitchenSink> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<f64> for *mut f64 {
-    fn wire2api(self) -> f64 {
-        unsafe { *support::box_from_leak_ptr(self) }
+impl Wire2Api<MySize> for *mut wire_MySize {
+    fn wire2api(self) -> support::anyhow::Result<MySize> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<i32> for *mut i32 {
-    fn wire2api(self) -> i32 {
-        unsafe { *support::box_from_leak_ptr(self) }
+impl Wire2Api<MyStruct> for *mut wire_MyStruct {
+    fn wire2api(self) -> support::anyhow::Result<MyStruct> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<i64> for *mut i64 {
-    fn wire2api(self) -> i64 {
-        unsafe { *support::box_from_leak_ptr(self) }
+impl Wire2Api<MyTreeNode> for *mut wire_MyTreeNode {
+    fn wire2api(self) -> support::anyhow::Result<MyTreeNode> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<KitchenSink> for *mut wire_KitchenSink {
-    fn wire2api(self) -> KitchenSink {
+impl Wire2Api<NewTypeInt> for *mut wire_NewTypeInt {
+    fn wire2api(self) -> support::anyhow::Result<NewTypeInt> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<MySize> for *mut wire_MySize {
-    fn wire2api(self) -> MySize {
+impl Wire2Api<SparseLists> for *mut wire_SparseLists {
+    fn wire2api(self) -> support::anyhow::Result<SparseLists> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<MyStruct> for *mut wire_MyStruct {
-    fn wire2api(self) -> MyStruct {
+impl Wire2Api<(f64, f64)> for *mut wire_TupleF64F64 {
+    fn wire2api(self) -> support::anyhow::Result<(f64, f64)> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<MyTreeNode> for *mut wire_MyTreeNode {
-    fn wire2api(self) -> MyTreeNode {
+impl Wire2Api<(i32, String)> for *mut wire_TupleI32String {
+    fn wire2api(self) -> support::anyhow::Result<(i32, String)> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
-impl Wire2Api<NewTypeInt> for *mut wire_NewTypeInt {
-    fn wire2api(self) -> NewTypeInt {
+impl Wire2Api<Weekdays> for *mut i32 {
+    fn wire2api(self) -> support::anyhow::Result<Weekdays> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        Wire2Api::<Weekdays>::wire2api(*wrap).map(Into::into)
     }
 }
 
 impl Wire2Api<Box<bool>> for *mut bool {
-    fn wire2api(self) -> Box<bool> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<bool>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
     }
 }
 
 impl Wire2Api<Box<ExoticOptionals>> for *mut wire_ExoticOptionals {
-    fn wire2api(self) -> Box<ExoticOptionals> {
+    fn wire2api(self) -> support::anyhow::Result<Box<ExoticOptionals>> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<Box<f64>> for *mut f64 {
-    fn wire2api(self) -> Box<f64> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<f64>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
     }
 }
 
 impl Wire2Api<Box<i32>> for *mut i32 {
-    fn wire2api(self) -> Box<i32> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<i32>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
     }
 }
 
 impl Wire2Api<Box<i64>> for *mut i64 {
-    fn wire2api(self) -> Box<i64> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<i64>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
     }
 }
 
 impl Wire2Api<Box<i8>> for *mut i8 {
-    fn wire2api(self) -> Box<i8> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<i8>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
     }
 }
 
 impl Wire2Api<Box<KitchenSink>> for *mut wire_KitchenSink {
-    fn wire2api(self) -> Box<KitchenSink> {
+    fn wire2api(self) -> support::anyhow::Result<Box<KitchenSink>> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<Box<MySize>> for *mut wire_MySize {
-    fn wire2api(self) -> Box<MySize> {
+    fn wire2api(self) -> support::anyhow::Result<Box<MySize>> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<Box<u8>> for *mut u8 {
-    fn wire2api(self) -> Box<u8> {
-        unsafe { support::box_from_leak_ptr(self) }
+    fn wire2api(self) -> support::anyhow::Result<Box<u8>> {
+        Ok(unsafe { support::box_from_leak_ptr(self) })
+    }
+}
+
+impl Wire2Api<std::collections::BTreeMap<i32, String>> for *mut wire_btree_map_i32_String {
+    fn wire2api(self) -> support::anyhow::Result<std::collections::BTreeMap<i32, String>> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<i32> = wrap.keys.wire2api()?;
+        let values: Vec<String> = wrap.values.wire2api()?;
+        Ok(keys.into_iter().zip(values).collect())
+    }
+}
+
+impl Wire2Api<Calculator> for wire_Calculator {
+    fn wire2api(self) -> support::anyhow::Result<Calculator> {
+        Ok(Calculator {
+            base: self.base.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<Customized> for wire_Customized {
-    fn wire2api(self) -> Customized {
-        Customized {
-            final_field: self.final_field.wire2api(),
-            non_final_field: self.non_final_field.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<Customized> {
+        Ok(Customized {
+            final_field: self.final_field.wire2api()?,
+            non_final_field: self.non_final_field.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<ExoticOptionals> for wire_ExoticOptionals {
-    fn wire2api(self) -> ExoticOptionals {
-        ExoticOptionals {
-            int32: self.int32.wire2api(),
-            int64: self.int64.wire2api(),
-            float64: self.float64.wire2api(),
-            boolean: self.boolean.wire2api(),
-            zerocopy: self.zerocopy.wire2api(),
-            int8list: self.int8list.wire2api(),
-            uint8list: self.uint8list.wire2api(),
-            int32list: self.int32list.wire2api(),
-            int64list: self.int64list.wire2api(),
-            float32list: self.float32list.wire2api(),
-            float64list: self.float64list.wire2api(),
-            attributes: self.attributes.wire2api(),
-            attributes_nullable: self.attributes_nullable.wire2api(),
-            nullable_attributes: self.nullable_attributes.wire2api(),
-            newtypeint: self.newtypeint.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<ExoticOptionals> {
+        Ok(ExoticOptionals {
+            int32: self.int32.wire2api()?,
+            int64: self.int64.wire2api()?,
+            float64: self.float64.wire2api()?,
+            boolean: self.boolean.wire2api()?,
+            zerocopy: self.zerocopy.wire2api()?,
+            int8list: self.int8list.wire2api()?,
+            uint8list: self.uint8list.wire2api()?,
+            int32list: self.int32list.wire2api()?,
+            int64list: self.int64list.wire2api()?,
+            float32list: self.float32list.wire2api()?,
+            float64list: self.float64list.wire2api()?,
+            attributes: self.attributes.wire2api()?,
+            attributes_nullable: self.attributes_nullable.wire2api()?,
+            nullable_attributes: self.nullable_attributes.wire2api()?,
+            newtypeint: self.newtypeint.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<f32> for f32 {
-    fn wire2api(self) -> f32 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<f32> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<f64> for f64 {
-    fn wire2api(self) -> f64 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<f64> {
+        Ok(self)
+    }
+}
+
+impl Wire2Api<[f64; 4]> for *mut wire_float_64_list {
+    fn wire2api(self) -> support::anyhow::Result<[f64; 4]> {
+        let vec: Vec<f64> = self.wire2api()?;
+        std::convert::TryInto::try_into(vec).map_err(|vec: Vec<f64>| {
+            support::anyhow::anyhow!("Expected a list of 4 items, but got {}", vec.len())
+        })
     }
 }
 
 impl Wire2Api<Vec<f32>> for *mut wire_float_32_list {
-    fn wire2api(self) -> Vec<f32> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<f32>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
 impl Wire2Api<Vec<f64>> for *mut wire_float_64_list {
-    fn wire2api(self) -> Vec<f64> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<f64>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
+    }
+}
+
+impl Wire2Api<std::collections::HashMap<String, i32>> for *mut wire_hash_map_String_i32 {
+    fn wire2api(self) -> support::anyhow::Result<std::collections::HashMap<String, i32>> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<String> = wrap.keys.wire2api()?;
+        let values: Vec<i32> = wrap.values.wire2api()?;
+        Ok(keys.into_iter().zip(values).collect())
+    }
+}
+
+impl Wire2Api<std::collections::HashSet<i32>> for *mut wire_int_32_list {
+    fn wire2api(self) -> support::anyhow::Result<std::collections::HashSet<i32>> {
+        let vec: Vec<i32> = self.wire2api()?;
+        Ok(vec.into_iter().collect())
+    }
+}
+
+impl Wire2Api<i128> for *mut wire_int_128 {
+    fn wire2api(self) -> support::anyhow::Result<i128> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Ok(((wrap.hi as i128) << 64) | wrap.lo as i128)
     }
 }
 
 impl Wire2Api<i32> for i32 {
-    fn wire2api(self) -> i32 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<i32> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<i64> for i64 {
-    fn wire2api(self) -> i64 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<i64> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<i8> for i8 {
-    fn wire2api(self) -> i8 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<i8> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<Vec<i32>> for *mut wire_int_32_list {
-    fn wire2api(self) -> Vec<i32> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<i32>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
 impl Wire2Api<Vec<i64>> for *mut wire_int_64_list {
-    fn wire2api(self) -> Vec<i64> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<i64>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
 impl Wire2Api<Vec<i8>> for *mut wire_int_8_list {
-    fn wire2api(self) -> Vec<i8> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<i8>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
 impl Wire2Api<KitchenSink> for wire_KitchenSink {
-    fn wire2api(self) -> KitchenSink {
+    fn wire2api(self) -> support::anyhow::Result<KitchenSink> {
         match self.tag {
-            0 => KitchenSink::Empty,
+            0 => Ok(KitchenSink::Empty),
             1 => unsafe {
                 let ans = support::box_from_leak_ptr(self.kind);
                 let ans = support::box_from_leak_ptr(ans.Primitives);
-                KitchenSink::Primitives {
-                    int32: ans.int32.wire2api(),
-                    float64: ans.float64.wire2api(),
-                    boolean: ans.boolean.wire2api(),
-                }
+                Ok(KitchenSink::Primitives {
+                    int32: ans.int32.wire2api()?,
+                    float64: ans.float64.wire2api()?,
+                    boolean: ans.boolean.wire2api()?,
+                })
             },
             2 => unsafe {
                 let ans = support::box_from_leak_ptr(self.kind);
                 let ans = support::box_from_leak_ptr(ans.Nested);
-                KitchenSink::Nested(ans.field0.wire2api())
+                Ok(KitchenSink::Nested(ans.field0.wire2api()?))
             },
             3 => unsafe {
                 let ans = support::box_from_leak_ptr(self.kind);
                 let ans = support::box_from_leak_ptr(ans.Optional);
-                KitchenSink::Optional(ans.field0.wire2api(), ans.field1.wire2api())
+                Ok(KitchenSink::Optional(
+                    ans.field0.wire2api()?,
+                    ans.field1.wire2api()?,
+                ))
             },
             4 => unsafe {
                 let ans = support::box_from_leak_ptr(self.kind);
                 let ans = support::box_from_leak_ptr(ans.Buffer);
-                KitchenSink::Buffer(ans.field0.wire2api())
+                Ok(KitchenSink::Buffer(ans.field0.wire2api()?))
             },
             5 => unsafe {
                 let ans = support::box_from_leak_ptr(self.kind);
                 let ans = support::box_from_leak_ptr(ans.Enums);
-                KitchenSink::Enums(ans.field0.wire2api())
+                Ok(KitchenSink::Enums(ans.field0.wire2api()?))
             },
             _ => unreachable!(),
         }
     }
 }
 
-impl Wire2Api<Vec<Attribute>> for *mut wire_list_attribute {
-    fn wire2api(self) -> Vec<Attribute> {
+impl Wire2Api<Vec<std::path::PathBuf>> for *mut wire_list_PathBuf {
+    fn wire2api(self) -> support::anyhow::Result<Vec<std::path::PathBuf>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Vec<String>>> for *mut wire_list_StringList {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Vec<String>>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Attribute>> for *mut wire_list_attribute {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Attribute>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Vec<f64>>> for *mut wire_list_float_64_list {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Vec<f64>>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -1228,7 +2891,7 @@ impl Wire2Api<Vec<Attribute>> for *mut wire_list_attribute {
 }
 
 impl Wire2Api<Vec<MySize>> for *mut wire_list_my_size {
-    fn wire2api(self) -> Vec<MySize> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<MySize>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -1238,7 +2901,17 @@ impl Wire2Api<Vec<MySize>> for *mut wire_list_my_size {
 }
 
 impl Wire2Api<Vec<MyTreeNode>> for *mut wire_list_my_tree_node {
-    fn wire2api(self) -> Vec<MyTreeNode> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<MyTreeNode>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Option<String>>> for *mut wire_list_opt_String {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Option<String>>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -1248,7 +2921,37 @@ impl Wire2Api<Vec<MyTreeNode>> for *mut wire_list_my_tree_node {
 }
 
 impl Wire2Api<Vec<Option<Attribute>>> for *mut wire_list_opt_box_autoadd_attribute {
-    fn wire2api(self) -> Vec<Option<Attribute>> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Option<Attribute>>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Option<Weekdays>>> for *mut wire_list_opt_box_autoadd_weekdays {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Option<Weekdays>>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Option<f64>>> for *mut wire_list_opt_f64 {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Option<f64>>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<Vec<Option<i32>>> for *mut wire_list_opt_i32 {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Option<i32>>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -1258,80 +2961,166 @@ impl Wire2Api<Vec<Option<Attribute>>> for *mut wire_list_opt_box_autoadd_attribu
 }
 
 impl Wire2Api<MyEnum> for i32 {
-    fn wire2api(self) -> MyEnum {
+    fn wire2api(self) -> support::anyhow::Result<MyEnum> {
         match self {
-            0 => MyEnum::False,
-            1 => MyEnum::True,
+            0 => Ok(MyEnum::False),
+            1 => Ok(MyEnum::True),
             _ => unreachable!("Invalid variant for MyEnum: {}", self),
         }
     }
 }
 
 impl Wire2Api<MySize> for wire_MySize {
-    fn wire2api(self) -> MySize {
-        MySize {
-            width: self.width.wire2api(),
-            height: self.height.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<MySize> {
+        Ok(MySize {
+            width: self.width.wire2api()?,
+            height: self.height.wire2api()?,
+        })
+    }
+}
+
+impl Wire2Api<[MySize; 2]> for *mut wire_list_my_size {
+    fn wire2api(self) -> support::anyhow::Result<[MySize; 2]> {
+        let vec: Vec<MySize> = self.wire2api()?;
+        std::convert::TryInto::try_into(vec).map_err(|vec: Vec<MySize>| {
+            support::anyhow::anyhow!("Expected a list of 2 items, but got {}", vec.len())
+        })
     }
 }
 
 impl Wire2Api<MyStruct> for wire_MyStruct {
-    fn wire2api(self) -> MyStruct {
-        MyStruct {
-            content: self.content.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<MyStruct> {
+        Ok(MyStruct {
+            content: self.content.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<MyTreeNode> for wire_MyTreeNode {
-    fn wire2api(self) -> MyTreeNode {
-        MyTreeNode {
-            value_i32: self.value_i32.wire2api(),
-            value_vec_u8: self.value_vec_u8.wire2api(),
-            value_boolean: self.value_boolean.wire2api(),
-            children: self.children.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<MyTreeNode> {
+        Ok(MyTreeNode {
+            value_i32: self.value_i32.wire2api()?,
+            value_vec_u8: self.value_vec_u8.wire2api()?,
+            value_boolean: self.value_boolean.wire2api()?,
+            children: self.children.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<NewTypeInt> for wire_NewTypeInt {
-    fn wire2api(self) -> NewTypeInt {
-        NewTypeInt(self.field0.wire2api())
+    fn wire2api(self) -> support::anyhow::Result<NewTypeInt> {
+        Ok(NewTypeInt(self.field0.wire2api()?))
+    }
+}
+
+impl Wire2Api<std::sync::Arc<Counter>> for *mut wire_Counter {
+    fn wire2api(self) -> support::anyhow::Result<std::sync::Arc<Counter>> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Ok(unsafe { support::opaque_from_dart::<Counter>(wrap.ptr) })
+    }
+}
+
+impl Wire2Api<Option<bool>> for wire_opt_bool {
+    fn wire2api(self) -> support::anyhow::Result<Option<bool>> {
+        self.is_some.then(|| self.value.wire2api()).transpose()
+    }
+}
+
+impl Wire2Api<Option<f64>> for wire_opt_f64 {
+    fn wire2api(self) -> support::anyhow::Result<Option<f64>> {
+        self.is_some.then(|| self.value.wire2api()).transpose()
+    }
+}
+
+impl Wire2Api<Option<i32>> for wire_opt_i32 {
+    fn wire2api(self) -> support::anyhow::Result<Option<i32>> {
+        self.is_some.then(|| self.value.wire2api()).transpose()
+    }
+}
+
+impl Wire2Api<Option<i64>> for wire_opt_i64 {
+    fn wire2api(self) -> support::anyhow::Result<Option<i64>> {
+        self.is_some.then(|| self.value.wire2api()).transpose()
+    }
+}
+
+impl Wire2Api<Priority> for i32 {
+    fn wire2api(self) -> support::anyhow::Result<Priority> {
+        match self {
+            0 => Ok(Priority::Low),
+            1 => Ok(Priority::High),
+            _ => unreachable!("Invalid variant for Priority: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<SparseLists> for wire_SparseLists {
+    fn wire2api(self) -> support::anyhow::Result<SparseLists> {
+        Ok(SparseLists {
+            values: self.values.wire2api()?,
+            labels: self.labels.wire2api()?,
+            weekdays: self.weekdays.wire2api()?,
+            attributes: self.attributes.wire2api()?,
+        })
+    }
+}
+
+impl Wire2Api<(f64, f64)> for wire_TupleF64F64 {
+    fn wire2api(self) -> support::anyhow::Result<(f64, f64)> {
+        Ok((self.field0.wire2api()?, self.field1.wire2api()?))
+    }
+}
+
+impl Wire2Api<(i32, String)> for wire_TupleI32String {
+    fn wire2api(self) -> support::anyhow::Result<(i32, String)> {
+        Ok((self.field0.wire2api()?, self.field1.wire2api()?))
+    }
+}
+
+impl Wire2Api<u128> for *mut wire_uint_128 {
+    fn wire2api(self) -> support::anyhow::Result<u128> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Ok(((wrap.hi as u128) << 64) | wrap.lo as u128)
     }
 }
 
 impl Wire2Api<u32> for u32 {
-    fn wire2api(self) -> u32 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<u32> {
+        Ok(self)
+    }
+}
+
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> support::anyhow::Result<u64> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<u8> for u8 {
-    fn wire2api(self) -> u8 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<u8> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
-    fn wire2api(self) -> Vec<u8> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<u8>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
 impl Wire2Api<Weekdays> for i32 {
-    fn wire2api(self) -> Weekdays {
+    fn wire2api(self) -> support::anyhow::Result<Weekdays> {
         match self {
-            0 => Weekdays::Monday,
-            1 => Weekdays::Tuesday,
-            2 => Weekdays::Wednesday,
-            3 => Weekdays::Thursday,
-            4 => Weekdays::Friday,
-            5 => Weekdays::Saturday,
-            6 => Weekdays::Sunday,
+            0 => Ok(Weekdays::Monday),
+            1 => Ok(Weekdays::Tuesday),
+            2 => Ok(Weekdays::Wednesday),
+            3 => Ok(Weekdays::Thursday),
+            4 => Ok(Weekdays::Friday),
+            5 => Ok(Weekdays::Saturday),
+            6 => Ok(Weekdays::Sunday),
             _ => unreachable!("Invalid variant for Weekdays: {}", self),
         }
     }
@@ -1358,6 +3147,23 @@ impl NewWithNullPtr for wire_Attribute {
     }
 }
 
+impl NewWithNullPtr for wire_btree_map_i32_String {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_Calculator {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            base: Default::default(),
+        }
+    }
+}
+
 impl NewWithNullPtr for wire_Customized {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1370,10 +3176,10 @@ impl NewWithNullPtr for wire_Customized {
 impl NewWithNullPtr for wire_ExoticOptionals {
     fn new_with_null_ptr() -> Self {
         Self {
-            int32: core::ptr::null_mut(),
-            int64: core::ptr::null_mut(),
-            float64: core::ptr::null_mut(),
-            boolean: core::ptr::null_mut(),
+            int32: NewWithNullPtr::new_with_null_ptr(),
+            int64: NewWithNullPtr::new_with_null_ptr(),
+            float64: NewWithNullPtr::new_with_null_ptr(),
+            boolean: NewWithNullPtr::new_with_null_ptr(),
             zerocopy: core::ptr::null_mut(),
             int8list: core::ptr::null_mut(),
             uint8list: core::ptr::null_mut(),
@@ -1389,6 +3195,15 @@ impl NewWithNullPtr for wire_ExoticOptionals {
     }
 }
 
+impl NewWithNullPtr for wire_hash_map_String_i32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
 impl NewWithNullPtr for wire_KitchenSink {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1398,51 +3213,81 @@ impl NewWithNullPtr for wire_KitchenSink {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn inflate_KitchenSink_Primitives() -> *mut KitchenSinkKind {
-    support::new_leak_box_ptr(KitchenSinkKind {
-        Primitives: support::new_leak_box_ptr(KitchenSink_Primitives {
+impl NewWithNullPtr for KitchenSink_Primitives {
+    fn new_with_null_ptr() -> Self {
+        Self {
             int32: Default::default(),
             float64: Default::default(),
             boolean: Default::default(),
-        }),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_KitchenSink_Primitives() -> *mut KitchenSinkKind {
+    support::new_leak_box_ptr(KitchenSinkKind {
+        Primitives: support::new_leak_box_ptr(KitchenSink_Primitives::new_with_null_ptr()),
     })
 }
 
+impl NewWithNullPtr for KitchenSink_Nested {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: core::ptr::null_mut(),
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn inflate_KitchenSink_Nested() -> *mut KitchenSinkKind {
     support::new_leak_box_ptr(KitchenSinkKind {
-        Nested: support::new_leak_box_ptr(KitchenSink_Nested {
-            field0: core::ptr::null_mut(),
-        }),
+        Nested: support::new_leak_box_ptr(KitchenSink_Nested::new_with_null_ptr()),
     })
 }
 
+impl NewWithNullPtr for KitchenSink_Optional {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: NewWithNullPtr::new_with_null_ptr(),
+            field1: NewWithNullPtr::new_with_null_ptr(),
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn inflate_KitchenSink_Optional() -> *mut KitchenSinkKind {
     support::new_leak_box_ptr(KitchenSinkKind {
-        Optional: support::new_leak_box_ptr(KitchenSink_Optional {
-            field0: core::ptr::null_mut(),
-            field1: core::ptr::null_mut(),
-        }),
+        Optional: support::new_leak_box_ptr(KitchenSink_Optional::new_with_null_ptr()),
     })
 }
 
+impl NewWithNullPtr for KitchenSink_Buffer {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: core::ptr::null_mut(),
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn inflate_KitchenSink_Buffer() -> *mut KitchenSinkKind {
     support::new_leak_box_ptr(KitchenSinkKind {
-        Buffer: support::new_leak_box_ptr(KitchenSink_Buffer {
-            field0: core::ptr::null_mut(),
-        }),
+        Buffer: support::new_leak_box_ptr(KitchenSink_Buffer::new_with_null_ptr()),
     })
 }
 
+impl NewWithNullPtr for KitchenSink_Enums {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn inflate_KitchenSink_Enums() -> *mut KitchenSinkKind {
     support::new_leak_box_ptr(KitchenSinkKind {
-        Enums: support::new_leak_box_ptr(KitchenSink_Enums {
-            field0: Default::default(),
-        }),
+        Enums: support::new_leak_box_ptr(KitchenSink_Enums::new_with_null_ptr()),
     })
 }
 
@@ -1482,20 +3327,119 @@ impl NewWithNullPtr for wire_NewTypeInt {
     }
 }
 
+impl NewWithNullPtr for wire_Counter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_opt_bool {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            is_some: false,
+            value: Default::default(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_opt_f64 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            is_some: false,
+            value: Default::default(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_opt_i32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            is_some: false,
+            value: Default::default(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_opt_i64 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            is_some: false,
+            value: Default::default(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_SparseLists {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            values: core::ptr::null_mut(),
+            labels: core::ptr::null_mut(),
+            weekdays: core::ptr::null_mut(),
+            attributes: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_TupleF64F64 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+            field1: Default::default(),
+        }
+    }
+}
+
+impl NewWithNullPtr for wire_TupleI32String {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+            field1: core::ptr::null_mut(),
+        }
+    }
+}
+
 // Section: impl IntoDart
 
 impl support::IntoDart for Attribute {
     fn into_dart(self) -> support::DartCObject {
-        vec![self.key.into_dart(), self.value.into_dart()].into_dart()
+        vec![
+            ZeroCopyBuffer(self.key.into_bytes()).into_dart(),
+            ZeroCopyBuffer(self.value.into_bytes()).into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Attribute {}
 
+impl support::IntoDart for Calculator {
+    fn into_dart(self) -> support::DartCObject {
+        vec![self.base.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Calculator {}
+
+impl support::IntoDart for CustomError {
+    fn into_dart(self) -> support::DartCObject {
+        vec![
+            ZeroCopyBuffer(self.message.into_bytes()).into_dart(),
+            self.code.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CustomError {}
+
 impl support::IntoDart for Element {
     fn into_dart(self) -> support::DartCObject {
         vec![
-            self.tag.into_dart(),
-            self.text.into_dart(),
+            self.tag
+                .map(|e| ZeroCopyBuffer(e.into_bytes()).into_dart())
+                .into_dart(),
+            self.text
+                .map(|e| ZeroCopyBuffer(e.into_bytes()).into_dart())
+                .into_dart(),
             self.attributes.into_dart(),
             self.children.into_dart(),
         ]
@@ -1542,7 +3486,7 @@ impl support::IntoDart for KitchenSink {
                 float64.into_dart(),
                 boolean.into_dart(),
             ],
-            Self::Nested(field0) => vec![2.into_dart(), field0.into_dart()],
+            Self::Nested(field0) => vec![2.into_dart(), (*field0).into_dart()],
             Self::Optional(field0, field1) => {
                 vec![3.into_dart(), field0.into_dart(), field1.into_dart()]
             }
@@ -1552,6 +3496,18 @@ impl support::IntoDart for KitchenSink {
         .into_dart()
     }
 }
+impl support::IntoDartExceptPrimitive for KitchenSink {}
+
+impl support::IntoDart for LoginError {
+    fn into_dart(self) -> support::DartCObject {
+        match self {
+            Self::WrongPassword => 0,
+            Self::Locked => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LoginError {}
 
 impl support::IntoDart for MySize {
     fn into_dart(self) -> support::DartCObject {
@@ -1580,6 +3536,44 @@ impl support::IntoDart for NewTypeInt {
 }
 impl support::IntoDartExceptPrimitive for NewTypeInt {}
 
+impl support::IntoDart for Counter {
+    fn into_dart(self) -> support::DartCObject {
+        support::opaque_into_dart(self)
+    }
+}
+impl support::IntoDartExceptPrimitive for Counter {}
+
+impl support::IntoDart for Priority {
+    fn into_dart(self) -> support::DartCObject {
+        match self {
+            Self::Low => 0,
+            Self::High => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Priority {}
+
+impl support::IntoDart for SparseLists {
+    fn into_dart(self) -> support::DartCObject {
+        vec![
+            self.values.into_dart(),
+            self.labels
+                .into_iter()
+                .map(|e| {
+                    e.map(|e| ZeroCopyBuffer(e.into_bytes()).into_dart())
+                        .into_dart()
+                })
+                .collect::<Vec<_>>()
+                .into_dart(),
+            self.weekdays.into_dart(),
+            self.attributes.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SparseLists {}
+
 impl support::IntoDart for VecOfPrimitivePack {
     fn into_dart(self) -> support::DartCObject {
         vec![
@@ -1613,6 +3607,7 @@ impl support::IntoDart for Weekdays {
         .into_dart()
     }
 }
+impl support::IntoDartExceptPrimitive for Weekdays {}
 
 impl support::IntoDart for ZeroCopyVecOfPrimitivePack {
     fn into_dart(self) -> support::DartCObject {
@@ -1639,11 +3634,47 @@ support::lazy_static! {
     pub static ref FLUTTER_RUST_BRIDGE_HANDLER: support::DefaultHandler = Default::default();
 }
 
+// Section: async runtime
+
+// Section: locks
+
+support::lazy_static! {
+    static ref FLUTTER_RUST_BRIDGE_LOCK_LOCKED_COUNT: std::sync::RwLock<()> = Default::default();
+}
+
+// Section: cancellation
+
+#[no_mangle]
+pub extern "C" fn cancel_task(id: i64) {
+    cancel::cancel_task(id)
+}
+
+// Section: callbacks
+
+#[no_mangle]
+pub extern "C" fn reply_callback_i32(call_id: i64, value: i32) {
+    callback::reply(call_id, value)
+}
+
+#[no_mangle]
+pub extern "C" fn fail_callback(call_id: i64) {
+    callback::fail(call_id)
+}
+
+// Section: wire hash
+
+pub const WIRE_HASH: u32 = 0xadf8cb7a;
+
+#[no_mangle]
+pub extern "C" fn get_wire_hash() -> u32 {
+    WIRE_HASH
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
 pub extern "C" fn free_WireSyncReturnStruct(val: support::WireSyncReturnStruct) {
     unsafe {
-        let _ = support::vec_from_leak_ptr(val.ptr, val.len);
+        let _ = flutter_rust_bridge::support::vec_from_leak_ptr(val.ptr, val.len);
     }
 }
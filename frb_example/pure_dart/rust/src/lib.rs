@@ -1,3 +1,4 @@
 mod api;
 mod bridge_generated;
 mod data;
+mod executor;
@@ -80,6 +80,8 @@ struct wire_list_tree_node *new_list_tree_node(int32_t len);
 
 struct wire_uint_8_list *new_uint_8_list(int32_t len);
 
+uint32_t get_wire_hash(void);
+
 void free_WireSyncReturnStruct(struct WireSyncReturnStruct val);
 
 void store_dart_post_cobject(DartPostCObjectFnType ptr);
@@ -103,6 +105,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) new_list_size);
     dummy_var ^= ((int64_t) (void*) new_list_tree_node);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list);
+    dummy_var ^= ((int64_t) (void*) get_wire_hash);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturnStruct);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
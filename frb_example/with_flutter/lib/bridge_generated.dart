@@ -1,7 +1,7 @@
 // AUTO GENERATED FILE, DO NOT EDIT.
 // Generated by `flutter_rust_bridge`.
 
-// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import
+// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import, deprecated_member_use_from_same_package
 
 import 'dart:convert';
 import 'dart:typed_data';
@@ -38,6 +38,16 @@ abstract class FlutterRustBridgeExample {
   Future<int> offTopicDeliberatelyReturnError({dynamic hint});
 
   Future<int> offTopicDeliberatelyPanic({dynamic hint});
+
+  /// Throws a [StateError] if the Rust library was generated from another version of the API
+  /// than this code, such as a stale build, instead of the functions failing in obscure ways.
+  /// Meant to be called once at startup.
+  void checkWireHash();
+
+  /// Tears down the bridge, such as between tests or before a hot restart: the streams still
+  /// open are closed, no function can be called anymore, and another instance of the API can
+  /// then be created. The calls already made still complete. Calling it again does nothing.
+  void dispose();
 }
 
 class Point {
@@ -213,6 +223,16 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
         hint: hint,
       ));
 
+  static const wireHash = 0xf7a1b233;
+
+  void checkWireHash() {
+    final rustWireHash = inner.get_wire_hash();
+    if (rustWireHash != wireHash) {
+      throw StateError(
+          'The Rust library was generated from another version of the API than this Dart code: its wire hash is 0x${rustWireHash.toRadixString(16)}, while 0x${wireHash.toRadixString(16)} was expected. Generate the code again, then rebuild the Rust library.');
+    }
+  }
+
   // Section: api2wire
   ffi.Pointer<wire_uint_8_list> _api2wire_String(String raw) {
     return _api2wire_uint_8_list(utf8.encoder.convert(raw));
@@ -298,53 +318,54 @@ class FlutterRustBridgeExampleImpl extends FlutterRustBridgeBase<FlutterRustBrid
     wireObj.name = _api2wire_String(apiObj.name);
     wireObj.children = _api2wire_list_tree_node(apiObj.children);
   }
-}
 
-// Section: wire2api
-String _wire2api_String(dynamic raw) {
-  return raw as String;
-}
+  // Section: wire2api
+  String _wire2api_String(dynamic raw) {
+    if (raw is String) return raw;
+    return utf8.decode(raw as Uint8List);
+  }
 
-Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
-  return raw as Uint8List;
-}
+  Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
+    return raw as Uint8List;
+  }
 
-int _wire2api_i32(dynamic raw) {
-  return raw as int;
-}
+  int _wire2api_i32(dynamic raw) {
+    return raw as int;
+  }
 
-List<Size> _wire2api_list_size(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_size).toList();
-}
+  List<Size> _wire2api_list_size(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_size).toList();
+  }
 
-List<TreeNode> _wire2api_list_tree_node(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_tree_node).toList();
-}
+  List<TreeNode> _wire2api_list_tree_node(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tree_node).toList();
+  }
 
-Size _wire2api_size(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-  return Size(
-    width: _wire2api_i32(arr[0]),
-    height: _wire2api_i32(arr[1]),
-  );
-}
+  Size _wire2api_size(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Size(
+      width: _wire2api_i32(arr[0]),
+      height: _wire2api_i32(arr[1]),
+    );
+  }
 
-TreeNode _wire2api_tree_node(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-  return TreeNode(
-    name: _wire2api_String(arr[0]),
-    children: _wire2api_list_tree_node(arr[1]),
-  );
-}
+  TreeNode _wire2api_tree_node(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TreeNode(
+      name: _wire2api_String(arr[0]),
+      children: _wire2api_list_tree_node(arr[1]),
+    );
+  }
 
-int _wire2api_u8(dynamic raw) {
-  return raw as int;
-}
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
 
-Uint8List _wire2api_uint_8_list(dynamic raw) {
-  return raw as Uint8List;
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
 }
 
 // ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
@@ -602,6 +623,13 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_8_list> Function(ffi.Int32)>>('new_uint_8_list');
   late final _new_uint_8_list = _new_uint_8_listPtr.asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  int get_wire_hash() {
+    return _get_wire_hash();
+  }
+
+  late final _get_wire_hashPtr = _lookup<ffi.NativeFunction<ffi.Uint32 Function()>>('get_wire_hash');
+  late final _get_wire_hash = _get_wire_hashPtr.asFunction<int Function()>();
+
   void free_WireSyncReturnStruct(
     WireSyncReturnStruct val,
   ) {
@@ -4,7 +4,8 @@
     clippy::redundant_closure,
     clippy::useless_conversion,
     clippy::unit_arg,
-    non_snake_case
+    non_snake_case,
+    deprecated
 )]
 // AUTO GENERATED FILE, DO NOT EDIT.
 // Generated by `flutter_rust_bridge`.
@@ -31,13 +32,13 @@ pub extern "C" fn wire_draw_mandelbrot(
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_image_size = image_size.wire2api();
-            let api_zoom_point = zoom_point.wire2api();
-            let api_scale = scale.wire2api();
-            let api_num_threads = num_threads.wire2api();
-            move |task_callback| {
+            let api_image_size = image_size.wire2api()?;
+            let api_zoom_point = zoom_point.wire2api()?;
+            let api_scale = scale.wire2api()?;
+            let api_num_threads = num_threads.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
                 draw_mandelbrot(api_image_size, api_zoom_point, api_scale, api_num_threads)
-            }
+            })
         },
     )
 }
@@ -51,8 +52,13 @@ pub extern "C" fn wire_passing_complex_structs(port_: i64, root: *mut wire_TreeN
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_root = root.wire2api();
-            move |task_callback| Ok(passing_complex_structs(api_root))
+            let api_root = root.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok({
+                    use support::IntoDart;
+                    ZeroCopyBuffer(passing_complex_structs(api_root).into_bytes()).into_dart()
+                })
+            })
         },
     )
 }
@@ -66,8 +72,10 @@ pub extern "C" fn wire_off_topic_memory_test_input_array(port_: i64, input: *mut
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_input_array(api_input))
+            let api_input = input.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_input_array(api_input))
+            })
         },
     )
 }
@@ -81,8 +89,10 @@ pub extern "C" fn wire_off_topic_memory_test_output_zero_copy_buffer(port_: i64,
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_len = len.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_output_zero_copy_buffer(api_len))
+            let api_len = len.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_output_zero_copy_buffer(api_len))
+            })
         },
     )
 }
@@ -96,8 +106,10 @@ pub extern "C" fn wire_off_topic_memory_test_output_vec_u8(port_: i64, len: i32)
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_len = len.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_output_vec_u8(api_len))
+            let api_len = len.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_output_vec_u8(api_len))
+            })
         },
     )
 }
@@ -114,8 +126,10 @@ pub extern "C" fn wire_off_topic_memory_test_input_vec_of_object(
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_input_vec_of_object(api_input))
+            let api_input = input.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_input_vec_of_object(api_input))
+            })
         },
     )
 }
@@ -129,8 +143,10 @@ pub extern "C" fn wire_off_topic_memory_test_output_vec_of_object(port_: i64, le
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_len = len.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_output_vec_of_object(api_len))
+            let api_len = len.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_output_vec_of_object(api_len))
+            })
         },
     )
 }
@@ -147,8 +163,10 @@ pub extern "C" fn wire_off_topic_memory_test_input_complex_struct(
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_input_complex_struct(api_input))
+            let api_input = input.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_input_complex_struct(api_input))
+            })
         },
     )
 }
@@ -162,8 +180,10 @@ pub extern "C" fn wire_off_topic_memory_test_output_complex_struct(port_: i64, l
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_len = len.wire2api();
-            move |task_callback| Ok(off_topic_memory_test_output_complex_struct(api_len))
+            let api_len = len.wire2api()?;
+            Ok(move |task_callback: rust2dart::TaskCallback| {
+                Ok(off_topic_memory_test_output_complex_struct(api_len))
+            })
         },
     )
 }
@@ -176,7 +196,9 @@ pub extern "C" fn wire_off_topic_deliberately_return_error(port_: i64) {
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| off_topic_deliberately_return_error(),
+        move || {
+            Ok(move |task_callback: rust2dart::TaskCallback| off_topic_deliberately_return_error())
+        },
     )
 }
 
@@ -188,7 +210,9 @@ pub extern "C" fn wire_off_topic_deliberately_panic(port_: i64) {
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(off_topic_deliberately_panic()),
+        move || {
+            Ok(move |task_callback: rust2dart::TaskCallback| Ok(off_topic_deliberately_panic()))
+        },
     )
 }
 
@@ -283,64 +307,64 @@ pub extern "C" fn new_uint_8_list(len: i32) -> *mut wire_uint_8_list {
 // Section: impl Wire2Api
 
 pub trait Wire2Api<T> {
-    fn wire2api(self) -> T;
+    fn wire2api(self) -> support::anyhow::Result<T>;
 }
 
 impl<T, S> Wire2Api<Option<T>> for *mut S
 where
     *mut S: Wire2Api<T>,
 {
-    fn wire2api(self) -> Option<T> {
+    fn wire2api(self) -> support::anyhow::Result<Option<T>> {
         if self.is_null() {
-            None
+            Ok(None)
         } else {
-            Some(self.wire2api())
+            self.wire2api().map(Some)
         }
     }
 }
 
 impl Wire2Api<String> for *mut wire_uint_8_list {
-    fn wire2api(self) -> String {
-        let vec: Vec<u8> = self.wire2api();
-        String::from_utf8_lossy(&vec).into_owned()
+    fn wire2api(self) -> support::anyhow::Result<String> {
+        let vec: Vec<u8> = self.wire2api()?;
+        Ok(String::from_utf8(vec)?)
     }
 }
 
 impl Wire2Api<Point> for *mut wire_Point {
-    fn wire2api(self) -> Point {
+    fn wire2api(self) -> support::anyhow::Result<Point> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<Size> for *mut wire_Size {
-    fn wire2api(self) -> Size {
+    fn wire2api(self) -> support::anyhow::Result<Size> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<TreeNode> for *mut wire_TreeNode {
-    fn wire2api(self) -> TreeNode {
+    fn wire2api(self) -> support::anyhow::Result<TreeNode> {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        (*wrap).wire2api().into()
+        (*wrap).wire2api().map(Into::into)
     }
 }
 
 impl Wire2Api<f64> for f64 {
-    fn wire2api(self) -> f64 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<f64> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<i32> for i32 {
-    fn wire2api(self) -> i32 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<i32> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<Vec<Size>> for *mut wire_list_size {
-    fn wire2api(self) -> Vec<Size> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<Size>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -350,7 +374,7 @@ impl Wire2Api<Vec<Size>> for *mut wire_list_size {
 }
 
 impl Wire2Api<Vec<TreeNode>> for *mut wire_list_tree_node {
-    fn wire2api(self) -> Vec<TreeNode> {
+    fn wire2api(self) -> support::anyhow::Result<Vec<TreeNode>> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -360,44 +384,44 @@ impl Wire2Api<Vec<TreeNode>> for *mut wire_list_tree_node {
 }
 
 impl Wire2Api<Point> for wire_Point {
-    fn wire2api(self) -> Point {
-        Point {
-            x: self.x.wire2api(),
-            y: self.y.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<Point> {
+        Ok(Point {
+            x: self.x.wire2api()?,
+            y: self.y.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<Size> for wire_Size {
-    fn wire2api(self) -> Size {
-        Size {
-            width: self.width.wire2api(),
-            height: self.height.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<Size> {
+        Ok(Size {
+            width: self.width.wire2api()?,
+            height: self.height.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<TreeNode> for wire_TreeNode {
-    fn wire2api(self) -> TreeNode {
-        TreeNode {
-            name: self.name.wire2api(),
-            children: self.children.wire2api(),
-        }
+    fn wire2api(self) -> support::anyhow::Result<TreeNode> {
+        Ok(TreeNode {
+            name: self.name.wire2api()?,
+            children: self.children.wire2api()?,
+        })
     }
 }
 
 impl Wire2Api<u8> for u8 {
-    fn wire2api(self) -> u8 {
-        self
+    fn wire2api(self) -> support::anyhow::Result<u8> {
+        Ok(self)
     }
 }
 
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
-    fn wire2api(self) -> Vec<u8> {
-        unsafe {
+    fn wire2api(self) -> support::anyhow::Result<Vec<u8>> {
+        Ok(unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        })
     }
 }
 
@@ -451,7 +475,11 @@ impl support::IntoDartExceptPrimitive for Size {}
 
 impl support::IntoDart for TreeNode {
     fn into_dart(self) -> support::DartCObject {
-        vec![self.name.into_dart(), self.children.into_dart()].into_dart()
+        vec![
+            ZeroCopyBuffer(self.name.into_bytes()).into_dart(),
+            self.children.into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for TreeNode {}
@@ -462,11 +490,20 @@ support::lazy_static! {
     pub static ref FLUTTER_RUST_BRIDGE_HANDLER: support::DefaultHandler = Default::default();
 }
 
+// Section: wire hash
+
+pub const WIRE_HASH: u32 = 0xf7a1b233;
+
+#[no_mangle]
+pub extern "C" fn get_wire_hash() -> u32 {
+    WIRE_HASH
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
 pub extern "C" fn free_WireSyncReturnStruct(val: support::WireSyncReturnStruct) {
     unsafe {
-        let _ = support::vec_from_leak_ptr(val.ptr, val.len);
+        let _ = flutter_rust_bridge::support::vec_from_leak_ptr(val.ptr, val.len);
     }
 }
@@ -0,0 +1,168 @@
+//! Calls to the Dart functions given to functions taking a [DartCallback], which Dart replies to.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Arc;
+
+use allo_isolate::ffi::DartCObject;
+use allo_isolate::{IntoDart, Isolate};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+
+/// A Dart function given to a Rust function, whose signature is the function pointer type `F`,
+/// e.g. `DartCallback<fn(i32, i32) -> i32>`. Calling it sends the arguments to Dart and blocks
+/// until Dart replies with what its function returns. The arguments can be numbers, booleans and
+/// strings, and the function can return a number, a boolean or nothing.
+///
+/// It can only be called while the Rust function it was given to runs: later calls fail with
+/// [CallbackError::Closed], since Dart stops listening once the function returned.
+pub struct DartCallback<F> {
+    state: Arc<CallbackState>,
+    signature: PhantomData<F>,
+}
+
+struct CallbackState {
+    /// The port of the Dart `RustCallback` receiving the calls
+    port: i64,
+    closed: AtomicBool,
+}
+
+impl<F> Clone for DartCallback<F> {
+    fn clone(&self) -> Self {
+        DartCallback {
+            state: self.state.clone(),
+            signature: PhantomData,
+        }
+    }
+}
+
+impl<F> DartCallback<F> {
+    /// The callback which Dart listens to on `port`, called by the generated code
+    pub fn new(port: i64) -> Self {
+        DartCallback {
+            state: Arc::new(CallbackState {
+                port,
+                closed: AtomicBool::new(false),
+            }),
+            signature: PhantomData,
+        }
+    }
+
+    /// Closes the callback once the guard is dropped, i.e. when the function it was given to
+    /// returns, even by panicking. The calls waiting for Dart then fail.
+    pub fn close_on_drop(&self) -> CloseOnDrop {
+        CloseOnDrop(self.state.clone())
+    }
+
+    fn call_with<R: 'static>(&self, args: Vec<DartCObject>) -> Result<R, CallbackError> {
+        let (sender, receiver) = channel();
+        let call_id = NEXT_CALL_ID.fetch_add(1, Ordering::SeqCst);
+        PENDING_CALLS
+            .lock()
+            .insert(call_id, (self.state.port, sender));
+        // Checked once the call is registered, for a close in between not to leave it waiting
+        if self.state.closed.load(Ordering::SeqCst) {
+            PENDING_CALLS.lock().remove(&call_id);
+            return Err(CallbackError::Closed);
+        }
+        let mut message = vec![call_id.into_dart()];
+        message.extend(args);
+        if !Isolate::new(self.state.port).post(message) {
+            PENDING_CALLS.lock().remove(&call_id);
+            return Err(CallbackError::Closed);
+        }
+        match receiver.recv() {
+            Ok(Some(value)) => Ok(*value
+                .downcast::<R>()
+                .expect("the reply has the return type of the callback")),
+            Ok(None) => Err(CallbackError::Threw),
+            // The callback was closed while waiting
+            Err(_) => Err(CallbackError::Closed),
+        }
+    }
+}
+
+macro_rules! impl_call {
+    ($($arg:ident: $ty:ident),*) => {
+        impl<$($ty: IntoDart,)* R: 'static> DartCallback<fn($($ty),*) -> R> {
+            /// Calls the Dart function, blocking until it returns
+            pub fn call(&self, $($arg: $ty),*) -> Result<R, CallbackError> {
+                self.call_with(vec![$($arg.into_dart()),*])
+            }
+        }
+    };
+}
+
+impl_call!();
+impl_call!(a: A);
+impl_call!(a: A, b: B);
+impl_call!(a: A, b: B, c: C);
+impl_call!(a: A, b: B, c: C, d: D);
+impl_call!(a: A, b: B, c: C, d: D, e: E);
+impl_call!(a: A, b: B, c: C, d: D, e: E, f: F);
+
+/// Closes a [DartCallback] when dropped, see [DartCallback::close_on_drop].
+pub struct CloseOnDrop(Arc<CallbackState>);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        self.0.closed.store(true, Ordering::SeqCst);
+        let port = self.0.port;
+        // Dropping the senders wakes the calls up
+        PENDING_CALLS
+            .lock()
+            .retain(|_, (call_port, _)| *call_port != port);
+    }
+}
+
+/// Why calling a [DartCallback] failed.
+#[derive(Debug)]
+pub enum CallbackError {
+    /// The Dart function threw an exception, which is reported in Dart as an uncaught error
+    Threw,
+    /// The function the callback was given to already returned
+    Closed,
+}
+
+impl std::fmt::Display for CallbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallbackError::Threw => f.write_str("the Dart callback threw an exception"),
+            CallbackError::Closed => f.write_str(
+                "the Dart callback was called after the function it was given to returned",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CallbackError {}
+
+/// What Dart replies to a call: the value its function returned, or `None` if it threw
+type Reply = Option<Box<dyn Any + Send>>;
+
+lazy_static! {
+    static ref NEXT_CALL_ID: AtomicI64 = AtomicI64::new(1);
+    /// The calls waiting for Dart, with the port of their callback, by id
+    static ref PENDING_CALLS: Mutex<HashMap<i64, (i64, Sender<Reply>)>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Gives the call `call_id` the value the Dart function returned.
+pub fn reply<R: Send + 'static>(call_id: i64, value: R) {
+    send_reply(call_id, Some(Box::new(value)));
+}
+
+/// Tells the call `call_id` that the Dart function threw.
+pub fn fail(call_id: i64) {
+    send_reply(call_id, None);
+}
+
+fn send_reply(call_id: i64, reply: Reply) {
+    // A call which is no longer waiting was closed in between
+    if let Some((_, sender)) = PENDING_CALLS.lock().remove(&call_id) {
+        let _ = sender.send(reply);
+    }
+}
@@ -0,0 +1,135 @@
+//! Cancellation of the tasks of functions taking a [CancellationToken], requested from Dart.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, Weak};
+use std::task::{Context, Poll, Waker};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+
+/// Tells a function whether Dart cancelled its task, for it to stop early. An `async` function
+/// is also stopped at its next `.await` once cancelled. Either way, Dart is then told that the task
+/// was cancelled rather than given what the function returns.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<TokenState>);
+
+struct TokenState {
+    cancelled: AtomicBool,
+    /// The futures of `async` functions waiting for the token. Locked with the `Mutex` of std,
+    /// which is `RefUnwindSafe`, since a token is captured by the task given to the handler.
+    wakers: std::sync::Mutex<Vec<Waker>>,
+}
+
+impl Default for TokenState {
+    fn default() -> Self {
+        TokenState {
+            cancelled: AtomicBool::new(false),
+            wakers: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl CancellationToken {
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// `Err(Cancelled)` once cancelled, to stop with the `?` operator
+    pub fn check(&self) -> anyhow::Result<()> {
+        if self.is_cancelled() {
+            Err(Cancelled.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        let mut wakers = self.0.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+        for waker in wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// The error of a cancelled task, which Dart receives with the code `CANCELLED_ERROR`.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the task was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+lazy_static! {
+    /// The tokens of the running tasks by the id of the Dart token, which several tasks can share
+    static ref TOKENS: Mutex<HashMap<i64, Weak<TokenState>>> = Mutex::new(HashMap::new());
+}
+
+/// The token of a task given the Dart token `id`, or one that is never cancelled for `0`, which
+/// Dart gives when there is no token.
+pub fn cancellation_token(id: i64) -> CancellationToken {
+    if id == 0 {
+        return CancellationToken::default();
+    }
+    let mut tokens = TOKENS.lock();
+    // The tokens of the tasks that ended are forgotten
+    tokens.retain(|_, state| state.strong_count() > 0);
+    if let Some(state) = tokens.get(&id).and_then(Weak::upgrade) {
+        return CancellationToken(state);
+    }
+    let token = CancellationToken::default();
+    tokens.insert(id, Arc::downgrade(&token.0));
+    token
+}
+
+/// Cancels the running tasks given the Dart token `id`, if any.
+pub fn cancel_task(id: i64) {
+    let state = TOKENS.lock().get(&id).and_then(Weak::upgrade);
+    if let Some(state) = state {
+        CancellationToken(state).cancel();
+    }
+}
+
+/// Polls `future` until it completes, or until `token` is cancelled, in which case the future is
+/// dropped.
+pub fn until_cancelled<F: Future>(token: CancellationToken, future: F) -> UntilCancelled<F> {
+    UntilCancelled {
+        token,
+        future: Box::pin(future),
+    }
+}
+
+pub struct UntilCancelled<F: Future> {
+    token: CancellationToken,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for UntilCancelled<F> {
+    type Output = anyhow::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        {
+            let mut wakers = self
+                .token
+                .0
+                .wakers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+        // Checked once the waker is registered, for a cancellation in between not to be missed
+        if self.token.is_cancelled() {
+            return Poll::Ready(Err(Cancelled.into()));
+        }
+        self.future.as_mut().poll(cx).map(Ok)
+    }
+}
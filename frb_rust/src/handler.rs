@@ -2,13 +2,16 @@ use std::any::Any;
 use std::mem::ManuallyDrop;
 use std::panic;
 use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::sync::PoisonError;
 
+use allo_isolate::ffi::DartCObject;
 use allo_isolate::IntoDart;
 use anyhow::Result;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
 use threadpool::ThreadPool;
 
+use crate::cancel::Cancelled;
 use crate::rust2dart::{Rust2Dart, TaskCallback};
 use crate::support::{into_leak_vec_ptr, WireSyncReturnStruct};
 use crate::SyncReturn;
@@ -31,9 +34,11 @@ pub struct WrapInfo {
 pub trait Handler {
     // Why separate [PrepareFn] and [TaskFn]: because some things cannot be [Send] (e.g. raw
     // pointers), so those can be done in [PrepareFn], while the real work is done in [TaskFn] with [Send].
+    // The errors of [PrepareFn], e.g. the arguments which Rust cannot accept, are reported like
+    // those of [TaskFn].
     fn wrap<PrepareFn, TaskFn, TaskRet>(&self, wrap_info: WrapInfo, prepare: PrepareFn)
     where
-        PrepareFn: FnOnce() -> TaskFn + UnwindSafe,
+        PrepareFn: FnOnce() -> Result<TaskFn> + UnwindSafe,
         TaskFn: FnOnce(TaskCallback) -> Result<TaskRet> + Send + UnwindSafe + 'static,
         TaskRet: IntoDart;
 
@@ -76,7 +81,7 @@ impl Default for DefaultHandler {
 impl<E: Executor, EH: ErrorHandler> Handler for SimpleHandler<E, EH> {
     fn wrap<PrepareFn, TaskFn, TaskRet>(&self, wrap_info: WrapInfo, prepare: PrepareFn)
     where
-        PrepareFn: FnOnce() -> TaskFn + UnwindSafe,
+        PrepareFn: FnOnce() -> Result<TaskFn> + UnwindSafe,
         TaskFn: FnOnce(TaskCallback) -> Result<TaskRet> + Send + UnwindSafe + 'static,
         TaskRet: IntoDart,
     {
@@ -90,9 +95,13 @@ impl<E: Executor, EH: ErrorHandler> Handler for SimpleHandler<E, EH> {
         // ref https://doc.rust-lang.org/nomicon/unwinding.html
         let _ = panic::catch_unwind(move || {
             let wrap_info2 = wrap_info.clone();
-            if let Err(error) = panic::catch_unwind(move || {
-                let task = prepare();
-                self.executor.execute(wrap_info2, task);
+            if let Err(error) = panic::catch_unwind(move || match prepare() {
+                Ok(task) => self.executor.execute(wrap_info2, task),
+                Err(error) => {
+                    self.error_handler
+                        .handle_error(wrap_info2.port.unwrap(), error.into());
+                    close_stream_if_needed(&wrap_info2);
+                }
             }) {
                 self.error_handler
                     .handle_error(wrap_info.port.unwrap(), Error::Panic(error));
@@ -208,13 +217,15 @@ impl<EH: ErrorHandler> Executor for ThreadPoolExecutor<EH> {
                         }
                     }
                     Err(error) => {
-                        eh2.handle_error(wrap_info2.port.unwrap(), Error::ResultError(error));
+                        eh2.handle_error(wrap_info2.port.unwrap(), error.into());
+                        close_stream_if_needed(&wrap_info2);
                     }
                 };
             });
 
             if let Err(error) = thread_result {
                 eh.handle_error(wrap_info.port.unwrap(), Error::Panic(error));
+                close_stream_if_needed(&wrap_info);
             }
         });
     }
@@ -231,23 +242,37 @@ impl<EH: ErrorHandler> Executor for ThreadPoolExecutor<EH> {
     }
 }
 
-#[derive(Debug)]
+/// Errors do not end Dart streams, so a stream whose function failed has to be closed explicitly
+fn close_stream_if_needed(wrap_info: &WrapInfo) {
+    if let FfiCallMode::Stream = wrap_info.mode {
+        Rust2Dart::new(wrap_info.port.unwrap()).close_stream();
+    }
+}
+
 pub enum Error {
     ResultError(anyhow::Error),
+    /// The `Err` of a function returning `Result<T, E>` with a custom `E`, whose data is sent to
+    /// Dart so that it can be thrown as a typed exception.
+    CustomError(Box<dyn BoxIntoDart>),
     Panic(Box<dyn Any + Send>),
+    /// The task of a function taking a `CancellationToken` was cancelled by Dart.
+    Cancelled,
 }
 
 impl Error {
     pub fn code(&self) -> &'static str {
         match self {
             Error::ResultError(_) => "RESULT_ERROR",
+            Error::CustomError(_) => "CUSTOM_ERROR",
             Error::Panic(_) => "PANIC_ERROR",
+            Error::Cancelled => "CANCELLED_ERROR",
         }
     }
 
     pub fn message(&self) -> String {
         match self {
             Error::ResultError(e) => format!("{:?}", e),
+            Error::CustomError(_) => "custom error".to_string(),
             Error::Panic(panic_err) => match panic_err.downcast_ref::<&'static str>() {
                 Some(s) => *s,
                 None => match panic_err.downcast_ref::<String>() {
@@ -256,10 +281,71 @@ impl Error {
                 },
             }
             .to_string(),
+            Error::Cancelled => Cancelled.to_string(),
         }
     }
 }
 
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ResultError(e) => f.debug_tuple("ResultError").field(e).finish(),
+            Error::CustomError(_) => f.write_str("CustomError(..)"),
+            Error::Panic(e) => f.debug_tuple("Panic").field(e).finish(),
+            Error::Cancelled => f.write_str("Cancelled"),
+        }
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<CustomErrorCarrier>() {
+            Ok(carrier) => Error::CustomError(
+                carrier
+                    .0
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner),
+            ),
+            Err(error) if error.is::<Cancelled>() => Error::Cancelled,
+            Err(error) => Error::ResultError(error),
+        }
+    }
+}
+
+/// Wraps the error of a function returning `Result<T, E>` with a custom `E`, so that it flows
+/// through the handler like any other error and is reported as [Error::CustomError].
+pub fn custom_error(error: impl IntoDart + Send + 'static) -> anyhow::Error {
+    anyhow::Error::new(CustomErrorCarrier(std::sync::Mutex::new(Box::new(error))))
+}
+
+/// `anyhow::Error` requires `Sync`, which the custom error does not have to be.
+struct CustomErrorCarrier(std::sync::Mutex<Box<dyn BoxIntoDart>>);
+
+impl std::fmt::Debug for CustomErrorCarrier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomError(..)")
+    }
+}
+
+impl std::fmt::Display for CustomErrorCarrier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("custom error")
+    }
+}
+
+impl std::error::Error for CustomErrorCarrier {}
+
+/// Object-safe version of [IntoDart], so that errors of any type can be carried by [Error].
+pub trait BoxIntoDart: Send {
+    fn box_into_dart(self: Box<Self>) -> DartCObject;
+}
+
+impl<T: IntoDart + Send> BoxIntoDart for T {
+    fn box_into_dart(self: Box<Self>) -> DartCObject {
+        (*self).into_dart()
+    }
+}
+
 pub trait ErrorHandler: UnwindSafe + RefUnwindSafe + Copy + Send + 'static {
     fn handle_error(&self, port: i64, error: Error);
 
@@ -271,7 +357,14 @@ pub struct ReportDartErrorHandler;
 
 impl ErrorHandler for ReportDartErrorHandler {
     fn handle_error(&self, port: i64, error: Error) {
-        Rust2Dart::new(port).error(error.code().to_string(), error.message());
+        let code = error.code().to_string();
+        let message = error.message();
+        match error {
+            Error::CustomError(details) => {
+                Rust2Dart::new(port).error_full(code, message, details.box_into_dart())
+            }
+            _ => Rust2Dart::new(port).error(code, message),
+        };
     }
 
     fn handle_error_sync(&self, error: Error) -> Vec<u8> {
@@ -1,9 +1,13 @@
 pub use allo_isolate::ZeroCopyBuffer;
 
+pub use callback::DartCallback;
+pub use cancel::CancellationToken;
 pub use flutter_rust_bridge_macros::frb;
 pub use handler::{FfiCallMode, Handler, WrapInfo};
 pub use rust2dart::StreamSink;
 
+pub mod callback;
+pub mod cancel;
 pub mod handler;
 pub mod rust2dart;
 pub mod support;
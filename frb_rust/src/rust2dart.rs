@@ -4,6 +4,8 @@ pub use allo_isolate::ffi::DartCObject;
 pub use allo_isolate::IntoDart;
 use allo_isolate::Isolate;
 
+use crate::handler::Error;
+
 #[derive(Copy, Clone)]
 pub struct Rust2Dart {
     isolate: Isolate,
@@ -84,6 +86,13 @@ impl<T: IntoDart> StreamSink<T> {
         self.rust2dart.success(value)
     }
 
+    /// Deliver an error to the listeners of the Dart stream. Unlike a failing function, this
+    /// does not close the stream.
+    pub fn add_error(&self, error: anyhow::Error) -> bool {
+        let error = Error::ResultError(error);
+        self.rust2dart.error(error.code().to_string(), error.message())
+    }
+
     pub fn close(&self) -> bool {
         self.rust2dart.close_stream()
     }
@@ -1,10 +1,13 @@
 //! Functions that support auto-generated Rust code.
 //! These functions are *not* meant to be used by humans directly.
 
+use std::ffi::c_void;
 use std::mem;
+use std::sync::Arc;
 
 pub use allo_isolate::ffi::DartCObject;
 pub use allo_isolate::{IntoDart, IntoDartExceptPrimitive};
+pub use anyhow;
 pub use lazy_static::lazy_static;
 
 pub use crate::handler::DefaultHandler;
@@ -41,6 +44,61 @@ pub unsafe fn box_from_leak_ptr<T>(ptr: *mut T) -> Box<T> {
     Box::from_raw(ptr)
 }
 
+/// Allocates the wire values that Dart fills for Rust, such as the lists and the boxes of the
+/// arguments, in place of the functions of this module using the global allocator. The generated
+/// code uses it when `--wire-allocator` names a type implementing it.
+///
+/// # Safety
+/// `vec_from_leak_ptr` and `box_from_leak_ptr` are given back the pointers made by
+/// `new_leak_vec_ptr` and `new_leak_box_ptr`, once each, and have to return values owned by the
+/// global allocator, e.g. by moving the items out of memory of their own.
+pub unsafe trait WireAllocator {
+    /// A pointer to `length` clones of `fill`
+    fn new_leak_vec_ptr<T: Clone>(fill: T, length: i32) -> *mut T;
+
+    /// # Safety
+    /// Use it in pair with [WireAllocator::new_leak_vec_ptr].
+    unsafe fn vec_from_leak_ptr<T>(ptr: *mut T, len: i32) -> Vec<T>;
+
+    fn new_leak_box_ptr<T>(t: T) -> *mut T;
+
+    /// # Safety
+    /// Use it in pair with [WireAllocator::new_leak_box_ptr].
+    unsafe fn box_from_leak_ptr<T>(ptr: *mut T) -> Box<T>;
+}
+
+/// Moves an opaque object to the heap, where it is owned by Dart through its address.
+pub fn opaque_into_dart<T>(value: T) -> DartCObject {
+    (Arc::into_raw(Arc::new(value)) as usize as i64).into_dart()
+}
+
+/// Gives Dart a reference to an object shared with Rust, as [opaque_into_dart] does for an object
+/// moved to the heap.
+pub fn opaque_shared_into_dart<T>(value: Arc<T>) -> DartCObject {
+    (Arc::into_raw(value) as usize as i64).into_dart()
+}
+
+/// Shares the object so that it stays alive during the call, even if Dart drops it meanwhile.
+///
+/// # Safety
+/// Use it with pointers made by [opaque_into_dart] for the same `T`, not yet given to [drop_opaque].
+pub unsafe fn opaque_from_dart<T>(ptr: *const c_void) -> Arc<T> {
+    let ptr = ptr as *const T;
+    Arc::increment_strong_count(ptr);
+    Arc::from_raw(ptr)
+}
+
+/// Releases the reference held by Dart, if any, as a null pointer stands for `None`.
+///
+/// # Safety
+/// Use it once per pointer made by [opaque_into_dart], for the same `T`.
+pub unsafe fn drop_opaque<T>(ptr: *const c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    drop(Arc::from_raw(ptr as *const T));
+}
+
 /// NOTE for maintainer: Please keep this struct in sync with [DUMMY_WIRE_CODE_FOR_BINDGEN]
 /// in the code generator
 #[repr(C)]